@@ -0,0 +1,82 @@
+//! Checks that the `no-panic` feature leaves no panic in the float APIs of
+//! `ang`, nor in the checked and saturating APIs of integer angles. The panic
+//! handler refers to a function which does not exist, so linking fails if any
+//! call to it survives optimization. Build with `cargo build --release` in
+//! this directory.
+
+#![no_std]
+#![no_main]
+
+use core::hint::black_box;
+use core::panic::PanicInfo;
+
+use ang::{Angle, AngleDelta, AngleUnit, Convertible, Degrees, Radians};
+use num_traits::{Euclid, Float};
+
+extern "C" {
+    fn ang_no_panic_found_a_panic() -> !;
+}
+
+#[panic_handler]
+fn panic(_: &PanicInfo) -> ! {
+    unsafe { ang_no_panic_found_a_panic() }
+}
+
+// Referenced by the precompiled `compiler_builtins`, but never called with
+// `panic = "abort"`.
+#[no_mangle]
+extern "C" fn rust_eh_personality() {}
+
+// Provides the entry point which calls `main`.
+#[link(name = "c")]
+extern "C" {}
+
+fn float<T: Float + Euclid + Convertible>(a: Angle<T>, b: Angle<T>) -> T {
+    let sum = a + AngleDelta::new(b);
+    let difference = a - AngleDelta::new(b);
+    let k = T::from(3).unwrap_or_else(T::nan);
+    sum.in_degrees()
+        + difference.in_radians()
+        + a.normalized().in_degrees()
+        + b.normalized_signed().in_radians()
+        + a.wrapping_add(b).in_degrees()
+        + a.wrapping_sub(b).in_degrees()
+        + a.normalize_around(b).in_degrees()
+        + a.min_dist(b).in_radians()
+        + a.signed_dist(b).in_radians()
+        + a.scale(k).in_degrees()
+        + (a * k).in_degrees()
+        + (a / k).in_radians()
+        + a.div_euclid(b)
+        + a.rem_euclid(b).in_degrees()
+        + a.sin()
+        + a.cos()
+        + AngleUnit::Degrees.full_turn_in::<T>()
+        + Angle::<T>::half().in_radians()
+        + if a < b { T::one() } else { T::zero() }
+}
+
+fn integer(a: Angle<i32>, b: Angle<i32>) -> i32 {
+    let mut v = a.saturating_in_degrees();
+    v = v.wrapping_add(a.in_radians());
+    v = v.wrapping_add(a.checked_in_degrees().unwrap_or(0));
+    v = v.wrapping_add(a.checked_normalized().map_or(0, |v| v.value()));
+    v = v.wrapping_add(a.checked_normalized_signed().map_or(0, |v| v.value()));
+    v = v.wrapping_add(a.saturating_add(b).value());
+    v = v.wrapping_add(a.saturating_sub(b).value());
+    v = v.wrapping_add(a.saturating_mul(3).value());
+    v = v.wrapping_add(a.checked_scale(1.5f64).map_or(0, |v| v.value()));
+    v = v.wrapping_add(AngleUnit::Radians.checked_full_turn_in().unwrap_or(0));
+    v.wrapping_add((a < b) as i32)
+}
+
+#[no_mangle]
+pub extern "C" fn main(_argc: i32, _argv: *const *const u8) -> i32 {
+    let total = float(black_box(Degrees(30.0f32)), black_box(Radians(1.0)))
+        + float(black_box(Radians(-7.5f32)), black_box(Degrees(400.0))) as f32;
+    let total = total as f64
+        + float(black_box(Degrees(30.0f64)), black_box(Radians(1.0)))
+        + integer(black_box(Degrees(30)), black_box(Radians(-4))) as f64;
+    black_box(total);
+    0
+}
@@ -0,0 +1,134 @@
+//! Generation of arbitrary angles for property tests with `quickcheck`.
+
+use alloc::boxed::Box;
+use core::f64::consts::{FRAC_PI_2, PI};
+use num_traits::cast::{cast, NumCast};
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{Angle, AngleUnit, Radians};
+
+/// Values in radians worth testing more often than a uniform choice would:
+/// the quarter, half and full turns, both sides of the wrap seam and huge
+/// magnitudes.
+const SPECIAL_RADIANS: [f64; 11] = [
+    0.0,
+    FRAC_PI_2,
+    PI,
+    -PI,
+    2.0 * PI,
+    -2.0 * PI,
+    2.0 * PI - 1.0e-9,
+    1.0e-9,
+    -1.0e-9,
+    1.0e12,
+    -1.0e12,
+];
+
+/// The counterparts of `SPECIAL_RADIANS` in degrees.
+const SPECIAL_DEGREES: [f64; 11] = [
+    0.0,
+    90.0,
+    180.0,
+    -180.0,
+    360.0,
+    -360.0,
+    360.0 - 1.0e-9,
+    1.0e-9,
+    -1.0e-9,
+    1.0e14,
+    -1.0e14,
+];
+
+/// Generate angles in either unit, one in four of them a special value like
+/// ±π, a value next to the wrap seam or a huge magnitude, if `T` can
+/// represent it.
+///
+/// Degrees shrink to the same angle in radians first, and values shrink
+/// toward zero as `T` does.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use quickcheck::quickcheck;
+///
+/// fn prop(angle: Angle<f64>) -> bool {
+///     !angle.is_finite() || angle.normalized() == angle.normalized().normalized()
+/// }
+/// quickcheck(prop as fn(Angle<f64>) -> bool);
+/// ```
+impl<T: Arbitrary + Copy + NumCast> Arbitrary for Angle<T> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let unit = if bool::arbitrary(g) {
+            AngleUnit::Radians
+        } else {
+            AngleUnit::Degrees
+        };
+
+        let special = match unit {
+            AngleUnit::Radians => &SPECIAL_RADIANS,
+            AngleUnit::Degrees => &SPECIAL_DEGREES,
+        };
+        let value = if u8::arbitrary(g) % 4 == 0 {
+            cast(special[usize::arbitrary(g) % special.len()])
+        } else {
+            None
+        };
+
+        Angle::from_parts(value.unwrap_or_else(|| T::arbitrary(g)), unit)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let (value, unit) = self.into_parts();
+        let simpler = match unit {
+            AngleUnit::Radians => None,
+            AngleUnit::Degrees => self.checked_in_radians().map(Radians),
+        };
+        Box::new(
+            simpler
+                .into_iter()
+                .chain(value.shrink().map(move |v| Angle::from_parts(v, unit))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, StdThreadGen};
+
+    use crate::*;
+
+    #[test]
+    fn test_arbitrary_special_values() {
+        let mut g = StdThreadGen::new(100);
+        let angles: Vec<Angle> = (0..10_000).map(|_| Angle::arbitrary(&mut g)).collect();
+        assert!(angles.iter().any(|&a| matches!(a, Radians(_))));
+        assert!(angles.iter().any(|&a| matches!(a, Degrees(_))));
+        assert!(angles.contains(&Radians(core::f64::consts::PI)));
+        assert!(angles.contains(&Degrees(-360.0)));
+        assert!(angles.iter().any(|&a| a.value().abs() >= 1.0e12));
+        assert!(angles.iter().any(|&a| a.value().fract() != 0.0));
+
+        // Special values which do not fit into the type are skipped.
+        let angles: Vec<Angle<i8>> = (0..1000).map(|_| Angle::arbitrary(&mut g)).collect();
+        assert!(angles.contains(&Degrees(90)));
+    }
+
+    #[test]
+    fn test_arbitrary_shrink() {
+        let shrunk: Vec<_> = Degrees(180.0).shrink().collect();
+        assert_eq!(shrunk[0], Radians(core::f64::consts::PI));
+        assert!(shrunk[1..].iter().all(|a| matches!(a, Degrees(_))));
+        assert!(shrunk.contains(&Degrees(0.0)));
+
+        assert!(Radians(0.0).shrink().next().is_none());
+        assert!(Radians(3.0).shrink().all(|a| matches!(a, Radians(_))));
+
+        // Shrinking terminates at zero radians.
+        let mut angle = Degrees(-12_345i32);
+        while let Some(next) = angle.shrink().next() {
+            angle = next;
+        }
+        assert_eq!(angle, Radians(0));
+    }
+}
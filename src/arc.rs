@@ -0,0 +1,663 @@
+//! Arcs of the circle.
+
+use num_traits::{cast::cast, Float};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Angle, AngleUnit, Steps, SweepArc};
+
+/// An arc of the circle, sweeping counter-clockwise, i.e. through increasing
+/// angles, from its start to its end.
+///
+/// The arc is kept in the unit of the angle it starts at, with the start
+/// normalized into the range of [0, 360)° or [0, 2π) rad. An arc whose start
+/// and end coincide has a length of zero; an arc covering the whole circle is
+/// created with [`full`](#method.full) or [`centered`](#method.centered).
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let sector = ArcRange::new(Degrees(350.0f64), Degrees(20.0));
+///
+/// assert_eq!(sector.length(), Degrees(30.0));
+/// assert_eq!(sector.midpoint(), Degrees(5.0));
+/// assert!(sector.contains(Degrees(0.0)));
+/// assert!(!sector.contains(Degrees(180.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ArcRange<T = f64> {
+    start: T,
+    length: T,
+    unit: AngleUnit,
+}
+
+impl<T: Float> ArcRange<T> {
+    /// Create the arc sweeping counter-clockwise from `start` to `end`.
+    #[inline]
+    pub fn new(start: Angle<T>, end: Angle<T>) -> ArcRange<T> {
+        let (start, unit) = start.normalized().into_parts();
+        let length = end.value_in(unit) - start;
+        ArcRange {
+            start,
+            length: Angle::from_parts(length, unit).normalized().value(),
+            unit,
+        }
+    }
+
+    /// Create the arc extending `half_width` to either side of `center`.
+    ///
+    /// A `half_width` of half a turn or more gives the whole circle, starting
+    /// opposite to `center`.
+    #[inline]
+    pub fn centered(center: Angle<T>, half_width: Angle<T>) -> ArcRange<T> {
+        let (center, unit) = center.into_parts();
+        let full: T = unit.full_turn_in();
+        let half_width = half_width.value_in(unit).abs().min(full / cast(2).unwrap());
+        ArcRange {
+            start: Angle::from_parts(center - half_width, unit)
+                .normalized()
+                .value(),
+            length: half_width + half_width,
+            unit,
+        }
+    }
+
+    /// Create the arc covering the whole circle, starting and ending at
+    /// `start`.
+    #[inline]
+    pub fn full(start: Angle<T>) -> ArcRange<T> {
+        let (start, unit) = start.normalized().into_parts();
+        ArcRange {
+            start,
+            length: unit.full_turn_in(),
+            unit,
+        }
+    }
+
+    /// Yield the angle the arc starts at.
+    #[inline]
+    pub fn start(&self) -> Angle<T> {
+        Angle::from_parts(self.start, self.unit)
+    }
+
+    /// Yield the angle the arc ends at, normalized.
+    #[inline]
+    pub fn end(&self) -> Angle<T> {
+        Angle::from_parts(self.start + self.length, self.unit).normalized()
+    }
+
+    /// Yield the length of the arc, in the range of [0, 360]° or [0, 2π] rad.
+    #[inline]
+    pub fn length(&self) -> Angle<T> {
+        Angle::from_parts(self.length, self.unit)
+    }
+
+    /// Yield the angle halfway along the arc, normalized.
+    #[inline]
+    pub fn midpoint(&self) -> Angle<T> {
+        let half = self.length / cast(2).unwrap();
+        Angle::from_parts(self.start + half, self.unit).normalized()
+    }
+
+    /// Return `true` if the arc covers the whole circle.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.length >= self.unit.full_turn_in()
+    }
+
+    /// Return `true` if `angle` lies on the arc, including its endpoints.
+    #[inline]
+    pub fn contains(&self, angle: Angle<T>) -> bool {
+        let offset = angle.value_in(self.unit) - self.start;
+        self.is_full() || Angle::from_parts(offset, self.unit).normalized().value() <= self.length
+    }
+
+    /// Clamp `angle` to the arc. An angle on the arc is returned unchanged,
+    /// and any other angle snaps to the end of the arc with the smaller
+    /// [`min_dist`](enum.Angle.html#method.min_dist), or to the start if both
+    /// are equally far, converted to the unit of `angle`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let sector = ArcRange::new(Degrees(350.0f64), Degrees(20.0));
+    /// assert_eq!(sector.clamp(Degrees(10.0)), Degrees(10.0));
+    /// assert_eq!(sector.clamp(Degrees(30.0)), Degrees(20.0));
+    /// assert_eq!(sector.clamp(Degrees(-40.0)), Degrees(350.0));
+    /// ```
+    #[inline]
+    pub fn clamp(&self, angle: Angle<T>) -> Angle<T> {
+        let bound = match self.nearer_end(angle) {
+            None => return angle,
+            Some(true) => self.start(),
+            Some(false) => self.end(),
+        };
+        let unit = angle.unit();
+        Angle::from_parts(bound.value_in(unit), unit)
+    }
+
+    /// Yield the rest of the circle, the arc sweeping counter-clockwise from
+    /// the end of this arc back to its start. The two arcs share their
+    /// endpoints, and their lengths add up to a full turn, so the complement
+    /// of a full arc has a length of zero and the other way around.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let rest = ArcRange::new(Degrees(350.0f64), Degrees(20.0)).complement();
+    /// assert_eq!(rest, ArcRange::new(Degrees(20.0), Degrees(350.0)));
+    /// assert_eq!(rest.length(), Degrees(330.0));
+    /// ```
+    #[inline]
+    pub fn complement(&self) -> ArcRange<T> {
+        ArcRange {
+            start: self.end().value(),
+            length: self.unit.full_turn_in::<T>() - self.length,
+            unit: self.unit,
+        }
+    }
+
+    /// Create an iterator over the normalized angles along the arc, `step`
+    /// apart. A positive step sweeps counter-clockwise from the start of the
+    /// arc, and a negative step clockwise from its end. The last angle is the
+    /// last one still on the arc, which is the other end if a whole number of
+    /// steps reaches it, so a full arc yields its start at both ends.
+    ///
+    /// Returns `None` if `step` is zero or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let sector = ArcRange::new(Degrees(350.0f64), Degrees(20.0));
+    ///
+    /// let sweep: Vec<_> = sector.iter_step(Degrees(10.0)).unwrap().collect();
+    /// assert_eq!(sweep, [Degrees(350.0), Degrees(0.0), Degrees(10.0), Degrees(20.0)]);
+    ///
+    /// let sweep: Vec<_> = sector.iter_step(Degrees(-12.0)).unwrap().collect();
+    /// assert_eq!(sweep, [Degrees(20.0), Degrees(8.0), Degrees(356.0)]);
+    /// ```
+    #[inline]
+    pub fn iter_step(&self, step: Angle<T>) -> Option<Steps<T>> {
+        let start = self.start();
+        let end = Angle::from_parts(self.start + self.length, self.unit);
+        let steps = if step.value_in(self.unit) < T::zero() {
+            Angle::steps_to(end, start, step)
+        } else {
+            Angle::steps_to(start, end, step)
+        };
+        steps.map(Steps::normalized)
+    }
+
+    /// Find which end of the arc is nearer to an angle off the arc, `true`
+    /// for the start, or return `None` if the angle is on the arc.
+    fn nearer_end(&self, angle: Angle<T>) -> Option<bool> {
+        if self.contains(angle) {
+            return None;
+        }
+        // Outside the arc, the distances to the ends are the rest of the way
+        // around to the start and the overshoot past the end.
+        let offset = Angle::from_parts(angle.value_in(self.unit) - self.start, self.unit)
+            .normalized()
+            .value();
+        let to_start = self.unit.full_turn_in::<T>() - offset;
+        let to_end = offset - self.length;
+        Some(to_start <= to_end)
+    }
+}
+
+impl<T: Float> Angle<T> {
+    /// Clamp the angle to the arc sweeping counter-clockwise from `lower` to
+    /// `upper`. An angle on the arc is returned unchanged, and any other
+    /// angle snaps to the bound with the smaller [`min_dist`](#method.min_dist),
+    /// or to `lower` if both are equally far. Bounds are returned as given,
+    /// converted to the unit of this angle.
+    ///
+    /// The arc may straddle 0°, and has a length of zero if the bounds
+    /// coincide.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let (lower, upper) = (Degrees(350.0f64), Degrees(20.0));
+    /// assert_eq!(Degrees(10.0).clamp_to(lower, upper), Degrees(10.0));
+    /// assert_eq!(Degrees(30.0).clamp_to(lower, upper), Degrees(20.0));
+    /// assert_eq!(Degrees(200.0).clamp_to(lower, upper), Degrees(350.0));
+    /// ```
+    pub fn clamp_to(self, lower: Angle<T>, upper: Angle<T>) -> Self {
+        let bound = match ArcRange::new(lower, upper).nearer_end(self) {
+            None => return self,
+            Some(true) => lower,
+            Some(false) => upper,
+        };
+        let unit = self.unit();
+        Angle::from_parts(bound.value_in(unit), unit)
+    }
+}
+
+/// Find the smallest arc sweeping counter-clockwise from its start to its
+/// end which contains all of the angles, by skipping the largest gap between
+/// neighbouring angles around the circle. Return the start and end of the arc
+/// as normalized angles in the unit of the first angle, converting the
+/// others where needed.
+///
+/// A single angle, or any number of identical angles, gives an arc of zero
+/// length. The arc may straddle 0° and may be longer than a half circle, but
+/// never covers the full circle. Where several gaps are equally large, the
+/// arc with the smallest start wins, so that for example evenly spread
+/// angles give the arc starting at the smallest of them.
+///
+/// Returns `None` if there are no angles or any angle is NaN or infinite.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let bearings = [Degrees(10.0f64), Degrees(350.0), Degrees(30.0)];
+/// assert_eq!(bounding_arc(bearings.iter().copied()), Some((Degrees(350.0), Degrees(30.0))));
+///
+/// let bearings = [Degrees(0.0f64), Degrees(100.0), Degrees(200.0)];
+/// assert_eq!(bounding_arc(bearings.iter().copied()), Some((Degrees(0.0), Degrees(200.0))));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn bounding_arc<T, I>(angles: I) -> Option<(Angle<T>, Angle<T>)>
+where
+    T: Float,
+    I: IntoIterator<Item = Angle<T>>,
+{
+    let mut angles = angles.into_iter().peekable();
+    let unit = angles.peek()?.unit();
+    let full_turn = unit.full_turn_in::<T>();
+
+    let mut values = Vec::new();
+    for angle in angles {
+        if !angle.is_finite() {
+            return None;
+        }
+        values.push(
+            Angle::from_parts(angle.value_in(unit), unit)
+                .normalized()
+                .value(),
+        );
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    // The gap across 0° comes first, as the arc after it has the smallest
+    // start.
+    let (first, last) = (values[0], values[values.len() - 1]);
+    let (mut start, mut end, mut gap) = (first, last, first + full_turn - last);
+    for pair in values.windows(2) {
+        if pair[1] - pair[0] > gap {
+            start = pair[1];
+            end = pair[0];
+            gap = pair[1] - pair[0];
+        }
+    }
+
+    Some((Angle::from_parts(start, unit), Angle::from_parts(end, unit)))
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Float + serde::Deserialize<'de>> serde::Deserialize<'de> for ArcRange<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected};
+
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "ArcRange")]
+        struct Fields<T> {
+            start: T,
+            length: T,
+            unit: AngleUnit,
+        }
+
+        let Fields {
+            start,
+            length,
+            unit,
+        } = Fields::<T>::deserialize(deserializer)?;
+        let full: T = unit.full_turn_in();
+        if !(start >= T::zero() && start < full) {
+            return Err(D::Error::invalid_value(
+                Unexpected::Float(cast(start).unwrap_or(f64::NAN)),
+                &"a normalized start in [0, 360)° or [0, 2π) rad",
+            ));
+        }
+        if !(length >= T::zero() && length <= full) {
+            return Err(D::Error::invalid_value(
+                Unexpected::Float(cast(length).unwrap_or(f64::NAN)),
+                &"a counter-clockwise length in [0, 360]° or [0, 2π] rad",
+            ));
+        }
+        Ok(ArcRange {
+            start,
+            length,
+            unit,
+        })
+    }
+}
+
+impl<T: Float> From<ArcRange<T>> for SweepArc<T> {
+    #[inline]
+    fn from(arc: ArcRange<T>) -> Self {
+        SweepArc::new(arc.start(), arc.length())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use quickcheck::quickcheck;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::*;
+
+    #[test]
+    fn test_bounding_arc() {
+        let arc = |angles: &[Angle]| bounding_arc(angles.iter().copied());
+
+        assert_eq!(arc(&[]), None);
+        assert_eq!(
+            arc(&[Degrees(-30.0)]),
+            Some((Degrees(330.0), Degrees(330.0)))
+        );
+        assert_eq!(
+            arc(&[Degrees(20.0), Degrees(380.0), Degrees(20.0)]),
+            Some((Degrees(20.0), Degrees(20.0)))
+        );
+        assert_eq!(arc(&[Degrees(10.0), Degrees(f64::NAN)]), None);
+        assert_eq!(arc(&[Radians(f64::INFINITY)]), None);
+
+        // Straddling 0°, with the result in the unit of the first angle.
+        assert_eq!(
+            arc(&[Degrees(5.0), Degrees(-20.0), Degrees(355.0), Degrees(40.0)]),
+            Some((Degrees(340.0), Degrees(40.0)))
+        );
+        let (start, end) = arc(&[Radians(0.1), Degrees(350.0)]).unwrap();
+        assert!(matches!(start, Radians(_)));
+        assert_that!(start.in_degrees(), close_to(350.0, 1.0e-10));
+        assert_eq!(end, Radians(0.1));
+
+        // Antipodal angles are bounded by the half circle from the smaller.
+        assert_eq!(
+            arc(&[Degrees(270.0), Degrees(90.0)]),
+            Some((Degrees(90.0), Degrees(270.0)))
+        );
+        assert_eq!(
+            arc(&[Degrees(0.0), Degrees(180.0)]),
+            Some((Degrees(0.0), Degrees(180.0)))
+        );
+
+        // More than a half circle, and angles covering the circle evenly.
+        assert_eq!(
+            arc(&[Degrees(0.0), Degrees(120.0), Degrees(250.0)]),
+            Some((Degrees(250.0), Degrees(120.0)))
+        );
+        let spread: Vec<_> = (0..8).rev().map(|i| Degrees(i as f64 * 45.0)).collect();
+        assert_eq!(arc(&spread), Some((Degrees(0.0), Degrees(315.0))));
+    }
+
+    #[test]
+    fn test_bounding_arc_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0x626172);
+        for n in 1..20 {
+            for _ in 0..10 {
+                let center = rng.gen_range(0.0..360.0);
+                let spread = rng.gen_range(0.0..360.0);
+                let angles: Vec<Angle> = (0..n)
+                    .map(|_| {
+                        Degrees(
+                            center
+                                + rng.gen_range(0.0..spread)
+                                + 360.0 * rng.gen_range(-2..3) as f64,
+                        )
+                    })
+                    .collect();
+
+                let (start, end) = bounding_arc(angles.iter().copied()).unwrap();
+                let found = ArcRange::new(start, end);
+                for &angle in &angles {
+                    let offset = start.delta_to(angle).angle().normalized().in_degrees();
+                    assert!(
+                        offset <= found.length().in_degrees() + 1.0e-9 || offset > 360.0 - 1.0e-9
+                    );
+                }
+
+                // The shortest arc starting at any of the angles.
+                let shortest = angles
+                    .iter()
+                    .map(|&first| {
+                        angles
+                            .iter()
+                            .map(|&angle| first.delta_to(angle).angle().normalized().in_degrees())
+                            .fold(0.0, f64::max)
+                    })
+                    .fold(f64::INFINITY, f64::min);
+                assert_that!(found.length().in_degrees(), close_to(shortest, 1.0e-9));
+            }
+        }
+    }
+
+    #[test]
+    fn test_arc_range() {
+        let arc = ArcRange::new(Degrees(-10.0), Degrees(380.0));
+        assert_eq!(arc.start(), Degrees(350.0));
+        assert_eq!(arc.end(), Degrees(20.0));
+        assert_eq!(arc.length(), Degrees(30.0));
+        assert_eq!(arc.midpoint(), Degrees(5.0));
+        assert!(!arc.is_full());
+
+        let arc = ArcRange::new(Radians(1.0), Degrees(0.0));
+        assert_that!(arc.length().in_radians(), close_to(2.0 * PI - 1.0, 1.0e-12));
+        assert!(matches!(arc.midpoint(), Radians(_)));
+
+        let arc = ArcRange::new(Degrees(45.0), Degrees(45.0));
+        assert_eq!(arc.length(), Degrees(0.0));
+        assert_eq!(arc.midpoint(), Degrees(45.0));
+        assert!(arc.contains(Degrees(405.0)));
+        assert!(!arc.contains(Degrees(46.0)));
+    }
+
+    #[test]
+    fn test_arc_range_centered() {
+        let arc = ArcRange::centered(Degrees(0.0), Degrees(15.0));
+        assert_eq!(arc.start(), Degrees(345.0));
+        assert_eq!(arc.end(), Degrees(15.0));
+        assert_eq!(arc.midpoint(), Degrees(0.0));
+        assert_eq!(ArcRange::centered(Degrees(0.0), Degrees(-15.0)), arc);
+
+        let arc = ArcRange::centered(Degrees(90.0), Degrees(200.0));
+        assert!(arc.is_full());
+        assert_eq!(arc.start(), Degrees(270.0));
+        assert_eq!(arc, ArcRange::full(Degrees(-90.0)));
+        assert!(arc.contains(Degrees(123.0)));
+    }
+
+    #[test]
+    fn test_arc_range_contains() {
+        let arc = ArcRange::new(Degrees(350.0), Degrees(20.0));
+        for &degrees in &[350.0, 355.0, 0.0, 10.0, 20.0, -5.0, 730.0] {
+            assert!(arc.contains(Degrees(degrees)), "{}", degrees);
+        }
+        for &degrees in &[349.0, 21.0, 180.0, -11.0] {
+            assert!(!arc.contains(Degrees(degrees)), "{}", degrees);
+        }
+        assert!(arc.contains(Radians(0.1)));
+        assert!(!arc.contains(Radians(1.0)));
+    }
+
+    #[test]
+    fn test_arc_range_clamp() {
+        let arc = ArcRange::new(Degrees(350.0), Degrees(20.0));
+        assert_eq!(arc.clamp(Degrees(5.0)), Degrees(5.0));
+        assert_eq!(arc.clamp(Degrees(725.0)), Degrees(725.0));
+        assert_eq!(arc.clamp(Degrees(100.0)), Degrees(20.0));
+        assert_eq!(arc.clamp(Degrees(260.0)), Degrees(350.0));
+        // Halfway round from both ends snaps to the start.
+        assert_eq!(arc.clamp(Degrees(185.0)), Degrees(350.0));
+        assert!(matches!(arc.clamp(Radians(2.0)), Radians(_)));
+        assert_that!(
+            arc.clamp(Radians(2.0)).in_degrees(),
+            close_to(20.0, 1.0e-12)
+        );
+
+        let point = ArcRange::new(Degrees(45.0), Degrees(45.0));
+        assert_eq!(point.clamp(Degrees(300.0)), Degrees(45.0));
+        let full = ArcRange::full(Degrees(45.0));
+        assert_eq!(full.clamp(Degrees(300.0)), Degrees(300.0));
+    }
+
+    #[test]
+    fn test_arc_range_complement() {
+        let arc = ArcRange::new(Degrees(350.0), Degrees(20.0));
+        let rest = arc.complement();
+        assert_eq!(rest.start(), Degrees(20.0));
+        assert_eq!(rest.end(), Degrees(350.0));
+        assert!(rest.contains(Degrees(180.0)));
+        assert!(!rest.contains(Degrees(0.0)));
+        assert_eq!(rest.complement(), arc);
+
+        let full = ArcRange::full(Degrees(90.0));
+        assert_eq!(full.complement().length(), Degrees(0.0));
+        assert_eq!(full.complement().start(), Degrees(90.0));
+        assert!(full.complement().complement().is_full());
+    }
+
+    #[test]
+    fn test_arc_range_iter_step() {
+        let arc = ArcRange::new(Degrees(-10.0), Degrees(20.0));
+        let sweep: Vec<_> = arc.iter_step(Degrees(7.0)).unwrap().collect();
+        assert_eq!(
+            sweep,
+            [
+                Degrees(350.0),
+                Degrees(357.0),
+                Degrees(4.0),
+                Degrees(11.0),
+                Degrees(18.0)
+            ]
+        );
+        let sweep: Vec<_> = arc.iter_step(Degrees(-10.0)).unwrap().collect();
+        assert_eq!(
+            sweep,
+            [Degrees(20.0), Degrees(10.0), Degrees(0.0), Degrees(350.0)]
+        );
+
+        let arc = ArcRange::full(Degrees(90.0));
+        let sweep: Vec<_> = arc.iter_step(Degrees(120.0)).unwrap().collect();
+        assert_eq!(
+            sweep,
+            [Degrees(90.0), Degrees(210.0), Degrees(330.0), Degrees(90.0)]
+        );
+
+        let point = ArcRange::new(Degrees(45.0), Degrees(45.0));
+        assert_eq!(point.iter_step(Degrees(1.0)).unwrap().len(), 1);
+        assert!(point.iter_step(Degrees(0.0)).is_none());
+    }
+
+    #[test]
+    fn prop_arc_range_contains_midpoint() {
+        fn prop(start: Angle, end: Angle) -> bool {
+            let arc = ArcRange::new(start, end);
+            let centered = ArcRange::centered(start, end);
+            arc.contains(arc.midpoint())
+                && arc.complement().contains(arc.complement().midpoint())
+                && centered.contains(centered.midpoint())
+        }
+        quickcheck(prop as fn(Angle, Angle) -> bool);
+    }
+
+    #[test]
+    fn prop_arc_range_complement_length() {
+        fn prop(start: Angle, end: Angle) -> bool {
+            let arc = ArcRange::new(start, end);
+            let full = start.unit().full_turn_in::<f64>();
+            let total = arc.length().value() + arc.complement().length().value();
+            (total - full).abs() <= full * f64::EPSILON
+        }
+        quickcheck(prop as fn(Angle, Angle) -> bool);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_arc_serde() {
+        let arc = ArcRange::new(Degrees(350.0), Degrees(20.0));
+        let json = serde_json::to_string(&arc).unwrap();
+        assert_eq!(json, r#"{"start":350.0,"length":30.0,"unit":"Degrees"}"#);
+        assert_eq!(serde_json::from_str::<ArcRange>(&json).unwrap(), arc);
+        let arc = ArcRange::full(Radians(1.0f32));
+        let json = serde_json::to_string(&arc).unwrap();
+        assert_eq!(serde_json::from_str::<ArcRange<f32>>(&json).unwrap(), arc);
+
+        let cases = [
+            (
+                r#"{"start":360.0,"length":30.0,"unit":"Degrees"}"#,
+                "normalized start",
+            ),
+            (
+                r#"{"start":-1.0,"length":30.0,"unit":"Degrees"}"#,
+                "normalized start",
+            ),
+            (
+                r#"{"start":0.0,"length":-30.0,"unit":"Degrees"}"#,
+                "counter-clockwise length",
+            ),
+            (
+                r#"{"start":0.0,"length":7.0,"unit":"Radians"}"#,
+                "counter-clockwise length",
+            ),
+        ];
+        for &(json, message) in &cases {
+            let err = serde_json::from_str::<ArcRange>(json).unwrap_err();
+            assert!(err.to_string().contains(message), "{}", err);
+        }
+        assert!(serde_json::from_str::<ArcRange>(r#"{"start":0.0,"length":1.0}"#).is_err());
+
+        let arc = SweepArc::new(Degrees(-30.0), Radians(-7.5));
+        let json = serde_json::to_string(&arc).unwrap();
+        assert_eq!(
+            json,
+            r#"{"start":{"Degrees":-30.0},"sweep":{"Radians":-7.5}}"#
+        );
+        assert_eq!(serde_json::from_str::<SweepArc>(&json).unwrap(), arc);
+    }
+
+    #[test]
+    fn test_clamp_to() {
+        let (lower, upper) = (Degrees(350.0), Degrees(20.0));
+        assert_eq!(Degrees(10.0).clamp_to(lower, upper), Degrees(10.0));
+        assert_eq!(Degrees(-5.0).clamp_to(lower, upper), Degrees(-5.0));
+        assert_eq!(Degrees(350.0).clamp_to(lower, upper), Degrees(350.0));
+        assert_eq!(Degrees(21.0).clamp_to(lower, upper), Degrees(20.0));
+        assert_eq!(Degrees(340.0).clamp_to(lower, upper), Degrees(350.0));
+        // 180° is 170° from 350° and 160° from 20°.
+        assert_eq!(Degrees(180.0).clamp_to(lower, upper), Degrees(20.0));
+        assert_eq!(Degrees(190.0).clamp_to(lower, upper), Degrees(350.0));
+        // 185° is equally far from both.
+        assert_eq!(Degrees(185.0).clamp_to(lower, upper), Degrees(350.0));
+
+        // A window of 300°, from 30° through 0° to 330°.
+        let (lower, upper) = (Degrees(30.0), Degrees(-30.0));
+        assert_eq!(Degrees(200.0).clamp_to(lower, upper), Degrees(200.0));
+        assert_eq!(Degrees(350.0).clamp_to(lower, upper), Degrees(-30.0));
+        assert_eq!(Degrees(10.0).clamp_to(lower, upper), Degrees(30.0));
+
+        // A window of zero width.
+        let bound = Degrees(90.0);
+        assert_eq!(Degrees(90.0).clamp_to(bound, bound), Degrees(90.0));
+        assert_eq!(Degrees(-270.0).clamp_to(bound, bound), Degrees(-270.0));
+        assert_eq!(Degrees(0.0).clamp_to(bound, bound), Degrees(90.0));
+        assert_eq!(Degrees(270.0).clamp_to(bound, bound), Degrees(90.0));
+
+        let clamped = Radians(0.75 * PI).clamp_to(Degrees(-90.0), Degrees(90.0));
+        assert!(matches!(clamped, Radians(_)));
+        assert_that!(clamped.in_degrees(), close_to(90.0, 1.0e-9));
+    }
+}
@@ -0,0 +1,125 @@
+//! Angular size and parallax relations.
+
+use core::f64::consts::PI;
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, Radians};
+
+/// Compute the angle subtended by an object of the given `linear_size` seen
+/// face-on from the given `distance`, using the exact relation 2·atan(s/2d).
+///
+/// Returns `None` if the distance is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// // The Moon, seen from the Earth.
+/// let moon = angular_diameter(3474.8f64, 384400.0).unwrap();
+/// assert!((moon.in_degrees() - 0.518).abs() < 0.001);
+/// ```
+#[inline]
+pub fn angular_diameter<T: Float>(linear_size: T, distance: T) -> Option<Angle<T>> {
+    if distance > T::zero() {
+        let two = T::one() + T::one();
+        Some(Radians(two * (linear_size / (two * distance)).atan()))
+    } else {
+        None
+    }
+}
+
+/// Compute the linear size of an object that subtends the given `angle` when
+/// seen face-on from the given `distance`. This is the inverse of
+/// [`angular_diameter`](fn.angular_diameter.html).
+#[inline]
+pub fn linear_size_from_angle<T: Float>(angle: Angle<T>, distance: T) -> T {
+    let two = T::one() + T::one();
+    two * distance * (angle.in_radians() / two).tan()
+}
+
+/// Compute the distance at which an object of the given `linear_size` subtends
+/// the given `angle`. This is the inverse of
+/// [`angular_diameter`](fn.angular_diameter.html).
+///
+/// A zero angle yields an infinite distance.
+#[inline]
+pub fn distance_from_angle<T: Float>(angle: Angle<T>, linear_size: T) -> T {
+    let two = T::one() + T::one();
+    linear_size / (two * (angle.in_radians() / two).tan())
+}
+
+/// Compute the distance to an object from its `parallax` angle, in units
+/// where a parallax of one arcsecond gives a distance of 1. When the parallax
+/// is measured against a baseline of 1 AU the result is in parsecs.
+///
+/// The sign of the parallax is ignored, and a zero parallax yields an
+/// infinite distance.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let d = parallax_distance(Degrees(0.1f64 / 3600.0));
+/// assert!((d - 10.0).abs() < 1.0e-6);
+/// ```
+#[inline]
+pub fn parallax_distance<T: Float>(parallax: Angle<T>) -> T {
+    let arcsec: T = cast(PI / 648_000.0).unwrap();
+    arcsec.tan() / parallax.in_radians().abs().tan()
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_angular_diameter() {
+        assert_that!(
+            angular_diameter(3474.8, 384_400.0).unwrap().in_degrees(),
+            close_to(0.518, 0.001)
+        );
+        assert_that!(
+            angular_diameter(2.0, 1.0).unwrap().in_degrees(),
+            close_to(90.0, 0.000001)
+        );
+        assert!(angular_diameter(1.0, 0.0).is_none());
+        assert!(angular_diameter(1.0, -1.0).is_none());
+        assert!(angular_diameter(1.0, f64::NAN).is_none());
+    }
+
+    #[test]
+    fn test_angular_size_round_trip() {
+        for &(size, distance) in &[(3474.8, 384_400.0), (1.0, 1.0), (0.01, 1.0e6)] {
+            let angle = angular_diameter(size, distance).unwrap();
+            assert_that!(
+                linear_size_from_angle(angle, distance),
+                close_to(size, size * 1.0e-12)
+            );
+            assert_that!(
+                distance_from_angle(angle, size),
+                close_to(distance, distance * 1.0e-12)
+            );
+        }
+        assert_eq!(distance_from_angle(Radians(0.0), 1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_parallax_distance() {
+        assert_that!(
+            parallax_distance(Degrees(1.0 / 3600.0)),
+            close_to(1.0, 1.0e-9)
+        );
+        assert_that!(
+            parallax_distance(Degrees(0.1 / 3600.0)),
+            close_to(10.0, 1.0e-6)
+        );
+        assert_that!(
+            parallax_distance(Degrees(-0.1 / 3600.0)),
+            close_to(10.0, 1.0e-6)
+        );
+        assert_eq!(parallax_distance(Radians(0.0)), f64::INFINITY);
+        assert_eq!(parallax_distance(Radians(-0.0f32)), f32::INFINITY);
+    }
+}
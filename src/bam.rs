@@ -0,0 +1,202 @@
+//! Binary angular measurement.
+
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use num_traits::cast::{cast, NumCast};
+
+use crate::{units::round, unwrap_cast, Angle, Convertible, Degrees};
+
+macro_rules! bam(
+    ($($name:ident($t:ident, $bits:expr)),*) => ($(
+        /// An angle in binary angular measurement, where the whole range of
+        /// the integer maps onto one turn, so that addition and subtraction
+        /// wrap around the circle for free.
+        ///
+        /// The arithmetic and [`min_dist`](#method.min_dist) only use
+        /// integer operations, which suits targets without a floating point
+        /// unit.
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name($t);
+
+        impl $name {
+            /// Create an angle from its raw value, in units of a full turn
+            #[doc = concat!("divided by 2^", stringify!($bits), ".")]
+            #[inline]
+            pub const fn from_raw(raw: $t) -> $name {
+                $name(raw)
+            }
+
+            /// Yield the raw value of the angle.
+            #[inline]
+            pub const fn raw(self) -> $t {
+                self.0
+            }
+
+            /// Convert `angle` to the nearest binary angle, wrapping it into
+            /// a single turn. NaN gives zero.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the value cannot be converted through an `f64`.
+            #[inline]
+            pub fn from_angle<T: Copy + NumCast>(angle: Angle<T>) -> $name {
+                let turns = angle.map(|v| cast::<T, f64>(v).unwrap()).in_turns() % 1.0;
+                // Wrapping around the turn is wrapping around the integer.
+                $name(round(turns * (1u64 << $bits) as f64) as $t)
+            }
+
+            /// Convert the angle to degrees, in the range of [0, 360)°.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the result cannot be represented in `T`.
+            #[inline]
+            pub fn to_angle<T: Convertible>(self) -> Angle<T> {
+                let degrees = self.0 as f64 * 360.0 / (1u64 << $bits) as f64;
+                Degrees(unwrap_cast(cast(degrees)))
+            }
+
+            /// Compute the minimal distance between two angles, at most half
+            /// a turn.
+            #[inline]
+            pub fn min_dist(self, other: $name) -> $name {
+                let d = self.0.wrapping_sub(other.0);
+                $name(d.min(d.wrapping_neg()))
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            #[inline]
+            fn add(self, rhs: $name) -> $name {
+                $name(self.0.wrapping_add(rhs.0))
+            }
+        }
+
+        impl AddAssign for $name {
+            #[inline]
+            fn add_assign(&mut self, rhs: $name) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            #[inline]
+            fn sub(self, rhs: $name) -> $name {
+                $name(self.0.wrapping_sub(rhs.0))
+            }
+        }
+
+        impl SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $name) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            #[inline]
+            fn neg(self) -> $name {
+                $name(self.0.wrapping_neg())
+            }
+        }
+    )*)
+);
+
+bam!(Bam16(u16, 16), Bam32(u32, 32));
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_bam_conversions() {
+        assert_eq!(Bam16::from_angle(Degrees(90.0)).raw(), 0x4000);
+        assert_eq!(Bam16::from_angle(Degrees(-90.0f32)).raw(), 0xc000);
+        assert_eq!(Bam16::from_angle(Degrees(360.0 + 45.0)).raw(), 0x2000);
+        assert_eq!(Bam16::from_angle(Degrees(360.0)).raw(), 0);
+        assert_eq!(
+            Bam16::from_angle(Radians(core::f64::consts::PI)).raw(),
+            0x8000
+        );
+        assert_eq!(Bam16::from_angle(Degrees(f64::NAN)).raw(), 0);
+        // Within half a step of a full turn rounds up to it.
+        assert_eq!(Bam16::from_angle(Degrees(359.999)).raw(), 0);
+        assert_eq!(Bam16::from_angle(Degrees(359.995)).raw(), 0xffff);
+        assert_eq!(Bam32::from_angle(Degrees(90i32)).raw(), 0x4000_0000);
+
+        assert_eq!(Bam16::from_raw(0x4000).to_angle(), Degrees(90.0f32));
+        assert_eq!(
+            Bam16::from_raw(0xffff).to_angle(),
+            Degrees(359.994_506_835_937_5)
+        );
+        assert_eq!(Bam32::from_raw(0xc000_0000).to_angle(), Degrees(270.0));
+        for raw in (0..=u16::MAX).step_by(97) {
+            let alpha = Bam16::from_raw(raw);
+            assert_eq!(Bam16::from_angle(alpha.to_angle::<f32>()), alpha);
+        }
+        for &raw in &[0, 1, 0x8000_0000, 0x1234_5678, u32::MAX] {
+            let alpha = Bam32::from_raw(raw);
+            assert_eq!(Bam32::from_angle(alpha.to_angle::<f64>()), alpha);
+        }
+    }
+
+    #[test]
+    fn test_bam_arithmetic() {
+        let a = Bam16::from_raw(0xfff0);
+        let b = Bam16::from_raw(0x0020);
+        assert_eq!(a + b, Bam16::from_raw(0x0010));
+        assert_eq!(b - a, Bam16::from_raw(0x0030));
+        assert_eq!(a - b, Bam16::from_raw(0xffd0));
+        assert_eq!(-b, Bam16::from_raw(0xffe0));
+        assert_eq!(-Bam16::default(), Bam16::default());
+
+        let mut c = Bam16::from_raw(u16::MAX);
+        c += Bam16::from_raw(1);
+        assert_eq!(c, Bam16::from_raw(0));
+        c -= Bam16::from_raw(1);
+        assert_eq!(c, Bam16::from_raw(u16::MAX));
+
+        let d = Bam32::from_raw(u32::MAX) + Bam32::from_raw(2);
+        assert_eq!(d, Bam32::from_raw(1));
+    }
+
+    #[test]
+    fn test_bam_min_dist() {
+        let a = Bam16::from_raw(0xfff0);
+        let b = Bam16::from_raw(0x0020);
+        assert_eq!(a.min_dist(b), Bam16::from_raw(0x0030));
+        assert_eq!(b.min_dist(a), Bam16::from_raw(0x0030));
+        assert_eq!(a.min_dist(a), Bam16::from_raw(0));
+
+        let half = Bam16::from_raw(0x8000);
+        assert_eq!(half.min_dist(Bam16::from_raw(0)), half);
+        assert_eq!(
+            Bam16::from_raw(0x7fff).min_dist(Bam16::from_raw(0xffff)),
+            Bam16::from_raw(0x8000)
+        );
+        assert_eq!(
+            Bam16::from_raw(0x0001).min_dist(Bam16::from_raw(0x8002)),
+            Bam16::from_raw(0x7fff)
+        );
+        assert_eq!(
+            Bam32::from_raw(5).min_dist(Bam32::from_raw(u32::MAX - 4)),
+            Bam32::from_raw(10)
+        );
+
+        for &(x, y) in &[(22.5, 337.5), (0.0, 135.0), (315.0, 90.0)] {
+            let (a, b) = (Bam16::from_angle(Degrees(x)), Bam16::from_angle(Degrees(y)));
+            let expected = Degrees(x).min_dist(Degrees(y)).in_degrees();
+            assert_that!(
+                a.min_dist(b).to_angle::<f64>().in_degrees(),
+                close_to(expected, 1.0e-12)
+            );
+        }
+    }
+}
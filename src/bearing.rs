@@ -0,0 +1,199 @@
+//! Compass bearings.
+
+use num_traits::{
+    cast::{cast, NumCast},
+    Num,
+};
+
+use crate::{unwrap_cast, Angle, Convertible, Degrees};
+
+/// A compass bearing, the direction measured clockwise from north and
+/// normalized into the range of [0, 360)° or [0, 2π) rad.
+///
+/// The angles elsewhere in this crate follow the mathematical convention of
+/// being measured counter-clockwise from the positive x axis, i.e. east.
+/// [`from_math_angle`](#method.from_math_angle) and
+/// [`to_math_angle`](#method.to_math_angle) convert between the two.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let bearing = Bearing::from_azimuth(-90.0f64);
+/// assert_eq!(bearing.in_degrees(), 270.0);
+/// assert_eq!(bearing.to_math_angle(), Degrees(180.0));
+/// assert_eq!(bearing.reverse().in_degrees(), 90.0);
+/// ```
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Bearing<T = f64>(Angle<T>);
+
+impl<T: Copy + Num + Convertible + PartialOrd> Bearing<T> {
+    /// Create a bearing from an angle measured clockwise from north,
+    /// normalizing it and keeping the unit it is stored in.
+    #[inline]
+    pub fn new(angle: Angle<T>) -> Bearing<T> {
+        Bearing(angle.normalized())
+    }
+
+    /// Create a bearing from an azimuth in degrees clockwise from north, such
+    /// as the possibly negative values computed by GIS libraries.
+    #[inline]
+    pub fn from_azimuth(degrees: T) -> Bearing<T> {
+        Bearing::new(Degrees(degrees))
+    }
+
+    /// Create a bearing from an angle measured counter-clockwise from east.
+    #[inline]
+    pub fn from_math_angle(angle: Angle<T>) -> Bearing<T> {
+        Bearing::new(reflect(angle))
+    }
+
+    /// Yield the bearing as an angle measured clockwise from north.
+    #[inline]
+    pub fn angle(self) -> Angle<T> {
+        self.0
+    }
+
+    /// Yield the bearing in radians.
+    #[inline]
+    pub fn in_radians(self) -> T {
+        self.0.in_radians()
+    }
+
+    /// Yield the bearing in degrees.
+    #[inline]
+    pub fn in_degrees(self) -> T {
+        self.0.in_degrees()
+    }
+
+    /// Yield the bearing as a normalized angle measured counter-clockwise
+    /// from east.
+    #[inline]
+    pub fn to_math_angle(self) -> Angle<T> {
+        reflect(self.0).normalized()
+    }
+
+    /// Yield the opposite bearing, half a turn away.
+    #[inline]
+    pub fn reverse(self) -> Bearing<T> {
+        let (v, unit) = self.0.into_parts();
+        let half = unit.full_turn_in::<T>() / (T::one() + T::one());
+        Bearing::new(Angle::from_parts(v + half, unit))
+    }
+}
+
+/// Reflect `angle` about the direction a quarter turn from zero, which swaps
+/// angles measured clockwise from north with angles measured counter-clockwise
+/// from east.
+#[inline]
+fn reflect<T: Copy + Num + Convertible>(angle: Angle<T>) -> Angle<T> {
+    let quarter = angle.unit().full_turn_in::<T>() / unwrap_cast(cast(4));
+    angle.map(|v| quarter - v)
+}
+
+impl<T> From<Bearing<T>> for Angle<T> {
+    #[inline]
+    fn from(bearing: Bearing<T>) -> Self {
+        bearing.0
+    }
+}
+
+impl<T: Copy + NumCast + PartialEq> PartialEq for Bearing<T> {
+    #[inline]
+    fn eq(&self, other: &Bearing<T>) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Bearing<T>
+where
+    T: Copy + Num + NumCast + PartialOrd + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected};
+
+        let angle = Angle::<T>::deserialize(deserializer)?;
+        let (v, unit) = angle.into_parts();
+        // Every value is below a full turn which does not fit in `T`.
+        let below_full_turn = unit.checked_full_turn_in().is_none_or(|full| v < full);
+        if v >= T::zero() && below_full_turn {
+            Ok(Bearing(angle))
+        } else {
+            Err(D::Error::invalid_value(
+                Unexpected::Float(cast(v).unwrap_or(f64::NAN)),
+                &"a normalized bearing in [0, 360)° or [0, 2π) rad",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_bearing() {
+        assert_eq!(Bearing::new(Degrees(-10.0)).in_degrees(), 350.0);
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(Bearing::new(Degrees(720)).angle(), Degrees(0));
+        assert_eq!(Bearing::from_azimuth(-180.0), Bearing::new(Degrees(180.0)));
+        assert_eq!(Angle::from(Bearing::from_azimuth(45.0)), Degrees(45.0));
+
+        let bearing = Bearing::new(Radians(-PI / 2.0));
+        assert!(matches!(bearing.angle(), Radians(_)));
+        assert_that!(bearing.in_degrees(), close_to(270.0, 1.0e-10));
+        assert_that!(bearing.reverse().in_degrees(), close_to(90.0, 1.0e-10));
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(Bearing::from_azimuth(300).reverse().in_degrees(), 120);
+    }
+
+    #[test]
+    fn test_bearing_math_angle() {
+        let cases = [
+            (0.0, 90.0),
+            (90.0, 0.0),
+            (180.0, 270.0),
+            (270.0, 180.0),
+            (30.0, 60.0),
+        ];
+        for &(bearing, math) in &cases {
+            let bearing = Bearing::from_azimuth(bearing);
+            assert_eq!(bearing.to_math_angle(), Degrees(math));
+            assert_eq!(Bearing::from_math_angle(Degrees(math)), bearing);
+        }
+
+        let bearing = Bearing::from_math_angle(Radians(PI));
+        assert_that!(bearing.in_degrees(), close_to(270.0, 1.0e-10));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_bearing_serde() {
+        let bearing = Bearing::from_azimuth(-90.0);
+        let json = serde_json::to_string(&bearing).unwrap();
+        assert_eq!(json, r#"{"Degrees":270.0}"#);
+        assert_eq!(serde_json::from_str::<Bearing>(&json).unwrap(), bearing);
+        let bearing = Bearing::new(Radians(1.5f32));
+        let json = serde_json::to_string(&bearing).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Bearing<f32>>(&json).unwrap(),
+            bearing
+        );
+
+        for json in &[
+            r#"{"Degrees":360.0}"#,
+            r#"{"Degrees":-10.0}"#,
+            r#"{"Radians":7.0}"#,
+        ] {
+            let err = serde_json::from_str::<Bearing>(json).unwrap_err();
+            assert!(err.to_string().contains("normalized bearing"), "{}", err);
+        }
+        assert!(serde_json::from_str::<Bearing<i32>>(r#"{"Degrees":400}"#).is_err());
+        assert!(serde_json::from_str::<Bearing>("270.0").is_err());
+    }
+}
@@ -0,0 +1,114 @@
+//! Interoperability with the angle types of the `cgmath` crate.
+
+use cgmath::{Deg, Rad};
+use num_traits::cast::NumCast;
+
+use crate::{Angle, Convertible, Degrees, Radians};
+
+impl<T> From<Rad<T>> for Angle<T> {
+    #[inline]
+    fn from(rad: Rad<T>) -> Self {
+        Radians(rad.0)
+    }
+}
+
+impl<T> From<Deg<T>> for Angle<T> {
+    #[inline]
+    fn from(deg: Deg<T>) -> Self {
+        Degrees(deg.0)
+    }
+}
+
+impl<T: Copy + NumCast> From<Angle<T>> for Rad<T> {
+    #[inline]
+    fn from(angle: Angle<T>) -> Self {
+        angle.into_cgmath_rad()
+    }
+}
+
+impl<T: Copy + Convertible> From<Angle<T>> for Deg<T> {
+    #[inline]
+    fn from(angle: Angle<T>) -> Self {
+        angle.into_cgmath_deg()
+    }
+}
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Convert the angle to a `cgmath::Rad`. An angle in radians keeps its
+    /// exact value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let rad = Degrees(180.0f32).into_cgmath_rad();
+    /// assert_eq!(rad, cgmath::Rad(core::f32::consts::PI));
+    /// assert_eq!(Angle::from(rad), Radians(core::f32::consts::PI));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn into_cgmath_rad(self) -> Rad<T> {
+        Rad(self.in_radians())
+    }
+
+    /// Convert the angle to a `cgmath::Deg`. An angle in degrees keeps its
+    /// exact value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn into_cgmath_deg(self) -> Deg<T>
+    where
+        T: Convertible,
+    {
+        Deg(self.in_degrees())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cgmath::{Deg, Rad};
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_cgmath_same_unit() {
+        // Values which do not survive a round trip through the other unit.
+        for &v in &[0.1f64, -1.0e-300, 1.0e300, 123.456_789_012_345, f64::MAX] {
+            assert_eq!(Angle::from(Rad(v)), Radians(v));
+            assert_eq!(Angle::from(Deg(v)), Degrees(v));
+            assert_eq!(Rad::from(Radians(v)).0.to_bits(), v.to_bits());
+            assert_eq!(Deg::from(Degrees(v)).0.to_bits(), v.to_bits());
+            assert_eq!(Radians(v).into_cgmath_rad().0.to_bits(), v.to_bits());
+            assert_eq!(Degrees(v).into_cgmath_deg().0.to_bits(), v.to_bits());
+        }
+        let v = 0.1f32;
+        assert_eq!(Rad::from(Radians(v)), Rad(v));
+        assert_eq!(Angle::from(Deg(v)), Degrees(v));
+    }
+
+    #[test]
+    fn test_cgmath_conversions() {
+        assert_that!(Rad::from(Degrees(90.0)).0, close_to(PI / 2.0, 1.0e-15));
+        assert_that!(Deg::from(Radians(-PI)).0, close_to(-180.0, 1.0e-12));
+
+        for &v in &[0.0, 1.0, -2.5, 7.0] {
+            let back: Angle = Deg::from(Radians(v)).into();
+            assert_that!(back.in_radians(), close_to(v, 1.0e-12));
+            let back: Angle = Rad::from(Degrees(v * 45.0)).into();
+            assert_that!(back.in_degrees(), close_to(v * 45.0, 1.0e-12));
+        }
+
+        // cgmath agrees on the conversion between its own units.
+        let deg: Deg<f64> = Rad(1.25).into();
+        assert_that!(Radians(1.25).into_cgmath_deg().0, close_to(deg.0, 1.0e-12));
+    }
+}
@@ -0,0 +1,301 @@
+//! Names of the points of the compass.
+
+use core::fmt::{Display, Error, Formatter};
+use num_traits::cast::{cast, NumCast};
+
+use crate::{units::round, Angle};
+
+/// The points of a 16-point compass rose, clockwise from north.
+const POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Name the point of a compass rose of 4, 8 or 16 `points` nearest to the
+    /// angle, taken to be a heading measured clockwise from north as a
+    /// [`Bearing`](struct.Bearing.html) is. Negative headings and headings
+    /// beyond a full turn are normalized first.
+    ///
+    /// Each point covers the sector from half a step counter-clockwise of it,
+    /// inclusive, to half a step clockwise of it, exclusive, so headings
+    /// exactly between two points round clockwise: 11.25° is `NNE` on a
+    /// 16-point rose and 45° is `E` on a 4-point one. NaN is `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(30.0).to_compass_point(16), "NNE");
+    /// assert_eq!(Degrees(30.0).to_compass_point(8), "NE");
+    /// assert_eq!(Degrees(-100i32).to_compass_point(4), "W");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is not 4, 8 or 16, or if the value cannot be
+    /// converted through an `f64`. See
+    /// [`checked_to_compass_point`](#method.checked_to_compass_point). The
+    /// method is not available with the `no-panic` feature.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn to_compass_point(self, points: u8) -> &'static str {
+        assert!(
+            valid_points(points),
+            "a compass rose has 4, 8 or 16 points, not {}",
+            points
+        );
+        point(heading(self), points)
+    }
+
+    /// Name the point of a compass rose of 4, 8 or 16 `points` nearest to the
+    /// angle as [`to_compass_point`](#method.to_compass_point) does, or
+    /// return `None` if `points` is not 4, 8 or 16.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(30.0).checked_to_compass_point(8), Some("NE"));
+    /// assert_eq!(Degrees(30.0).checked_to_compass_point(12), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64`.
+    pub fn checked_to_compass_point(self, points: u8) -> Option<&'static str> {
+        if valid_points(points) {
+            Some(point(heading(self), points))
+        } else {
+            None
+        }
+    }
+
+    /// Format the angle as the nearest point of a 16-point compass rose,
+    /// followed by the heading in whole degrees, e.g. `NNE (22°)`. See
+    /// [`to_compass_point`](#method.to_compass_point) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(22.0).display_compass().to_string(), "NNE (22°)");
+    /// assert_eq!(Degrees(-90.4).display_compass().to_string(), "W (270°)");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64`.
+    pub fn display_compass(self) -> Compass {
+        Compass {
+            heading: heading(self),
+            points: 16,
+        }
+    }
+}
+
+/// Return `true` if a compass rose can have `points` points.
+#[inline]
+fn valid_points(points: u8) -> bool {
+    matches!(points, 4 | 8 | 16)
+}
+
+/// Name the point of a compass rose of 4, 8 or 16 `points` nearest to
+/// `heading` in degrees, in the range of [0, 360).
+#[inline]
+fn point(heading: f64, points: u8) -> &'static str {
+    let points = points as usize;
+    let step = 360.0 / points as f64;
+    let sector = (heading + step / 2.0) / step;
+    POINTS[sector as usize % points * (16 / points)]
+}
+
+/// Yield `angle` as a heading in degrees in the range of [0, 360).
+#[inline]
+fn heading<T: Copy + NumCast>(angle: Angle<T>) -> f64 {
+    let degrees = angle.map(|v| cast::<T, f64>(v).unwrap()).in_degrees() % 360.0;
+    if degrees < 0.0 {
+        // Tiny negative values round up to a full turn.
+        (degrees + 360.0) % 360.0
+    } else {
+        degrees
+    }
+}
+
+/// Helper struct for formatting an angle as a point of the compass.
+///
+/// This `struct` is created by the [`display_compass`] method on [`Angle`].
+/// See its documentation for more.
+///
+/// [`display_compass`]: enum.Angle.html#method.display_compass
+/// [`Angle`]: enum.Angle.html
+#[derive(Copy, Clone, Debug)]
+pub struct Compass {
+    heading: f64,
+    points: u8,
+}
+
+impl Compass {
+    /// Use a compass rose of 4, 8 or 16 `points` instead of 16.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is not 4, 8 or 16. See
+    /// [`checked_points`](#method.checked_points). The method is not
+    /// available with the `no-panic` feature.
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    pub fn points(self, points: u8) -> Compass {
+        assert!(
+            valid_points(points),
+            "a compass rose has 4, 8 or 16 points, not {}",
+            points
+        );
+        Compass { points, ..self }
+    }
+
+    /// Use a compass rose of 4, 8 or 16 `points` instead of 16, or return
+    /// `None` if `points` is not 4, 8 or 16.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Degrees(22.0).display_compass();
+    /// assert_eq!(alpha.checked_points(4).unwrap().to_string(), "N (22°)");
+    /// assert!(alpha.checked_points(12).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_points(self, points: u8) -> Option<Compass> {
+        if valid_points(points) {
+            Some(Compass { points, ..self })
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Compass {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let point = point(self.heading, self.points);
+        write!(f, "{} ({}°)", point, round(self.heading) % 360)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    fn test_compass_point_sectors() {
+        let names = [
+            "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW",
+            "NW", "NNW",
+        ];
+        for &points in &[4u8, 8, 16] {
+            let step = 360.0 / points as f64;
+            let mut counts = [0; 16];
+            for degrees in 0..360 {
+                let point = Degrees(degrees as f64).to_compass_point(points);
+                let index = names.iter().position(|&n| n == point).unwrap();
+                counts[index] += 1;
+
+                // The nearest point, with ties going clockwise.
+                let expected = ((degrees as f64 + step / 2.0) / step).floor() as usize;
+                let expected = expected % points as usize * (16 / points as usize);
+                assert_eq!(index, expected, "{}° on {} points", degrees, points);
+            }
+            for (index, &count) in counts.iter().enumerate() {
+                if index % (16 / points as usize) == 0 {
+                    assert!(
+                        (count as f64 - step).abs() <= 1.0,
+                        "{} has {} degrees",
+                        names[index],
+                        count
+                    );
+                } else {
+                    assert_eq!(count, 0);
+                }
+            }
+        }
+        assert_eq!(
+            (0..360)
+                .filter(|&d| Degrees(d).to_compass_point(4) == "N")
+                .count(),
+            90
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    fn test_compass_point_boundaries() {
+        assert_eq!(Degrees(11.25).to_compass_point(16), "NNE");
+        assert_eq!(Degrees(11.249).to_compass_point(16), "N");
+        assert_eq!(Degrees(348.75).to_compass_point(16), "N");
+        assert_eq!(Degrees(348.749).to_compass_point(16), "NNW");
+        assert_eq!(Degrees(22.5).to_compass_point(8), "NE");
+        assert_eq!(Degrees(22.499).to_compass_point(8), "N");
+        assert_eq!(Degrees(45.0).to_compass_point(4), "E");
+        assert_eq!(Degrees(-45.0).to_compass_point(4), "N");
+        assert_eq!(Degrees(315.0f32).to_compass_point(4), "N");
+        assert_eq!(Degrees(f64::NAN).to_compass_point(16), "N");
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    fn test_compass_point_normalization() {
+        assert_eq!(Degrees(-90.0).to_compass_point(16), "W");
+        assert_eq!(Degrees(-22.5).to_compass_point(16), "NNW");
+        assert_eq!(Degrees(-1.0e-20).to_compass_point(16), "N");
+        assert_eq!(Degrees(720.0 + 180.0).to_compass_point(8), "S");
+        assert_eq!(
+            Radians(-core::f64::consts::FRAC_PI_2).to_compass_point(4),
+            "W"
+        );
+        assert_eq!(Radians(3i32).to_compass_point(16), "S");
+        assert_eq!(Degrees(-135i16).to_compass_point(8), "SW");
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    #[should_panic(expected = "4, 8 or 16 points")]
+    fn test_compass_point_invalid() {
+        Degrees(0.0).to_compass_point(12);
+    }
+
+    #[test]
+    fn test_checked_compass_point() {
+        assert_eq!(Degrees(30.0).checked_to_compass_point(16), Some("NNE"));
+        assert_eq!(Degrees(-45.0).checked_to_compass_point(4), Some("N"));
+        assert_eq!(Radians(3i32).checked_to_compass_point(16), Some("S"));
+        assert_eq!(Degrees(-135i16).checked_to_compass_point(8), Some("SW"));
+        assert_eq!(Degrees(f64::NAN).checked_to_compass_point(16), Some("N"));
+        for &points in &[0u8, 1, 2, 12, 32, 255] {
+            assert_eq!(Degrees(0.0).checked_to_compass_point(points), None);
+        }
+
+        let alpha = Degrees(200i32).display_compass();
+        assert_eq!(alpha.checked_points(8).unwrap().to_string(), "S (200°)");
+        assert_eq!(alpha.checked_points(4).unwrap().to_string(), "S (200°)");
+        assert!(alpha.checked_points(12).is_none());
+    }
+
+    #[test]
+    fn test_display_compass() {
+        assert_eq!(Degrees(22.0).display_compass().to_string(), "NNE (22°)");
+        assert_eq!(Degrees(0.0).display_compass().to_string(), "N (0°)");
+        assert_eq!(Degrees(359.6).display_compass().to_string(), "N (0°)");
+        assert_eq!(Degrees(-90.0).display_compass().to_string(), "W (270°)");
+        assert_eq!(
+            Radians(core::f64::consts::PI).display_compass().to_string(),
+            "S (180°)"
+        );
+        let alpha = Degrees(200i32).display_compass();
+        assert_eq!(alpha.to_string(), "SSW (200°)");
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(alpha.points(8).to_string(), "S (200°)");
+            assert_eq!(alpha.points(4).to_string(), "S (200°)");
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Fixed-point trigonometry with CORDIC, without any floating point.
+
+use crate::{Angle, Bam16, Bam32, Degrees, Radians};
+
+/// The arctangents of 2^-i, in units of a full turn divided by 2^64.
+const ATAN: [i64; 40] = [
+    2_305_843_009_213_693_952,
+    1_361_218_612_134_873_190,
+    719_230_530_580_881_038,
+    365_092_647_525_521_947,
+    183_254_791_493_294_829,
+    91_716_730_292_036_216,
+    45_869_556_482_713_130,
+    22_936_177_926_750_895,
+    11_468_263_948_075_831,
+    5_734_153_847_876_408,
+    2_867_079_658_191_483,
+    1_433_540_170_878_135,
+    716_770_128_161_890,
+    358_385_069_421_298,
+    179_192_535_378_193,
+    89_596_267_772_540,
+    44_798_133_896_700,
+    22_399_066_949_654,
+    11_199_533_474_990,
+    5_599_766_737_515,
+    2_799_883_368_760,
+    1_399_941_684_380,
+    699_970_842_190,
+    349_985_421_095,
+    174_992_710_548,
+    87_496_355_274,
+    43_748_177_637,
+    21_874_088_818,
+    10_937_044_409,
+    5_468_522_205,
+    2_734_261_102,
+    1_367_130_551,
+    683_565_276,
+    341_782_638,
+    170_891_319,
+    85_445_659,
+    42_722_830,
+    21_361_415,
+    10_680_707,
+    5_340_354,
+];
+
+/// The reciprocal of the gain of the iterations, in Q40.
+const GAIN: i64 = 667_681_663_043;
+
+/// A degree, in units of a full turn divided by 2^64.
+const PHASE_PER_DEGREE: u64 = 51_240_955_760_304_310;
+
+/// A radian, in units of a full turn divided by 2^64.
+const PHASE_PER_RADIAN: u64 = 2_935_890_503_282_001_226;
+
+/// Compute the sine and cosine in Q40 of `phase`, in units of a full turn
+/// divided by 2^64.
+fn sin_cos_q40(phase: u64) -> (i64, i64) {
+    // Fold the phase to within an eighth of a turn of the nearest quarter
+    // turn, well inside the range in which the iterations converge.
+    let quadrant = phase.wrapping_add(1 << 61) >> 62;
+    let mut z = phase.wrapping_sub(quadrant << 62) as i64;
+
+    let (mut x, mut y) = (GAIN, 0i64);
+    for (i, &atan) in ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= atan;
+        } else {
+            x += dx;
+            y -= dy;
+            z += atan;
+        }
+    }
+
+    match quadrant {
+        0 => (y, x),
+        1 => (x, -y),
+        2 => (-y, -x),
+        _ => (-x, y),
+    }
+}
+
+/// Round `v` in Q40 to the nearest value in Q`bits`, saturating at
+/// ±(2^bits - 1).
+#[inline]
+fn round_q40(v: i64, bits: u32) -> i64 {
+    let shift = 40 - bits;
+    let max = (1 << bits) - 1;
+    ((v + (1 << (shift - 1))) >> shift).clamp(-max, max)
+}
+
+macro_rules! cordic_angle(
+    ($($t:ident, $bits:expr);*) => ($(
+        impl Angle<$t> {
+            /// Compute the sine and cosine of the angle with the shift-add
+            /// CORDIC iteration, which uses integer arithmetic only, for
+            /// targets without a floating point unit.
+            ///
+            #[doc = concat!(
+                "Both are returned in Q", stringify!($bits), ", so that 1 is 2^",
+                stringify!($bits), ", saturating at ±(2^", stringify!($bits), " - 1)."
+            )]
+            /// They are within one unit in the last place of the exact values.
+            /// Angles in degrees are reduced exactly, and angles in radians
+            /// with an error far below that.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use ang::*;
+            #[doc = concat!(
+                "let (sin, cos) = Degrees(30", stringify!($t), ").sin_cos_cordic();"
+            )]
+            #[doc = concat!("assert_eq!(sin, 1 << (", stringify!($bits), " - 1));")]
+            #[doc = concat!(
+                "assert_eq!(Degrees(-90", stringify!($t), ").sin_cos_cordic(), (-",
+                stringify!($t), "::MAX, 0));"
+            )]
+            /// ```
+            pub fn sin_cos_cordic(self) -> ($t, $t) {
+                let phase = match self {
+                    Degrees(v) => (v.rem_euclid(360) as u64).wrapping_mul(PHASE_PER_DEGREE),
+                    Radians(v) => (v as i64 as u64).wrapping_mul(PHASE_PER_RADIAN),
+                };
+                let (sin, cos) = sin_cos_q40(phase);
+                (round_q40(sin, $bits) as $t, round_q40(cos, $bits) as $t)
+            }
+        }
+    )*)
+);
+
+cordic_angle!(i16, 15; i32, 31);
+
+impl Bam16 {
+    /// Compute the sine and cosine of the angle in Q15 with CORDIC, as
+    /// [`Angle::sin_cos_cordic`](enum.Angle.html#method.sin_cos_cordic) does.
+    #[inline]
+    pub fn sin_cos_cordic(self) -> (i16, i16) {
+        let (sin, cos) = sin_cos_q40((self.raw() as u64) << 48);
+        (round_q40(sin, 15) as i16, round_q40(cos, 15) as i16)
+    }
+}
+
+impl Bam32 {
+    /// Compute the sine and cosine of the angle in Q31 with CORDIC, as
+    /// [`Angle::sin_cos_cordic`](enum.Angle.html#method.sin_cos_cordic) does.
+    #[inline]
+    pub fn sin_cos_cordic(self) -> (i32, i32) {
+        let (sin, cos) = sin_cos_q40((self.raw() as u64) << 32);
+        (round_q40(sin, 31) as i32, round_q40(cos, 31) as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// Return the error of `actual` in Q`bits` against `expected`, in units
+    /// in the last place.
+    fn ulps(actual: i64, expected: f64, bits: i32) -> f64 {
+        let scale = 2.0f64.powi(bits);
+        let expected = (expected * scale).clamp(-(scale - 1.0), scale - 1.0);
+        (actual as f64 - expected).abs()
+    }
+
+    #[test]
+    fn test_cordic_degrees() {
+        for v in -720i32..=720 {
+            let (sin, cos) = (v as f64).to_radians().sin_cos();
+
+            let (s, c) = Degrees(v as i16).sin_cos_cordic();
+            assert!(ulps(s as i64, sin, 15) <= 1.0, "sin {}° = {}", v, s);
+            assert!(ulps(c as i64, cos, 15) <= 1.0, "cos {}° = {}", v, c);
+
+            let (s, c) = Degrees(v).sin_cos_cordic();
+            assert!(ulps(s as i64, sin, 31) <= 1.0, "sin {}° = {}", v, s);
+            assert!(ulps(c as i64, cos, 31) <= 1.0, "cos {}° = {}", v, c);
+        }
+
+        // The quadrant boundaries are exact.
+        let cases = [
+            (0i32, (0, i32::MAX)),
+            (90, (i32::MAX, 0)),
+            (180, (0, -i32::MAX)),
+        ];
+        for &(v, expected) in &cases {
+            assert_eq!(Degrees(v).sin_cos_cordic(), expected);
+        }
+        assert_eq!(Degrees(270i16).sin_cos_cordic(), (-i16::MAX, 0));
+        assert_eq!(
+            Degrees(i32::MIN).sin_cos_cordic(),
+            Degrees(i32::MIN % 360).sin_cos_cordic()
+        );
+    }
+
+    #[test]
+    fn test_cordic_radians() {
+        for v in -1000i32..=1000 {
+            let (sin, cos) = (v as f64).sin_cos();
+            let (s, c) = Radians(v).sin_cos_cordic();
+            assert!(ulps(s as i64, sin, 31) <= 1.0, "sin {} = {}", v, s);
+            assert!(ulps(c as i64, cos, 31) <= 1.0, "cos {} = {}", v, c);
+        }
+        assert_eq!(Radians(0i16).sin_cos_cordic(), (0, i16::MAX));
+    }
+
+    #[test]
+    fn test_cordic_bam() {
+        for raw in (0..=u16::MAX).step_by(7) {
+            let alpha = Bam16::from_raw(raw);
+            let (sin, cos) = alpha.to_angle::<f64>().sin_cos();
+            let (s, c) = alpha.sin_cos_cordic();
+            assert!(ulps(s as i64, sin, 15) <= 1.0, "sin {:?} = {}", alpha, s);
+            assert!(ulps(c as i64, cos, 15) <= 1.0, "cos {:?} = {}", alpha, c);
+        }
+        for raw in (0..=u32::MAX).step_by(104_729) {
+            let alpha = Bam32::from_raw(raw);
+            let turns = raw as f64 / 2.0f64.powi(32);
+            let (sin, cos) = (2.0 * core::f64::consts::PI * turns).sin_cos();
+            let (s, c) = alpha.sin_cos_cordic();
+            assert!(ulps(s as i64, sin, 31) <= 1.0, "sin {:?} = {}", alpha, s);
+            assert!(ulps(c as i64, cos, 31) <= 1.0, "cos {:?} = {}", alpha, c);
+        }
+        assert_eq!(Bam32::from_raw(0x4000_0000).sin_cos_cordic(), (i32::MAX, 0));
+        assert_eq!(Bam16::from_raw(0xc000).sin_cos_cordic(), (-i16::MAX, 0));
+    }
+}
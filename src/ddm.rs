@@ -0,0 +1,224 @@
+//! Degrees and decimal minutes.
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt::{Display, Error, Formatter};
+use num_traits::cast::{cast, NumCast};
+
+use crate::{degrees_f64, Angle, Degrees, ParseAngleError};
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Create an angle from whole `degrees` and decimal `minutes`.
+    ///
+    /// The sign of `degrees` applies to the whole quantity. Angles between 0°
+    /// and -1° are expressed with zero (or negative zero) degrees and negative
+    /// `minutes`. Returns `ParseAngleError::MinutesOutOfRange` if `minutes` is
+    /// not in the range [0, 60), or is negative while `degrees` is not zero,
+    /// and `ParseAngleError::OutOfRange` if the angle cannot be represented in
+    /// `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Angle::from_ddm(-49.0f64, 30.0).unwrap();
+    /// assert!((alpha.in_degrees() + 49.5).abs() < 1.0e-10);
+    ///
+    /// let beta = Angle::from_ddm(0.0f64, -30.0).unwrap();
+    /// assert!((beta.in_degrees() + 0.5).abs() < 1.0e-10);
+    /// ```
+    pub fn from_ddm(degrees: T, minutes: T) -> Result<Angle<T>, ParseAngleError> {
+        let degrees: f64 = cast(degrees).ok_or(ParseAngleError::InvalidNumber)?;
+        let minutes: f64 = cast(minutes).ok_or(ParseAngleError::InvalidNumber)?;
+
+        let negative = if degrees == 0.0 {
+            degrees.is_sign_negative() || minutes < 0.0
+        } else if minutes < 0.0 {
+            return Err(ParseAngleError::MinutesOutOfRange);
+        } else {
+            degrees < 0.0
+        };
+        if minutes.is_nan() || minutes.abs() >= 60.0 {
+            return Err(ParseAngleError::MinutesOutOfRange);
+        }
+
+        let value = degrees.abs() + minutes.abs() / 60.0;
+        let value = if negative { -value } else { value };
+        cast(value).map(Degrees).ok_or(ParseAngleError::OutOfRange)
+    }
+
+    /// Decompose the angle into its sign, whole degrees and decimal minutes,
+    /// with the minutes rounded to `decimals` places (at most 9).
+    ///
+    /// The sign is `1` or `-1`. Rounding carries into the degrees, so the
+    /// minutes are always in the range [0, 60).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(-49.5f64).to_ddm(3), (-1, 49, 30.0));
+    /// assert_eq!(Degrees(48.9999999f64).to_ddm(3), (1, 49, 0.0));
+    /// ```
+    pub fn to_ddm(self, decimals: usize) -> (i8, u32, f64) {
+        let degrees = degrees_f64(self).unwrap_or(f64::NAN);
+        let (whole, minutes, scale) = split_minutes(degrees, decimals);
+        let sign = if degrees.is_sign_negative() { -1 } else { 1 };
+        (sign, whole as u32, minutes as f64 / scale as f64)
+    }
+
+    /// Format the angle in degrees and decimal minutes, e.g. `49° 30.254'`,
+    /// with the minutes rounded to `decimals` places (at most 9).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Degrees(49.504233f64);
+    /// assert_eq!(alpha.display_ddm(3).to_string(), "49° 30.254'");
+    /// ```
+    pub fn display_ddm(self, decimals: usize) -> Ddm {
+        Ddm {
+            degrees: degrees_f64(self).unwrap_or(f64::NAN),
+            decimals: decimals.min(9),
+        }
+    }
+
+    /// Format the angle in degrees and decimal minutes into a string. See
+    /// [`display_ddm`](#method.display_ddm) for details.
+    #[cfg(feature = "alloc")]
+    pub fn format_ddm(self, decimals: usize) -> String {
+        self.display_ddm(decimals).to_string()
+    }
+}
+
+/// Helper struct for formatting an angle in degrees and decimal minutes.
+///
+/// This `struct` is created by the [`display_ddm`] method on [`Angle`]. See its
+/// documentation for more.
+///
+/// [`display_ddm`]: enum.Angle.html#method.display_ddm
+/// [`Angle`]: enum.Angle.html
+#[derive(Copy, Clone, Debug)]
+pub struct Ddm {
+    degrees: f64,
+    decimals: usize,
+}
+
+impl Display for Ddm {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let (whole, minutes, scale) = split_minutes(self.degrees, self.decimals);
+        if self.degrees.is_sign_negative() {
+            write!(f, "-")?;
+        }
+        write!(f, "{}° {:02}", whole, minutes / scale)?;
+        if self.decimals > 0 {
+            write!(f, ".{:0d$}", minutes % scale, d = self.decimals)?;
+        }
+        write!(f, "'")
+    }
+}
+
+/// Split the magnitude of a value in degrees into whole degrees and minutes
+/// scaled by the returned power of ten, rounding the minutes to `decimals`
+/// places (at most 9) and carrying into the degrees.
+pub(crate) fn split_minutes(degrees: f64, decimals: usize) -> (u64, u64, u64) {
+    let scale = 10u64.pow(decimals.min(9) as u32);
+    let total = (degrees.abs() * 60.0 * scale as f64 + 0.5) as u64;
+    (total / (60 * scale), total % (60 * scale), scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_from_ddm() {
+        assert_that!(
+            Angle::from_ddm(49.0, 30.254).unwrap().in_degrees(),
+            close_to(49.504233, 0.000001)
+        );
+        assert_that!(
+            Angle::from_ddm(-49.0, 30.254).unwrap().in_degrees(),
+            close_to(-49.504233, 0.000001)
+        );
+        assert_that!(
+            Angle::from_ddm(-0.0, 30.0).unwrap().in_degrees(),
+            close_to(-0.5, 0.000001)
+        );
+        assert_eq!(
+            Angle::from_ddm(49.0, 60.0),
+            Err(ParseAngleError::MinutesOutOfRange)
+        );
+        assert_eq!(
+            Angle::from_ddm(49.0, -1.0),
+            Err(ParseAngleError::MinutesOutOfRange)
+        );
+        assert_eq!(
+            Angle::from_ddm(49.0, f64::NAN),
+            Err(ParseAngleError::MinutesOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_to_ddm() {
+        assert_eq!(Degrees(49.5).to_ddm(3), (1, 49, 30.0));
+        assert_eq!(Degrees(-0.5).to_ddm(1), (-1, 0, 30.0));
+        assert_eq!(Degrees(90i32).to_ddm(0), (1, 90, 0.0));
+
+        let (sign, degrees, minutes) = Radians(1.0).to_ddm(4);
+        assert_eq!((sign, degrees), (1, 57));
+        assert_that!(minutes, close_to(17.7468, 0.00001));
+
+        // 59.999994' must carry into the degrees rather than print as 60'.
+        assert_eq!(Degrees(48.999_999_9).to_ddm(4), (1, 49, 0.0));
+        assert_eq!(Degrees(-48.999_999_9).to_ddm(4), (-1, 49, 0.0));
+        assert_eq!(Degrees(48.999_999).to_ddm(5), (1, 48, 59.99994));
+    }
+
+    #[test]
+    fn test_ddm_round_trip() {
+        for &decimals in &[0, 1, 3, 6] {
+            for &value in &[0.0, 12.345_678, -45.5, 179.999_999_9, -0.25, 359.0] {
+                let (sign, degrees, minutes) = Degrees(value).to_ddm(decimals);
+                let back = Angle::from_ddm(sign as f64 * degrees as f64, minutes).unwrap();
+                let tolerance = 0.5 / 60.0 / 10f64.powi(decimals as i32) + 1.0e-12;
+                assert_that!(back.in_degrees(), close_to(value, tolerance));
+            }
+        }
+    }
+
+    #[test]
+    fn test_display_ddm() {
+        assert_eq!(
+            Degrees(49.504_233).display_ddm(3).to_string(),
+            "49° 30.254'"
+        );
+        assert_eq!(Degrees(-3.5).display_ddm(1).to_string(), "-3° 30.0'");
+        assert_eq!(Degrees(-0.5).display_ddm(0).to_string(), "-0° 30'");
+        assert_eq!(Degrees(7.1).display_ddm(2).to_string(), "7° 06.00'");
+        assert_eq!(Degrees(48.999_999).format_ddm(3), "49° 00.000'");
+    }
+
+    #[test]
+    fn test_parse_ddm() {
+        for &(text, decimals) in &[
+            ("49° 30.254'", 3),
+            ("-3° 30.0'", 1),
+            ("-0° 30'", 0),
+            ("123° 06.1000'", 4),
+        ] {
+            let angle: Angle = text.parse().unwrap();
+            assert_eq!(angle.format_ddm(decimals), text);
+        }
+
+        let plotter: Angle = "49°30.254'N".parse().unwrap();
+        assert_that!(plotter.in_degrees(), close_to(49.504233, 0.000001));
+        let plotter: Angle = "S 33° 47.934'".parse().unwrap();
+        assert_that!(plotter.in_degrees(), close_to(-33.7989, 0.000001));
+        let plotter: Angle = "123° 11.12′ W".parse().unwrap();
+        assert_that!(plotter.in_degrees(), close_to(-123.185333, 0.000001));
+    }
+}
@@ -0,0 +1,324 @@
+//! Support for angles of `rust_decimal::Decimal`.
+
+use core::cmp::Ordering;
+use rust_decimal::Decimal;
+
+use crate::{Angle, AngleUnit, Degrees, ParseAngleError, Radians};
+
+/// π to the 28 decimal places of a `Decimal`.
+const PI: Decimal = Decimal::from_parts(1_102_470_953, 185_874_565, 1_703_060_790, false, 28);
+
+/// Methods on angles of `rust_decimal::Decimal`, computed in decimal
+/// arithmetic without converting to binary floating point.
+///
+/// `Decimal` does not implement `NumCast`, which the generic methods and
+/// operators of [`Angle`](enum.Angle.html) rely on, so this trait provides
+/// the equivalents for decimal angles. Operations on angles in degrees are
+/// exact as long as the result fits in a `Decimal`. Conversions to and from
+/// radians multiply or divide by π, and are rounded to the 28 significant
+/// digits of a `Decimal`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use rust_decimal::Decimal;
+///
+/// let tenth = Degrees(Decimal::new(1, 1));
+/// let mut sum = Degrees(Decimal::ZERO);
+/// for _ in 0..10 {
+///     sum = sum.add(tenth);
+/// }
+/// assert_eq!(sum.in_degrees(), Decimal::ONE);
+/// assert_eq!(sum.to_string(), "1.0°");
+/// ```
+pub trait DecimalAngle: Sized {
+    /// Yield the value encoded in radians, rounding the conversion from
+    /// degrees.
+    fn in_radians(self) -> Decimal;
+
+    /// Yield the value encoded in degrees, rounding the conversion from
+    /// radians.
+    fn in_degrees(self) -> Decimal;
+
+    /// Create a new angle by normalizing the value into the range of
+    /// [0, 360)° or [0, 2π) rad, keeping the unit it is stored in.
+    fn normalized(self) -> Self;
+
+    /// Add two angles. The result is in degrees if both angles are in
+    /// degrees, and in radians otherwise.
+    fn add(self, rhs: Self) -> Self;
+
+    /// Subtract two angles. The result is in degrees if both angles are in
+    /// degrees, and in radians otherwise.
+    fn sub(self, rhs: Self) -> Self;
+
+    /// Compare two angles. Angles in different units are compared in
+    /// degrees.
+    fn cmp(&self, other: &Self) -> Ordering;
+
+    /// Compute the minimal unsigned distance between two angles. The result
+    /// is in the range of [0, 180]° if both angles are in degrees, and in the
+    /// range of [0, π] rad otherwise.
+    fn min_dist(self, other: Self) -> Self;
+
+    /// Create an angle from whole `degrees`, whole `minutes` and decimal
+    /// `seconds`.
+    ///
+    /// The sign of the first non-zero component applies to the whole
+    /// quantity, and the components after it must not be negative. Returns
+    /// `ParseAngleError::MinutesOutOfRange` or
+    /// `ParseAngleError::SecondsOutOfRange` if a component is out of the
+    /// range [0, 60) or negative when it may not be.
+    fn from_dms(
+        degrees: Decimal,
+        minutes: Decimal,
+        seconds: Decimal,
+    ) -> Result<Self, ParseAngleError>;
+
+    /// Decompose the angle into its sign, whole degrees, whole minutes and
+    /// decimal seconds, keeping all the digits of the value in degrees.
+    ///
+    /// The sign is `1` or `-1`.
+    fn to_dms(self) -> (i8, Decimal, Decimal, Decimal);
+}
+
+impl DecimalAngle for Angle<Decimal> {
+    #[inline]
+    fn in_radians(self) -> Decimal {
+        match self {
+            Radians(v) => v,
+            Degrees(v) => v * PI / Decimal::from(180),
+        }
+    }
+
+    #[inline]
+    fn in_degrees(self) -> Decimal {
+        match self {
+            Radians(v) => v * Decimal::from(180) / PI,
+            Degrees(v) => v,
+        }
+    }
+
+    fn normalized(self) -> Self {
+        let full = full_turn(self.unit());
+        self.map(|v| {
+            let v = v % full;
+            if v.is_sign_negative() && !v.is_zero() {
+                v + full
+            } else {
+                v.abs()
+            }
+        })
+    }
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Degrees(a), Degrees(b)) => Degrees(a + b),
+            _ => Radians(self.in_radians() + rhs.in_radians()),
+        }
+    }
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Degrees(a), Degrees(b)) => Degrees(a - b),
+            _ => Radians(self.in_radians() - rhs.in_radians()),
+        }
+    }
+
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (*self, *other) {
+            (Radians(a), Radians(b)) => a.cmp(&b),
+            _ => self.in_degrees().cmp(&other.in_degrees()),
+        }
+    }
+
+    fn min_dist(self, other: Self) -> Self {
+        let (a, b) = match (self, other) {
+            (Degrees(_), Degrees(_)) => (self, other),
+            _ => (Radians(self.in_radians()), Radians(other.in_radians())),
+        };
+        let (a, b) = (a.normalized(), b.normalized());
+        let full = full_turn(a.unit());
+        a.sub(b).normalized().map(|d| d.min(full - d))
+    }
+
+    fn from_dms(
+        degrees: Decimal,
+        minutes: Decimal,
+        seconds: Decimal,
+    ) -> Result<Self, ParseAngleError> {
+        let sixty = Decimal::from(60);
+        if minutes.abs() >= sixty || !minutes.fract().is_zero() {
+            return Err(ParseAngleError::MinutesOutOfRange);
+        }
+        if seconds.abs() >= sixty {
+            return Err(ParseAngleError::SecondsOutOfRange);
+        }
+
+        let negative = if !degrees.is_zero() {
+            degrees.is_sign_negative()
+        } else if !minutes.is_zero() {
+            minutes.is_sign_negative()
+        } else {
+            seconds.is_sign_negative()
+        };
+        if !degrees.is_zero() && minutes.is_sign_negative() && !minutes.is_zero() {
+            return Err(ParseAngleError::MinutesOutOfRange);
+        }
+        if !(degrees.is_zero() && minutes.is_zero()) && seconds.is_sign_negative() {
+            return Err(ParseAngleError::SecondsOutOfRange);
+        }
+
+        let value = degrees.abs() + (minutes.abs() * sixty + seconds.abs()) / Decimal::from(3600);
+        Ok(Degrees(if negative { -value } else { value }))
+    }
+
+    fn to_dms(self) -> (i8, Decimal, Decimal, Decimal) {
+        let degrees = self.in_degrees();
+        let sign = if degrees.is_sign_negative() && !degrees.is_zero() {
+            -1
+        } else {
+            1
+        };
+
+        let sixty = Decimal::from(60);
+        let degrees = degrees.abs();
+        let minutes = degrees.fract() * sixty;
+        let seconds = minutes.fract() * sixty;
+        (sign, degrees.trunc(), minutes.trunc(), seconds)
+    }
+}
+
+/// The size of a full turn in the given unit.
+#[inline]
+fn full_turn(unit: AngleUnit) -> Decimal {
+    match unit {
+        AngleUnit::Radians => PI + PI,
+        AngleUnit::Degrees => Decimal::from(360),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use core::str::FromStr;
+    use rust_decimal::Decimal;
+
+    use crate::*;
+
+    fn d(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_decimal_arithmetic() {
+        let mut sum = Degrees(Decimal::ZERO);
+        for _ in 0..10 {
+            sum = sum.add(Degrees(d("0.1")));
+        }
+        assert_eq!(sum.value(), Decimal::ONE);
+        assert_eq!(sum.sub(Degrees(d("0.3"))).value(), d("0.7"));
+        assert_eq!((Degrees(d("0.1")) * Decimal::from(3)).value(), d("0.3"));
+        assert_eq!((-Degrees(d("12.5"))).to_string(), "-12.5°");
+        assert_eq!(Radians(d("0.25")).to_string(), "0.25rad");
+    }
+
+    #[test]
+    fn test_decimal_normalized() {
+        assert_eq!(
+            Degrees(d("720.000000001")).normalized().value(),
+            d("0.000000001")
+        );
+        assert_eq!(
+            Degrees(d("-0.000000001")).normalized().value(),
+            d("359.999999999")
+        );
+        assert_eq!(Degrees(d("-720")).normalized().value(), Decimal::ZERO);
+        assert!(!Degrees(d("-720")).normalized().value().is_sign_negative());
+
+        let alpha = Radians(d("-1")).normalized().value();
+        assert_eq!(alpha, d("5.2831853071795864769252867666"));
+    }
+
+    #[test]
+    fn test_decimal_comparison() {
+        assert_eq!(
+            Degrees(d("0.1")).cmp(&Degrees(d("0.10000000000000000001"))),
+            Ordering::Less
+        );
+        assert_eq!(
+            Degrees(d("180")).cmp(&Radians(d("3.14"))),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Degrees(d("350.5")).min_dist(Degrees(d("10.25"))).value(),
+            d("19.75")
+        );
+        let dist = Degrees(d("90")).min_dist(Radians(Decimal::ZERO));
+        let quarter = d("1.5707963267948966192313216916");
+        assert!((dist.value() - quarter).abs() <= d("1e-28"));
+    }
+
+    #[test]
+    fn test_decimal_dms() {
+        let alpha = Degrees(d("49.50423312345678901"));
+        let (sign, degrees, minutes, seconds) = alpha.to_dms();
+        assert_eq!((sign, degrees, minutes), (1, d("49"), d("30")));
+        assert_eq!(seconds, d("15.239244444440436"));
+
+        for &text in &[
+            "49.50423312345678901",
+            "-0.5",
+            "-123.000000000001",
+            "359.99",
+        ] {
+            let (sign, degrees, minutes, seconds) = Degrees(d(text)).to_dms();
+            let back = if !degrees.is_zero() {
+                Angle::from_dms(Decimal::from(sign) * degrees, minutes, seconds)
+            } else {
+                Angle::from_dms(degrees, Decimal::from(sign) * minutes, seconds)
+            };
+            assert_eq!(back.unwrap().value().normalize(), d(text));
+        }
+
+        use ParseAngleError::*;
+        assert_eq!(
+            Angle::from_dms(d("1"), d("60"), Decimal::ZERO).err(),
+            Some(MinutesOutOfRange)
+        );
+        assert_eq!(
+            Angle::from_dms(d("1"), d("1.5"), Decimal::ZERO).err(),
+            Some(MinutesOutOfRange)
+        );
+        assert_eq!(
+            Angle::from_dms(d("1"), d("-1"), Decimal::ZERO).err(),
+            Some(MinutesOutOfRange)
+        );
+        assert_eq!(
+            Angle::from_dms(d("1"), d("1"), d("60")).err(),
+            Some(SecondsOutOfRange)
+        );
+        assert_eq!(
+            Angle::from_dms(Decimal::ZERO, d("1"), d("-1")).err(),
+            Some(SecondsOutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_decimal_radians() {
+        assert_eq!(
+            Degrees(d("180")).in_radians(),
+            d("3.1415926535897932384626433833")
+        );
+
+        // The conversion to radians rounds to 28 significant digits, so
+        // converting back does not restore the value exactly.
+        let alpha = Radians(Degrees(d("1")).in_radians());
+        assert_ne!(alpha.in_degrees(), Decimal::ONE);
+        assert!((alpha.in_degrees() - Decimal::ONE).abs() < d("1e-25"));
+    }
+}
@@ -0,0 +1,401 @@
+//! Relative rotations between angles.
+
+use core::cmp::Ordering;
+use core::fmt::{Display, Error, Formatter};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use num_traits::cast::NumCast;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+
+use crate::{Angle, Convertible};
+
+/// The direction of a rotation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RotationDirection {
+    /// A rotation clockwise, by a negative angle.
+    Clockwise,
+    /// A rotation counter-clockwise, by a positive angle.
+    CounterClockwise,
+    /// No rotation at all.
+    None,
+}
+
+/// A relative rotation, as opposed to an [`Angle`](enum.Angle.html)
+/// describing an absolute orientation.
+///
+/// The operators only allow the combinations which are meaningful: rotating
+/// an angle by a delta gives another angle, deltas add to and subtract from
+/// each other, and only deltas are scaled. Positive deltas rotate
+/// counter-clockwise. Deltas are not normalized, so that rotations by more
+/// than a full turn are kept.
+///
+/// Adding two angles with `+` remains available on `Angle` for compatibility,
+/// but treats both as rotations from zero; prefer combining an angle with a
+/// delta. The `strict-algebra` feature removes `+`, `+=`, `-=` and `Sum`
+/// between angles, and makes the difference of two angles a delta.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let heading = Degrees(350.0f64);
+/// let turn = heading.delta_to(Degrees(20.0));
+/// assert_eq!(turn, AngleDelta::new(Degrees(-330.0)));
+///
+/// let turn = heading.shortest_delta_to(Degrees(20.0));
+/// assert_eq!(turn, AngleDelta::new(Degrees(30.0)));
+/// assert_eq!((heading + turn * 2.0).normalized(), Degrees(50.0));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AngleDelta<T = f64>(Angle<T>);
+
+impl<T> AngleDelta<T> {
+    /// Create a delta rotating by the given angle.
+    #[inline]
+    pub fn new(angle: Angle<T>) -> AngleDelta<T> {
+        AngleDelta(angle)
+    }
+
+    /// Yield the rotation as an angle.
+    #[inline]
+    pub fn angle(self) -> Angle<T> {
+        self.0
+    }
+}
+
+impl<T: Copy + NumCast> AngleDelta<T> {
+    /// Yield the rotation in radians.
+    #[inline]
+    pub fn in_radians(self) -> T {
+        self.0.in_radians()
+    }
+
+    /// Yield the rotation in degrees.
+    #[inline]
+    pub fn in_degrees(self) -> T
+    where
+        T: Convertible,
+    {
+        self.0.in_degrees()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> AngleDelta<T> {
+    /// Returns `true` if the rotation is NaN.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+}
+
+impl<T: Sub<Output = T> + Copy + NumCast> Angle<T> {
+    /// Compute the rotation from this angle to `other`, without wrapping it
+    /// into any range.
+    ///
+    /// The delta is in degrees if both angles are in degrees, and in radians
+    /// otherwise.
+    #[inline]
+    pub fn delta_to(self, other: Angle<T>) -> AngleDelta<T> {
+        AngleDelta(other.sub_angle(self))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> Angle<T> {
+    /// Compute the shortest rotation from this angle to `other`, in the range
+    /// of (-180, 180]° or (-π, π] rad. Its magnitude is the
+    /// [`min_dist`](#method.min_dist) between the angles.
+    ///
+    /// The delta is in degrees if both angles are in degrees, and in radians
+    /// otherwise.
+    #[inline]
+    pub fn shortest_delta_to(self, other: Angle<T>) -> AngleDelta<T> {
+        let (v, unit) = self.delta_to(other).angle().into_parts();
+        let full: T = unit.full_turn_in();
+        let half = full / (T::one() + T::one());
+
+        let v = v % full;
+        let v = if v > half {
+            v - full
+        } else if v <= -half {
+            v + full
+        } else {
+            v
+        };
+        AngleDelta(Angle::from_parts(v, unit))
+    }
+
+    /// Compute the direction of the shortest rotation from this angle to
+    /// `target`, the sign of [`shortest_delta_to`](#method.shortest_delta_to).
+    ///
+    /// When the angles are exactly opposite, both directions are equally
+    /// short and this rotates counter-clockwise. See
+    /// [`direction_to_with_tiebreak`](#method.direction_to_with_tiebreak) to
+    /// choose otherwise. NaN gives `RotationDirection::None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let heading = Degrees(350.0f64);
+    /// assert_eq!(heading.direction_to(Degrees(20.0)), RotationDirection::CounterClockwise);
+    /// assert_eq!(heading.direction_to(Degrees(-20.0)), RotationDirection::Clockwise);
+    /// assert_eq!(heading.direction_to(Degrees(-10.0)), RotationDirection::None);
+    /// ```
+    #[inline]
+    pub fn direction_to(self, target: Angle<T>) -> RotationDirection {
+        self.direction_to_with_tiebreak(target, RotationDirection::CounterClockwise)
+    }
+
+    /// Compute the direction of the shortest rotation from this angle to
+    /// `target` as [`direction_to`](#method.direction_to) does, but return
+    /// `tiebreak` when the angles are exactly opposite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let direction = Degrees(0.0f64).direction_to_with_tiebreak(Degrees(180.0), RotationDirection::Clockwise);
+    /// assert_eq!(direction, RotationDirection::Clockwise);
+    /// ```
+    pub fn direction_to_with_tiebreak(
+        self,
+        target: Angle<T>,
+        tiebreak: RotationDirection,
+    ) -> RotationDirection {
+        let (v, unit) = self.shortest_delta_to(target).angle().into_parts();
+        let half = unit.full_turn_in::<T>() / (T::one() + T::one());
+
+        if v == half {
+            tiebreak
+        } else if v > T::zero() {
+            RotationDirection::CounterClockwise
+        } else if v < T::zero() {
+            RotationDirection::Clockwise
+        } else {
+            RotationDirection::None
+        }
+    }
+}
+
+impl<T> From<Angle<T>> for AngleDelta<T> {
+    #[inline]
+    fn from(angle: Angle<T>) -> Self {
+        AngleDelta(angle)
+    }
+}
+
+impl<T> From<AngleDelta<T>> for Angle<T> {
+    #[inline]
+    fn from(delta: AngleDelta<T>) -> Self {
+        delta.0
+    }
+}
+
+impl<T: Copy + NumCast + PartialEq> PartialEq for AngleDelta<T> {
+    #[inline]
+    fn eq(&self, other: &AngleDelta<T>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: PartialOrd + Copy + NumCast> PartialOrd for AngleDelta<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &AngleDelta<T>) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: Display> Display for AngleDelta<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! delta_additive(
+    ($bound:ident, $func:ident, $assign_bound:ident, $assign_func:ident) => (
+        impl<T: $bound<Output = T> + Copy + NumCast> $bound<AngleDelta<T>> for Angle<T> {
+            type Output = Angle<T>;
+            #[inline]
+            fn $func(self, rhs: AngleDelta<T>) -> Self::Output {
+                self.combine(rhs.0, $bound::$func)
+            }
+        }
+
+        impl<T: $bound<Output = T> + Copy + NumCast> $bound for AngleDelta<T> {
+            type Output = AngleDelta<T>;
+            #[inline]
+            fn $func(self, rhs: AngleDelta<T>) -> Self::Output {
+                AngleDelta(self.0.combine(rhs.0, $bound::$func))
+            }
+        }
+
+        impl<T: $bound<Output = T> + Copy + NumCast> $assign_bound<AngleDelta<T>> for Angle<T> {
+            #[inline]
+            fn $assign_func(&mut self, rhs: AngleDelta<T>) {
+                *self = self.combine(rhs.0, $bound::$func)
+            }
+        }
+
+        impl<T: $bound<Output = T> + Copy + NumCast> $assign_bound for AngleDelta<T> {
+            #[inline]
+            fn $assign_func(&mut self, rhs: AngleDelta<T>) {
+                self.0 = self.0.combine(rhs.0, $bound::$func)
+            }
+        }
+    );
+);
+
+delta_additive!(Add, add, AddAssign, add_assign);
+delta_additive!(Sub, sub, SubAssign, sub_assign);
+
+macro_rules! delta_multiplicative(
+    ($bound:ident, $func:ident, $assign_bound:ident, $assign_func:ident, $($t:ident),*) => (
+        impl<T: $bound<Output = T> + Copy> $bound<T> for AngleDelta<T> {
+            type Output = AngleDelta<T>;
+            #[inline]
+            fn $func(self, rhs: T) -> Self::Output {
+                AngleDelta(self.0.$func(rhs))
+            }
+        }
+
+        impl<T: $assign_bound> $assign_bound<T> for AngleDelta<T> {
+            #[inline]
+            fn $assign_func(&mut self, rhs: T) {
+                self.0.$assign_func(rhs)
+            }
+        }
+
+        $(
+            impl $bound<AngleDelta<$t>> for $t {
+                type Output = AngleDelta<$t>;
+                #[inline]
+                fn $func(self, rhs: AngleDelta<$t>) -> Self::Output {
+                    AngleDelta(self.$func(rhs.0))
+                }
+            }
+        )*
+    );
+);
+
+delta_multiplicative!(
+    Mul, mul, MulAssign, mul_assign, u8, u16, u32, u64, i8, i16, i32, i64, usize, isize, f32, f64
+);
+delta_multiplicative!(
+    Div, div, DivAssign, div_assign, u8, u16, u32, u64, i8, i16, i32, i64, usize, isize, f32, f64
+);
+
+impl<T: Neg> Neg for AngleDelta<T> {
+    type Output = AngleDelta<T::Output>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        AngleDelta(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use quickcheck::quickcheck;
+
+    use crate::*;
+
+    #[test]
+    fn test_delta_arithmetic() {
+        let alpha = Degrees(30.0);
+        let delta = alpha.delta_to(Degrees(50.0));
+        assert_eq!(delta, AngleDelta::new(Degrees(20.0)));
+        assert_eq!(alpha + delta, Degrees(50.0));
+        assert_eq!(Degrees(50.0) - delta, alpha);
+        assert_eq!(delta + delta, AngleDelta::new(Degrees(40.0)));
+        assert_eq!(delta - delta * 3.0, AngleDelta::new(Degrees(-40.0)));
+        assert_eq!(2.0 * delta / 4.0, AngleDelta::new(Degrees(10.0)));
+        assert_eq!(-delta, AngleDelta::new(Degrees(-20.0)));
+
+        let mut beta = alpha;
+        beta += delta;
+        beta -= delta * 2.0;
+        assert_eq!(beta, Degrees(10.0));
+
+        let mut gamma = delta;
+        gamma += AngleDelta::new(Radians(1.0));
+        assert!(matches!(gamma.angle(), Radians(_)));
+        gamma *= 0.0;
+        assert_eq!(gamma.in_radians(), 0.0);
+
+        let delta = Degrees(10).delta_to(Degrees(370));
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(delta.in_degrees(), 360);
+        assert_eq!(Angle::from(delta), Degrees(360));
+        assert_eq!(AngleDelta::from(Degrees(1)), AngleDelta::new(Degrees(1)));
+        assert_eq!(delta.to_string(), "360°");
+    }
+
+    #[test]
+    fn test_shortest_delta() {
+        let delta = Degrees(350.0).shortest_delta_to(Degrees(20.0));
+        assert_eq!(delta, AngleDelta::new(Degrees(30.0)));
+        let delta = Degrees(20.0).shortest_delta_to(Degrees(-350.0));
+        assert_eq!(delta, AngleDelta::new(Degrees(-10.0)));
+        let delta = Degrees(0.0).shortest_delta_to(Degrees(180.0));
+        assert_eq!(delta, AngleDelta::new(Degrees(180.0)));
+        let delta = Degrees(180.0).shortest_delta_to(Degrees(0.0));
+        assert_eq!(delta, AngleDelta::new(Degrees(180.0)));
+
+        let delta = Radians(0.5).shortest_delta_to(Degrees(0.0));
+        assert!(matches!(delta.angle(), Radians(_)));
+        assert_that!(delta.in_radians(), close_to(-0.5, 1.0e-12));
+    }
+
+    #[test]
+    fn test_direction_to() {
+        use RotationDirection::*;
+
+        assert_eq!(Degrees(10.0).direction_to(Degrees(20.0)), CounterClockwise);
+        assert_eq!(Degrees(20.0).direction_to(Degrees(10.0)), Clockwise);
+        assert_eq!(Degrees(20.0).direction_to(Degrees(20.0)), None);
+        assert_eq!(Degrees(20.0).direction_to(Degrees(380.0)), None);
+        assert_eq!(Radians(0.0).direction_to(Degrees(0.0)), None);
+        assert_eq!(Degrees(f64::NAN).direction_to(Degrees(0.0)), None);
+
+        // Just either side of the seam.
+        assert_eq!(Degrees(359.9).direction_to(Degrees(0.1)), CounterClockwise);
+        assert_eq!(Degrees(0.1).direction_to(Degrees(359.9)), Clockwise);
+        assert_eq!(Degrees(-0.1).direction_to(Degrees(720.1)), CounterClockwise);
+        assert_eq!(Radians(0.1).direction_to(Degrees(-0.1)), Clockwise);
+
+        // Exactly opposite.
+        assert_eq!(Degrees(0.0).direction_to(Degrees(180.0)), CounterClockwise);
+        assert_eq!(Degrees(180.0).direction_to(Degrees(0.0)), CounterClockwise);
+        assert_eq!(
+            Degrees(-90.0).direction_to(Degrees(450.0)),
+            CounterClockwise
+        );
+        assert_eq!(
+            Radians(0.0).direction_to(Radians(core::f64::consts::PI)),
+            CounterClockwise
+        );
+        for &tiebreak in &[Clockwise, CounterClockwise, None] {
+            let direction = Degrees(30.0).direction_to_with_tiebreak(Degrees(210.0), tiebreak);
+            assert_eq!(direction, tiebreak);
+            let direction = Degrees(30.0).direction_to_with_tiebreak(Degrees(211.0), tiebreak);
+            assert_eq!(direction, Clockwise);
+        }
+    }
+
+    #[test]
+    fn prop_shortest_delta_is_min_dist() {
+        fn prop(alpha: Angle, beta: Angle) -> bool {
+            let delta = alpha.shortest_delta_to(beta);
+            let dist = alpha.min_dist(beta);
+            // The error of reducing by whole turns grows with the magnitude.
+            let tol = 1.0e-10 + alpha.in_radians().abs().max(beta.in_radians().abs()) * 1.0e-14;
+            (delta.in_radians().abs() - dist.in_radians()).abs() <= tol
+                && (alpha + delta).min_dist(beta).in_radians() <= tol
+        }
+        quickcheck(prop as fn(Angle, Angle) -> bool);
+    }
+}
@@ -0,0 +1,543 @@
+//! Probability distributions on the circle.
+
+use core::f64::consts::PI;
+use num_traits::{cast::cast, Float};
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use crate::random::standard_normal;
+use crate::{Angle, Radians};
+
+/// The von Mises distribution, the circular analogue of the normal
+/// distribution, with the mean direction `mu` and the concentration `kappa`.
+///
+/// A concentration of zero gives the uniform distribution on the circle, and
+/// large concentrations approach a normal distribution with a variance of
+/// 1/`kappa`. A negative concentration is equivalent to the positive one
+/// around the opposite direction. Samples are normalized angles in radians.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use rand::Rng;
+///
+/// let noise = VonMises { mu: Degrees(90.0), kappa: 200.0 };
+/// let reading = rand::thread_rng().sample(noise);
+///
+/// assert!(matches!(reading, Radians(_)));
+/// assert!(reading.min_dist(Degrees(90.0)).in_degrees() < 45.0);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct VonMises<T = f64> {
+    /// The mean direction.
+    pub mu: Angle<T>,
+    /// The concentration around the mean direction.
+    pub kappa: T,
+}
+
+impl<T: Float> VonMises<T> {
+    /// Compute the probability density of the distribution at `x`, per
+    /// radian.
+    ///
+    /// The density is evaluated in a scaled form which neither overflows nor
+    /// underflows for large concentrations.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// # use std::f64::consts::PI;
+    /// let uniform = VonMises { mu: Radians(0.0), kappa: 0.0 };
+    /// assert!((uniform.pdf(Degrees(123.0)) - 1.0 / (2.0 * PI)).abs() < 1.0e-15);
+    /// ```
+    pub fn pdf(&self, x: Angle<T>) -> T {
+        let kappa: f64 = cast(self.kappa).unwrap();
+        let d: f64 = cast((x - self.mu).in_radians()).unwrap();
+        let density =
+            Float::exp(kappa * Float::cos(d) - Float::abs(kappa)) / (2.0 * PI * bessel_i0e(kappa));
+        cast(density).unwrap()
+    }
+}
+
+impl<T: Float> Distribution<Angle<T>> for VonMises<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle<T> {
+        let mu: f64 = cast(self.mu.in_radians()).unwrap();
+        let kappa: f64 = cast(self.kappa).unwrap();
+        let (mu, kappa) = if kappa < 0.0 {
+            (mu + PI, -kappa)
+        } else {
+            (mu, kappa)
+        };
+
+        let offset = if kappa.is_nan() {
+            kappa
+        } else if kappa < 1.0e-8 {
+            PI * (2.0 * rng.gen::<f64>() - 1.0)
+        } else if kappa > 1.0e6 {
+            // The rejection sampler loses precision and the distribution
+            // is normal to well within it.
+            standard_normal::<f64, R>(rng) / Float::sqrt(kappa)
+        } else {
+            best_fisher(kappa, rng)
+        };
+
+        Radians(cast(mu + offset).unwrap()).normalized()
+    }
+}
+
+/// The wrapped normal distribution, a normal distribution with the mean
+/// `mu` and the standard deviation `sigma` wrapped around the circle.
+///
+/// A standard deviation of zero always yields `mu`, and large standard
+/// deviations approach the uniform distribution on the circle. Samples are
+/// normalized angles in radians.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use rand::Rng;
+///
+/// let noise = WrappedNormal { mu: Degrees(350.0), sigma: Degrees(2.0) };
+/// let reading = rand::thread_rng().sample(noise);
+///
+/// assert!(matches!(reading, Radians(_)));
+/// assert!(reading.min_dist(Degrees(350.0)).in_degrees() < 20.0);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct WrappedNormal<T = f64> {
+    /// The mean direction.
+    pub mu: Angle<T>,
+    /// The standard deviation of the unwrapped normal distribution.
+    pub sigma: Angle<T>,
+}
+
+impl<T: Float> WrappedNormal<T> {
+    /// Compute the probability density of the distribution at `x`, per
+    /// radian.
+    ///
+    /// The density is the theta function series of the wrapped normal
+    /// distribution, summed over the wrapped copies of the normal density for
+    /// standard deviations below 2 rad and over its Fourier series otherwise.
+    /// Either series is truncated once its terms fall below 10⁻¹⁷ of the
+    /// sum, which is accurate to a few units in the last place of an `f64`.
+    /// For a standard deviation of zero, the density is infinite at `mu` and
+    /// zero elsewhere. If `x`, `mu` or the standard deviation is not finite,
+    /// the density is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// # use std::f64::consts::PI;
+    /// let dist = WrappedNormal { mu: Degrees(0.0), sigma: Radians(0.1) };
+    /// let peak = 1.0 / (0.1 * (2.0 * PI).sqrt());
+    /// assert!((dist.pdf(Degrees(360.0)) - peak).abs() < 1.0e-12);
+    /// ```
+    pub fn pdf(&self, x: Angle<T>) -> T {
+        let sigma = Float::abs(cast::<T, f64>(self.sigma.in_radians()).unwrap());
+        let d = wrap(cast::<T, f64>((x - self.mu).in_radians()).unwrap());
+        if !d.is_finite() || !sigma.is_finite() {
+            return T::nan();
+        }
+
+        let density = if sigma == 0.0 {
+            if d == 0.0 {
+                f64::INFINITY
+            } else {
+                0.0
+            }
+        } else if sigma < 2.0 {
+            let gauss = |v: f64| Float::exp(-v * v / (2.0 * sigma * sigma));
+            let mut sum = gauss(d);
+            for k in 1..=MAX_SERIES_TERMS {
+                let shift = 2.0 * PI * k as f64;
+                let term = gauss(d + shift) + gauss(d - shift);
+                sum += term;
+                if term <= sum * 1.0e-17 {
+                    break;
+                }
+            }
+            sum / (sigma * Float::sqrt(2.0 * PI))
+        } else {
+            let rho = Float::exp(-sigma * sigma / 2.0);
+            let mut sum = 1.0;
+            for n in 1..=MAX_SERIES_TERMS {
+                let n = n as f64;
+                let term = Float::powf(rho, n * n);
+                sum += 2.0 * term * Float::cos(n * d);
+                if term <= 1.0e-17 {
+                    break;
+                }
+            }
+            sum / (2.0 * PI)
+        };
+        cast(density).unwrap()
+    }
+}
+
+impl<T: Float> Distribution<Angle<T>> for WrappedNormal<T> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle<T> {
+        let mu: f64 = cast(self.mu.in_radians()).unwrap();
+        let sigma = Float::abs(cast::<T, f64>(self.sigma.in_radians()).unwrap());
+
+        let offset = if sigma > 10.0 {
+            // The mean resultant length is below 10⁻²¹, so the distribution
+            // is uniform to well within the precision of an `f64`, and huge
+            // offsets would not wrap meaningfully.
+            PI * (2.0 * rng.gen::<f64>() - 1.0)
+        } else {
+            sigma * standard_normal::<f64, R>(rng)
+        };
+
+        Radians(cast(mu + offset).unwrap()).normalized()
+    }
+}
+
+/// The most terms summed by either series of the wrapped normal density.
+///
+/// Both series fall below 10⁻¹⁷ of their sum within six terms for any finite
+/// standard deviation, so this is only a guard against runaway loops.
+const MAX_SERIES_TERMS: u32 = 32;
+
+/// Wrap a value in radians into the range of [-π, π].
+#[inline]
+fn wrap(v: f64) -> f64 {
+    let v = v % (2.0 * PI);
+    if v > PI {
+        v - 2.0 * PI
+    } else if v < -PI {
+        v + 2.0 * PI
+    } else {
+        v
+    }
+}
+
+/// Draw an offset from the mean of a von Mises distribution with the
+/// rejection sampler of Best and Fisher (1979).
+fn best_fisher<R: Rng + ?Sized>(kappa: f64, rng: &mut R) -> f64 {
+    let s = if kappa < 1.0e-5 {
+        // The general form below cancels catastrophically.
+        1.0 / kappa + kappa
+    } else {
+        let r = 1.0 + Float::sqrt(1.0 + 4.0 * kappa * kappa);
+        let rho = (r - Float::sqrt(2.0 * r)) / (2.0 * kappa);
+        (1.0 + rho * rho) / (2.0 * rho)
+    };
+
+    let w = loop {
+        let z = Float::cos(PI * rng.gen::<f64>());
+        let w = (1.0 + s * z) / (s + z);
+        let y = kappa * (s - w);
+        let v: f64 = rng.gen();
+        if y * (2.0 - y) - v >= 0.0 || Float::ln(y / v) + 1.0 - y >= 0.0 {
+            break w;
+        }
+    };
+
+    let theta = Float::acos(w.clamp(-1.0, 1.0));
+    if rng.gen::<bool>() {
+        theta
+    } else {
+        -theta
+    }
+}
+
+/// Compute the exponentially scaled modified Bessel function of the first
+/// kind of order zero, e^-|x| I₀(x), to close to machine precision.
+pub(crate) fn bessel_i0e(x: f64) -> f64 {
+    let x = Float::abs(x);
+    let mut sum = 1.0;
+    let mut term = 1.0;
+
+    if x <= 20.0 {
+        // The power series, whose terms are all positive.
+        let q = x * x / 4.0;
+        let mut k = 1.0;
+        while term > sum * 1.0e-17 {
+            term *= q / (k * k);
+            sum += term;
+            k += 1.0;
+        }
+        sum * Float::exp(-x)
+    } else {
+        // The asymptotic expansion, summed up to its smallest term.
+        for k in 1..60 {
+            let k = k as f64;
+            let ratio = (2.0 * k - 1.0) * (2.0 * k - 1.0) / (8.0 * k * x);
+            if ratio >= 1.0 || term < sum * 1.0e-17 {
+                break;
+            }
+            term *= ratio;
+            sum += term;
+        }
+        sum / Float::sqrt(2.0 * PI * x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::bessel_i0e;
+    use crate::*;
+
+    #[test]
+    fn test_bessel_i0e() {
+        // I₀(x) from its power series in exact arithmetic.
+        let cases = [
+            (0.0, 1.0),
+            (1.0, 1.266_065_877_752_008_4),
+            (2.0, 2.279_585_302_336_067),
+            (10.0, 2_815.716_628_466_25),
+        ];
+        for &(x, i0) in &cases {
+            assert_that!(bessel_i0e(x), close_to(i0 * (-x).exp(), 1.0e-15));
+            assert_eq!(bessel_i0e(-x), bessel_i0e(x));
+        }
+        for &(x, i0e) in &[
+            (20.0, 0.089_780_311_884_826_6),
+            (25.0, 0.080_196_773_547_437_2),
+        ] {
+            assert_that!(bessel_i0e(x), close_to(i0e, 1.0e-15));
+        }
+        assert_that!(
+            bessel_i0e(1.0e12) * (2.0 * PI * 1.0e12).sqrt(),
+            close_to(1.0, 1.0e-12)
+        );
+    }
+
+    #[test]
+    fn test_von_mises_moments() {
+        // The mean resultant lengths I₁(κ) / I₀(κ).
+        let cases = [
+            (0.0, 0.0),
+            (0.5, 0.242_499_612_580_801_85),
+            (2.0, 0.697_774_657_964_008_2),
+            (10.0, 0.948_599_825_954_845_6),
+        ];
+        let mut rng = StdRng::seed_from_u64(0x766d);
+        for &(kappa, a) in &cases {
+            let mu = Degrees(300.0);
+            let dist = VonMises { mu, kappa };
+            let samples: Vec<Angle> = (&mut rng).sample_iter(dist).take(100_000).collect();
+            assert!(samples
+                .iter()
+                .all(|&alpha| matches!(alpha, Radians(_)) && alpha == alpha.normalized()));
+
+            let stats: CircularStats = samples.iter().collect();
+            assert_that!(stats.resultant_length(), close_to(a, 0.01));
+            if kappa > 0.0 {
+                let mean = stats.mean().unwrap();
+                assert_that!(mean.min_dist(mu).in_degrees(), close_to(0.0, 1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_von_mises_concentrated() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for &kappa in &[1.0e5, 1.0e7, 1.0e300, f64::INFINITY] {
+            let dist = VonMises {
+                mu: Degrees(0.0),
+                kappa,
+            };
+            for _ in 0..1000 {
+                let alpha: Angle = rng.sample(dist);
+                assert!(alpha.min_dist(Radians(0.0)).in_radians() < 0.05);
+            }
+        }
+
+        let dist = VonMises {
+            mu: Radians(1.0f32),
+            kappa: 1.0e8,
+        };
+        let offsets: Vec<f64> = (&mut rng)
+            .sample_iter(dist)
+            .take(10_000)
+            .map(|alpha: Angle<f32>| alpha.in_radians() as f64 - 1.0)
+            .collect();
+        let variance = offsets.iter().map(|d| d * d).sum::<f64>() / offsets.len() as f64;
+        assert_that!(variance.sqrt(), close_to(1.0e-4, 1.0e-5));
+
+        let dist = VonMises {
+            mu: Radians(0.0),
+            kappa: f64::NAN,
+        };
+        assert!(rng.sample(dist).is_nan());
+    }
+
+    #[test]
+    fn test_von_mises_negative_kappa() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let dist = VonMises {
+            mu: Degrees(10.0),
+            kappa: -5.0,
+        };
+        let stats: CircularStats = (&mut rng).sample_iter(dist).take(10_000).collect();
+        assert_that!(
+            stats.mean().unwrap().min_dist(Degrees(190.0)).in_degrees(),
+            close_to(0.0, 2.0)
+        );
+
+        let flipped = VonMises {
+            mu: Degrees(190.0),
+            kappa: 5.0,
+        };
+        for &x in &[0.0, 1.0, 3.0, 5.5] {
+            assert_that!(
+                dist.pdf(Radians(x)),
+                close_to(flipped.pdf(Radians(x)), 1.0e-15)
+            );
+        }
+    }
+
+    #[test]
+    fn test_wrapped_normal_variance() {
+        let mut rng = StdRng::seed_from_u64(0x776e);
+        for &sigma in &[Radians(0.3), Radians(1.0), Degrees(120.0), Radians(2.5)] {
+            let mu = Degrees(10.0);
+            let dist = WrappedNormal { mu, sigma };
+            let samples: Vec<Angle> = (&mut rng).sample_iter(dist).take(100_000).collect();
+            assert!(samples
+                .iter()
+                .all(|&alpha| matches!(alpha, Radians(_)) && alpha == alpha.normalized()));
+
+            let stats: CircularStats = samples.iter().collect();
+            let s = sigma.in_radians();
+            assert_that!(stats.variance(), close_to(1.0 - (-s * s / 2.0).exp(), 0.01));
+            let mean = stats.mean().unwrap();
+            assert_that!(mean.min_dist(mu).in_degrees(), close_to(0.0, 2.0));
+        }
+    }
+
+    #[test]
+    fn test_wrapped_normal_degenerate() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let dist = WrappedNormal {
+            mu: Degrees(-90.0),
+            sigma: Degrees(0.0),
+        };
+        for _ in 0..100 {
+            assert_that!(rng.sample(dist).in_degrees(), close_to(270.0, 1.0e-12));
+        }
+
+        for &sigma in &[20.0, 1.0e300, f64::INFINITY] {
+            let dist = WrappedNormal {
+                mu: Radians(1.0),
+                sigma: Radians(sigma),
+            };
+            let stats: CircularStats = (&mut rng).sample_iter(dist).take(10_000).collect();
+            assert!(stats.count() == 10_000 && stats.variance() > 0.95);
+        }
+
+        let dist = WrappedNormal {
+            mu: Radians(1.0),
+            sigma: Radians(0.0),
+        };
+        assert_eq!(dist.pdf(Radians(1.0)), f64::INFINITY);
+        assert_eq!(dist.pdf(Radians(2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_wrapped_normal_pdf() {
+        // The density integrates to one.
+        let n = 100_000;
+        for &sigma in &[0.01, 0.5, 1.999, 2.0, 3.0, 50.0] {
+            let dist = WrappedNormal {
+                mu: Degrees(200.0),
+                sigma: Radians(sigma),
+            };
+            let h = 2.0 * PI / n as f64;
+            let integral: f64 = (0..n).map(|i| dist.pdf(Radians(i as f64 * h)) * h).sum();
+            assert_that!(integral, close_to(1.0, 1.0e-9));
+        }
+
+        // Both series agree where they meet.
+        let below = WrappedNormal {
+            mu: Radians(0.0),
+            sigma: Radians(2.0 - 1.0e-12),
+        };
+        let above = WrappedNormal {
+            mu: Radians(0.0),
+            sigma: Radians(2.0),
+        };
+        for &x in &[0.0, 1.0, PI, -2.5] {
+            assert_that!(
+                below.pdf(Radians(x)),
+                close_to(above.pdf(Radians(x)), 1.0e-12)
+            );
+        }
+
+        // Small standard deviations are not approximated by a von Mises
+        // distribution.
+        let dist = WrappedNormal {
+            mu: Radians(0.0),
+            sigma: Radians(0.1),
+        };
+        assert_that!(
+            dist.pdf(Radians(0.3)),
+            close_to((-4.5f64).exp() / (0.1 * (2.0 * PI).sqrt()), 1.0e-12)
+        );
+        assert_eq!(dist.pdf(Radians(PI)), dist.pdf(Radians(-PI)));
+    }
+
+    #[test]
+    fn test_wrapped_normal_pdf_non_finite() {
+        for &sigma in &[0.5, 3.0] {
+            let dist = WrappedNormal {
+                mu: Radians(0.0),
+                sigma: Radians(sigma),
+            };
+            for &x in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+                assert!(dist.pdf(Radians(x)).is_nan());
+            }
+        }
+
+        for &sigma in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let dist = WrappedNormal {
+                mu: Radians(0.0),
+                sigma: Radians(sigma),
+            };
+            assert!(dist.pdf(Radians(1.0)).is_nan());
+        }
+
+        let dist = WrappedNormal {
+            mu: Radians(f64::NAN),
+            sigma: Radians(1.0),
+        };
+        assert!(dist.pdf(Radians(1.0)).is_nan());
+    }
+
+    #[test]
+    fn test_von_mises_pdf() {
+        let dist = VonMises {
+            mu: Radians(0.0),
+            kappa: 1.0,
+        };
+        assert_that!(
+            dist.pdf(Radians(0.0)),
+            close_to(0.341_710_488_623_463_2, 1.0e-15)
+        );
+        assert_that!(
+            dist.pdf(Degrees(360.0)),
+            close_to(0.341_710_488_623_463_2, 1.0e-15)
+        );
+
+        // The density integrates to one, even where e^κ overflows.
+        let n = 100_000;
+        for &kappa in &[0.0, 0.5, 10.0, 800.0] {
+            let dist = VonMises {
+                mu: Degrees(45.0),
+                kappa,
+            };
+            let h = 2.0 * PI / n as f64;
+            let integral: f64 = (0..n).map(|i| dist.pdf(Radians(i as f64 * h)) * h).sum();
+            assert_that!(integral, close_to(1.0, 1.0e-9));
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Degrees, minutes and seconds.
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt::{Display, Error, Formatter};
+use num_traits::cast::NumCast;
+
+use crate::{
+    degrees_f64,
+    hms::{round_seconds, split_seconds},
+    Angle,
+};
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Format the angle in whole degrees, whole minutes and decimal seconds,
+    /// e.g. `48°51'29.60"`, with the seconds rounded to `decimals` places (at
+    /// most 9).
+    ///
+    /// Rounding carries into the minutes and degrees, so the seconds are
+    /// always below 60. Negative angles have a single leading minus, unless
+    /// they round to zero. An angle which is NaN or infinite prints as `NaN`,
+    /// `inf` or `-inf`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Degrees(48.858_222_2f64);
+    /// assert_eq!(alpha.display_dms(2).to_string(), "48°51'29.60\"");
+    /// assert_eq!((-alpha).display_dms(0).to_string(), "-48°51'30\"");
+    /// ```
+    pub fn display_dms(self, decimals: usize) -> Dms {
+        Dms {
+            degrees: degrees_f64(self).unwrap_or(f64::NAN),
+            decimals: decimals.min(9),
+        }
+    }
+
+    /// Format the angle in degrees, minutes and decimal seconds into a
+    /// string. See [`display_dms`](#method.display_dms) for details.
+    #[cfg(feature = "alloc")]
+    pub fn format_dms(self, decimals: usize) -> String {
+        self.display_dms(decimals).to_string()
+    }
+}
+
+/// Helper struct for formatting an angle in degrees, minutes and decimal
+/// seconds.
+///
+/// This `struct` is created by the [`display_dms`] method on [`Angle`]. See its
+/// documentation for more.
+///
+/// [`display_dms`]: enum.Angle.html#method.display_dms
+/// [`Angle`]: enum.Angle.html
+#[derive(Copy, Clone, Debug)]
+pub struct Dms {
+    degrees: f64,
+    decimals: usize,
+}
+
+impl Display for Dms {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let (degrees, rest, scale) = match round_seconds(self.degrees, self.decimals) {
+            Some(rounded) => rounded,
+            None => return write!(f, "{}", self.degrees),
+        };
+        if self.degrees.is_sign_negative() && (degrees != 0.0 || rest != 0) {
+            write!(f, "-")?;
+        }
+
+        let (minutes, seconds) = split_seconds(rest, scale);
+        write!(f, "{}°{:02}'{:02}", degrees, minutes, seconds / scale)?;
+        if self.decimals > 0 {
+            write!(f, ".{:0d$}", seconds % scale, d = self.decimals)?;
+        }
+        write!(f, "\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_display_dms() {
+        let alpha = Degrees(48.858_222_2);
+        assert_eq!(alpha.display_dms(2).to_string(), "48°51'29.60\"");
+        assert_eq!(alpha.display_dms(0).to_string(), "48°51'30\"");
+        assert_eq!((-alpha).display_dms(1).to_string(), "-48°51'29.6\"");
+        assert_eq!(Degrees(7.1).format_dms(0), "7°06'00\"");
+        assert_eq!(Degrees(-0.5).format_dms(0), "-0°30'00\"");
+        assert_eq!(Degrees(-0.0001).format_dms(2), "-0°00'00.36\"");
+        assert_eq!(Radians(core::f64::consts::PI).format_dms(0), "180°00'00\"");
+        assert_eq!(Degrees(400i32).format_dms(1), "400°00'00.0\"");
+    }
+
+    #[test]
+    fn test_display_dms_zero() {
+        assert_eq!(Degrees(0.0).format_dms(0), "0°00'00\"");
+        assert_eq!(Degrees(0.0).format_dms(3), "0°00'00.000\"");
+        assert_eq!(Degrees(-0.0).format_dms(1), "0°00'00.0\"");
+        // Negative angles which round to zero have no sign.
+        assert_eq!(Degrees(-0.000_001).format_dms(2), "0°00'00.00\"");
+        assert_eq!(Degrees(-0.000_002).format_dms(2), "-0°00'00.01\"");
+    }
+
+    #[test]
+    fn test_display_dms_out_of_range() {
+        assert_eq!(Degrees(f64::NAN).format_dms(2), "NaN");
+        assert_eq!(Degrees(f64::INFINITY).format_dms(2), "inf");
+        assert_eq!(Radians(f64::NEG_INFINITY).format_dms(0), "-inf");
+        assert_eq!(
+            Degrees(1.0e20).format_dms(9),
+            "100000000000000000000°00'00.000000000\""
+        );
+        assert_eq!(
+            Degrees(-1.0e20).format_dms(0),
+            "-100000000000000000000°00'00\""
+        );
+    }
+
+    #[test]
+    fn test_display_dms_carries() {
+        // 29.999" rounds up into the next tenth of a second.
+        let alpha = Degrees(10.0 + 51.0 / 60.0 + 29.999 / 3600.0);
+        assert_eq!(alpha.format_dms(1), "10°51'30.0\"");
+        assert_eq!(alpha.format_dms(3), "10°51'29.999\"");
+
+        // 59.96" rolls the minute, and 59'59.96" the degree.
+        let alpha = Degrees(10.0 + 20.0 / 60.0 + 59.96 / 3600.0);
+        assert_eq!(alpha.format_dms(1), "10°21'00.0\"");
+        let alpha = Degrees(10.0 + 59.0 / 60.0 + 59.96 / 3600.0);
+        assert_eq!(alpha.format_dms(1), "11°00'00.0\"");
+        assert_eq!(alpha.format_dms(2), "10°59'59.96\"");
+        assert_eq!((-alpha).format_dms(1), "-11°00'00.0\"");
+        assert_eq!((-alpha).format_dms(0), "-11°00'00\"");
+        let alpha = Degrees(359.0 + 59.0 / 60.0 + 59.6 / 3600.0);
+        assert_eq!(alpha.format_dms(0), "360°00'00\"");
+    }
+
+    #[test]
+    fn test_parse_dms_round_trip() {
+        for &(text, decimals) in &[
+            ("48°51'29.60\"", 2),
+            ("-0°30'00\"", 0),
+            ("123°06'00.1000\"", 4),
+            ("-11°00'00.0\"", 1),
+        ] {
+            let angle = Angle::from_dms_str(text).unwrap();
+            assert_eq!(angle.format_dms(decimals), text);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Interoperability with the angles and rotations of the `euclid` crate.
+
+use euclid::Rotation2D;
+use num_traits::cast::NumCast;
+
+use crate::{Angle, Radians};
+
+impl<T> From<euclid::Angle<T>> for Angle<T> {
+    #[inline]
+    fn from(angle: euclid::Angle<T>) -> Self {
+        Radians(angle.radians)
+    }
+}
+
+impl<T: Copy + NumCast> From<Angle<T>> for euclid::Angle<T> {
+    #[inline]
+    fn from(angle: Angle<T>) -> Self {
+        euclid::Angle::radians(angle.in_radians())
+    }
+}
+
+impl<T: Copy + NumCast, Src, Dst> From<Angle<T>> for Rotation2D<T, Src, Dst> {
+    #[inline]
+    fn from(angle: Angle<T>) -> Self {
+        angle.to_rotation2d()
+    }
+}
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Build a `euclid` rotation by the angle from the coordinate space `Src`
+    /// to `Dst`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// use euclid::{default::Rotation2D, point2};
+    ///
+    /// let rotation: Rotation2D<f64> = Degrees(30.0).to_rotation2d();
+    /// let p = rotation.transform_point(point2(1.0, 0.0));
+    /// assert!((p.y - 0.5).abs() < 1.0e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn to_rotation2d<Src, Dst>(self) -> Rotation2D<T, Src, Dst> {
+        Rotation2D::new(self.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use euclid::default::{Point2D, Rotation2D};
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_euclid_radians_round_trip() {
+        for &v in &[0.1f64, -1.0e-300, 1.0e300, 123.456_789_012_345, f64::MAX] {
+            let angle: euclid::Angle<f64> = Radians(v).into();
+            assert_eq!(angle.radians.to_bits(), v.to_bits());
+            assert_eq!(Angle::from(angle), Radians(v));
+        }
+        let angle: euclid::Angle<f32> = Radians(0.1f32).into();
+        assert_eq!(Angle::from(angle), Radians(0.1f32));
+    }
+
+    #[test]
+    fn test_euclid_degrees() {
+        for &v in &[0.0, 30.0, -90.0, 180.0, 725.0] {
+            let angle: euclid::Angle<f64> = Degrees(v).into();
+            assert_that!(angle.radians, close_to(v.to_radians(), 1.0e-12));
+            assert_that!(Angle::from(angle).in_degrees(), close_to(v, 1.0e-10));
+        }
+        let angle: euclid::Angle<f64> = Degrees(180.0).into();
+        assert_that!(angle.radians, close_to(PI, 1.0e-15));
+    }
+
+    #[test]
+    fn test_euclid_rotation2d() {
+        let p = Point2D::new(2.0, -0.5);
+        for &v in &[0.0, 30.0, -135.0, 400.0] {
+            let rotation: Rotation2D<f64> = Degrees(v).into();
+            let expected = Degrees(v).to_rotation2d().transform_point(p);
+            let rotated = rotation.transform_point(p);
+            assert_eq!(rotated, expected);
+            let (sin, cos) = v.to_radians().sin_cos();
+            assert_that!(rotated.x, close_to(p.x * cos - p.y * sin, 1.0e-12));
+            assert_that!(rotated.y, close_to(p.x * sin + p.y * cos, 1.0e-12));
+        }
+    }
+}
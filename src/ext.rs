@@ -0,0 +1,586 @@
+//! Extension traits for collections of angles.
+
+use core::borrow::Borrow;
+use num_traits::Signed;
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::stats;
+use crate::{Angle, AngleUnit, Convertible};
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+
+/// Methods on slices of angles.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let mut headings = vec![Degrees(-10.0f64), Degrees(370.0)];
+/// headings.normalized_in_place();
+/// assert_eq!(headings, [Degrees(350.0), Degrees(10.0)]);
+///
+/// let mu = headings.circular_mean().unwrap();
+/// assert!(mu.min_dist(Degrees(0.0)).in_degrees() < 1.0e-10);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+pub trait AngleSliceExt<T> {
+    /// Compute the circular mean of the angles. See
+    /// [`mean_angle`](fn.mean_angle.html).
+    fn circular_mean(&self) -> Option<Angle<T>>;
+
+    /// Compute the circular variance of the angles. See
+    /// [`circular_variance`](fn.circular_variance.html).
+    fn circular_variance(&self) -> T;
+
+    /// Normalize every angle into the range of [0, 2π) rad, keeping the unit
+    /// each is stored in.
+    fn normalized_in_place(&mut self);
+
+    /// Find the angle with the smallest [`min_dist`] to `target`, or `None`
+    /// if the slice is empty.
+    ///
+    /// [`min_dist`]: enum.Angle.html#method.min_dist
+    fn min_dist_to(&self, target: Angle<T>) -> Option<&Angle<T>>;
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> AngleSliceExt<T> for [Angle<T>] {
+    #[inline]
+    fn circular_mean(&self) -> Option<Angle<T>> {
+        stats::mean(self)
+    }
+
+    #[inline]
+    fn circular_variance(&self) -> T {
+        stats::variance(self)
+    }
+
+    #[inline]
+    fn normalized_in_place(&mut self) {
+        for angle in self.iter_mut() {
+            *angle = angle.normalized();
+        }
+    }
+
+    #[inline]
+    fn min_dist_to(&self, target: Angle<T>) -> Option<&Angle<T>> {
+        self.iter().fold(None, |nearest, angle| match nearest {
+            Some(nearest) if target.min_dist(*nearest) <= target.min_dist(*angle) => Some(nearest),
+            _ => Some(angle),
+        })
+    }
+}
+
+/// Methods on iterators of angles, or references to angles.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let total = (0..8).map(|i| Degrees(i as f64 * 45.0)).turning_sum();
+/// assert!((total.in_degrees() - 315.0).abs() < 1.0e-10);
+/// ```
+pub trait AngleIteratorExt<T>: Iterator + Sized {
+    /// Compute the circular mean of the angles. See
+    /// [`mean_angle`](fn.mean_angle.html).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn circular_mean(self) -> Option<Angle<T>>
+    where
+        T: Float;
+
+    /// Compute the total signed rotation along the angles. See
+    /// [`turning_sum`](fn.turning_sum.html).
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn turning_sum(self) -> Angle<T>
+    where
+        T: Float;
+
+    /// Create an iterator yielding the shortest signed difference between
+    /// each consecutive pair of angles, in the range of (-π, π] rad.
+    /// Counter-clockwise turns are positive.
+    ///
+    /// The differences are in the unit of the first angle, converting the
+    /// others where needed. An iterator of `n` angles yields `n - 1`
+    /// differences. The values must be of a signed type, since the
+    /// differences may be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let headings = [Degrees(350.0), Degrees(10.0), Degrees(5.0)];
+    /// let diffs: Vec<_> = headings.iter().circular_diffs().collect();
+    /// assert_eq!(diffs, [Degrees(20.0), Degrees(-5.0)]);
+    /// ```
+    fn circular_diffs(self) -> CircularDiffs<Self, T>;
+
+    /// Create an iterator yielding the plain difference between each
+    /// consecutive pair of angles, for angles which have already been
+    /// unwrapped.
+    ///
+    /// Follows the same conventions as
+    /// [`circular_diffs`](#tymethod.circular_diffs) for units.
+    fn circular_diffs_raw(self) -> CircularDiffs<Self, T>;
+
+    /// Create an iterator removing the jumps of a full turn from a sequence
+    /// of wrapped angles, like `numpy.unwrap`, so that it can be
+    /// differentiated. See [`unwrapped_with_threshold`] with a threshold of
+    /// half a turn.
+    ///
+    /// [`unwrapped_with_threshold`]: #tymethod.unwrapped_with_threshold
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let encoder = [Degrees(340.0f64), Degrees(355.0), Degrees(10.0), Degrees(25.0)];
+    /// let angles: Vec<_> = encoder.iter().unwrapped().collect();
+    /// assert_eq!(angles, [Degrees(340.0), Degrees(355.0), Degrees(370.0), Degrees(385.0)]);
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn unwrapped(self) -> Unwrapped<Self, T>
+    where
+        T: Float;
+
+    /// Create an iterator removing the jumps of a full turn from a sequence
+    /// of wrapped angles. Whenever consecutive angles differ by at least
+    /// `threshold`, a multiple of a full turn is added to all later angles to
+    /// bring the difference into the range of (-π, π] rad. A threshold
+    /// below half a turn therefore acts as half a turn.
+    ///
+    /// The angles are in the unit of the first angle, converting the others
+    /// where needed. An angle which is NaN or infinite is yielded as it is
+    /// and the next angle starts afresh, as if it were the first.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn unwrapped_with_threshold(self, threshold: Angle<T>) -> Unwrapped<Self, T>
+    where
+        T: Float;
+}
+
+impl<T, I> AngleIteratorExt<T> for I
+where
+    I: Iterator,
+    I::Item: Borrow<Angle<T>>,
+{
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn circular_mean(self) -> Option<Angle<T>>
+    where
+        T: Float,
+    {
+        stats::mean(self)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn turning_sum(self) -> Angle<T>
+    where
+        T: Float,
+    {
+        stats::turning(self)
+    }
+
+    #[inline]
+    fn circular_diffs(self) -> CircularDiffs<Self, T> {
+        CircularDiffs::new(self, true)
+    }
+
+    #[inline]
+    fn circular_diffs_raw(self) -> CircularDiffs<Self, T> {
+        CircularDiffs::new(self, false)
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn unwrapped(self) -> Unwrapped<Self, T>
+    where
+        T: Float,
+    {
+        self.unwrapped_with_threshold(Angle::Degrees(T::from(180).unwrap()))
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn unwrapped_with_threshold(self, threshold: Angle<T>) -> Unwrapped<Self, T>
+    where
+        T: Float,
+    {
+        Unwrapped {
+            iter: self,
+            threshold,
+            unit: None,
+            prev: None,
+            offset: T::zero(),
+        }
+    }
+}
+
+/// Remove the jumps of a full turn from a slice of wrapped angles, like
+/// `numpy.unwrap`. See
+/// [`AngleIteratorExt::unwrapped`](trait.AngleIteratorExt.html#tymethod.unwrapped).
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let encoder = [Radians(6.0f64), Radians(0.1), Radians(0.5)];
+/// let angles = unwrap_angles(&encoder);
+///
+/// assert_eq!(angles[0], Radians(6.0));
+/// assert!((angles[2].in_radians() - (0.5 + 2.0 * core::f64::consts::PI)).abs() < 1.0e-12);
+/// ```
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+#[inline]
+pub fn unwrap_angles<T: Float>(angles: &[Angle<T>]) -> Vec<Angle<T>> {
+    angles.iter().unwrapped().collect()
+}
+
+/// An iterator over the differences between consecutive angles.
+///
+/// This `struct` is created by the [`circular_diffs`] and
+/// [`circular_diffs_raw`] methods on [`AngleIteratorExt`]. See their
+/// documentation for more.
+///
+/// [`circular_diffs`]: trait.AngleIteratorExt.html#tymethod.circular_diffs
+/// [`circular_diffs_raw`]: trait.AngleIteratorExt.html#tymethod.circular_diffs_raw
+/// [`AngleIteratorExt`]: trait.AngleIteratorExt.html
+#[derive(Clone, Debug)]
+pub struct CircularDiffs<I, T> {
+    iter: I,
+    prev: Option<(T, AngleUnit)>,
+    wrap: bool,
+}
+
+impl<I, T> CircularDiffs<I, T> {
+    #[inline]
+    fn new(iter: I, wrap: bool) -> Self {
+        CircularDiffs {
+            iter,
+            prev: None,
+            wrap,
+        }
+    }
+}
+
+impl<I, T> Iterator for CircularDiffs<I, T>
+where
+    I: Iterator,
+    I::Item: Borrow<Angle<T>>,
+    T: Copy + Convertible + PartialOrd + Signed,
+{
+    type Item = Angle<T>;
+
+    fn next(&mut self) -> Option<Angle<T>> {
+        let (prev, unit) = match self.prev {
+            Some(prev) => prev,
+            None => self.iter.next()?.borrow().into_parts(),
+        };
+
+        let next = *self.iter.next()?.borrow();
+        let next = match unit {
+            AngleUnit::Radians => next.in_radians(),
+            AngleUnit::Degrees => next.in_degrees(),
+        };
+        self.prev = Some((next, unit));
+
+        let diff = if self.wrap {
+            // Reduce both sides first so that integer values far apart cannot
+            // overflow the subtraction.
+            let full: T = unit.full_turn_in();
+            let half = full / (T::one() + T::one());
+            let diff = (next % full - prev % full) % full;
+            if diff > half {
+                diff - full
+            } else if diff <= -half {
+                diff + full
+            } else {
+                diff
+            }
+        } else {
+            next - prev
+        };
+        Some(Angle::from_parts(diff, unit))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.prev.is_some() {
+            (lower, upper)
+        } else {
+            (lower.saturating_sub(1), upper.map(|n| n.saturating_sub(1)))
+        }
+    }
+}
+
+/// An iterator removing the jumps of a full turn from wrapped angles.
+///
+/// This `struct` is created by the [`unwrapped`] and
+/// [`unwrapped_with_threshold`] methods on [`AngleIteratorExt`]. See their
+/// documentation for more.
+///
+/// [`unwrapped`]: trait.AngleIteratorExt.html#tymethod.unwrapped
+/// [`unwrapped_with_threshold`]: trait.AngleIteratorExt.html#tymethod.unwrapped_with_threshold
+/// [`AngleIteratorExt`]: trait.AngleIteratorExt.html
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Debug)]
+pub struct Unwrapped<I, T> {
+    iter: I,
+    threshold: Angle<T>,
+    unit: Option<AngleUnit>,
+    prev: Option<T>,
+    offset: T,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<I, T> Iterator for Unwrapped<I, T>
+where
+    I: Iterator,
+    I::Item: Borrow<Angle<T>>,
+    T: Float,
+{
+    type Item = Angle<T>;
+
+    fn next(&mut self) -> Option<Angle<T>> {
+        let angle = *self.iter.next()?.borrow();
+        let unit = *self.unit.get_or_insert(angle.unit());
+        let value = angle.value_in(unit);
+
+        if !value.is_finite() {
+            self.prev = None;
+            self.offset = T::zero();
+            return Some(Angle::from_parts(value, unit));
+        }
+
+        if let Some(prev) = self.prev {
+            let diff = value - prev;
+            if diff.abs() >= self.threshold.value_in(unit) {
+                let full: T = unit.full_turn_in();
+                let half = full / (T::one() + T::one());
+                let mut wrapped = diff % full;
+                if wrapped > half {
+                    wrapped = wrapped - full;
+                } else if wrapped <= -half {
+                    wrapped = wrapped + full;
+                }
+                self.offset = self.offset + wrapped - diff;
+            }
+        }
+        self.prev = Some(value);
+
+        Some(Angle::from_parts(value + self.offset, unit))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_slice_ext() {
+        let angles = [Degrees(20.0), Degrees(350.0), Radians(0.1)];
+        let vec = angles.to_vec();
+
+        assert_eq!(angles.circular_mean(), mean_angle(&angles));
+        assert_eq!(vec.circular_mean(), mean_angle(&angles));
+        assert_eq!(angles.circular_variance(), circular_variance(&angles));
+        assert_eq!(vec.circular_variance(), circular_variance(&angles));
+    }
+
+    #[test]
+    fn test_slice_ext_normalized_in_place() {
+        let mut angles = [Degrees(-90.0), Radians(7.0), Degrees(45.0)];
+        let mut vec = angles.to_vec();
+        angles.normalized_in_place();
+        vec.normalized_in_place();
+        assert_eq!(vec, angles);
+
+        assert_eq!(angles[0], Degrees(270.0));
+        assert!(matches!(angles[1], Radians(_)));
+        assert_that!(
+            angles[1].in_radians(),
+            close_to(7.0 - 2.0 * core::f64::consts::PI, 0.000001)
+        );
+        assert_eq!(angles[2], Degrees(45.0));
+    }
+
+    #[test]
+    fn test_slice_ext_min_dist_to() {
+        let angles = [Degrees(90.0), Degrees(355.0), Degrees(180.0)];
+        assert_eq!(angles.min_dist_to(Degrees(10.0)), Some(&Degrees(355.0)));
+        assert_eq!(angles.min_dist_to(Degrees(100.0)), Some(&Degrees(90.0)));
+        assert_eq!(
+            angles.to_vec().min_dist_to(Degrees(-170.0)),
+            Some(&Degrees(180.0))
+        );
+        assert_eq!([].min_dist_to(Degrees(0.0)), None);
+    }
+
+    #[test]
+    fn test_iterator_ext() {
+        let angles = [Degrees(20.0), Degrees(350.0), Radians(0.1)];
+        let vec = angles.to_vec();
+
+        assert_eq!(angles.iter().circular_mean(), mean_angle(&angles));
+        assert_eq!(angles.iter().copied().circular_mean(), mean_angle(&angles));
+        assert_eq!(angles.iter().turning_sum(), turning_sum(&angles));
+        assert_eq!(vec.into_iter().turning_sum(), turning_sum(&angles));
+    }
+
+    #[test]
+    fn test_circular_diffs() {
+        let ramp: Vec<_> = (0..10)
+            .map(|i| Degrees(340.0 + 5.0 * i as f64).normalized())
+            .collect();
+        let diffs: Vec<_> = ramp.iter().circular_diffs().collect();
+        assert_eq!(diffs.len(), 9);
+        for diff in diffs {
+            assert_that!(diff.in_degrees(), close_to(5.0, 0.000001));
+        }
+
+        let backwards = [Radians(0.1), Radians(6.2), Radians(6.0)];
+        let diffs: Vec<_> = backwards.iter().copied().circular_diffs().collect();
+        assert_that!(
+            diffs[0].in_radians(),
+            close_to(6.1 - 2.0 * core::f64::consts::PI, 0.000001)
+        );
+        assert_that!(diffs[1].in_radians(), close_to(-0.2, 0.000001));
+
+        #[cfg(not(feature = "no-panic"))]
+        {
+            let constant = [Degrees(90), Degrees(90), Degrees(90)];
+            assert!(constant.iter().circular_diffs().all(|d| d == Degrees(0)));
+            assert_eq!(
+                [Degrees(180), Degrees(0), Degrees(-180)]
+                    .iter()
+                    .circular_diffs()
+                    .collect::<Vec<_>>(),
+                [Degrees(180), Degrees(180)]
+            );
+            assert_eq!(
+                [Degrees(-32000i16), Degrees(32000)]
+                    .iter()
+                    .circular_diffs()
+                    .collect::<Vec<_>>(),
+                [Degrees(-80)]
+            );
+        }
+
+        assert_eq!([Degrees(1.0)].iter().circular_diffs().next(), None);
+        assert_eq!(core::iter::empty::<Angle>().circular_diffs().next(), None);
+    }
+
+    #[test]
+    fn test_circular_diffs_units() {
+        let mixed = [Degrees(350.0), Radians(0.0), Degrees(10.0)];
+        let diffs: Vec<_> = mixed.iter().circular_diffs().collect();
+        assert!(diffs.iter().all(|d| matches!(d, Degrees(_))));
+        assert_that!(diffs[0].in_degrees(), close_to(10.0, 0.000001));
+        assert_that!(diffs[1].in_degrees(), close_to(10.0, 0.000001));
+
+        let mixed = [Radians(0.0), Degrees(90.0)];
+        let diffs: Vec<_> = mixed.iter().circular_diffs().collect();
+        assert!(matches!(diffs[0], Radians(_)));
+        assert_that!(diffs[0].in_degrees(), close_to(90.0, 0.000001));
+    }
+
+    #[test]
+    fn test_circular_diffs_raw() {
+        let unwrapped = [Degrees(340.0), Degrees(365.0), Degrees(720.0)];
+        let diffs: Vec<_> = unwrapped.iter().circular_diffs_raw().collect();
+        assert_eq!(diffs, [Degrees(25.0), Degrees(355.0)]);
+
+        let iter = unwrapped.iter().circular_diffs_raw();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn test_unwrapped() {
+        // A smooth ramp in both directions, wrapped into [0, 2π).
+        let ramp: Vec<Angle> = (0..400)
+            .map(|i| {
+                let t = i as f64 * 0.05;
+                Radians(0.3 + 4.0 * t - 0.25 * t * t)
+            })
+            .collect();
+        let wrapped: Vec<Angle> = ramp.iter().map(|angle| angle.normalized()).collect();
+
+        let unwrapped = unwrap_angles(&wrapped);
+        assert_eq!(unwrapped.len(), ramp.len());
+        for (angle, expected) in unwrapped.iter().zip(&ramp) {
+            assert!(matches!(angle, Radians(_)));
+            assert_that!(angle.in_radians(), close_to(expected.in_radians(), 1.0e-9));
+        }
+
+        let wrapped: Vec<Angle<f32>> = (0..100)
+            .map(|i| Degrees(i as f32 * -25.0).normalized())
+            .collect();
+        for (i, angle) in wrapped.iter().unwrapped().enumerate() {
+            assert!(matches!(angle, Degrees(_)));
+            assert_that!(
+                angle.in_degrees() as f64,
+                close_to(i as f64 * -25.0, 1.0e-3)
+            );
+        }
+
+        assert_eq!(unwrap_angles::<f64>(&[]), []);
+        assert_eq!(unwrap_angles(&[Degrees(400.0)]), [Degrees(400.0)]);
+        assert_eq!(wrapped.iter().unwrapped().size_hint(), (100, Some(100)));
+    }
+
+    #[test]
+    fn test_unwrapped_threshold() {
+        let angles = [Degrees(0.0), Degrees(200.0), Degrees(10.0)];
+        assert_eq!(
+            unwrap_angles(&angles),
+            [Degrees(0.0), Degrees(-160.0), Degrees(10.0)]
+        );
+
+        // Jumps short of the threshold are taken as they are.
+        let unwrapped: Vec<_> = angles
+            .iter()
+            .unwrapped_with_threshold(Degrees(270.0))
+            .collect();
+        assert_eq!(unwrapped, [Degrees(0.0), Degrees(200.0), Degrees(10.0)]);
+
+        // A threshold below half a turn changes nothing.
+        let unwrapped: Vec<_> = angles
+            .iter()
+            .unwrapped_with_threshold(Radians(0.1))
+            .collect();
+        assert_eq!(unwrapped, unwrap_angles(&angles));
+
+        // Later angles follow the unit of the first.
+        let unwrapped = unwrap_angles(&[Degrees(350.0), Radians(0.0), Degrees(5.0)]);
+        assert_eq!(unwrapped, [Degrees(350.0), Degrees(360.0), Degrees(365.0)]);
+    }
+
+    #[test]
+    fn test_unwrapped_nan() {
+        let angles = [
+            Degrees(350.0),
+            Degrees(10.0),
+            Degrees(f64::NAN),
+            Degrees(350.0),
+            Degrees(10.0),
+            Degrees(f64::INFINITY),
+            Degrees(20.0),
+        ];
+        let unwrapped = unwrap_angles(&angles);
+        assert_eq!(unwrapped[..2], [Degrees(350.0), Degrees(370.0)]);
+        assert!(unwrapped[2].is_nan());
+        assert_eq!(unwrapped[3..5], [Degrees(350.0), Degrees(370.0)]);
+        assert_eq!(unwrapped[5], Degrees(f64::INFINITY));
+        assert_eq!(unwrapped[6], Degrees(20.0));
+    }
+}
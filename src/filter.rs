@@ -0,0 +1,218 @@
+//! Filters fusing angular measurements.
+
+use core::time::Duration;
+use num_traits::{cast::cast, Float};
+
+use crate::stats::wrap_signed;
+use crate::{Angle, AngleDelta, AngularVelocity, Radians};
+
+/// A complementary filter, fusing an angular rate, such as from a gyroscope,
+/// with measurements of the absolute angle, such as from an accelerometer or
+/// an encoder.
+///
+/// Each update integrates the rate and then blends the result towards the
+/// absolute measurement along the shortest path, with a weight of
+/// α = τ/(τ+dt) on the integrated angle. Rates dominate over periods shorter
+/// than the time constant τ, and absolute measurements over longer ones, so
+/// a constant bias in the rate only offsets the angle by about the bias
+/// times τ instead of drifting without bound.
+///
+/// Absolute measurements may be sparse: the time integrated without one
+/// since the last counts towards dt, so a measurement after a long gap
+/// corrects as strongly as a stream of them over the same period would.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// # use std::time::Duration;
+/// let mut filter = ComplementaryFilter::new(Duration::from_millis(500));
+/// let rate = AngularVelocity::degrees_per_second(0.0f64);
+/// let dt = Duration::from_millis(10);
+///
+/// for _ in 0..1000 {
+///     filter.update(rate, Degrees(30.0), dt);
+/// }
+/// assert!(filter.angle().min_dist(Degrees(30.0)).in_degrees() < 1.0e-6);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ComplementaryFilter<T = f64> {
+    time_constant: T,
+    angle: Angle<T>,
+    unmeasured: T,
+}
+
+impl<T: Float> ComplementaryFilter<T> {
+    /// Create a filter with the given time constant, starting at an angle of
+    /// zero.
+    #[inline]
+    pub fn new(time_constant: Duration) -> ComplementaryFilter<T> {
+        ComplementaryFilter {
+            time_constant: cast(time_constant.as_secs_f64()).unwrap(),
+            angle: Radians(T::zero()),
+            unmeasured: T::zero(),
+        }
+    }
+
+    /// Yield the current estimate of the angle, normalized into a single
+    /// turn. The estimate is in the unit of the angle the filter was last
+    /// [`reset`](#method.reset) to, or in radians if it never was, so it is in
+    /// the range of [0, 360)° after resetting to an angle in degrees.
+    #[inline]
+    pub fn angle(&self) -> Angle<T> {
+        self.angle
+    }
+
+    /// Restart the filter from the given angle.
+    #[inline]
+    pub fn reset(&mut self, angle: Angle<T>) {
+        self.angle = angle.normalized();
+        self.unmeasured = T::zero();
+    }
+
+    /// Integrate the `rate` over `dt` without an absolute measurement,
+    /// returning the new estimate of the angle.
+    #[inline]
+    pub fn integrate(&mut self, rate: AngularVelocity<T>, dt: Duration) -> Angle<T> {
+        self.unmeasured = self.unmeasured + cast(dt.as_secs_f64()).unwrap();
+        self.angle = (self.angle + AngleDelta::new(rate.over(dt))).normalized();
+        self.angle
+    }
+
+    /// Integrate the `rate` over `dt` and blend in the `absolute` measurement
+    /// of the angle, returning the new estimate of the angle.
+    pub fn update(
+        &mut self,
+        rate: AngularVelocity<T>,
+        absolute: Angle<T>,
+        dt: Duration,
+    ) -> Angle<T> {
+        let dt: T = cast(dt.as_secs_f64()).unwrap();
+        let elapsed = self.unmeasured + dt;
+        self.unmeasured = T::zero();
+        let alpha = if self.time_constant + elapsed > T::zero() {
+            self.time_constant / (self.time_constant + elapsed)
+        } else {
+            T::zero()
+        };
+
+        let predicted = self.angle + AngleDelta::new(rate.angle_per_second() * dt);
+        let correction = wrap_signed(absolute.in_radians() - predicted.in_radians());
+        let correction = Radians(correction * (T::one() - alpha));
+        self.angle = (predicted + AngleDelta::new(correction)).normalized();
+        self.angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    const DT: Duration = Duration::from_millis(10);
+
+    #[test]
+    fn test_complementary_filter_converges() {
+        let mut filter = ComplementaryFilter::new(Duration::from_secs(1));
+        filter.reset(Degrees(90.0));
+        assert_eq!(filter.angle(), Degrees(90.0));
+
+        let rate = AngularVelocity::degrees_per_second(10.0);
+        let mut truth = Degrees(0.0);
+        for _ in 0..2000 {
+            truth = (truth + AngleDelta::new(rate.over(DT))).normalized();
+            filter.update(rate, truth, DT);
+        }
+        assert_that!(
+            filter.angle().min_dist(truth).in_degrees(),
+            close_to(0.0, 1.0e-6)
+        );
+
+        for _ in 0..100 {
+            truth = (truth + AngleDelta::new(rate.over(DT))).normalized();
+            filter.integrate(rate, DT);
+        }
+        assert_that!(
+            filter.angle().min_dist(truth).in_degrees(),
+            close_to(0.0, 1.0e-6)
+        );
+    }
+
+    #[test]
+    fn test_complementary_filter_bias() {
+        let tau = 0.5;
+        let bias = 2.0;
+        let mut filter = ComplementaryFilter::new(Duration::from_millis(500));
+
+        let mut truth = Degrees(0.0);
+        let mut worst: f64 = 0.0;
+        for step in 0..100_000 {
+            let rate =
+                AngularVelocity::degrees_per_second(if step < 50_000 { 20.0 } else { -35.0 });
+            truth = (truth + AngleDelta::new(rate.over(DT))).normalized();
+            let biased = AngularVelocity::degrees_per_second(rate.in_degrees_per_second() + bias);
+            filter.update(biased, truth, DT);
+            worst = worst.max(filter.angle().min_dist(truth).in_degrees());
+        }
+
+        // The offset settles at about the bias times the time constant.
+        assert!(worst <= bias * tau + 1.0e-9);
+        assert_that!(
+            filter.angle().min_dist(truth).in_degrees(),
+            close_to(bias * tau, 0.05)
+        );
+    }
+
+    #[test]
+    fn test_complementary_filter_seam() {
+        let mut filter = ComplementaryFilter::new(Duration::from_millis(200));
+        filter.reset(Degrees(350.0));
+
+        let rate = AngularVelocity::degrees_per_second(50.0);
+        let mut truth = Degrees(350.0);
+        let mut previous = filter.angle();
+        for _ in 0..2000 {
+            truth = (truth + AngleDelta::new(rate.over(DT))).normalized();
+            let angle = filter.update(rate, truth, DT);
+            assert!(angle.min_dist(truth).in_degrees() < 1.0);
+            assert!(angle.min_dist(previous).in_degrees() < 1.0);
+            assert!(angle >= Degrees(0.0) && angle < Degrees(360.0));
+            previous = angle;
+        }
+    }
+
+    #[test]
+    fn test_complementary_filter_sparse() {
+        let tau = 0.5;
+        let bias = 2.0;
+        let gap = 50;
+        let mut filter = ComplementaryFilter::new(Duration::from_millis(500));
+        filter.reset(Degrees(300.0));
+
+        let rate = AngularVelocity::degrees_per_second(20.0);
+        let biased = AngularVelocity::degrees_per_second(20.0 + bias);
+        let mut truth = Degrees(300.0);
+        let mut worst: f64 = 0.0;
+        for step in 1..=20_000 {
+            truth = (truth + AngleDelta::new(rate.over(DT))).normalized();
+            if step % gap == 0 {
+                filter.update(biased, truth, DT);
+            } else {
+                filter.integrate(biased, DT);
+            }
+            worst = worst.max(filter.angle().min_dist(truth).in_degrees());
+        }
+
+        // The rate drifts by the bias times the gap between measurements,
+        // and each measurement pulls the offset back to the bias times the
+        // time constant, as for dense measurements.
+        let drift = bias * gap as f64 * DT.as_secs_f64();
+        assert!(worst <= bias * tau + drift + 1.0e-9);
+        assert_that!(
+            filter.angle().min_dist(truth).in_degrees(),
+            close_to(bias * tau, 1.0e-6)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Support for half-precision angles.
+//!
+//! `half::f16` implements the `num-traits` traits, so most of the API works
+//! on `Angle<f16>` as it does on the primitive floats. Conversions between
+//! units are computed in `f64` and rounded to the nearest `f16` once, and the
+//! trigonometric functions are computed in `f32` and rounded the same way, so
+//! results are within half an `f16` ulp of the exact value of the rounded
+//! input.
+
+use core::ops::{Div, Mul};
+use half::f16;
+
+use crate::{Angle, Degrees, Radians};
+
+impl Mul<Angle<f16>> for f16 {
+    type Output = Angle<f16>;
+    #[inline]
+    fn mul(self, rhs: Angle<f16>) -> Self::Output {
+        match rhs {
+            Radians(v) => Radians(self * v),
+            Degrees(v) => Degrees(self * v),
+        }
+    }
+}
+
+impl Div<Angle<f16>> for f16 {
+    type Output = Angle<f16>;
+    #[inline]
+    fn div(self, rhs: Angle<f16>) -> Self::Output {
+        match rhs {
+            Radians(v) => Radians(self / v),
+            Degrees(v) => Degrees(self / v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::PI;
+    use half::f16;
+
+    use crate::*;
+
+    fn h(v: f32) -> f16 {
+        f16::from_f32(v)
+    }
+
+    /// One unit in the last place of `v` in `f16`.
+    fn ulp(v: f32) -> f32 {
+        let v = h(v.abs());
+        f16::from_bits(v.to_bits() + 1).to_f32() - v.to_f32()
+    }
+
+    #[test]
+    fn test_f16_conversions() {
+        for &deg in &[0.0f32, 1.0, 45.0, 90.0, 137.5, 359.9, -720.0, 1.0e4] {
+            let alpha = Degrees(h(deg));
+            let reference = h(deg).to_f32().to_radians();
+            let rad = alpha.in_radians().to_f32();
+            assert!((rad - reference).abs() <= ulp(reference) / 2.0);
+
+            let back = Radians(alpha.in_radians()).in_degrees().to_f32();
+            assert!((back - h(deg).to_f32()).abs() <= ulp(deg));
+        }
+        assert_eq!(Radians(h(PI)).in_degrees(), h(180.0));
+    }
+
+    #[test]
+    fn test_f16_normalized() {
+        assert_eq!(Degrees(f16::MAX).normalized(), Degrees(h(344.0)));
+        assert_eq!(Degrees(-f16::MAX).normalized(), Degrees(h(16.0)));
+        assert_eq!(Degrees(h(-90.0)).normalized(), Degrees(h(270.0)));
+
+        let alpha = Radians(h(-1.0)).normalized();
+        assert!(alpha.in_radians() >= f16::ZERO);
+        assert!(alpha.in_radians() < AngleUnit::Radians.full_turn_in());
+    }
+
+    #[test]
+    fn test_f16_trigonometry() {
+        for &deg in &[0.0f32, 30.0, 45.0, 90.0, 200.0] {
+            let alpha = Degrees(h(deg));
+            let (sin, cos) = alpha.in_radians().to_f32().sin_cos();
+            assert_eq!(alpha.sin(), h(sin));
+            assert_eq!(alpha.cos(), h(cos));
+        }
+
+        // The distance is computed in radians, where a full turn is only
+        // accurate to an ulp of 2π.
+        let d = Degrees(h(350.0)).min_dist(Degrees(h(10.0))).in_radians();
+        assert!((d.to_f32() - 20.0f32.to_radians()).abs() <= 2.0 * ulp(2.0 * PI));
+    }
+
+    #[test]
+    fn test_f16_arithmetic() {
+        let alpha = Degrees(h(12.3));
+        let reference = h(h(12.3).to_f32() * h(3.7).to_f32());
+        assert_eq!(h(3.7) * alpha, Degrees(reference));
+        assert_eq!(alpha * h(3.7), Degrees(reference));
+        assert_eq!(alpha / h(2.0), Degrees(h(h(12.3).to_f32() / 2.0)));
+        assert_eq!(h(90.0) / Degrees(h(2.0)), Degrees(h(45.0)));
+
+        let mut beta = Radians(h(1.5));
+        beta += AngleDelta::new(Radians(h(0.25)));
+        beta *= h(2.0);
+        assert_eq!(beta, Radians(h(3.5)));
+        assert_eq!(
+            (Degrees(h(12.5)) + AngleDelta::new(Degrees(h(0.25)))).to_string(),
+            "12.75°"
+        );
+    }
+}
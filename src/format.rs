@@ -0,0 +1,391 @@
+//! Formatting of angles into fixed buffers, without `core::fmt` or
+//! allocation.
+
+use core::fmt::{Display, Error, Formatter};
+use num_traits::float::FloatCore;
+
+use crate::{Angle, Degrees, Radians};
+
+/// The error returned when a formatted angle does not fit into the buffer it
+/// is written to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BufferTooSmall;
+
+impl Display for BufferTooSmall {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "buffer too small for formatted angle")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+impl<T: FloatCore> Angle<T> {
+    /// Write the angle into `buf` with the given number of `decimals`,
+    /// followed by its unit, and return the written part of the buffer.
+    ///
+    /// The output is the same as `format!("{:.N}", angle.value())` followed
+    /// by `rad` or `°`, including the rounding of ties to even, the sign of
+    /// negative zero and the spelling of `NaN` and `inf`. The exact value of
+    /// the angle is rounded, so even large numbers of decimals agree.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let mut buf = [0; 16];
+    /// assert_eq!(Degrees(-9.96f64).format_into(&mut buf, 1), Ok("-10.0°"));
+    /// assert_eq!(Radians(0.125f32).format_into(&mut buf, 2), Ok("0.12rad"));
+    /// assert_eq!(Degrees(1.0e6f64).format_into(&mut buf, 8), Err(BufferTooSmall));
+    /// ```
+    pub fn format_into(self, buf: &mut [u8], decimals: u8) -> Result<&str, BufferTooSmall> {
+        let (v, suffix) = match self {
+            Radians(v) => (v, "rad"),
+            Degrees(v) => (v, "°"),
+        };
+
+        let mut writer = Writer { buf, len: 0 };
+        write_fixed(v, usize::from(decimals), &mut writer)?;
+        writer.push_str(suffix)?;
+
+        let Writer { buf, len } = writer;
+        Ok(core::str::from_utf8(&buf[..len]).unwrap())
+    }
+
+    /// Format the angle as [`format_into`](#method.format_into) does into a
+    /// `heapless::String` with a capacity of `N` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let s = Degrees(45.25f64).to_heapless_string::<8>(1).unwrap();
+    /// assert_eq!(s, "45.2°");
+    /// ```
+    #[cfg(feature = "heapless")]
+    pub fn to_heapless_string<const N: usize>(
+        self,
+        decimals: u8,
+    ) -> Result<heapless::String<N>, BufferTooSmall> {
+        let mut buf = [0; N];
+        let mut s = heapless::String::new();
+        s.push_str(self.format_into(&mut buf, decimals)?)
+            .map_err(|_| BufferTooSmall)?;
+        Ok(s)
+    }
+}
+
+/// A cursor writing bytes into the start of a buffer.
+struct Writer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> Writer<'a> {
+    #[inline]
+    fn push(&mut self, b: u8) -> Result<(), BufferTooSmall> {
+        let slot = self.buf.get_mut(self.len).ok_or(BufferTooSmall)?;
+        *slot = b;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn push_str(&mut self, s: &str) -> Result<(), BufferTooSmall> {
+        s.bytes().try_for_each(|b| self.push(b))
+    }
+}
+
+/// Write `v` in fixed-point notation with `decimals` digits after the point.
+fn write_fixed<T: FloatCore>(
+    v: T,
+    decimals: usize,
+    out: &mut Writer,
+) -> Result<(), BufferTooSmall> {
+    if v.is_nan() {
+        return out.push_str("NaN");
+    }
+    if v.is_sign_negative() {
+        out.push(b'-')?;
+    }
+    if v.is_infinite() {
+        return out.push_str("inf");
+    }
+
+    // The value is exactly mantissa·2^exponent. Split it into an integer
+    // part and a fraction of `scale` bits.
+    let (mantissa, exponent, _) = v.integer_decode();
+    let (mut int, mut frac, scale) = if exponent >= 0 {
+        (Big::shl(mantissa, exponent as usize), Big::zero(), 0)
+    } else {
+        let scale = usize::from(exponent.unsigned_abs());
+        let int = if scale < 64 { mantissa >> scale } else { 0 };
+        let frac = if scale < 64 {
+            mantissa & ((1 << scale) - 1)
+        } else {
+            mantissa
+        };
+        (Big::shl(int, 0), Big::shl(frac, 0), scale)
+    };
+
+    // Write the digits of the integer part backwards, then reverse them.
+    let start = out.len;
+    loop {
+        out.push(b'0' + int.div_small(10) as u8)?;
+        if int.is_zero() {
+            break;
+        }
+    }
+    out.buf[start..out.len].reverse();
+
+    if decimals > 0 {
+        out.push(b'.')?;
+        for _ in 0..decimals {
+            frac.mul_small(10);
+            out.push(b'0' + frac.split_off(scale) as u8)?;
+        }
+    }
+
+    // Round the rest of the fraction, with ties to even.
+    if scale > 0 {
+        let last = out.buf[out.len - 1];
+        let round_up = match frac.cmp_pow2(scale - 1) {
+            core::cmp::Ordering::Greater => true,
+            core::cmp::Ordering::Equal => (last - b'0') % 2 == 1,
+            core::cmp::Ordering::Less => false,
+        };
+        if round_up {
+            increment(out, start)?;
+        }
+    }
+    Ok(())
+}
+
+/// Add one to the last digit of the number written from `start`, carrying
+/// into the digits before it.
+fn increment(out: &mut Writer, start: usize) -> Result<(), BufferTooSmall> {
+    for i in (start..out.len).rev() {
+        match out.buf[i] {
+            b'.' => continue,
+            b'9' => out.buf[i] = b'0',
+            d => {
+                out.buf[i] = d + 1;
+                return Ok(());
+            }
+        }
+    }
+
+    // Every digit was a nine, so the number gains a leading one.
+    out.push(b'0')?;
+    out.buf.copy_within(start..out.len - 1, start + 1);
+    out.buf[start] = b'1';
+    Ok(())
+}
+
+/// An unsigned integer wide enough for the integer part of any `f64`, or its
+/// fraction scaled by ten.
+#[derive(Copy, Clone)]
+struct Big {
+    limbs: [u32; 36],
+}
+
+impl Big {
+    #[inline]
+    fn zero() -> Big {
+        Big { limbs: [0; 36] }
+    }
+
+    /// Create `v·2^shift`.
+    fn shl(v: u64, shift: usize) -> Big {
+        let mut big = Big::zero();
+        let (limb, bit) = (shift / 32, shift % 32);
+        let v = u128::from(v) << bit;
+        for i in 0..3 {
+            if let Some(l) = big.limbs.get_mut(limb + i) {
+                *l = (v >> (32 * i)) as u32;
+            }
+        }
+        big
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&l| l == 0)
+    }
+
+    /// Divide by `d` in place and return the remainder.
+    fn div_small(&mut self, d: u32) -> u32 {
+        let mut rem = 0u64;
+        for l in self.limbs.iter_mut().rev() {
+            let cur = (rem << 32) | u64::from(*l);
+            *l = (cur / u64::from(d)) as u32;
+            rem = cur % u64::from(d);
+        }
+        rem as u32
+    }
+
+    /// Multiply by `m` in place.
+    fn mul_small(&mut self, m: u32) {
+        let mut carry = 0u64;
+        for l in self.limbs.iter_mut() {
+            let cur = u64::from(*l) * u64::from(m) + carry;
+            *l = cur as u32;
+            carry = cur >> 32;
+        }
+    }
+
+    /// Remove and return the bits from `bit` upwards, which must fit into a
+    /// `u32`.
+    fn split_off(&mut self, bit: usize) -> u32 {
+        let (limb, offset) = (bit / 32, bit % 32);
+        let low = u64::from(self.limbs[limb]);
+        let high = self.limbs.get(limb + 1).map_or(0, |&l| u64::from(l));
+        let top = (((high << 32) | low) >> offset) as u32;
+
+        self.limbs[limb] &= ((1u64 << offset) - 1) as u32;
+        for l in self.limbs[limb + 1..].iter_mut() {
+            *l = 0;
+        }
+        top
+    }
+
+    /// Compare with `2^bit`.
+    fn cmp_pow2(&self, bit: usize) -> core::cmp::Ordering {
+        let mut pow = Big::zero();
+        pow.limbs[bit / 32] = 1 << (bit % 32);
+        self.limbs.iter().rev().cmp(pow.limbs.iter().rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use crate::*;
+
+    #[test]
+    fn test_format_into() {
+        let mut buf = [0; 32];
+        assert_eq!(Degrees(1.5).format_into(&mut buf, 0), Ok("2°"));
+        assert_eq!(Degrees(2.5).format_into(&mut buf, 0), Ok("2°"));
+        assert_eq!(Degrees(0.25).format_into(&mut buf, 1), Ok("0.2°"));
+        assert_eq!(Degrees(0.35).format_into(&mut buf, 1), Ok("0.3°"));
+        assert_eq!(Radians(1.23456).format_into(&mut buf, 3), Ok("1.235rad"));
+        assert_eq!(Radians(12.0f32).format_into(&mut buf, 2), Ok("12.00rad"));
+        assert_eq!(
+            Degrees(0.1).format_into(&mut buf, 20),
+            Ok("0.10000000000000000555°")
+        );
+    }
+
+    #[test]
+    fn test_format_into_signs_and_carries() {
+        let mut buf = [0; 32];
+        assert_eq!(Degrees(-1.25).format_into(&mut buf, 1), Ok("-1.2°"));
+        assert_eq!(Degrees(-0.04).format_into(&mut buf, 1), Ok("-0.0°"));
+        assert_eq!(Degrees(-0.0).format_into(&mut buf, 2), Ok("-0.00°"));
+        assert_eq!(Degrees(9.99).format_into(&mut buf, 1), Ok("10.0°"));
+        assert_eq!(Degrees(-99.96).format_into(&mut buf, 1), Ok("-100.0°"));
+        assert_eq!(Degrees(999.5).format_into(&mut buf, 0), Ok("1000°"));
+        assert_eq!(Degrees(0.96).format_into(&mut buf, 1), Ok("1.0°"));
+        assert_eq!(Degrees(f64::NAN).format_into(&mut buf, 2), Ok("NaN°"));
+        assert_eq!(
+            Radians(-f64::INFINITY).format_into(&mut buf, 2),
+            Ok("-infrad")
+        );
+    }
+
+    #[test]
+    fn test_format_into_buffer_sizes() {
+        // "-12.38°" takes 8 bytes, as the degree sign takes two.
+        let alpha = Degrees(-12.375);
+        for len in 0..8 {
+            let mut buf = [0; 8];
+            assert_eq!(alpha.format_into(&mut buf[..len], 2), Err(BufferTooSmall));
+        }
+        let mut buf = [0; 8];
+        assert_eq!(alpha.format_into(&mut buf, 2), Ok("-12.38°"));
+
+        // Carries which add a digit need room for it.
+        let mut buf = [0; 6];
+        assert_eq!(Degrees(9.96).format_into(&mut buf, 1), Ok("10.0°"));
+        assert_eq!(
+            Degrees(9.96).format_into(&mut buf[..5], 1),
+            Err(BufferTooSmall)
+        );
+        let mut buf = [0; 4];
+        assert_eq!(Radians(9.6).format_into(&mut buf, 0), Err(BufferTooSmall));
+        let mut buf = [0; 5];
+        assert_eq!(Radians(9.6).format_into(&mut buf, 0), Ok("10rad"));
+    }
+
+    #[test]
+    fn test_format_into_matches_display() {
+        let mut buf = [0; 512];
+        let values = [
+            0.0,
+            0.5,
+            0.05,
+            0.005,
+            1.0e-7,
+            2.675,
+            1.005,
+            123.456,
+            359.9999,
+            1.0e21,
+            1.0e300,
+            5.0e-324,
+            1.7976931348623157e308,
+        ];
+        for &v in &values {
+            for &v in &[v, -v] {
+                for decimals in 0..8 {
+                    let expected = format!("{:.*}°", decimals, v);
+                    assert_eq!(
+                        Degrees(v).format_into(&mut buf, decimals as u8),
+                        Ok(&*expected)
+                    );
+                }
+            }
+        }
+
+        for i in -2000..2000 {
+            let v = i as f64 * 0.005;
+            for decimals in 0..4 {
+                let expected = format!("{:.*}rad", decimals, v);
+                assert_eq!(
+                    Radians(v).format_into(&mut buf, decimals as u8),
+                    Ok(&*expected)
+                );
+                let expected = format!("{:.*}rad", decimals, v as f32);
+                assert_eq!(
+                    Radians(v as f32).format_into(&mut buf, decimals as u8),
+                    Ok(&*expected)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn prop_format_into_matches_display() {
+        fn prop(bits: u64, decimals: u8) -> bool {
+            let v = f64::from_bits(bits);
+            let decimals = decimals % 40;
+            let mut buf = [0; 400];
+            let expected = format!("{:.*}°", decimals as usize, v);
+            Degrees(v).format_into(&mut buf, decimals) == Ok(&*expected)
+        }
+        quickcheck(prop as fn(u64, u8) -> bool);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_to_heapless_string() {
+        let s = Radians(-0.5f64).to_heapless_string::<16>(3).unwrap();
+        assert_eq!(s, "-0.500rad");
+        assert_eq!(
+            Degrees(100.0f64).to_heapless_string::<5>(1),
+            Err(BufferTooSmall)
+        );
+        assert!(Degrees(100.0f64).to_heapless_string::<7>(1).is_ok());
+    }
+}
@@ -0,0 +1,146 @@
+//! Interoperability with the points and bearings of the `geo` crate.
+//!
+//! [`bearing_between`](../fn.bearing_between.html) and
+//! [`geodesic_bearing_between`](../fn.geodesic_bearing_between.html) are
+//! also available at the crate root. [`destination`](fn.destination.html)
+//! is not, as it would clash with the great circle
+//! [`destination`](../fn.destination.html) on angles which it builds on.
+
+use geo::{GeodesicBearing, HaversineBearing, Point};
+
+use crate::{Bearing, Degrees, Radians};
+
+/// The mean radius of the Earth in meters, as used by `geo`'s haversine
+/// algorithms.
+const MEAN_EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// Compute the initial bearing of the great circle path from `from` to `to`
+/// with `geo`'s haversine formula. Points are given as longitude and latitude
+/// in degrees.
+///
+/// `geo` yields azimuths in the range of (-180, 180]°, which are normalized
+/// into a [`Bearing`](struct.Bearing.html).
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use geo::Point;
+///
+/// let bearing = bearing_between(Point::new(10.0, 0.0), Point::new(9.0, 0.0));
+/// assert!((bearing.in_degrees() - 270.0).abs() < 1.0e-10);
+/// ```
+#[inline]
+pub fn bearing_between(from: Point<f64>, to: Point<f64>) -> Bearing<f64> {
+    Bearing::from_azimuth(from.haversine_bearing(to))
+}
+
+/// Compute the initial bearing of the geodesic from `from` to `to` on the
+/// WGS84 ellipsoid with `geo`'s geodesic methods. Points are given as
+/// longitude and latitude in degrees.
+#[inline]
+pub fn geodesic_bearing_between(from: Point<f64>, to: Point<f64>) -> Bearing<f64> {
+    Bearing::from_azimuth(from.geodesic_bearing(to))
+}
+
+/// Compute the point reached by travelling `distance` meters from `from`
+/// along the great circle with the initial `bearing`, on a sphere of the
+/// same mean radius as `geo`'s haversine algorithms. Points are given as
+/// longitude and latitude in degrees.
+///
+/// This is the great circle [`destination`](../fn.destination.html) with
+/// the distance converted into a central angle, and agrees with `geo`'s
+/// `HaversineDestination` to within rounding.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use geo::Point;
+///
+/// let start = Point::new(0.0, 0.0);
+/// let end = gis::destination(start, Bearing::from_azimuth(90.0), 1000.0);
+/// assert!(end.y().abs() < 1.0e-10 && end.x() > 0.0);
+/// ```
+#[inline]
+pub fn destination(from: Point<f64>, bearing: Bearing<f64>, distance: f64) -> Point<f64> {
+    let (lat, lon) = crate::destination(
+        Degrees(from.y()),
+        Degrees(from.x()),
+        bearing.angle(),
+        Radians(distance / MEAN_EARTH_RADIUS),
+    );
+    Point::new(lon.in_degrees(), lat.in_degrees())
+}
+
+#[cfg(test)]
+mod tests {
+    use geo::{GeodesicBearing, HaversineBearing, HaversineDestination, Point};
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    fn pairs() -> [(Point<f64>, Point<f64>); 5] {
+        [
+            (Point::new(9.0, 47.0), Point::new(9.0, 48.0)),
+            (Point::new(9.0, 0.0), Point::new(10.0, 0.0)),
+            (Point::new(-0.1278, 51.5074), Point::new(-74.0060, 40.7128)),
+            (
+                Point::new(151.2093, -33.8688),
+                Point::new(139.6917, 35.6895),
+            ),
+            (Point::new(179.5, 10.0), Point::new(-179.5, 9.0)),
+        ]
+    }
+
+    #[test]
+    fn test_bearing_between() {
+        for &(from, to) in &pairs() {
+            let azimuth = from.haversine_bearing(to);
+            let bearing = bearing_between(from, to);
+            assert!(bearing.in_degrees() >= 0.0 && bearing.in_degrees() < 360.0);
+            assert_that!(
+                bearing.angle().min_dist(Degrees(azimuth)).in_degrees(),
+                close_to(0.0, 1.0e-10)
+            );
+
+            let azimuth = from.geodesic_bearing(to);
+            let bearing = geodesic_bearing_between(from, to);
+            assert!(bearing.in_degrees() >= 0.0 && bearing.in_degrees() < 360.0);
+            assert_that!(
+                bearing.angle().min_dist(Degrees(azimuth)).in_degrees(),
+                close_to(0.0, 1.0e-10)
+            );
+        }
+
+        // London to New York heads west of north, which geo gives as negative.
+        let (london, new_york) = pairs()[2];
+        let azimuth = london.haversine_bearing(new_york);
+        assert!(azimuth < 0.0);
+        assert_that!(
+            bearing_between(london, new_york).in_degrees(),
+            close_to(azimuth + 360.0, 1.0e-10)
+        );
+    }
+
+    #[test]
+    fn test_destination() {
+        for &(from, to) in &pairs() {
+            let bearing = bearing_between(from, to);
+            for &distance in &[0.0, 50_000.0, 2_000_000.0, -10_000.0] {
+                let expected = from.haversine_destination(bearing.in_degrees(), distance);
+                let end = gis::destination(from, bearing, distance);
+                assert_that!(end.x(), close_to(expected.x(), 1.0e-9));
+                assert_that!(end.y(), close_to(expected.y(), 1.0e-9));
+            }
+        }
+
+        // Crossing the antimeridian wraps the longitude.
+        let end = gis::destination(
+            Point::new(179.9, 0.0),
+            Bearing::from_azimuth(90.0),
+            50_000.0,
+        );
+        assert!(end.x() < -179.0);
+    }
+}
@@ -0,0 +1,162 @@
+//! Interoperability with the embedded-graphics crate.
+//!
+//! The angles of embedded-graphics start at the positive x axis, like the
+//! angles of this crate, but increase clockwise on screen, as its y axis
+//! points down. The `From` conversions keep the value of an angle as it is,
+//! while the drawing helpers mirror angles across the x axis so that
+//! counter-clockwise angles stay counter-clockwise on screen.
+
+use embedded_graphics::geometry::{Angle as EgAngle, Point};
+use embedded_graphics::primitives::Arc;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+
+use crate::{Angle, Degrees, Radians, SweepArc};
+
+impl From<Angle<f32>> for EgAngle {
+    #[inline]
+    fn from(angle: Angle<f32>) -> Self {
+        match angle {
+            Radians(v) => EgAngle::from_radians(v),
+            Degrees(v) => EgAngle::from_degrees(v),
+        }
+    }
+}
+
+impl From<EgAngle> for Angle<f32> {
+    #[inline]
+    fn from(angle: EgAngle) -> Self {
+        Radians(angle.to_radians())
+    }
+}
+
+/// Create the embedded-graphics arc of the circle around `center` with the
+/// given `radius` in pixels which sweeps as `sweep` does on screen.
+///
+/// Both angles are mirrored into the clockwise convention of
+/// embedded-graphics and are otherwise kept as they are, so a negative sweep
+/// or one of more than a full turn carries over. The diameter saturates at
+/// `u32::MAX` for radii beyond half of it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use embedded_graphics::geometry::Point;
+///
+/// // A quarter of a circle from 3 o'clock up to 12 o'clock.
+/// let arc = to_eg_arc(Point::new(32, 32), 10, SweepArc::new(Degrees(0.0), Degrees(90.0)));
+/// assert_eq!(arc.angle_start.to_degrees(), 0.0);
+/// assert_eq!(arc.angle_sweep.to_degrees(), -90.0);
+/// ```
+#[inline]
+pub fn to_eg_arc(center: Point, radius: u32, sweep: SweepArc<f32>) -> Arc {
+    Arc::with_center(
+        center,
+        radius.saturating_mul(2),
+        EgAngle::from(-sweep.start()),
+        EgAngle::from(-sweep.sweep()),
+    )
+}
+
+/// Compute the endpoints of a gauge needle of the given `radius` in pixels,
+/// from `center` towards `angle` measured counter-clockwise from 3 o'clock on
+/// screen. The tip is rounded to the nearest pixel.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use embedded_graphics::geometry::Point;
+///
+/// let (center, tip) = gauge_needle_points(Point::new(32, 32), 20, Degrees(90.0));
+/// assert_eq!(center, Point::new(32, 32));
+/// assert_eq!(tip, Point::new(32, 12));
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn gauge_needle_points(center: Point, radius: u32, angle: Angle<f32>) -> (Point, Point) {
+    let (sin, cos) = angle.sin_cos();
+    let r = radius as f32;
+    let tip = Point::new(
+        center.x.saturating_add(Float::round(r * cos) as i32),
+        center.y.saturating_sub(Float::round(r * sin) as i32),
+    );
+    (center, tip)
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::geometry::{Angle as EgAngle, Point};
+    use embedded_graphics::primitives::PointsIter;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_eg_angle_conversions() {
+        let angle = EgAngle::from(Degrees(90.0f32));
+        assert_that!(angle.to_degrees(), close_to(90.0, 1.0e-4));
+        let angle = EgAngle::from(Radians(-7.0f32));
+        assert_eq!(angle.to_radians(), -7.0);
+
+        let alpha = Angle::from(EgAngle::from_degrees(450.0));
+        assert!(matches!(alpha, Radians(_)));
+        assert_that!(alpha.in_degrees(), close_to(450.0, 1.0e-3));
+    }
+
+    #[test]
+    fn test_to_eg_arc() {
+        let center = Point::new(50, 50);
+        let cases = [
+            ((0.0, 90.0), (0.0, -90.0)),
+            ((90.0, -45.0), (-90.0, 45.0)),
+            ((-30.0, 450.0), (30.0, -450.0)),
+            ((400.0, -720.0), (-400.0, 720.0)),
+        ];
+        for &((start, sweep), (eg_start, eg_sweep)) in &cases {
+            let arc = to_eg_arc(center, 20, SweepArc::new(Degrees(start), Degrees(sweep)));
+            assert_eq!(arc.center(), center);
+            assert_eq!(arc.diameter, 40);
+            assert_that!(arc.angle_start.to_degrees(), close_to(eg_start, 1.0e-3));
+            assert_that!(arc.angle_sweep.to_degrees(), close_to(eg_sweep, 1.0e-3));
+        }
+        let sweep = SweepArc::new(Degrees(0.0), Degrees(90.0));
+        assert_eq!(to_eg_arc(center, u32::MAX, sweep).diameter, u32::MAX);
+
+        // A counter-clockwise quarter from 3 o'clock is drawn above the
+        // center on screen.
+        let arc = to_eg_arc(center, 20, SweepArc::new(Degrees(0.0), Degrees(90.0)));
+        assert!(arc.points().all(|p| p.x >= 50 && p.y <= 50));
+        let arc = to_eg_arc(center, 20, SweepArc::new(Degrees(0.0), Degrees(-90.0)));
+        assert!(arc.points().all(|p| p.x >= 50 && p.y >= 50));
+    }
+
+    #[test]
+    fn test_gauge_needle_points() {
+        let center = Point::new(32, 32);
+        let cases = [
+            (0.0, Point::new(42, 32)),
+            (90.0, Point::new(32, 22)),
+            (180.0, Point::new(22, 32)),
+            (270.0, Point::new(32, 42)),
+            (-45.0, Point::new(39, 39)),
+        ];
+        for &(degrees, tip) in &cases {
+            assert_eq!(
+                gauge_needle_points(center, 10, Degrees(degrees)),
+                (center, tip)
+            );
+        }
+
+        // The tip saturates at the bounds of the screen coordinates.
+        let (_, tip) = gauge_needle_points(Point::new(100, 0), u32::MAX, Degrees(0.0));
+        assert_eq!(tip, Point::new(i32::MAX, 0));
+        let (_, tip) = gauge_needle_points(Point::new(-100, 0), u32::MAX, Degrees(180.0));
+        assert_eq!(tip.x, i32::MIN);
+        let (_, tip) = gauge_needle_points(Point::new(0, -100), u32::MAX, Degrees(90.0));
+        assert_eq!(tip.y, i32::MIN);
+        let (_, tip) = gauge_needle_points(Point::new(0, 100), u32::MAX, Degrees(270.0));
+        assert_eq!(tip.y, i32::MAX);
+    }
+}
@@ -0,0 +1,156 @@
+//! Queries of angles against grids of multiples of a step.
+
+use num_traits::Float;
+
+use crate::{Angle, AngleDelta};
+
+impl<T: Float> Angle<T> {
+    /// Find the multiple of `step` nearest to the angle, or return `None` if
+    /// `step` is not positive and finite.
+    ///
+    /// The grid restarts at every full turn, so an angle just short of a full
+    /// turn is near zero even if `step` does not divide a full turn. When the
+    /// angle lies halfway between two multiples, the lower one is chosen. The
+    /// result is normalized and in the unit this angle is stored in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(37.0f64).nearest_multiple(Degrees(15.0)), Some(Degrees(30.0)));
+    /// assert_eq!(Degrees(359.0f64).nearest_multiple(Degrees(7.0)), Some(Degrees(0.0)));
+    /// assert_eq!(Degrees(-5.0f64).nearest_multiple(Degrees(10.0)), Some(Degrees(350.0)));
+    /// ```
+    #[inline]
+    pub fn nearest_multiple(self, step: Angle<T>) -> Option<Angle<T>> {
+        let offset = self.offset_from_grid(step)?;
+        Some((self.normalized() - AngleDelta::new(offset)).normalized())
+    }
+
+    /// Compute the signed distance from the nearest multiple of `step` to
+    /// the angle, in the range of (-step/2, step/2], or return `None` if
+    /// `step` is not positive and finite.
+    ///
+    /// The nearest multiple is chosen as in
+    /// [`nearest_multiple`](#method.nearest_multiple), and the result is in
+    /// the unit this angle is stored in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let offset = Degrees(359.99f64).offset_from_grid(Degrees(10.0)).unwrap();
+    /// assert!((offset.in_degrees() + 0.01).abs() < 1.0e-10);
+    /// ```
+    pub fn offset_from_grid(self, step: Angle<T>) -> Option<Angle<T>> {
+        let (v, unit) = self.normalized().into_parts();
+        let step = step.value_in(unit);
+        if !step.is_finite() || step <= T::zero() {
+            return None;
+        }
+
+        let full: T = unit.full_turn_in();
+        let half = step / (T::one() + T::one());
+        let offset = v - ((v - half) / step).ceil() * step;
+
+        // The multiple at the end of the turn coincides with zero, which may
+        // be nearer than the last multiple within the turn.
+        let wrapped = v - full;
+        let offset = if wrapped.abs() < offset.abs() {
+            wrapped
+        } else {
+            offset
+        };
+        Some(Angle::from_parts(offset, unit))
+    }
+
+    /// Return `true` if the angle lies within `tolerance` of a multiple of
+    /// `step`, as found by [`nearest_multiple`](#method.nearest_multiple).
+    /// Returns `false` if `step` is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert!(Degrees(359.99f64).is_multiple_of(Degrees(10.0), Degrees(0.02)));
+    /// assert!(!Degrees(5.0f64).is_multiple_of(Degrees(10.0), Degrees(0.02)));
+    /// ```
+    #[inline]
+    pub fn is_multiple_of(self, step: Angle<T>, tolerance: Angle<T>) -> bool {
+        self.offset_from_grid(step)
+            .is_some_and(|offset| offset.value().abs() <= tolerance.value_in(offset.unit()).abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_grid_points() {
+        let step = Degrees(10.0);
+        for i in -40..40 {
+            let alpha = Degrees(i as f64 * 10.0);
+            assert!(alpha.is_multiple_of(step, Degrees(1.0e-9)));
+            assert_eq!(alpha.offset_from_grid(step), Some(Degrees(0.0)));
+            assert_eq!(alpha.nearest_multiple(step), Some(alpha.normalized()));
+        }
+
+        let step = Radians(PI / 4.0);
+        let alpha = Degrees(135.0);
+        assert!(alpha.is_multiple_of(step, Degrees(1.0e-9)));
+        assert_that!(
+            alpha.nearest_multiple(step).unwrap().in_degrees(),
+            close_to(135.0, 1.0e-9)
+        );
+    }
+
+    #[test]
+    fn test_grid_near_misses() {
+        let step = Degrees(10.0);
+        let tolerance = Degrees(0.02);
+        assert!(Degrees(20.01).is_multiple_of(step, tolerance));
+        assert!(Degrees(19.99).is_multiple_of(step, tolerance));
+        assert!(!Degrees(20.03).is_multiple_of(step, tolerance));
+        assert!(!Degrees(19.97).is_multiple_of(step, tolerance));
+        assert!(Degrees(20.03).is_multiple_of(step, Degrees(-0.05)));
+        assert!(Degrees(20.01).is_multiple_of(step, Radians(0.02f64.to_radians())));
+
+        let offset = Degrees(23.0).offset_from_grid(step).unwrap();
+        assert_that!(offset.in_degrees(), close_to(3.0, 1.0e-9));
+        let offset = Degrees(27.0).offset_from_grid(step).unwrap();
+        assert_that!(offset.in_degrees(), close_to(-3.0, 1.0e-9));
+        assert_eq!(Degrees(25.0).offset_from_grid(step), Some(Degrees(5.0)));
+        assert_eq!(Degrees(25.0).nearest_multiple(step), Some(Degrees(20.0)));
+    }
+
+    #[test]
+    fn test_grid_seam() {
+        let step = Degrees(10.0);
+        assert!(Degrees(359.99).is_multiple_of(step, Degrees(0.02)));
+        assert!(Degrees(-0.01).is_multiple_of(step, Degrees(0.02)));
+        assert_eq!(Degrees(359.99).nearest_multiple(step), Some(Degrees(0.0)));
+        assert_eq!(Degrees(-7.0).nearest_multiple(step), Some(Degrees(350.0)));
+        assert_eq!(Degrees(-725.0).nearest_multiple(step), Some(Degrees(350.0)));
+
+        // 7° does not divide a full turn, so the last multiple is 357°.
+        let step = Degrees(7.0);
+        assert_eq!(Degrees(358.0).nearest_multiple(step), Some(Degrees(357.0)));
+        assert_eq!(Degrees(359.0).nearest_multiple(step), Some(Degrees(0.0)));
+        let offset = Degrees(359.0).offset_from_grid(step).unwrap();
+        assert_that!(offset.in_degrees(), close_to(-1.0, 1.0e-9));
+        assert_eq!(Degrees(3.5).nearest_multiple(step), Some(Degrees(0.0)));
+    }
+
+    #[test]
+    fn test_grid_rejects_non_positive_steps() {
+        for &step in &[0.0, -10.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(Degrees(10.0).nearest_multiple(Degrees(step)), None);
+            assert_eq!(Degrees(10.0).offset_from_grid(Degrees(step)), None);
+            assert!(!Degrees(10.0).is_multiple_of(Degrees(step), Degrees(1.0)));
+        }
+    }
+}
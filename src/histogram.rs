@@ -0,0 +1,422 @@
+//! Histograms of angles binned into equal sectors of the circle.
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, AngleDelta, CircularStats};
+
+/// A histogram counting angles in equal sectors of the circle.
+///
+/// Bin `i` covers the half-open sector from `offset + i·w` up to
+/// `offset + (i + 1)·w` counter-clockwise, for the bin width `w` of a full
+/// turn divided by the number of bins. An angle exactly on the boundary
+/// between two bins is counted in the bin starting there. An offset of minus
+/// half a bin centers the first bin on zero.
+///
+/// Alongside the counts the histogram keeps the
+/// [`CircularStats`](struct.CircularStats.html) of the angles added, which
+/// are not affected by the binning.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let mut histogram = CircularHistogram::new(4, Degrees(-45.0f64)).unwrap();
+/// histogram.extend(&[Degrees(10.0), Degrees(350.0), Degrees(100.0), Degrees(45.0)]);
+///
+/// assert_eq!(histogram.counts(), &[2, 2, 0, 0]);
+/// assert_eq!(histogram.bin_of(Degrees(180.0)), Some(2));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct CircularHistogram<T = f64> {
+    counts: Vec<u64>,
+    offset: Angle<T>,
+    stats: CircularStats<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float> CircularHistogram<T> {
+    /// Create an empty histogram with the given number of `bins`, the first
+    /// of which starts at `offset`, or return `None` if `bins` is zero or the
+    /// offset is not finite.
+    pub fn new(bins: usize, offset: Angle<T>) -> Option<CircularHistogram<T>> {
+        if bins == 0 || !offset.value().is_finite() {
+            return None;
+        }
+
+        Some(CircularHistogram {
+            counts: vec![0; bins],
+            offset: offset.normalized(),
+            stats: CircularStats::new(),
+        })
+    }
+
+    /// Add an angle to the histogram. Angles which are not finite are
+    /// ignored.
+    #[inline]
+    pub fn add(&mut self, angle: Angle<T>) {
+        if let Some(i) = self.bin_of(angle) {
+            self.counts[i] += 1;
+            self.stats.push(angle);
+        }
+    }
+
+    /// Find the bin the angle falls in, or return `None` if it is not
+    /// finite.
+    #[inline]
+    pub fn bin_of(&self, angle: Angle<T>) -> Option<usize> {
+        bin_of(angle, self.offset, self.counts.len())
+    }
+
+    /// Yield the number of bins.
+    #[inline]
+    pub fn bins(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Yield the number of angles counted in each bin.
+    #[inline]
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Yield the number of angles counted in all bins.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Yield the normalized start of the first bin.
+    #[inline]
+    pub fn offset(&self) -> Angle<T> {
+        self.offset
+    }
+
+    /// Yield the width of each bin, in the unit of the offset.
+    #[inline]
+    pub fn bin_width(&self) -> Angle<T> {
+        bin_width(self.offset, self.counts.len())
+    }
+
+    /// Yield the normalized start of bin `i`, or `None` if there is no such
+    /// bin.
+    #[inline]
+    pub fn bin_start(&self, i: usize) -> Option<Angle<T>> {
+        bin_start(self.offset, self.counts.len(), i)
+    }
+
+    /// Yield the normalized center of bin `i`, or `None` if there is no such
+    /// bin.
+    #[inline]
+    pub fn bin_center(&self, i: usize) -> Option<Angle<T>> {
+        bin_center(self.offset, self.counts.len(), i)
+    }
+
+    /// Yield the statistics of the angles added.
+    #[inline]
+    pub fn stats(&self) -> CircularStats<T> {
+        self.stats
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float, A: Borrow<Angle<T>>> Extend<A> for CircularHistogram<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = A>>(&mut self, angles: I) {
+        for angle in angles {
+            self.add(*angle.borrow());
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Copy + num_traits::NumCast + PartialEq> PartialEq for CircularHistogram<T> {
+    #[inline]
+    fn eq(&self, other: &CircularHistogram<T>) -> bool {
+        self.counts == other.counts && self.offset == other.offset && self.stats == other.stats
+    }
+}
+
+/// A histogram summing the weights of angles in `N` equal sectors of the
+/// circle, without allocating.
+///
+/// The bins are laid out as for
+/// [`CircularHistogram`](struct.CircularHistogram.html): bin `i` covers the
+/// half-open sector from `offset + i·w` up to `offset + (i + 1)·w`
+/// counter-clockwise, and an angle exactly on a boundary is counted in the
+/// bin starting there. An offset of minus half a bin centers the first bin
+/// on zero, e.g. north for a rose plot of
+/// [`Bearing`](struct.Bearing.html)s.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let mut histogram = AngleHistogram::<f64, 4>::new(Degrees(-45.0)).unwrap();
+/// histogram.add(Degrees(10.0));
+/// histogram.add(Degrees(350.0));
+/// histogram.add_weighted(Degrees(100.0), 2.5);
+///
+/// assert_eq!(histogram.weights(), &[2.0, 2.5, 0.0, 0.0]);
+/// assert_eq!(histogram.bin_of(Degrees(180.0)), Some(2));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct AngleHistogram<T, const N: usize> {
+    weights: [T; N],
+    offset: Angle<T>,
+    stats: CircularStats<T>,
+}
+
+impl<T: Float, const N: usize> AngleHistogram<T, N> {
+    /// Create an empty histogram, the first bin of which starts at
+    /// `offset`, or return `None` if `N` is zero or the offset is not
+    /// finite.
+    pub fn new(offset: Angle<T>) -> Option<AngleHistogram<T, N>> {
+        if N == 0 || !offset.value().is_finite() {
+            return None;
+        }
+
+        Some(AngleHistogram {
+            weights: [T::zero(); N],
+            offset: offset.normalized(),
+            stats: CircularStats::new(),
+        })
+    }
+
+    /// Add an angle to the histogram with a weight of one. Angles which are
+    /// not finite are ignored.
+    #[inline]
+    pub fn add(&mut self, angle: Angle<T>) {
+        self.add_weighted(angle, T::one());
+    }
+
+    /// Add an angle to the histogram with the given weight. Angles which
+    /// are not finite and negative or NaN weights are ignored.
+    #[inline]
+    pub fn add_weighted(&mut self, angle: Angle<T>, weight: T) {
+        if weight.is_nan() || weight < T::zero() {
+            return;
+        }
+        if let Some(i) = self.bin_of(angle) {
+            self.weights[i] = self.weights[i] + weight;
+            self.stats.push_weighted(angle, weight);
+        }
+    }
+
+    /// Find the bin the angle falls in, or return `None` if it is not
+    /// finite.
+    #[inline]
+    pub fn bin_of(&self, angle: Angle<T>) -> Option<usize> {
+        bin_of(angle, self.offset, N)
+    }
+
+    /// Yield the total weight of the angles in each bin.
+    #[inline]
+    pub fn weights(&self) -> &[T; N] {
+        &self.weights
+    }
+
+    /// Yield the total weight of the angles in all bins.
+    #[inline]
+    pub fn total(&self) -> T {
+        self.weights.iter().fold(T::zero(), |sum, &w| sum + w)
+    }
+
+    /// Yield the normalized start of the first bin.
+    #[inline]
+    pub fn offset(&self) -> Angle<T> {
+        self.offset
+    }
+
+    /// Yield the width of each bin, in the unit of the offset.
+    #[inline]
+    pub fn bin_width(&self) -> Angle<T> {
+        bin_width(self.offset, N)
+    }
+
+    /// Yield the normalized start of bin `i`, or `None` if there is no such
+    /// bin.
+    #[inline]
+    pub fn bin_start(&self, i: usize) -> Option<Angle<T>> {
+        bin_start(self.offset, N, i)
+    }
+
+    /// Yield the normalized center of bin `i`, or `None` if there is no such
+    /// bin.
+    #[inline]
+    pub fn bin_center(&self, i: usize) -> Option<Angle<T>> {
+        bin_center(self.offset, N, i)
+    }
+
+    /// Yield the weighted statistics of the angles added.
+    #[inline]
+    pub fn stats(&self) -> CircularStats<T> {
+        self.stats
+    }
+}
+
+impl<T: Float, A: Borrow<Angle<T>>, const N: usize> Extend<A> for AngleHistogram<T, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = A>>(&mut self, angles: I) {
+        for angle in angles {
+            self.add(*angle.borrow());
+        }
+    }
+}
+
+/// Find the bin of `bins` starting at `offset` which the angle falls in.
+fn bin_of<T: Float>(angle: Angle<T>, offset: Angle<T>, bins: usize) -> Option<usize> {
+    let v = angle.value_in(offset.unit());
+    if !v.is_finite() {
+        return None;
+    }
+
+    let full: T = offset.unit().full_turn_in();
+    let v = Angle::from_parts(v - offset.value(), offset.unit())
+        .normalized()
+        .value();
+    let n: T = cast(bins).unwrap();
+    let i: usize = cast((v / full * n).floor()).unwrap();
+
+    // Rounding may push angles just short of a full turn past the last bin.
+    Some(i.min(bins - 1))
+}
+
+#[inline]
+fn bin_width<T: Float>(offset: Angle<T>, bins: usize) -> Angle<T> {
+    let full: T = offset.unit().full_turn_in();
+    let bins: T = cast(bins).unwrap();
+    Angle::from_parts(full / bins, offset.unit())
+}
+
+#[inline]
+fn bin_start<T: Float>(offset: Angle<T>, bins: usize, i: usize) -> Option<Angle<T>> {
+    if i < bins {
+        let i: T = cast(i).unwrap();
+        Some((offset + AngleDelta::new(bin_width(offset, bins) * i)).normalized())
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn bin_center<T: Float>(offset: Angle<T>, bins: usize, i: usize) -> Option<Angle<T>> {
+    let half = bin_width(offset, bins) / (T::one() + T::one());
+    bin_start(offset, bins, i).map(|start| (start + AngleDelta::new(half)).normalized())
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+
+    use crate::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_histogram_bins() {
+        let mut histogram = CircularHistogram::new(8, Degrees(0.0)).unwrap();
+        for i in 0..360 {
+            histogram.add(Degrees(i as f64 + 0.5));
+        }
+        assert_eq!(histogram.counts(), &[45; 8]);
+        assert_eq!(histogram.total(), 360);
+        assert_eq!(histogram.bins(), 8);
+        assert_eq!(histogram.bin_width(), Degrees(45.0));
+        assert_eq!(histogram.stats().count(), 360);
+
+        // Boundaries belong to the bin starting there.
+        assert_eq!(histogram.bin_of(Degrees(0.0)), Some(0));
+        assert_eq!(histogram.bin_of(Degrees(45.0)), Some(1));
+        assert_eq!(histogram.bin_of(Degrees(-45.0)), Some(7));
+        assert_eq!(histogram.bin_of(Degrees(359.999_999)), Some(7));
+        assert_eq!(histogram.bin_of(Radians(PI)), Some(4));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_histogram_offset() {
+        let histogram = CircularHistogram::new(4, Degrees(-45.0)).unwrap();
+        assert_eq!(histogram.offset(), Degrees(315.0));
+        assert_eq!(histogram.bin_of(Degrees(0.0)), Some(0));
+        assert_eq!(histogram.bin_of(Degrees(315.0)), Some(0));
+        assert_eq!(histogram.bin_of(Degrees(314.0)), Some(3));
+        assert_eq!(histogram.bin_of(Degrees(45.0)), Some(1));
+        assert_eq!(histogram.bin_start(0), Some(Degrees(315.0)));
+        assert_eq!(histogram.bin_start(1), Some(Degrees(45.0)));
+        assert_eq!(histogram.bin_center(0), Some(Degrees(0.0)));
+        assert_eq!(histogram.bin_center(3), Some(Degrees(270.0)));
+        assert_eq!(histogram.bin_center(4), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_histogram_invalid() {
+        assert!(CircularHistogram::new(0, Degrees(0.0)).is_none());
+        assert!(CircularHistogram::new(4, Degrees(f64::NAN)).is_none());
+
+        let mut histogram = CircularHistogram::new(4, Radians(0.0)).unwrap();
+        assert_eq!(histogram.bin_of(Radians(f64::INFINITY)), None);
+        histogram.extend(vec![Radians(f64::NAN), Radians(1.0)]);
+        assert_eq!(histogram.counts(), &[1, 0, 0, 0]);
+        assert_eq!(histogram.stats().count(), 1);
+    }
+
+    #[test]
+    fn test_angle_histogram_sweep() {
+        // With north centered in the first of 12 bins, every bin gets the
+        // 30 whole degrees starting 15 degrees before its center.
+        let mut histogram = AngleHistogram::<f64, 12>::new(Degrees(-15.0)).unwrap();
+        for i in 0..360 {
+            histogram.add(Degrees(i as f64));
+        }
+        assert_eq!(histogram.weights(), &[30.0; 12]);
+        assert_eq!(histogram.total(), 360.0);
+        assert_eq!(histogram.stats().count(), 360);
+        assert_eq!(histogram.bin_center(0), Some(Degrees(0.0)));
+        assert_eq!(histogram.bin_start(1), Some(Degrees(15.0)));
+
+        // Boundaries belong to the bin starting there.
+        assert_eq!(histogram.bin_of(Degrees(15.0)), Some(1));
+        assert_eq!(histogram.bin_of(Degrees(14.999)), Some(0));
+        assert_eq!(histogram.bin_of(Degrees(345.0)), Some(0));
+        assert_eq!(histogram.bin_of(Degrees(344.999)), Some(11));
+        assert_eq!(histogram.bin_of(Degrees(-720.0)), Some(0));
+        assert_eq!(histogram.bin_of(Radians(PI)), Some(6));
+
+        // Sweeping in radians with many samples per bin.
+        let mut histogram = AngleHistogram::<f64, 8>::new(Radians(0.0)).unwrap();
+        for i in 0..8000 {
+            histogram.add(Radians((i as f64 + 0.5) * PI / 4000.0));
+        }
+        assert_eq!(histogram.weights(), &[1000.0; 8]);
+    }
+
+    #[test]
+    fn test_angle_histogram_weighted() {
+        let mut histogram = AngleHistogram::<f32, 4>::new(Degrees(0.0)).unwrap();
+        histogram.add_weighted(Degrees(10.0), 2.0);
+        histogram.add_weighted(Degrees(100.0), 0.5);
+        histogram.add_weighted(Degrees(110.0), -1.0);
+        histogram.add_weighted(Degrees(200.0), f32::NAN);
+        histogram.add_weighted(Degrees(f32::INFINITY), 1.0);
+        histogram.extend(&[Degrees(280.0), Degrees(20.0)]);
+        assert_eq!(histogram.weights(), &[3.0, 0.5, 0.0, 1.0]);
+        assert_eq!(histogram.total(), 4.5);
+        assert_eq!(histogram.stats().count(), 4);
+        assert_eq!(histogram.bin_width(), Degrees(90.0));
+    }
+
+    #[test]
+    fn test_angle_histogram_invalid() {
+        assert!(AngleHistogram::<f64, 0>::new(Degrees(0.0)).is_none());
+        assert!(AngleHistogram::<f64, 4>::new(Degrees(f64::NAN)).is_none());
+        let histogram = AngleHistogram::<f64, 4>::new(Degrees(0.0)).unwrap();
+        assert_eq!(histogram.bin_of(Degrees(f64::NAN)), None);
+        assert_eq!(histogram.bin_center(4), None);
+    }
+}
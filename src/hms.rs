@@ -0,0 +1,388 @@
+//! Hours, minutes and seconds of hour angle and right ascension.
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::f64::consts::PI;
+use core::fmt::{Display, Error, Formatter};
+use num_traits::cast::{cast, NumCast};
+
+use crate::{unwrap_cast, Angle, Convertible, Degrees, ParseAngleError, Radians};
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Create an angle from a value in hours, with 24 h in a full circle. The
+    /// angle is stored in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_hours(6.0f64), Degrees(90.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn from_hours(hours: T) -> Angle<T>
+    where
+        T: Convertible,
+    {
+        let degrees = cast::<T, f64>(hours).unwrap() * 15.0;
+        Degrees(unwrap_cast(cast(degrees)))
+    }
+
+    /// Yield the value encoded in hours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn in_hours(self) -> T
+    where
+        T: Convertible,
+    {
+        let hours = match self {
+            Radians(v) => cast::<T, f64>(v).unwrap() / PI * 12.0,
+            Degrees(v) => cast::<T, f64>(v).unwrap() / 15.0,
+        };
+        unwrap_cast(cast(hours))
+    }
+
+    /// Create an angle from whole `hours`, whole `minutes` and decimal
+    /// `seconds`. The angle is stored in degrees.
+    ///
+    /// The sign of the first non-zero component applies to the whole
+    /// quantity, and the components after it must not be negative, so hour
+    /// angles between 0h and -1h are expressed with zero (or negative zero)
+    /// hours and negative minutes or seconds. Returns `ParseAngleError::MinutesOutOfRange` or
+    /// `ParseAngleError::SecondsOutOfRange` if a component is out of the
+    /// range [0, 60) or negative when it may not be, and
+    /// `ParseAngleError::OutOfRange` if the angle cannot be represented in
+    /// `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Angle::from_hms(-1.0f64, 30.0, 0.0).unwrap();
+    /// assert!((alpha.in_hours() + 1.5).abs() < 1.0e-10);
+    ///
+    /// let beta = Angle::from_hms(0.0f64, -30.0, 0.0).unwrap();
+    /// assert!((beta.in_hours() + 0.5).abs() < 1.0e-10);
+    /// ```
+    pub fn from_hms(hours: T, minutes: T, seconds: T) -> Result<Angle<T>, ParseAngleError> {
+        let hours: f64 = cast(hours).ok_or(ParseAngleError::InvalidNumber)?;
+        let minutes: f64 = cast(minutes).ok_or(ParseAngleError::InvalidNumber)?;
+        let seconds: f64 = cast(seconds).ok_or(ParseAngleError::InvalidNumber)?;
+
+        if minutes.is_nan() || minutes.abs() >= 60.0 {
+            return Err(ParseAngleError::MinutesOutOfRange);
+        }
+        if seconds.is_nan() || seconds.abs() >= 60.0 {
+            return Err(ParseAngleError::SecondsOutOfRange);
+        }
+        let negative = if hours != 0.0 {
+            hours < 0.0
+        } else if minutes != 0.0 {
+            hours.is_sign_negative() || minutes < 0.0
+        } else {
+            hours.is_sign_negative() || seconds < 0.0
+        };
+        if hours != 0.0 && minutes < 0.0 {
+            return Err(ParseAngleError::MinutesOutOfRange);
+        }
+        if (hours != 0.0 || minutes != 0.0) && seconds < 0.0 {
+            return Err(ParseAngleError::SecondsOutOfRange);
+        }
+
+        let value = hours.abs() + (minutes.abs() * 60.0 + seconds.abs()) / 3600.0;
+        let value = 15.0 * if negative { -value } else { value };
+        cast(value).map(Degrees).ok_or(ParseAngleError::OutOfRange)
+    }
+
+    /// Decompose the angle into its sign, whole hours, whole minutes and
+    /// decimal seconds, with the seconds rounded to `decimals` places (at
+    /// most 9).
+    ///
+    /// The sign is `1` or `-1`. Rounding carries into the minutes and hours,
+    /// so the seconds are always in the range [0, 60). The hours are not
+    /// wrapped into a day, and saturate at `u32::MAX`. An angle which is NaN
+    /// or infinite yields zero hours and minutes, and NaN or infinite seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_hours(-1.5f64).to_hms(0), (-1, 1, 30, 0.0));
+    /// assert_eq!(Degrees(188.736_25f64).to_hms(1), (1, 12, 34, 56.7));
+    /// ```
+    pub fn to_hms(self, decimals: usize) -> (i8, u32, u8, f64) {
+        let hours = self.checked_hours();
+        let sign = if hours.is_sign_negative() { -1 } else { 1 };
+        match round_seconds(hours, decimals) {
+            Some((whole, rest, scale)) => {
+                let (minutes, seconds) = split_seconds(rest, scale);
+                (
+                    sign,
+                    whole as u32,
+                    minutes as u8,
+                    seconds as f64 / scale as f64,
+                )
+            }
+            None => (sign, 0, 0, hours.abs()),
+        }
+    }
+
+    /// Format the angle in hours, minutes and decimal seconds, e.g.
+    /// `12h34m56.7s`, with the seconds rounded to `decimals` places (at most
+    /// 9). An angle which is NaN or infinite prints as `NaN`, `inf` or
+    /// `-inf`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Degrees(188.736_25f64);
+    /// assert_eq!(alpha.display_hms(1).to_string(), "12h34m56.7s");
+    /// let beta = Degrees(548.736_25f64);
+    /// assert_eq!(beta.display_hms(1).to_string(), "36h34m56.7s");
+    /// assert_eq!(beta.display_hms(1).wrapped().to_string(), "12h34m56.7s");
+    /// ```
+    pub fn display_hms(self, decimals: usize) -> Hms {
+        Hms {
+            hours: self.checked_hours(),
+            decimals: decimals.min(9),
+            wrap: false,
+        }
+    }
+
+    /// Format the angle in hours, minutes and decimal seconds into a string.
+    /// See [`display_hms`](#method.display_hms) for details.
+    #[cfg(feature = "alloc")]
+    pub fn format_hms(self, decimals: usize) -> String {
+        self.display_hms(decimals).to_string()
+    }
+
+    /// Yield the value in hours as an `f64`, or NaN if it cannot be
+    /// converted.
+    #[inline]
+    fn checked_hours(self) -> f64 {
+        self.checked_in_degrees()
+            .and_then(|v| cast::<T, f64>(v))
+            .map_or(f64::NAN, |v| v / 15.0)
+    }
+}
+
+/// Helper struct for formatting an angle in hours, minutes and decimal
+/// seconds.
+///
+/// This `struct` is created by the [`display_hms`] method on [`Angle`]. See its
+/// documentation for more.
+///
+/// [`display_hms`]: enum.Angle.html#method.display_hms
+/// [`Angle`]: enum.Angle.html
+#[derive(Copy, Clone, Debug)]
+pub struct Hms {
+    hours: f64,
+    decimals: usize,
+    wrap: bool,
+}
+
+impl Hms {
+    /// Wrap the hours into a day, the range of [0, 24) h, before formatting.
+    /// The wrapping happens after rounding, so a value which rounds up to
+    /// 24h prints as `0h`.
+    #[inline]
+    pub fn wrapped(self) -> Hms {
+        Hms { wrap: true, ..self }
+    }
+}
+
+impl Display for Hms {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let (mut hours, mut rest, scale) = match round_seconds(self.hours, self.decimals) {
+            Some(rounded) => rounded,
+            None => return write!(f, "{}", self.hours),
+        };
+        let negative = self.hours.is_sign_negative();
+        if self.wrap {
+            hours %= 24.0;
+            if negative && rest != 0 {
+                rest = 3600 * scale - rest;
+                hours = 23.0 - hours;
+            } else if negative && hours != 0.0 {
+                hours = 24.0 - hours;
+            }
+        } else if negative {
+            write!(f, "-")?;
+        }
+
+        let (minutes, seconds) = split_seconds(rest, scale);
+        write!(f, "{}h{:02}m{:02}", hours, minutes, seconds / scale)?;
+        if self.decimals > 0 {
+            write!(f, ".{:0d$}", seconds % scale, d = self.decimals)?;
+        }
+        write!(f, "s")
+    }
+}
+
+/// Round the magnitude of a value in hours, or degrees, to seconds with
+/// `decimals` places (at most 9). Returns the whole hours, the seconds
+/// within the hour scaled by the returned power of ten, and that power, or
+/// `None` if the value is NaN or infinite.
+///
+/// The whole hours are split off first, so that values too large for the
+/// scaled seconds to fit in a `u64` keep their magnitude.
+#[inline]
+pub(crate) fn round_seconds(hours: f64, decimals: usize) -> Option<(f64, u64, u64)> {
+    if !hours.is_finite() {
+        return None;
+    }
+    let scale = 10u64.pow(decimals.min(9) as u32);
+    let hour = 3600 * scale;
+    let abs = hours.abs();
+    // Every value from 2⁵² on is a whole number.
+    let whole = if abs < 4_503_599_627_370_496.0 {
+        abs as u64 as f64
+    } else {
+        abs
+    };
+    let rest = ((abs - whole) * hour as f64 + 0.5) as u64;
+    if rest >= hour {
+        Some((whole + 1.0, rest - hour, scale))
+    } else {
+        Some((whole, rest, scale))
+    }
+}
+
+/// Split seconds within an hour (or degree) scaled by `scale` into whole
+/// minutes and the remaining scaled seconds.
+#[inline]
+pub(crate) fn split_seconds(rest: u64, scale: u64) -> (u64, u64) {
+    (rest / (60 * scale), rest % (60 * scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_hours() {
+        assert_eq!(Angle::from_hours(6.0), Degrees(90.0));
+        assert_eq!(Angle::from_hours(-12.5), Degrees(-187.5));
+        assert_eq!(Angle::from_hours(30.0), Degrees(450.0));
+        assert_eq!(Degrees(450.0).in_hours(), 30.0);
+        assert_eq!(Radians(PI).in_hours(), 12.0);
+        assert_eq!(Radians(-PI / 2.0).in_hours(), -6.0);
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(Angle::from_hours(2i32), Degrees(30));
+        assert_eq!(Angle::from_hours(30.0).normalized().in_hours(), 6.0);
+    }
+
+    #[test]
+    fn test_from_hms() {
+        let alpha = Angle::from_hms(12.0, 34.0, 56.7).unwrap();
+        assert_that!(alpha.in_degrees(), close_to(188.736_25, 1.0e-10));
+        let cases = [
+            ((-1.0, 30.0, 0.0), -1.5),
+            ((0.0, -30.0, 0.0), -0.5),
+            ((-0.0, 30.0, 0.0), -0.5),
+            ((0.0, 0.0, -36.0), -0.01),
+            ((0.0, 30.0, 0.0), 0.5),
+            ((25.0, 0.0, 0.0), 25.0),
+        ];
+        for &((h, m, s), hours) in &cases {
+            let alpha = Angle::from_hms(h, m, s).unwrap();
+            assert_that!(alpha.in_hours(), close_to(hours, 1.0e-12));
+        }
+
+        let errors = [
+            ((1.0, 60.0, 0.0), ParseAngleError::MinutesOutOfRange),
+            ((1.0, -1.0, 0.0), ParseAngleError::MinutesOutOfRange),
+            ((1.0, f64::NAN, 0.0), ParseAngleError::MinutesOutOfRange),
+            ((1.0, 0.0, 60.0), ParseAngleError::SecondsOutOfRange),
+            ((0.0, 1.0, -1.0), ParseAngleError::SecondsOutOfRange),
+        ];
+        for &((h, m, s), error) in &errors {
+            assert_eq!(Angle::from_hms(h, m, s), Err(error));
+        }
+        assert_eq!(Angle::from_hms(1i32, 0, 0), Ok(Degrees(15)));
+    }
+
+    #[test]
+    fn test_to_hms() {
+        assert_eq!(Degrees(188.736_25).to_hms(1), (1, 12, 34, 56.7));
+        assert_eq!(Angle::from_hours(-0.5).to_hms(0), (-1, 0, 30, 0.0));
+        assert_eq!(Angle::from_hours(30.0).to_hms(0), (1, 30, 0, 0.0));
+        // 59.99996s must carry into the hours rather than come out as 60s.
+        let alpha = Angle::from_hms(4.0, 59.0, 59.999_96).unwrap();
+        assert_eq!(alpha.to_hms(3), (1, 5, 0, 0.0));
+        assert_eq!((-alpha).to_hms(3), (-1, 5, 0, 0.0));
+
+        for &decimals in &[0, 2, 5] {
+            for &hours in &[0.0, 12.345_678, -5.5, 23.999_999_9, -0.25, 48.0] {
+                let (sign, h, m, s) = Angle::from_hours(hours).to_hms(decimals);
+                let back = Angle::from_hms(sign as f64 * h as f64, m as f64, s).unwrap();
+                let tolerance = 0.5 / 3600.0 / 10f64.powi(decimals as i32) + 1.0e-12;
+                assert_that!(back.in_hours(), close_to(hours, tolerance));
+            }
+        }
+    }
+
+    #[test]
+    fn test_display_hms() {
+        let alpha = Angle::from_hms(12.0, 34.0, 56.7).unwrap();
+        assert_eq!(alpha.display_hms(1).to_string(), "12h34m56.7s");
+        assert_eq!(alpha.display_hms(0).to_string(), "12h34m57s");
+        assert_eq!((-alpha).display_hms(2).to_string(), "-12h34m56.70s");
+        assert_eq!(Angle::from_hours(-0.5).format_hms(0), "-0h30m00s");
+        assert_eq!(Angle::from_hours(1.0 / 60.0).format_hms(0), "0h01m00s");
+
+        // Seconds which round up to 60 carry into the minutes and hours.
+        let alpha = Angle::from_hms(4.0, 59.0, 59.96).unwrap();
+        assert_eq!(alpha.format_hms(1), "5h00m00.0s");
+        assert_eq!(alpha.format_hms(2), "4h59m59.96s");
+
+        let alpha = Angle::from_hours(36.5);
+        assert_eq!(alpha.format_hms(0), "36h30m00s");
+        assert_eq!(alpha.display_hms(0).wrapped().to_string(), "12h30m00s");
+        let alpha = Angle::from_hours(-1.25);
+        assert_eq!(alpha.display_hms(0).wrapped().to_string(), "22h45m00s");
+        let alpha = Angle::from_hours(-2.0);
+        assert_eq!(alpha.display_hms(0).wrapped().to_string(), "22h00m00s");
+        let alpha = Angle::from_hours(-48.0);
+        assert_eq!(alpha.display_hms(0).wrapped().to_string(), "0h00m00s");
+        let alpha = Angle::from_hms(23.0, 59.0, 59.96).unwrap();
+        assert_eq!(alpha.display_hms(1).wrapped().to_string(), "0h00m00.0s");
+    }
+
+    #[test]
+    fn test_display_hms_out_of_range() {
+        assert_eq!(Degrees(f64::NAN).format_hms(1), "NaN");
+        assert_eq!(Degrees(f64::INFINITY).format_hms(1), "inf");
+        assert_eq!(Radians(f64::NEG_INFINITY).format_hms(1), "-inf");
+        assert_eq!(
+            Degrees(f64::NAN).display_hms(0).wrapped().to_string(),
+            "NaN"
+        );
+
+        let alpha = Angle::from_hours(1.0e20);
+        assert_eq!(
+            alpha.format_hms(9),
+            "100000000000000000000h00m00.000000000s"
+        );
+        assert_eq!((-alpha).format_hms(0), "-100000000000000000000h00m00s");
+        assert_eq!(alpha.display_hms(0).wrapped().to_string(), "16h00m00s");
+        assert_eq!(alpha.to_hms(0), (1, u32::MAX, 0, 0.0));
+
+        let (sign, hours, minutes, seconds) = Degrees(-f64::NAN).to_hms(2);
+        assert_eq!((sign, hours, minutes), (-1, 0, 0));
+        assert!(seconds.is_nan());
+        assert_eq!(Degrees(f64::INFINITY).to_hms(2), (1, 0, 0, f64::INFINITY));
+    }
+}
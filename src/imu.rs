@@ -0,0 +1,369 @@
+//! Orientation from inertial and magnetic sensors.
+
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, Radians};
+
+/// Compute the static pitch and roll of a device from the gravity measured by
+/// its 3-axis accelerometer, in units of standard gravity, `g`.
+///
+/// Returns `None` if the magnitude of the measurement differs from 1 g by more
+/// than 0.1 g, which means the device is accelerating and the measurement is
+/// not dominated by gravity. See
+/// [`pitch_roll_from_accel_with_tolerance`](fn.pitch_roll_from_accel_with_tolerance.html)
+/// for the conventions used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let (pitch, roll) = pitch_roll_from_accel(0.0f64, 0.0, 1.0).unwrap();
+/// assert_eq!((pitch, roll), (Radians(0.0), Radians(0.0)));
+///
+/// assert!(pitch_roll_from_accel(0.0f64, 0.0, 1.5).is_none());
+/// ```
+#[inline]
+pub fn pitch_roll_from_accel<T: Float>(ax: T, ay: T, az: T) -> Option<(Angle<T>, Angle<T>)> {
+    pitch_roll_from_accel_with_tolerance(ax, ay, az, cast(0.1).unwrap())
+}
+
+/// Compute the static pitch and roll of a device from the gravity measured by
+/// its 3-axis accelerometer, in units of standard gravity, `g`.
+///
+/// The device's x axis points forward, its y axis to the right and its z axis
+/// down, and the accelerometer measures the reaction to gravity, so a level
+/// device at rest reads `(0, 0, 1)`. The orientation is the rotation by the
+/// roll about the x axis followed by the pitch about the y axis, as in NXP
+/// application note AN3461:
+///
+/// * roll = atan2(ay, az), in the range of (-π, π] rad, positive with the
+///   right side down,
+/// * pitch = atan2(-ax, √(ay² + az²)), in the range of [-π/2, π/2] rad,
+///   positive with the nose up.
+///
+/// When the x axis points straight up or down, the roll is undefined and
+/// reported as zero.
+///
+/// Returns `None` if the magnitude of the measurement differs from 1 g by more
+/// than `tolerance`, or any component is not finite.
+pub fn pitch_roll_from_accel_with_tolerance<T: Float>(
+    ax: T,
+    ay: T,
+    az: T,
+    tolerance: T,
+) -> Option<(Angle<T>, Angle<T>)> {
+    let magnitude = (ax * ax + ay * ay + az * az).sqrt();
+    if !magnitude.is_finite() || (magnitude - T::one()).abs() > tolerance {
+        return None;
+    }
+
+    let (pitch, roll) = tilt(ax, ay, az);
+    Some((Radians(pitch), Radians(roll)))
+}
+
+/// The calibration of a magnetometer, correcting a measurement `m` to
+/// `(m - offset) * scale` on each axis.
+///
+/// The `offset` removes hard-iron distortion, the constant field of magnetized
+/// parts near the sensor, and the `scale` equalizes the sensitivity of the
+/// axes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MagCalibration<T = f64> {
+    /// The hard-iron offset subtracted from each axis.
+    pub offset: [T; 3],
+    /// The factor each axis is multiplied with after subtracting the offset.
+    pub scale: [T; 3],
+}
+
+impl<T: Float> MagCalibration<T> {
+    /// Apply the calibration to a measurement.
+    #[inline]
+    pub fn apply(&self, mag: [T; 3]) -> [T; 3] {
+        let mut corrected = mag;
+        for (i, value) in corrected.iter_mut().enumerate() {
+            *value = (*value - self.offset[i]) * self.scale[i];
+        }
+        corrected
+    }
+}
+
+impl<T: Float> Default for MagCalibration<T> {
+    #[inline]
+    fn default() -> Self {
+        MagCalibration {
+            offset: [T::zero(); 3],
+            scale: [T::one(); 3],
+        }
+    }
+}
+
+/// Compute the compass heading of a device from its magnetometer, compensating
+/// for the tilt measured by its accelerometer. The `calibration`, if any, is
+/// applied to the magnetometer measurement first.
+///
+/// The axes and the pitch and roll follow the conventions of
+/// [`pitch_roll_from_accel_with_tolerance`](fn.pitch_roll_from_accel_with_tolerance.html),
+/// but the magnitude of the acceleration is not checked. The magnetic field
+/// is rotated into the horizontal plane as in NXP application note AN4248,
+/// and the heading of the x axis is returned clockwise from magnetic north,
+/// normalized into the range of [0, 2π) rad.
+///
+/// Returns `None` if either measurement is zero or not finite, or if the
+/// magnetic field is vertical.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// // A level device facing east sees the northward field on its left.
+/// let heading = tilt_compensated_heading([0.0f64, -0.2, 0.4], [0.0, 0.0, 1.0], None).unwrap();
+/// assert!(heading.min_dist(Degrees(90.0)).in_degrees() < 1.0e-10);
+///
+/// // A constant field from the device itself is removed first.
+/// let calibration = MagCalibration {
+///     offset: [0.1, 0.1, 0.0],
+///     scale: [1.0; 3],
+/// };
+/// let raw = [0.1f64, -0.1, 0.4];
+/// let heading = tilt_compensated_heading(raw, [0.0, 0.0, 1.0], Some(calibration)).unwrap();
+/// assert!(heading.min_dist(Degrees(90.0)).in_degrees() < 1.0e-10);
+/// ```
+pub fn tilt_compensated_heading<T: Float>(
+    mag: [T; 3],
+    accel: [T; 3],
+    calibration: Option<MagCalibration<T>>,
+) -> Option<Angle<T>> {
+    let mag = calibration.map_or(mag, |calibration| calibration.apply(mag));
+    let [ax, ay, az] = accel;
+    if !(ax * ax + ay * ay + az * az).is_normal() {
+        return None;
+    }
+
+    let (pitch, roll) = tilt(ax, ay, az);
+    let (sin_pitch, cos_pitch) = pitch.sin_cos();
+    let (sin_roll, cos_roll) = roll.sin_cos();
+
+    let [mx, my, mz] = mag;
+    let x = mx * cos_pitch + (my * sin_roll + mz * cos_roll) * sin_pitch;
+    let y = mz * sin_roll - my * cos_roll;
+    if !(x * x + y * y).is_normal() {
+        return None;
+    }
+    Some(Radians(y.atan2(x)).normalized())
+}
+
+/// Compute the pitch and roll in radians from a measurement of gravity.
+#[inline]
+fn tilt<T: Float>(ax: T, ay: T, az: T) -> (T, T) {
+    let level = ay.hypot(az);
+    let roll = if level.is_zero() {
+        T::zero()
+    } else {
+        ay.atan2(az)
+    };
+    ((-ax).atan2(level), roll)
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_pitch_roll_axis_aligned() {
+        let cases = [
+            ((0.0, 0.0, 1.0), (0.0, 0.0)),
+            ((-1.0, 0.0, 0.0), (90.0, 0.0)),
+            ((1.0, 0.0, 0.0), (-90.0, 0.0)),
+            ((0.0, 1.0, 0.0), (0.0, 90.0)),
+            ((0.0, -1.0, 0.0), (0.0, -90.0)),
+            ((0.0, 0.0, -1.0), (0.0, 180.0)),
+        ];
+        for &((ax, ay, az), (pitch, roll)) in &cases {
+            let (alpha, beta) = pitch_roll_from_accel(ax, ay, az).unwrap();
+            assert_eq!(alpha.in_degrees(), pitch);
+            assert_eq!(beta.in_degrees(), roll);
+        }
+    }
+
+    #[test]
+    fn test_pitch_roll_tilted() {
+        let s = 0.5f64.sqrt();
+        let (pitch, roll) = pitch_roll_from_accel(0.0, s, s).unwrap();
+        assert_that!(pitch.in_degrees(), close_to(0.0, 1.0e-10));
+        assert_that!(roll.in_degrees(), close_to(45.0, 1.0e-10));
+
+        let (pitch, roll) = pitch_roll_from_accel(-s, 0.0, s).unwrap();
+        assert_that!(pitch.in_degrees(), close_to(45.0, 1.0e-10));
+        assert_that!(roll.in_degrees(), close_to(0.0, 1.0e-10));
+
+        // Roll by 30° then pitch by -20°, rotating the gravity reaction into
+        // the body frame.
+        let (pitch, roll) = (-20f64.to_radians(), 30f64.to_radians());
+        let g = (
+            -pitch.sin(),
+            roll.sin() * pitch.cos(),
+            roll.cos() * pitch.cos(),
+        );
+        let (alpha, beta) = pitch_roll_from_accel(g.0, g.1, g.2).unwrap();
+        assert_that!(alpha.in_radians(), close_to(pitch, 1.0e-12));
+        assert_that!(beta.in_radians(), close_to(roll, 1.0e-12));
+    }
+
+    #[test]
+    fn test_pitch_roll_reference() {
+        // Reference values from the ZYX Euler decomposition of the normalized
+        // measurement, pitch = asin(-x) and roll = atan2(y, z), computed
+        // independently in double precision.
+        let cases = [
+            (
+                (0.12, -0.35, 0.93),
+                (-6.885_878_037_722_142, -20.623_531_383_325_23),
+            ),
+            (
+                (-0.5, 0.5, 0.7),
+                (30.166_789_983_634_562, 35.537_677_791_974_39),
+            ),
+            (
+                (0.8, 0.1, -0.6),
+                (-52.752_626_797_618_79, 170.537_677_791_974_37),
+            ),
+            ((0.0, -0.7, -0.7), (0.0, -135.0)),
+            (
+                (-0.98, 0.05, 0.2),
+                (78.120_328_116_281_84, 14.036_243_467_926_479),
+            ),
+        ];
+        for &((ax, ay, az), (pitch, roll)) in &cases {
+            let (alpha, beta) = pitch_roll_from_accel(ax, ay, az).unwrap();
+            assert_that!(alpha.in_degrees(), close_to(pitch, 1.0e-9));
+            assert_that!(beta.in_degrees(), close_to(roll, 1.0e-9));
+        }
+    }
+
+    /// Rotate a vector in the north-east-down frame into the frame of a
+    /// device with the given heading, pitch and roll.
+    fn to_body(v: [f64; 3], heading: f64, pitch: f64, roll: f64) -> [f64; 3] {
+        let (s, c) = heading.to_radians().sin_cos();
+        let v = [c * v[0] + s * v[1], -s * v[0] + c * v[1], v[2]];
+        let (s, c) = pitch.to_radians().sin_cos();
+        let v = [c * v[0] - s * v[2], v[1], s * v[0] + c * v[2]];
+        let (s, c) = roll.to_radians().sin_cos();
+        [v[0], c * v[1] + s * v[2], -s * v[1] + c * v[2]]
+    }
+
+    #[test]
+    fn test_tilt_compensated_heading_level() {
+        for &(mx, my) in &[(0.3, 0.1), (-0.2, 0.25), (0.0, -0.4), (-0.1, -0.1)] {
+            let heading = tilt_compensated_heading([mx, my, 0.45], [0.0, 0.0, 1.0], None).unwrap();
+            let raw = Radians(f64::atan2(-my, mx)).normalized();
+            assert_that!(heading.min_dist(raw).in_degrees(), close_to(0.0, 1.0e-10));
+        }
+    }
+
+    #[test]
+    fn test_tilt_compensated_heading_tilted() {
+        // A field with a dip of about 60°, as at mid northern latitudes.
+        let field = [0.2, 0.0, 0.35];
+        let gravity = [0.0, 0.0, 1.0];
+        for &heading in &[0.0, 37.0, 90.0, 181.0, 300.0] {
+            for &(pitch, roll) in &[
+                (0.0, 0.0),
+                (20.0, 0.0),
+                (0.0, -35.0),
+                (-40.0, 25.0),
+                (60.0, 150.0),
+            ] {
+                let mag = to_body(field, heading, pitch, roll);
+                let accel = to_body(gravity, heading, pitch, roll);
+                let alpha = tilt_compensated_heading(mag, accel, None).unwrap();
+                assert_that!(
+                    alpha.min_dist(Degrees(heading)).in_degrees(),
+                    close_to(0.0, 0.1)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tilt_compensated_heading_calibrated() {
+        let calibration = MagCalibration {
+            offset: [0.5, -0.25, 0.1],
+            scale: [2.0, 1.0, 0.5],
+        };
+        let field = to_body([0.2, 0.0, 0.35], 123.0, 10.0, -5.0);
+        let accel = to_body([0.0, 0.0, 1.0], 123.0, 10.0, -5.0);
+        let raw = [field[0] / 2.0 + 0.5, field[1] - 0.25, field[2] / 0.5 + 0.1];
+        let heading = tilt_compensated_heading(raw, accel, Some(calibration)).unwrap();
+        assert_that!(
+            heading.min_dist(Degrees(123.0)).in_degrees(),
+            close_to(0.0, 1.0e-9)
+        );
+        assert_eq!(MagCalibration::default().apply(raw), raw);
+    }
+
+    #[test]
+    fn test_tilt_compensated_heading_reference() {
+        // Reference headings from undoing the roll and then the pitch of the
+        // normalized acceleration with rotation matrices, and taking
+        // atan2(-y, x) of the levelled field, computed independently in
+        // double precision. The fields are in µT.
+        let cases = [
+            (
+                [22.1, -5.3, 43.8],
+                [0.02, -0.01, 0.99],
+                12.899_044_395_148_604,
+            ),
+            (
+                [-12.4, 18.9, 40.2],
+                [0.31, 0.22, 0.91],
+                199.323_581_791_275_4,
+            ),
+            (
+                [8.7, -30.5, -25.0],
+                [-0.45, 0.6, -0.62],
+                278.983_066_750_792_1,
+            ),
+            ([35.0, 12.0, -20.0], [0.7, -0.1, 0.7], 347.249_055_403_081_3),
+        ];
+        for &(mag, accel, heading) in &cases {
+            let alpha = tilt_compensated_heading(mag, accel, None).unwrap();
+            assert_that!(alpha.in_degrees(), close_to(heading, 1.0e-9));
+        }
+
+        let calibration = MagCalibration {
+            offset: [4.5, -2.25, 1.0],
+            scale: [1.1, 0.95, 1.02],
+        };
+        let alpha =
+            tilt_compensated_heading([-12.4, 18.9, 40.2], [0.31, 0.22, 0.91], Some(calibration))
+                .unwrap();
+        assert_that!(alpha.in_degrees(), close_to(197.914_654_888_478_3, 1.0e-9));
+    }
+
+    #[test]
+    fn test_tilt_compensated_heading_degenerate() {
+        let field = [0.2, 0.0, 0.35];
+        assert!(tilt_compensated_heading(field, [0.0, 0.0, 0.0], None).is_none());
+        assert!(tilt_compensated_heading([0.0, 0.0, 0.0], [0.0, 0.0, 1.0], None).is_none());
+        assert!(tilt_compensated_heading([0.0, 0.0, 0.5], [0.0, 0.0, 1.0], None).is_none());
+        assert!(tilt_compensated_heading(field, [f64::NAN, 0.0, 1.0], None).is_none());
+        assert!(
+            tilt_compensated_heading([f64::INFINITY, 0.0, 0.0], [0.0, 0.0, 1.0], None).is_none()
+        );
+    }
+
+    #[test]
+    fn test_pitch_roll_magnitude() {
+        assert!(pitch_roll_from_accel(0.0, 0.0, 1.09).is_some());
+        assert!(pitch_roll_from_accel(0.0, 0.0, 1.11).is_none());
+        assert!(pitch_roll_from_accel(0.0, 0.0, 0.0).is_none());
+        assert!(pitch_roll_from_accel(f64::NAN, 0.0, 1.0).is_none());
+        assert!(pitch_roll_from_accel(0.0, f64::INFINITY, 1.0).is_none());
+
+        let (pitch, _) = pitch_roll_from_accel_with_tolerance(0.0, 0.0, 3.0, 2.5).unwrap();
+        assert_eq!(pitch, Radians(0.0));
+        assert!(pitch_roll_from_accel_with_tolerance(0.0, 0.0, 1.01, 0.0).is_none());
+    }
+}
@@ -0,0 +1,174 @@
+//! Long-running integration of angular rates.
+
+use core::f64::consts::PI;
+use core::time::Duration;
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, AngleUnit, AngularVelocity};
+
+/// An accumulator for integrating many small angular increments, such as a
+/// gyroscope rate sampled at a high frequency for hours.
+///
+/// Adding small increments to a growing total loses their low bits, so a
+/// plain `+=` drifts further the longer it runs. The integrator instead keeps
+/// the angle wrapped into a single turn with a compensation term for the
+/// rounding of each addition, as in Kahan summation, and counts whole turns
+/// separately. The precision of the angle therefore stays constant over time.
+///
+/// The angle is kept in the unit of the initial angle. In radians the
+/// rounding of the full turn of 2π is compensated as well.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let mut integrator = AngleIntegrator::new(Radians(0.0f32));
+/// for _ in 0..100_000 {
+///     integrator.add(Radians(0.001));
+/// }
+///
+/// // 100 rad is 15 whole turns and about 5.752 rad.
+/// assert_eq!(integrator.total_turns(), 15);
+/// assert!((integrator.wrapped().in_radians() - 5.752_220).abs() < 1.0e-4);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AngleIntegrator<T = f64> {
+    value: T,
+    compensation: T,
+    turns: i64,
+    unit: AngleUnit,
+}
+
+impl<T: Float> AngleIntegrator<T> {
+    /// Create an integrator starting at `initial`, normalized, with no whole
+    /// turns counted.
+    #[inline]
+    pub fn new(initial: Angle<T>) -> AngleIntegrator<T> {
+        let (value, unit) = initial.normalized().into_parts();
+        AngleIntegrator {
+            value,
+            compensation: T::zero(),
+            turns: 0,
+            unit,
+        }
+    }
+
+    /// Add the increment `delta`, which is positive counter-clockwise.
+    pub fn add(&mut self, delta: Angle<T>) {
+        // Kahan summation, carrying the rounding error of each addition over
+        // into the next one.
+        let y = delta.value_in(self.unit) - self.compensation;
+        let t = self.value + y;
+        self.compensation = (t - self.value) - y;
+        self.value = t;
+
+        let (full, full_error) = self.full_turn();
+        if self.value >= full || self.value < T::zero() {
+            let k = (self.value / full).floor();
+            self.value = self.value - k * full;
+            // The full turn as stored falls short of the true one by its
+            // rounding error, which is left for the next additions to take
+            // out.
+            self.compensation = self.compensation + k * full_error;
+            self.turns += cast::<T, i64>(k).unwrap_or(0);
+        }
+    }
+
+    /// Add the angle turned at the rate `velocity` over the duration `dt`.
+    #[inline]
+    pub fn add_rate(&mut self, velocity: AngularVelocity<T>, dt: Duration) {
+        self.add(velocity.over(dt));
+    }
+
+    /// Yield the integrated angle, normalized into the range of [0, 360)° or
+    /// [0, 2π) rad.
+    #[inline]
+    pub fn wrapped(&self) -> Angle<T> {
+        Angle::from_parts(self.value - self.compensation, self.unit).normalized()
+    }
+
+    /// Yield the number of whole turns integrated, which is negative for
+    /// clockwise turns.
+    #[inline]
+    pub fn total_turns(&self) -> i64 {
+        self.turns
+    }
+
+    /// Yield the full turn in the unit of the integrator, with the error of
+    /// rounding it to `T`.
+    #[inline]
+    fn full_turn(&self) -> (T, T) {
+        match self.unit {
+            AngleUnit::Degrees => (cast(360).unwrap(), T::zero()),
+            AngleUnit::Radians => {
+                let full: T = cast(2.0 * PI).unwrap();
+                let error = cast(2.0 * PI - cast::<T, f64>(full).unwrap()).unwrap();
+                (full, error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use core::time::Duration;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_integrator_against_naive_sum() {
+        let steps = 10_000_000;
+        let rate = AngularVelocity::radians_per_second(1.234_567f32);
+        let dt = Duration::from_millis(1);
+        let delta = rate.over(dt).in_radians() as f64;
+
+        let mut integrator = AngleIntegrator::new(Radians(0.0f32));
+        let mut naive = 0.0f32;
+        for _ in 0..steps {
+            integrator.add_rate(rate, dt);
+            naive += rate.over(dt).in_radians();
+        }
+
+        let exact = delta * steps as f64;
+        let turns = (exact / (2.0 * PI)).floor();
+        assert_eq!(integrator.total_turns(), turns as i64);
+        let wrapped = integrator.wrapped().in_radians() as f64;
+        assert_that!(wrapped, close_to(exact - turns * 2.0 * PI, 1.0e-5));
+        assert!((naive as f64 - exact).abs() > 1.0);
+    }
+
+    #[test]
+    fn test_integrator_turns() {
+        let mut integrator = AngleIntegrator::new(Degrees(350.0));
+        assert_eq!(integrator.wrapped(), Degrees(350.0));
+        integrator.add(Degrees(20.0));
+        assert_eq!(integrator.total_turns(), 1);
+        assert_eq!(integrator.wrapped(), Degrees(10.0));
+        integrator.add(Degrees(-30.0));
+        assert_eq!(integrator.total_turns(), 0);
+        assert_eq!(integrator.wrapped(), Degrees(340.0));
+        integrator.add(Degrees(-1100.0));
+        assert_eq!(integrator.total_turns(), -3);
+        assert_eq!(integrator.wrapped(), Degrees(320.0));
+        integrator.add(Radians(4.0 * PI));
+        assert_eq!(integrator.total_turns(), -1);
+        assert_that!(integrator.wrapped().in_degrees(), close_to(320.0, 1.0e-9));
+
+        let mut integrator = AngleIntegrator::new(Radians(-0.5f32));
+        assert_that!(
+            integrator.wrapped().in_radians() as f64,
+            close_to(2.0 * PI - 0.5, 1.0e-6)
+        );
+        let spin = AngularVelocity::degrees_per_second(-36.0);
+        for _ in 0..1000 {
+            integrator.add_rate(spin, Duration::from_millis(100));
+        }
+        assert_eq!(integrator.total_turns(), -10);
+        assert_that!(
+            integrator.wrapped().in_radians() as f64,
+            close_to(2.0 * PI - 0.5, 1.0e-4)
+        );
+    }
+}
@@ -1,22 +1,126 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 use core::cmp::Ordering;
 use core::f64::consts::PI;
-use core::fmt::{Display, Error, Formatter};
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::fmt::{Alignment, Display, Error, Formatter, Write};
+use core::hash::{Hash, Hasher};
+#[cfg(not(feature = "strict-algebra"))]
+use core::iter::Sum;
+#[cfg(any(feature = "std", feature = "libm"))]
+use core::num::FpCategory;
+#[cfg(not(feature = "strict-algebra"))]
+use core::ops::{Add, AddAssign, SubAssign};
+use core::ops::{Div, DivAssign, Mul, MulAssign, Neg, Sub};
 use num_traits::{
+    bounds::Bounded,
     cast::{cast, NumCast},
-    Num, Signed, Zero,
+    float::TotalOrder,
+    Euclid, Num, Signed, Zero,
 };
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 use num_traits::Float;
 
+#[cfg(any(test, feature = "quickcheck"))]
+mod arbitrary;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod arc;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod astro;
+mod bam;
+mod bearing;
+#[cfg(feature = "cgmath")]
+mod cg;
+mod compass;
+#[cfg(feature = "cordic")]
+mod cordic;
+mod ddm;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod delta;
+#[cfg(all(feature = "rand", any(feature = "std", feature = "libm")))]
+mod distribution;
+mod dms;
+#[cfg(feature = "euclid")]
+mod euclidean;
+mod ext;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod filter;
+#[cfg(feature = "half")]
+mod float16;
+mod format;
+#[cfg(all(feature = "geo", any(feature = "std", feature = "libm")))]
+pub mod gis;
+#[cfg(feature = "embedded-graphics")]
+mod graphics;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod grid;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod histogram;
+mod hms;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod imu;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod integrator;
+#[cfg(feature = "lut")]
+mod lut;
+mod nmea;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod odometry;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod optics;
+mod parse;
+mod pi;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod quant;
+#[cfg(feature = "uom")]
+mod quantity;
+#[cfg(all(feature = "rand", any(feature = "std", feature = "libm")))]
+mod random;
+#[cfg(feature = "rational")]
+mod rational;
+mod raw;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+mod resample;
+#[cfg(feature = "std")]
+mod rose;
+mod saturating;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod slope;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod solar;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+mod sort;
+mod spacing;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod sphere;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+mod spline;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod stats;
+mod sweep;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod turn;
+mod units;
+#[cfg(all(feature = "glam", any(feature = "std", feature = "libm")))]
+mod vector;
+mod velocity;
+mod wide;
+
 /// An angle.
 ///
 /// Might be a value in degrees or in radians.
-#[derive(Copy, Clone, Debug, Hash)]
+///
+/// With the `serde` feature, an angle is serialized with its unit as an
+/// externally tagged enum, e.g. `{"Degrees":45.0}` in JSON.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub enum Angle<T = f64> {
     /// The angle value in radians.
     Radians(T),
@@ -24,53 +128,347 @@ pub enum Angle<T = f64> {
     Degrees(T),
 }
 
+/// The unit an angle is stored in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum AngleUnit {
+    /// Radians, with 2π rad in a full turn.
+    Radians,
+    /// Degrees, with 360° in a full turn.
+    Degrees,
+}
+
+impl AngleUnit {
+    /// The size of a full turn in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(AngleUnit::Degrees.full_turn_in::<f32>(), 360.0);
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(AngleUnit::Degrees.full_turn_in::<i32>(), 360);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full turn cannot be represented in `T`, such as 360° in an
+    /// `i8`. See [`checked_full_turn_in`](#method.checked_full_turn_in). With
+    /// the `no-panic` feature, `T` must be [`Convertible`] instead.
+    #[inline]
+    pub fn full_turn_in<T: Convertible>(self) -> T {
+        unwrap_cast(self.checked_full_turn_in())
+    }
+
+    /// The size of a full turn in this unit, or `None` if it cannot be
+    /// represented in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(AngleUnit::Degrees.checked_full_turn_in::<i16>(), Some(360));
+    /// assert_eq!(AngleUnit::Degrees.checked_full_turn_in::<i8>(), None);
+    /// ```
+    #[inline]
+    pub fn checked_full_turn_in<T: NumCast>(self) -> Option<T> {
+        match self {
+            AngleUnit::Radians => cast(2.0 * PI),
+            AngleUnit::Degrees => cast(360.0),
+        }
+    }
+}
+
+impl<T> Angle<T> {
+    /// Create an angle from a value in the given unit.
+    #[inline]
+    pub fn from_parts(value: T, unit: AngleUnit) -> Angle<T> {
+        match unit {
+            AngleUnit::Radians => Radians(value),
+            AngleUnit::Degrees => Degrees(value),
+        }
+    }
+
+    /// Split the angle into its stored value and the unit it is stored in.
+    #[inline]
+    pub fn into_parts(self) -> (T, AngleUnit) {
+        match self {
+            Radians(v) => (v, AngleUnit::Radians),
+            Degrees(v) => (v, AngleUnit::Degrees),
+        }
+    }
+
+    /// Yield the unit the angle is stored in.
+    #[inline]
+    pub fn unit(&self) -> AngleUnit {
+        match *self {
+            Radians(_) => AngleUnit::Radians,
+            Degrees(_) => AngleUnit::Degrees,
+        }
+    }
+
+    /// Apply `f` to the stored value, keeping the unit it is stored in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Degrees(45.25f64).map(|v| v as f32);
+    /// assert_eq!(alpha, Degrees(45.25f32));
+    /// ```
+    #[inline]
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Angle<U> {
+        match self {
+            Radians(v) => Radians(f(v)),
+            Degrees(v) => Degrees(f(v)),
+        }
+    }
+}
+
+impl<T: Copy> Angle<T> {
+    /// Yield the stored value, without converting it to another unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// # use std::f64::consts::PI;
+    /// assert_eq!(Radians(PI).value(), PI);
+    /// assert_eq!(Degrees(180.0).value(), 180.0);
+    /// ```
+    #[inline]
+    pub fn value(&self) -> T {
+        match *self {
+            Radians(v) => v,
+            Degrees(v) => v,
+        }
+    }
+}
+
 impl<T: Copy + NumCast> Angle<T> {
     /// Yield the value encoded in radians.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`, which cannot happen for the
+    /// primitive numeric types. See
+    /// [`checked_in_radians`](#method.checked_in_radians).
     #[inline]
     pub fn in_radians(self) -> T {
+        unwrap_or_saturate(self.checked_in_radians(), || {
+            cast::<T, f64>(self.value()).map_or(f64::NAN, |v| match self {
+                Radians(_) => v,
+                Degrees(_) => v / 180.0 * PI,
+            })
+        })
+    }
+
+    /// Yield the value encoded in degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`, such as `Radians(100i8)`. See
+    /// [`checked_in_degrees`](#method.checked_in_degrees) and
+    /// [`saturating_in_degrees`](#method.saturating_in_degrees). With the
+    /// `no-panic` feature, `T` must be [`Convertible`] instead.
+    #[inline]
+    pub fn in_degrees(self) -> T
+    where
+        T: Convertible,
+    {
+        unwrap_cast(self.checked_in_degrees())
+    }
+
+    /// Yield the value of the angle in `unit`.
+    #[inline]
+    pub(crate) fn in_unit_of(self, unit: AngleUnit) -> T
+    where
+        T: Convertible,
+    {
+        match unit {
+            AngleUnit::Radians => self.in_radians(),
+            AngleUnit::Degrees => self.in_degrees(),
+        }
+    }
+
+    /// Yield the value of the angle in `unit`, or `None` if it cannot be
+    /// represented in `T`.
+    #[inline]
+    pub(crate) fn checked_in_unit_of(self, unit: AngleUnit) -> Option<T> {
+        match unit {
+            AngleUnit::Radians => self.checked_in_radians(),
+            AngleUnit::Degrees => self.checked_in_degrees(),
+        }
+    }
+
+    /// Yield the value encoded in radians, or `None` if the conversion
+    /// cannot be represented in `T`.
+    #[inline]
+    pub fn checked_in_radians(self) -> Option<T> {
         match self {
-            Radians(v) => v,
-            Degrees(v) => cast(cast::<T, f64>(v).unwrap() / 180.0 * PI).unwrap(),
+            Radians(v) => Some(v),
+            Degrees(v) => cast(cast::<T, f64>(v)? / 180.0 * PI),
         }
     }
 
-    /// Yield the value encoded in degrees.
+    /// Yield the value encoded in degrees, or `None` if the conversion
+    /// cannot be represented in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Radians(1i8).checked_in_degrees(), Some(57));
+    /// assert_eq!(Radians(100i8).checked_in_degrees(), None);
+    /// ```
     #[inline]
-    pub fn in_degrees(self) -> T {
+    pub fn checked_in_degrees(self) -> Option<T> {
         match self {
-            Radians(v) => cast(cast::<T, f64>(v).unwrap() / PI * 180.0).unwrap(),
-            Degrees(v) => v,
+            Radians(v) => cast(cast::<T, f64>(v)? / PI * 180.0),
+            Degrees(v) => Some(v),
+        }
+    }
+
+    /// Yield the value encoded in the given unit.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    pub(crate) fn value_in(self, unit: AngleUnit) -> T
+    where
+        T: Convertible,
+    {
+        match unit {
+            AngleUnit::Radians => self.in_radians(),
+            AngleUnit::Degrees => self.in_degrees(),
+        }
+    }
+
+    /// Apply `f` to the value in radians, keeping the unit the angle is stored
+    /// in.
+    #[inline]
+    pub fn map_in_radians<F: FnOnce(T) -> T>(self, f: F) -> Self
+    where
+        T: Convertible,
+    {
+        let v = Radians(f(self.in_radians()));
+        match self {
+            Radians(_) => v,
+            Degrees(_) => Degrees(v.in_degrees()),
+        }
+    }
+
+    /// Apply `f` to the value in degrees, keeping the unit the angle is stored
+    /// in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// # use std::f64::consts::PI;
+    /// let alpha = Radians(PI).map_in_degrees(|v| v.min(90.0));
+    /// assert!((alpha.in_radians() - PI / 2.0).abs() < 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn map_in_degrees<F: FnOnce(T) -> T>(self, f: F) -> Self
+    where
+        T: Convertible,
+    {
+        let v = Degrees(f(self.in_degrees()));
+        match self {
+            Radians(_) => Radians(v.in_radians()),
+            Degrees(_) => v,
         }
     }
 
+    /// Convert the stored value to another numeric type, keeping the unit it
+    /// is stored in.
+    ///
+    /// Returns `None` if the value cannot be represented in the new type.
+    /// Narrowing between floating point types rounds to the nearest value,
+    /// overflowing to infinity like an `as` conversion.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(90.0f64).cast_into::<i16>(), Some(Degrees(90)));
+    /// assert_eq!(Degrees(1.0e6f64).cast_into::<i16>(), None);
+    /// ```
+    #[inline]
+    pub fn cast_into<U: NumCast>(self) -> Option<Angle<U>> {
+        let (v, unit) = self.into_parts();
+        U::from(v).map(|v| Angle::from_parts(v, unit))
+    }
+
+    /// Convert the stored value to another numeric type, keeping the unit it
+    /// is stored in.
+    ///
+    /// Values which cannot be represented in the new type saturate to its
+    /// minimum or maximum value, and NaN becomes zero when the new type has
+    /// no NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(1.0e6f64).lossy_cast_into::<i16>(), Degrees(i16::MAX));
+    /// ```
+    #[inline]
+    pub fn lossy_cast_into<U: NumCast + Bounded + Zero>(self) -> Angle<U> {
+        let (v, unit) = self.into_parts();
+        let v = U::from(v).unwrap_or_else(|| match cast::<T, f64>(v) {
+            Some(v) if v > 0.0 => U::max_value(),
+            Some(v) if v < 0.0 => U::min_value(),
+            _ => U::zero(),
+        });
+        Angle::from_parts(v, unit)
+    }
+
     /// An angle of 45°.
     #[inline]
     pub fn eighth() -> Angle<T> {
-        Degrees(cast(45).unwrap())
+        Degrees(unwrap_or_saturate(cast(45), || 45.0))
     }
 
     /// An angle of 90° (right angle).
     #[inline]
     pub fn quarter() -> Angle<T> {
-        Degrees(cast(90).unwrap())
+        Degrees(unwrap_or_saturate(cast(90), || 90.0))
     }
 
     /// An angle of 180° (straight).
+    ///
+    /// # Panics
+    ///
+    /// Panics if 180 cannot be represented in `T`. With the `no-panic`
+    /// feature, `T` must be [`Convertible`] instead.
     #[inline]
-    pub fn half() -> Angle<T> {
-        Degrees(cast(180).unwrap())
+    pub fn half() -> Angle<T>
+    where
+        T: Convertible,
+    {
+        Degrees(unwrap_cast(cast(180)))
     }
 
     /// An angle of 360° (perigon).
+    ///
+    /// # Panics
+    ///
+    /// Panics if 360 cannot be represented in `T`. With the `no-panic`
+    /// feature, `T` must be [`Convertible`] instead.
     #[inline]
-    pub fn full() -> Angle<T> {
-        Degrees(cast(360).unwrap())
+    pub fn full() -> Angle<T>
+    where
+        T: Convertible,
+    {
+        Degrees(unwrap_cast(cast(360)))
     }
 }
 
 impl<T: Copy + Num + NumCast + PartialOrd> Angle<T> {
     /// Create a new angle by normalizing the value into the range of
-    /// [0, 2π) rad.
+    /// [0, 2π) rad. An infinite or NaN value gives NaN, in the same unit.
     ///
     /// # Examples
     ///
@@ -82,45 +480,287 @@ impl<T: Copy + Num + NumCast + PartialOrd> Angle<T> {
     ///
     /// let beta = Radians(2.0 * PI).normalized();
     /// assert!((beta.in_radians() - 0.0).abs() < 1.0e-10);
+    ///
+    /// assert!(Degrees(f64::INFINITY).normalized().value().is_nan());
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full turn cannot be represented in `T`. See
+    /// [`checked_normalized`](#method.checked_normalized). With the
+    /// `no-panic` feature, `T` must be [`Convertible`] instead.
     #[inline]
-    pub fn normalized(self) -> Self {
-        let (v, upper) = match self {
-            Radians(v) => (v, cast(2.0 * PI).unwrap()),
-            Degrees(v) => (v, cast(360.0).unwrap()),
-        };
+    pub fn normalized(self) -> Self
+    where
+        T: Convertible,
+    {
+        let (v, unit) = self.into_parts();
+        let upper = unit.full_turn_in();
+        Angle::from_parts(normalize(v, upper), unit)
+    }
 
-        let normalized = if v < upper && v >= Zero::zero() {
-            v
-        } else {
-            let v = v % upper;
+    /// Create a new angle by normalizing the value into the range of
+    /// [0, 2π) rad, or return `None` if a full turn cannot be represented in
+    /// `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(-90i16).checked_normalized(), Some(Degrees(270)));
+    /// assert_eq!(Degrees(-90i8).checked_normalized(), None);
+    /// ```
+    #[inline]
+    pub fn checked_normalized(self) -> Option<Self> {
+        let (v, unit) = self.into_parts();
+        let upper = unit.checked_full_turn_in()?;
+        Some(Angle::from_parts(normalize(v, upper), unit))
+    }
 
-            if v >= Zero::zero() {
-                v
-            } else {
-                v + upper
-            }
-        };
+    /// Create a new angle by normalizing the value into the range of
+    /// (-π, π] rad, so that small negative angles stay negative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(-10.0f64).normalized_signed(), Degrees(-10.0));
+    /// assert_eq!(Degrees(350.0f64).normalized_signed(), Degrees(-10.0));
+    /// assert_eq!(Degrees(-180.0f64).normalized_signed(), Degrees(180.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full turn cannot be represented in `T`. See
+    /// [`checked_normalized_signed`](#method.checked_normalized_signed). With
+    /// the `no-panic` feature, `T` must be [`Convertible`] instead.
+    #[inline]
+    pub fn normalized_signed(self) -> Self
+    where
+        T: Convertible,
+    {
+        let (v, unit) = self.normalized().into_parts();
+        let upper: T = unit.full_turn_in();
+        Angle::from_parts(normalize_signed(v, upper), unit)
+    }
 
-        match self {
-            Radians(_) => Radians(normalized),
-            Degrees(_) => Degrees(normalized),
-        }
+    /// Create a new angle by normalizing the value into the range of
+    /// (-π, π] rad, or return `None` if a full turn cannot be represented in
+    /// `T`.
+    #[inline]
+    pub fn checked_normalized_signed(self) -> Option<Self> {
+        let (v, unit) = self.checked_normalized()?.into_parts();
+        let upper: T = unit.checked_full_turn_in()?;
+        Some(Angle::from_parts(normalize_signed(v, upper), unit))
+    }
+
+    /// Add `rhs` and normalize the sum into the range of [0, 2π) rad, in the
+    /// unit of this angle.
+    ///
+    /// Both angles are normalized before they are added, so the sum cannot
+    /// overflow for integer angles however many turns they span.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(350.0f64).wrapping_add(Degrees(20.0)), Degrees(10.0));
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(Degrees(i16::MAX).wrapping_add(Degrees(i16::MAX)), Degrees(14));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full turn or `rhs` in the unit of this angle cannot be
+    /// represented in `T`. With the `no-panic` feature, `T` must be
+    /// [`Convertible`] instead.
+    pub fn wrapping_add(self, rhs: Angle<T>) -> Self
+    where
+        T: Convertible,
+    {
+        let (a, b, full, unit) = self.wrapping_operands(rhs);
+        // Adding the complement of `b` cannot exceed the full turn.
+        let v = if a >= full - b { a - (full - b) } else { a + b };
+        Angle::from_parts(wrap_rounded(v, full), unit)
+    }
+
+    /// Subtract `rhs` and normalize the difference into the range of
+    /// [0, 2π) rad, in the unit of this angle, as
+    /// [`wrapping_add`](#method.wrapping_add) does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(10.0f64).wrapping_sub(Degrees(20.0)), Degrees(350.0));
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(Degrees(10u16).wrapping_sub(Degrees(20)), Degrees(350));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full turn or `rhs` in the unit of this angle cannot be
+    /// represented in `T`. With the `no-panic` feature, `T` must be
+    /// [`Convertible`] instead.
+    pub fn wrapping_sub(self, rhs: Angle<T>) -> Self
+    where
+        T: Convertible,
+    {
+        let (a, b, full, unit) = self.wrapping_operands(rhs);
+        let v = if a >= b { a - b } else { a + (full - b) };
+        Angle::from_parts(wrap_rounded(v, full), unit)
+    }
+
+    /// Yield the normalized values of this angle and `rhs` in the unit of
+    /// this angle, with the full turn in that unit.
+    #[inline]
+    fn wrapping_operands(self, rhs: Angle<T>) -> (T, T, T, AngleUnit)
+    where
+        T: Convertible,
+    {
+        let (a, unit) = self.normalized().into_parts();
+        let b = Angle::from_parts(rhs.in_unit_of(unit), unit)
+            .normalized()
+            .value();
+        (a, b, unit.full_turn_in(), unit)
+    }
+
+    /// Create a new angle of half the size, in the same unit. Not to be
+    /// confused with [`half`](#method.half), the straight angle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(270.0f64).halved(), Degrees(135.0));
+    /// assert_eq!(Degrees(-90i32).halved(), Degrees(-45));
+    /// ```
+    #[inline]
+    pub fn halved(self) -> Self {
+        self.map(|v| v / (T::one() + T::one()))
+    }
+
+    /// Create a new angle of twice the size, in the same unit. The result is
+    /// not normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(270.0f64).doubled(), Degrees(540.0));
+    /// ```
+    #[inline]
+    pub fn doubled(self) -> Self {
+        self.map(|v| v + v)
+    }
+
+    /// Create a new angle by normalizing the value into the range of half a
+    /// turn on either side of `center`, [center - π, center + π) rad. The
+    /// result keeps the unit of this angle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// // A joint with a legal range of [-45, 315)°.
+    /// let center = Degrees(135.0f64);
+    /// assert_eq!(Degrees(330.0).normalize_around(center), Degrees(-30.0));
+    /// assert_eq!(Degrees(-45.0).normalize_around(center), Degrees(-45.0));
+    /// assert_eq!(Degrees(315.0).normalize_around(center), Degrees(-45.0));
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(Degrees(-400i16).normalize_around(Degrees(90)), Degrees(-40));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full turn, `center` in the unit of this angle or the lower
+    /// end of the range cannot be represented in `T`. With the `no-panic`
+    /// feature, `T` must be [`Convertible`] instead.
+    pub fn normalize_around(self, center: Angle<T>) -> Self
+    where
+        T: Convertible,
+    {
+        let unit = self.unit();
+        let full: T = unit.full_turn_in();
+        let half = full / (T::one() + T::one());
+        let lower = match unit {
+            AngleUnit::Radians => center.in_radians(),
+            AngleUnit::Degrees => center.in_degrees(),
+        } - half;
+
+        // Measure the offset from the lower end between normalized values,
+        // which keeps every subtraction in range for unsigned types.
+        let v = self.normalized().value();
+        let l = Angle::from_parts(lower, unit).normalized().value();
+        let offset = if v >= l { v - l } else { full - (l - v) };
+        // Rounding may carry an offset just short of a full turn up to it.
+        let offset = if offset < full { offset } else { T::zero() };
+        Angle::from_parts(lower + offset, unit)
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: Float> Angle<T> {
-    /// Computes the minimal unsigned distance between two normalized angles. Returns an
-    /// angle in the range of [0, π] rad.
+    /// Return `true` if the value of the angle is NaN, in whichever unit it
+    /// is stored.
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert!(Degrees(f64::NAN).is_nan());
+    /// assert!(!Radians(f64::INFINITY).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.value().is_nan()
+    }
+
+    /// Return `true` if the value of the angle is neither infinite nor NaN.
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert!(Degrees(1.0e300).is_finite());
+    /// assert!(!Radians(f64::NEG_INFINITY).is_finite());
+    /// ```
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.value().is_finite()
+    }
+
+    /// Return `true` if the value of the angle is positive or negative
+    /// infinity.
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.value().is_infinite()
+    }
+
+    /// Return the floating point category of the value of the angle. The
+    /// value is not converted, so a large angle in degrees is
+    /// [`Normal`](core::num::FpCategory::Normal) even if it would overflow
+    /// in radians and the other way around.
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// use std::num::FpCategory;
+    /// assert_eq!(Degrees(-0.0f32).classify(), FpCategory::Zero);
+    /// assert_eq!(Radians(1.0e-310f64).classify(), FpCategory::Subnormal);
+    /// ```
+    #[inline]
+    pub fn classify(self) -> FpCategory {
+        self.value().classify()
+    }
+
+    /// Computes the minimal unsigned distance between two normalized angles. Returns a
+    /// delta in the range of [0, π] rad, or NaN if either angle is infinite
+    /// or NaN.
     ///
     /// ```rust
     /// # use ang::*;
     /// let distance = Degrees(345.0).min_dist(Degrees(15.0));
     /// assert!((distance.in_degrees() - 30.0) < 1.0e-10);
+    /// assert!(Degrees(f64::INFINITY).min_dist(Degrees(15.0)).is_nan());
     /// ```
     #[inline]
-    pub fn min_dist(self, other: Angle<T>) -> Angle<T> {
+    pub fn min_dist(self, other: Angle<T>) -> AngleDelta<T> {
         let pi = cast(PI).unwrap();
         let two_pi = cast(2.0 * PI).unwrap();
 
@@ -130,13 +770,34 @@ impl<T: Float> Angle<T> {
         let d = (a - b).abs();
 
         // short-circuit if both angles are normalized
-        Radians(
+        AngleDelta::new(Radians(
             if a >= T::zero() && a < two_pi && b >= T::zero() && b < two_pi {
                 d.min(two_pi - d)
             } else {
                 pi - ((d % two_pi) - pi).abs()
             },
-        )
+        ))
+    }
+
+    /// Computes the signed minimal distance from `other` to this angle, in
+    /// the range of (-180, 180]° or (-π, π] rad, so that `other` rotated by
+    /// the result is coterminal with this angle. Its magnitude is the
+    /// [`min_dist`](#method.min_dist) between the angles, and its sign tells
+    /// which way to turn. This is the
+    /// [`shortest_delta_to`](#method.shortest_delta_to) from `other` as an
+    /// angle.
+    ///
+    /// The result is in degrees if both angles are in degrees, and in
+    /// radians otherwise.
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(350.0).signed_dist(Degrees(10.0)), Degrees(-20.0));
+    /// assert_eq!(Degrees(10.0).signed_dist(Degrees(350.0)), Degrees(20.0));
+    /// ```
+    #[inline]
+    pub fn signed_dist(self, other: Angle<T>) -> Angle<T> {
+        other.shortest_delta_to(self).angle()
     }
 }
 
@@ -182,7 +843,7 @@ impl<T: Signed> Angle<T> {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: Float + NumCast> Angle<T> {
     /// Compute the sine of the angle.
     #[inline]
@@ -190,27 +851,384 @@ impl<T: Float + NumCast> Angle<T> {
         self.in_radians().sin()
     }
 
-    /// Compute the cosine of the angle.
-    #[inline]
-    pub fn cos(self) -> T {
-        self.in_radians().cos()
+    /// Compute the cosine of the angle.
+    #[inline]
+    pub fn cos(self) -> T {
+        self.in_radians().cos()
+    }
+
+    /// Compute the tangent of the angle.
+    #[inline]
+    pub fn tan(self) -> T {
+        self.in_radians().tan()
+    }
+
+    /// Compute the secant of the angle, 1 / cos(x).
+    ///
+    /// The cosine of 90° in radians is not exactly zero, so the secant there
+    /// is enormous rather than infinite. A cosine of exactly zero gives
+    /// infinity.
+    #[inline]
+    pub fn sec(self) -> T {
+        self.cos().recip()
+    }
+
+    /// Compute the cosecant of the angle, 1 / sin(x).
+    ///
+    /// The cosecant of zero is infinite, with the sign of the zero, and that
+    /// of 180° is enormous rather than infinite.
+    #[inline]
+    pub fn csc(self) -> T {
+        self.sin().recip()
+    }
+
+    /// Compute the cotangent of the angle, cos(x) / sin(x).
+    ///
+    /// The cotangent of zero is infinite, with the sign of the zero, and that
+    /// of 180° is enormous rather than infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert!((Degrees(45.0f64).cot() - 1.0).abs() < 1.0e-10);
+    /// assert_eq!(Degrees(0.0f64).cot(), f64::INFINITY);
+    /// ```
+    #[inline]
+    pub fn cot(self) -> T {
+        let (sin, cos) = self.sin_cos();
+        cos / sin
+    }
+
+    /// Compute the sine of half the angle.
+    ///
+    /// Halving is exact, so the sign follows the quadrant of the original,
+    /// unnormalized angle: it is negative for angles in (2π, 4π) rad, for
+    /// which the half angle lies in (π, 2π) rad. Unlike the half-angle formula
+    /// √((1 - cos x) / 2), this keeps its precision near zero.
+    #[inline]
+    pub fn sin_half(self) -> T {
+        self.halved().sin()
+    }
+
+    /// Compute the cosine of half the angle, with the sign of the quadrant
+    /// of the half angle as for [`sin_half`](#method.sin_half).
+    #[inline]
+    pub fn cos_half(self) -> T {
+        self.halved().cos()
+    }
+
+    /// Compute the tangent of half the angle, as used in the tangent
+    /// half-angle substitution, from the sine and cosine of the angle.
+    ///
+    /// This is sin(x) / (1 + cos(x)), switching to the equivalent
+    /// (1 - cos(x)) / sin(x) when the cosine is negative, where the first
+    /// form would cancel catastrophically near π. The result tends to
+    /// infinity near π rad.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert!((Degrees(90.0f64).tan_half() - 1.0).abs() < 1.0e-15);
+    /// assert!((Degrees(-90.0f64).tan_half() + 1.0).abs() < 1.0e-15);
+    /// ```
+    #[inline]
+    pub fn tan_half(self) -> T {
+        let (sin, cos) = self.sin_cos();
+        if cos >= T::zero() {
+            sin / (T::one() + cos)
+        } else {
+            (T::one() - cos) / sin
+        }
+    }
+
+    /// Compute the hyperbolic sine of the value of the angle in radians.
+    #[inline]
+    pub fn sinh(self) -> T {
+        self.in_radians().sinh()
+    }
+
+    /// Compute the hyperbolic cosine of the value of the angle in radians.
+    #[inline]
+    pub fn cosh(self) -> T {
+        self.in_radians().cosh()
+    }
+
+    /// Compute the hyperbolic tangent of the value of the angle in radians.
+    #[inline]
+    pub fn tanh(self) -> T {
+        self.in_radians().tanh()
+    }
+
+    /// Compute the inverse Gudermannian function of the angle, asinh(tan(x)),
+    /// which is the isometric latitude, or the northing of the Mercator
+    /// projection on the unit sphere, of a latitude. See
+    /// [`gudermannian`](fn.gudermannian.html).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let psi = Degrees(45.0f64).inverse_gudermannian();
+    /// assert!((gudermannian(psi).in_degrees() - 45.0).abs() < 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn inverse_gudermannian(self) -> T {
+        self.tan().asinh()
+    }
+
+    /// Compute the unnormalized sinc function of the value of the angle in
+    /// radians, sin(x) / x, which is exactly 1 at zero and 0 at infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Radians(0.0f64).sinc(), 1.0);
+    /// assert!((Degrees(90.0f64).sinc() - 2.0 / std::f64::consts::PI).abs() < 1.0e-15);
+    /// ```
+    #[inline]
+    pub fn sinc(self) -> T {
+        let x = self.in_radians();
+        if x.is_infinite() {
+            T::zero()
+        } else if x.abs() < sinc_series_limit() {
+            sinc_series(x)
+        } else {
+            x.sin() / x
+        }
+    }
+
+    /// Compute the normalized sinc function of the value of the angle in
+    /// radians, sin(πx) / (πx), which is exactly 1 at zero and exactly 0 at
+    /// the other integers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Radians(0.0f64).sinc_pi(), 1.0);
+    /// assert_eq!(Radians(3.0f64).sinc_pi(), 0.0);
+    /// ```
+    #[inline]
+    pub fn sinc_pi(self) -> T {
+        let x = self.in_radians();
+        let pi_x = x * cast(PI).unwrap();
+        if x.is_infinite() {
+            T::zero()
+        } else if pi_x.abs() < sinc_series_limit() {
+            sinc_series(pi_x)
+        } else {
+            // sin(πx) as half a turn of x, which is exact at the integers.
+            turn::sin_turn(x / (T::one() + T::one())) / pi_x
+        }
+    }
+
+    /// Simultaneously compute the sine and cosine of the number, `x`.
+    ///
+    /// Return `(sin(x), cos(x))`.
+    #[inline]
+    pub fn sin_cos(self) -> (T, T) {
+        self.in_radians().sin_cos()
+    }
+
+    /// Compute the dot product of the unit vectors in the directions of the
+    /// two angles, the cosine of the difference between them. Return value is
+    /// in the range of [-1, 1], where 1 means the directions are aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert!(Degrees(350.0f64).dot(Degrees(80.0)).abs() < 1.0e-10);
+    /// assert!((Degrees(10.0f64).dot(Degrees(370.0)) - 1.0).abs() < 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn dot(self, other: Angle<T>) -> T {
+        self.diff_sin_cos(other).1
+    }
+
+    /// Compute the perpendicular dot product, or two dimensional cross
+    /// product, of the unit vectors in the directions of the two angles, the
+    /// sine of the difference between them. Return value is positive if
+    /// `other` lies counter-clockwise of this angle, within half a turn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert!((Degrees(350.0f64).perp_dot(Degrees(80.0)) - 1.0).abs() < 1.0e-10);
+    /// assert!((Degrees(80.0f64).perp_dot(Degrees(350.0)) + 1.0).abs() < 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn perp_dot(self, other: Angle<T>) -> T {
+        self.diff_sin_cos(other).0
+    }
+
+    /// Compute the scalar component along the direction `onto` of a vector
+    /// with the given `magnitude` in the direction of this angle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let along = Degrees(60.0f64).project(10.0, Degrees(0.0));
+    /// assert!((along - 5.0).abs() < 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn project(self, magnitude: T, onto: Angle<T>) -> T {
+        magnitude * self.dot(onto)
+    }
+
+    /// Compute the sine and cosine of the difference from this angle to
+    /// `other`, wrapped into the range of (-π, π] rad.
+    #[inline]
+    fn diff_sin_cos(self, other: Angle<T>) -> (T, T) {
+        stats::wrap_signed(other.in_radians() - self.in_radians()).sin_cos()
+    }
+}
+
+macro_rules! convertible(
+    ($($bound:tt)*) => (
+        /// The types of values accepted by the methods which convert the value
+        /// of an angle or a constant into `T` and cannot report a failure, such
+        /// as [`Angle::in_degrees`] or [`Angle::normalized`].
+        ///
+        /// Without the `no-panic` feature, this is every `NumCast` type, and the
+        /// methods panic if the result cannot be represented in `T`. With the
+        /// feature, it is only the floating point types, which represent every
+        /// result, if only as an infinity. The `checked_*` and `saturating_*`
+        /// methods cover integer angles then.
+        pub trait Convertible: $($bound)* {}
+
+        impl<T: $($bound)*> Convertible for T {}
+    );
+);
+
+#[cfg(not(feature = "no-panic"))]
+convertible!(NumCast);
+#[cfg(all(feature = "no-panic", any(feature = "std", feature = "libm")))]
+convertible!(Float);
+#[cfg(all(feature = "no-panic", not(any(feature = "std", feature = "libm"))))]
+convertible!(NumCast + num_traits::float::FloatCore);
+
+/// Unwrap the result of converting a value into `T`.
+///
+/// This panics if the conversion failed. With the `no-panic` feature, `T` is
+/// a floating point type, into which the conversions do not fail, and NaN
+/// stands in for a failure so that no panic remains.
+#[inline]
+pub(crate) fn unwrap_cast<T: Convertible>(value: Option<T>) -> T {
+    #[cfg(feature = "no-panic")]
+    return value.unwrap_or_else(T::nan);
+    #[cfg(not(feature = "no-panic"))]
+    return value.unwrap();
+}
+
+/// Unwrap the result of converting a value into `T`, whose exact value is
+/// `exact`, where the conversion cannot fail for the primitive numeric
+/// types.
+///
+/// This panics if the conversion failed. With the `no-panic` feature, the
+/// exact value saturates to the range of `T` instead.
+#[inline]
+pub(crate) fn unwrap_or_saturate<T: NumCast>(value: Option<T>, exact: impl FnOnce() -> f64) -> T {
+    #[cfg(feature = "no-panic")]
+    let value = value.or_else(|| saturating_cast(exact()));
+    #[cfg(not(feature = "no-panic"))]
+    let _ = exact;
+    value.unwrap()
+}
+
+/// Convert `v` into `T`, saturating to the largest or smallest value of `T`
+/// if it is out of range. NaN becomes zero if `T` has no NaN. Returns `None`
+/// only for types which cannot represent the bounds of any primitive integer
+/// or zero.
+#[cfg(feature = "no-panic")]
+pub(crate) fn saturating_cast<T: NumCast>(v: f64) -> Option<T> {
+    let bound = if v > 0.0 {
+        T::from(v)
+            .or_else(|| T::from(u128::MAX))
+            .or_else(|| T::from(i128::MAX))
+            .or_else(|| T::from(u64::MAX))
+            .or_else(|| T::from(i64::MAX))
+            .or_else(|| T::from(u32::MAX))
+            .or_else(|| T::from(i32::MAX))
+            .or_else(|| T::from(u16::MAX))
+            .or_else(|| T::from(i16::MAX))
+            .or_else(|| T::from(u8::MAX))
+            .or_else(|| T::from(i8::MAX))
+    } else if v < 0.0 {
+        T::from(v)
+            .or_else(|| T::from(i128::MIN))
+            .or_else(|| T::from(i64::MIN))
+            .or_else(|| T::from(i32::MIN))
+            .or_else(|| T::from(i16::MIN))
+            .or_else(|| T::from(i8::MIN))
+    } else {
+        T::from(v)
+    };
+    bound.or_else(|| T::from(0))
+}
+
+/// Normalize `v` into the range of [0, `upper`).
+#[inline]
+pub(crate) fn normalize<T: Copy + Num + PartialOrd>(v: T, upper: T) -> T {
+    if v < upper && v >= T::zero() {
+        v
+    } else {
+        let v = v % upper;
+
+        if v >= T::zero() {
+            v
+        } else {
+            v + upper
+        }
     }
+}
 
-    /// Compute the tangent of the angle.
-    #[inline]
-    pub fn tan(self) -> T {
-        self.in_radians().tan()
+/// Move a value normalized into the range of [0, `upper`) into the range of
+/// (-`upper` / 2, `upper` / 2].
+#[inline]
+fn normalize_signed<T: Copy + Num + PartialOrd>(v: T, upper: T) -> T {
+    let half = upper / (T::one() + T::one());
+    if v > half {
+        v - upper
+    } else {
+        v
     }
+}
 
-    /// Simultaneously compute the sine and cosine of the number, `x`.
-    ///
-    /// Return `(sin(x), cos(x))`.
-    #[inline]
-    pub fn sin_cos(self) -> (T, T) {
-        self.in_radians().sin_cos()
+/// Wrap a sum of normalized floating point values which rounded up to the
+/// full turn `full` back to zero.
+#[inline]
+fn wrap_rounded<T: Num + PartialOrd>(v: T, full: T) -> T {
+    if v < full {
+        v
+    } else {
+        T::zero()
     }
 }
 
+/// The bound below which the series of the sinc function is exact to the
+/// precision of `T`, the fourth root of its epsilon.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn sinc_series_limit<T: Float>() -> T {
+    T::epsilon().sqrt().sqrt()
+}
+
+/// Compute sin(x) / x for small `x` from the first terms of its series,
+/// 1 - x²/6 + x⁴/120.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+fn sinc_series<T: Float>(x: T) -> T {
+    let x2 = x * x;
+    T::one() - x2 / cast(6).unwrap() * (T::one() - x2 / cast(20).unwrap())
+}
+
+#[cfg(not(feature = "strict-algebra"))]
 impl<T: Zero + Copy + NumCast> Zero for Angle<T> {
     #[inline]
     fn zero() -> Self {
@@ -219,9 +1237,9 @@ impl<T: Zero + Copy + NumCast> Zero for Angle<T> {
 
     #[inline]
     fn is_zero(&self) -> bool {
-        match self {
-            &Radians(ref v) => v.is_zero(),
-            &Degrees(ref v) => v.is_zero(),
+        match *self {
+            Radians(ref v) => v.is_zero(),
+            Degrees(ref v) => v.is_zero(),
         }
     }
 }
@@ -239,6 +1257,17 @@ impl<T: Copy + NumCast + PartialEq> PartialEq for Angle<T> {
 
 impl<T: Copy + Eq + NumCast> Eq for Angle<T> {}
 
+/// Angles are hashed by their value in radians, as they are compared, so
+/// that equal angles in different units hash equally.
+impl<T: Copy + Hash + NumCast> Hash for Angle<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.in_radians().hash(state);
+    }
+}
+
+/// Angles are compared in radians, whichever units they are stored in, so
+/// that the epsilon is always in radians.
 impl<T: AbsDiffEq + Copy + NumCast> AbsDiffEq for Angle<T> {
     type Epsilon = T::Epsilon;
 
@@ -249,13 +1278,11 @@ impl<T: AbsDiffEq + Copy + NumCast> AbsDiffEq for Angle<T> {
 
     #[inline]
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        match (*self, *other) {
-            (Radians(ref v0), Radians(ref v1)) => v0.abs_diff_eq(&v1, epsilon),
-            (_, _) => self.in_degrees().abs_diff_eq(&other.in_degrees(), epsilon),
-        }
+        self.in_radians().abs_diff_eq(&other.in_radians(), epsilon)
     }
 }
 
+/// Angles are compared in radians, as for [`AbsDiffEq`].
 impl<T: RelativeEq + Copy + NumCast> RelativeEq for Angle<T> {
     #[inline]
     fn default_max_relative() -> Self::Epsilon {
@@ -269,15 +1296,12 @@ impl<T: RelativeEq + Copy + NumCast> RelativeEq for Angle<T> {
         epsilon: Self::Epsilon,
         max_relative: Self::Epsilon,
     ) -> bool {
-        match (*self, *other) {
-            (Radians(ref v0), Radians(ref v1)) => v0.relative_eq(&v1, epsilon, max_relative),
-            (_, _) => self
-                .in_degrees()
-                .relative_eq(&other.in_degrees(), epsilon, max_relative),
-        }
+        self.in_radians()
+            .relative_eq(&other.in_radians(), epsilon, max_relative)
     }
 }
 
+/// Angles are compared in radians, as for [`AbsDiffEq`].
 impl<T: UlpsEq + Copy + NumCast> UlpsEq for Angle<T> {
     #[inline]
     fn default_max_ulps() -> u32 {
@@ -286,30 +1310,46 @@ impl<T: UlpsEq + Copy + NumCast> UlpsEq for Angle<T> {
 
     #[inline]
     fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
-        match (*self, *other) {
-            (Radians(ref v0), Radians(ref v1)) => v0.ulps_eq(&v1, epsilon, max_ulps),
-            (_, _) => self
-                .in_degrees()
-                .ulps_eq(&other.in_degrees(), epsilon, max_ulps),
+        self.in_radians()
+            .ulps_eq(&other.in_radians(), epsilon, max_ulps)
+    }
+}
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Combine the values of two angles with `op`, in degrees if both angles
+    /// are in degrees and in radians otherwise.
+    #[inline]
+    pub(crate) fn combine<U>(self, rhs: Angle<T>, op: impl FnOnce(T, T) -> U) -> Angle<U> {
+        if let (Degrees(a), Degrees(b)) = (self, rhs) {
+            Degrees(op(a, b))
+        } else {
+            Radians(op(self.in_radians(), rhs.in_radians()))
         }
     }
+
+    /// Subtract two angles as `-` does without the `strict-algebra` feature,
+    /// yielding an angle rather than a delta.
+    #[inline]
+    pub(crate) fn sub_angle(self, rhs: Angle<T>) -> Angle<T>
+    where
+        T: Sub<Output = T>,
+    {
+        self.combine(rhs, Sub::sub)
+    }
 }
 
+#[cfg(not(feature = "strict-algebra"))]
 macro_rules! math_additive(
     ($bound:ident, $func:ident, $assign_bound:ident, $assign_func:ident) => (
         impl<T: $bound + Copy + NumCast> $bound for Angle<T> {
             type Output = Angle<T::Output>;
             #[inline]
             fn $func(self, rhs: Angle<T>) -> Self::Output {
-                if let (Degrees(a), Degrees(b)) = (self, rhs) {
-                    Degrees(a.$func(b))
-                } else {
-                    Radians(self.in_radians().$func(rhs.in_radians()))
-                }
+                self.combine(rhs, $bound::$func)
             }
         }
 
-        impl<T: $assign_bound + Copy + NumCast  > $assign_bound for Angle<T> {
+        impl<T: $assign_bound + Copy + NumCast> $assign_bound for Angle<T> {
             #[inline]
             fn $assign_func(&mut self, rhs: Angle<T>) {
                 if let (Degrees(ref mut a), Degrees(b)) = (*self, rhs)  {
@@ -325,9 +1365,79 @@ macro_rules! math_additive(
     );
 );
 
+#[cfg(not(feature = "strict-algebra"))]
 math_additive!(Add, add, AddAssign, add_assign);
+#[cfg(not(feature = "strict-algebra"))]
 math_additive!(Sub, sub, SubAssign, sub_assign);
 
+/// With the `strict-algebra` feature, the difference between two absolute
+/// angles is the rotation from one to the other.
+#[cfg(feature = "strict-algebra")]
+impl<T: Sub + Copy + NumCast> Sub for Angle<T> {
+    type Output = AngleDelta<T::Output>;
+    #[inline]
+    fn sub(self, rhs: Angle<T>) -> Self::Output {
+        AngleDelta::new(self.combine(rhs, Sub::sub))
+    }
+}
+
+macro_rules! forward_ref_additive(
+    ($bound:ident, $func:ident, $output:ident) => (
+        impl<'a, T: $bound + Copy + NumCast> $bound<Angle<T>> for &'a Angle<T> {
+            type Output = $output<T::Output>;
+            #[inline]
+            fn $func(self, rhs: Angle<T>) -> Self::Output {
+                (*self).$func(rhs)
+            }
+        }
+
+        impl<'b, T: $bound + Copy + NumCast> $bound<&'b Angle<T>> for Angle<T> {
+            type Output = $output<T::Output>;
+            #[inline]
+            fn $func(self, rhs: &'b Angle<T>) -> Self::Output {
+                self.$func(*rhs)
+            }
+        }
+
+        impl<'a, 'b, T: $bound + Copy + NumCast> $bound<&'b Angle<T>> for &'a Angle<T> {
+            type Output = $output<T::Output>;
+            #[inline]
+            fn $func(self, rhs: &'b Angle<T>) -> Self::Output {
+                (*self).$func(*rhs)
+            }
+        }
+    );
+);
+
+#[cfg(not(feature = "strict-algebra"))]
+forward_ref_additive!(Add, add, Angle);
+#[cfg(not(feature = "strict-algebra"))]
+forward_ref_additive!(Sub, sub, Angle);
+#[cfg(feature = "strict-algebra")]
+forward_ref_additive!(Sub, sub, AngleDelta);
+
+#[cfg(not(feature = "strict-algebra"))]
+impl<T: Add<Output = T> + Copy + NumCast + Zero> Sum for Angle<T> {
+    /// Add up the angles as `+` does, in degrees if all of them are in
+    /// degrees and in radians otherwise. The sum of no angles is
+    /// `Radians(0)`, as for [`Zero`].
+    #[inline]
+    fn sum<I: Iterator<Item = Angle<T>>>(mut iter: I) -> Self {
+        match iter.next() {
+            Some(first) => iter.fold(first, Add::add),
+            None => Zero::zero(),
+        }
+    }
+}
+
+#[cfg(not(feature = "strict-algebra"))]
+impl<'a, T: Add<Output = T> + Copy + NumCast + Zero + 'a> Sum<&'a Angle<T>> for Angle<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Angle<T>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 macro_rules! math_multiplicative(
     ($bound:ident, $func:ident, $assign_bound:ident, $assign_func:ident, $($t:ident),*) => (
         impl<T: $bound + Copy> $bound<T> for Angle<T> {
@@ -366,6 +1476,37 @@ macro_rules! math_multiplicative(
     );
 );
 
+macro_rules! forward_ref_multiplicative(
+    ($bound:ident, $func:ident) => (
+        impl<'a, T: $bound + Copy> $bound<T> for &'a Angle<T> {
+            type Output = Angle<T::Output>;
+            #[inline]
+            fn $func(self, rhs: T) -> Self::Output {
+                <Angle<T> as $bound<T>>::$func(*self, rhs)
+            }
+        }
+
+        impl<'b, T: $bound + Copy> $bound<&'b T> for Angle<T> {
+            type Output = Angle<T::Output>;
+            #[inline]
+            fn $func(self, rhs: &'b T) -> Self::Output {
+                <Angle<T> as $bound<T>>::$func(self, *rhs)
+            }
+        }
+
+        impl<'a, 'b, T: $bound + Copy> $bound<&'b T> for &'a Angle<T> {
+            type Output = Angle<T::Output>;
+            #[inline]
+            fn $func(self, rhs: &'b T) -> Self::Output {
+                <Angle<T> as $bound<T>>::$func(*self, *rhs)
+            }
+        }
+    );
+);
+
+forward_ref_multiplicative!(Mul, mul);
+forward_ref_multiplicative!(Div, div);
+
 math_multiplicative!(
     Mul, mul, MulAssign, mul_assign, u8, u16, u32, u64, i8, i16, i32, i64, usize, isize, f32, f64
 );
@@ -373,6 +1514,188 @@ math_multiplicative!(
     Div, div, DivAssign, div_assign, u8, u16, u32, u64, i8, i16, i32, i64, usize, isize, f32, f64
 );
 
+macro_rules! math_mixed_scalar(
+    ($bound:ident, $func:ident, $assign_bound:ident, $assign_func:ident, $($t:ident: $($u:ident),*;)*) => (
+        $($(
+            impl $bound<$u> for Angle<$t> {
+                type Output = Angle<$t>;
+                #[inline]
+                fn $func(self, rhs: $u) -> Self::Output {
+                    self.$func(rhs as $t)
+                }
+            }
+
+            impl $assign_bound<$u> for Angle<$t> {
+                #[inline]
+                fn $assign_func(&mut self, rhs: $u) {
+                    self.$assign_func(rhs as $t)
+                }
+            }
+
+            impl $bound<Angle<$t>> for $u {
+                type Output = Angle<$t>;
+                #[inline]
+                fn $func(self, rhs: Angle<$t>) -> Self::Output {
+                    (self as $t).$func(rhs)
+                }
+            }
+        )*)*
+    );
+);
+
+// Floating point angles can be scaled by the other primitive numbers, which
+// are converted to the type of the angle first. Only `f64` scalars can round
+// when converted, to the nearest `f32`.
+math_mixed_scalar!(
+    Mul, mul, MulAssign, mul_assign,
+    f64: f32, i8, i16, i32, u8, u16, u32;
+    f32: f64, i8, i16, u8, u16;
+);
+math_mixed_scalar!(
+    Div, div, DivAssign, div_assign,
+    f64: f32, i8, i16, i32, u8, u16, u32;
+    f32: f64, i8, i16, u8, u16;
+);
+
+impl<T: Mul<Output = T> + Copy + NumCast> Angle<T> {
+    /// Multiply the value of the angle by the scalar `k` of any numeric
+    /// type, keeping its unit. `k` is converted to `T` first, so it is
+    /// rounded for floating point angles and truncated towards zero for
+    /// integer angles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(30.0f64).scale(3u64), Degrees(90.0));
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(Degrees(30i32).scale(2.9f32), Degrees(60));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` cannot be represented in `T`. See
+    /// [`checked_scale`](#method.checked_scale). With the `no-panic`
+    /// feature, `T` must be [`Convertible`] instead.
+    #[inline]
+    pub fn scale<U: NumCast>(self, k: U) -> Self
+    where
+        T: Convertible,
+    {
+        let k: T = unwrap_cast(cast(k));
+        self.map(|v| v * k)
+    }
+
+    /// Multiply the value of the angle by the scalar `k` as
+    /// [`scale`](#method.scale) does, or return `None` if `k` cannot be
+    /// represented in `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(30i16).checked_scale(1.0e6f64), None);
+    /// assert_eq!(Degrees(30i16).checked_scale(f64::NAN), None);
+    /// ```
+    #[inline]
+    pub fn checked_scale<U: NumCast>(self, k: U) -> Option<Self> {
+        let k: T = cast(k)?;
+        Some(self.map(|v| v * k))
+    }
+}
+
+impl<T: Div<Output = T> + Copy + NumCast> Div for Angle<T> {
+    type Output = T;
+
+    /// Compute the ratio of two angles. Angles in the same unit are divided
+    /// directly, and otherwise in radians.
+    #[inline]
+    fn div(self, rhs: Angle<T>) -> T {
+        match (self, rhs) {
+            (Degrees(a), Degrees(b)) | (Radians(a), Radians(b)) => a / b,
+            _ => self.in_radians() / rhs.in_radians(),
+        }
+    }
+}
+
+impl<T: Euclid + Copy + NumCast> Angle<T> {
+    /// Compute how many whole times `rhs` fits into this angle, rounding
+    /// towards negative infinity for a positive `rhs`, as the `div_euclid`
+    /// of numbers does. `rhs` is converted to the unit of this angle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(100.0f64).div_euclid(Degrees(30.0)), 3.0);
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(Degrees(-100i32).div_euclid(Degrees(30)), -4);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` cannot be represented in the unit of this angle, or
+    /// if it is zero for integer angles. With the `no-panic` feature, `T`
+    /// must be [`Convertible`] instead.
+    #[inline]
+    pub fn div_euclid(self, rhs: Angle<T>) -> T
+    where
+        T: Convertible,
+    {
+        let (v, unit) = self.into_parts();
+        v.div_euclid(&rhs.in_unit_of(unit))
+    }
+
+    /// Compute the remainder of [`div_euclid`](#method.div_euclid), which is
+    /// in the range of [0, |rhs|), in the unit of this angle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(100.0f64).rem_euclid(Degrees(30.0)), Degrees(10.0));
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(Degrees(-100i32).rem_euclid(Degrees(30)), Degrees(20));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` cannot be represented in the unit of this angle, or
+    /// if it is zero for integer angles. With the `no-panic` feature, `T`
+    /// must be [`Convertible`] instead.
+    #[inline]
+    pub fn rem_euclid(self, rhs: Angle<T>) -> Angle<T>
+    where
+        T: Convertible,
+    {
+        let (v, unit) = self.into_parts();
+        Angle::from_parts(v.rem_euclid(&rhs.in_unit_of(unit)), unit)
+    }
+}
+
+macro_rules! widening_from(
+    ($($from:ident => $($to:ident),*;)*) => (
+        $($(
+            impl From<Angle<$from>> for Angle<$to> {
+                #[inline]
+                fn from(angle: Angle<$from>) -> Self {
+                    angle.map(Into::into)
+                }
+            }
+        )*)*
+    );
+);
+
+widening_from!(
+    f32 => f64;
+    i8 => i16, i32, i64, f32, f64;
+    i16 => i32, i64, f32, f64;
+    i32 => i64, f64;
+    u8 => u16, u32, u64, i16, i32, i64, f32, f64;
+    u16 => u32, u64, i32, i64, f32, f64;
+    u32 => u64, i64, f64;
+);
+
 impl<T: Neg> Neg for Angle<T> {
     type Output = Angle<T::Output>;
     #[inline]
@@ -384,12 +1707,23 @@ impl<T: Neg> Neg for Angle<T> {
     }
 }
 
+impl<T: Neg + Clone> Neg for &Angle<T> {
+    type Output = Angle<T::Output>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
 impl<T: PartialOrd + Copy + NumCast> PartialOrd<Angle<T>> for Angle<T> {
     #[inline]
     fn partial_cmp(&self, other: &Angle<T>) -> Option<Ordering> {
         match (*self, *other) {
-            (Radians(ref v0), Radians(ref v1)) => v0.partial_cmp(&v1),
-            (_, _) => self.in_degrees().partial_cmp(&other.in_degrees()),
+            (Radians(ref v0), Radians(ref v1)) => v0.partial_cmp(v1),
+            (_, _) => match (self.checked_in_degrees(), other.checked_in_degrees()) {
+                (Some(v0), Some(v1)) => v0.partial_cmp(&v1),
+                _ => degrees_f64(*self)?.partial_cmp(&degrees_f64(*other)?),
+            },
         }
     }
 }
@@ -398,26 +1732,173 @@ impl<T: Ord + Eq + Copy + NumCast> Ord for Angle<T> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         match (*self, *other) {
-            (Radians(ref v0), Radians(ref v1)) => v0.cmp(&v1),
-            (_, _) => self.in_degrees().cmp(&other.in_degrees()),
+            (Radians(ref v0), Radians(ref v1)) => v0.cmp(v1),
+            (_, _) => match (self.checked_in_degrees(), other.checked_in_degrees()) {
+                (Some(v0), Some(v1)) => v0.cmp(&v1),
+                _ => degrees_f64(*self)
+                    .partial_cmp(&degrees_f64(*other))
+                    .unwrap_or(Ordering::Equal),
+            },
+        }
+    }
+}
+
+/// Yield the value of `angle` in degrees as an `f64`, for comparing angles
+/// whose value in degrees does not fit in `T`.
+#[inline]
+pub(crate) fn degrees_f64<T: Copy + NumCast>(angle: Angle<T>) -> Option<f64> {
+    let v: f64 = cast(angle.value())?;
+    Some(match angle {
+        Radians(_) => v / PI * 180.0,
+        Degrees(_) => v,
+    })
+}
+
+impl<T: TotalOrder + Copy + Convertible> Angle<T> {
+    /// Compare two angles with the total order of IEEE 754, so that NaN and
+    /// signed zeros have a place of their own, e.g. to sort with
+    /// `slice::sort_by(Angle::total_cmp)`.
+    ///
+    /// Both angles are compared in degrees. Angles that are equal in degrees
+    /// are then ordered radians first, and by their stored value, so that
+    /// only identical angles compare equal and the order does not depend on
+    /// the order of the operands. Negative NaN sorts before everything else
+    /// and positive NaN after.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let mut angles = [Degrees(90.0), Degrees(f64::NAN), Radians(-0.0), Degrees(0.0)];
+    /// angles.sort_by(Angle::total_cmp);
+    /// assert_eq!(angles[..3], [Radians(-0.0), Degrees(0.0), Degrees(90.0)]);
+    /// assert!(angles[3].value().is_nan());
+    /// ```
+    #[inline]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        let by_degrees = self.in_degrees().total_cmp(&other.in_degrees());
+        by_degrees
+            .then_with(|| self.unit().cmp(&other.unit()))
+            .then_with(|| self.value().total_cmp(&other.value()))
+    }
+
+    /// Return the greater of two angles by [`total_cmp`](#method.total_cmp),
+    /// or `other` if they are identical.
+    #[inline]
+    pub fn max_by_total(self, other: Self) -> Self {
+        match self.total_cmp(&other) {
+            Ordering::Greater => self,
+            _ => other,
+        }
+    }
+
+    /// Return the lesser of two angles by [`total_cmp`](#method.total_cmp),
+    /// or `self` if they are identical.
+    #[inline]
+    pub fn min_by_total(self, other: Self) -> Self {
+        match self.total_cmp(&other) {
+            Ordering::Greater => other,
+            _ => self,
         }
     }
 }
 
+/// The value is followed by `rad` or `°`. The precision and the `+` flag
+/// apply to the value, and the width, fill and alignment to the value and
+/// its unit together, which are aligned right by default as numbers are.
+/// The `0` flag pads the value with zeros after its sign.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// assert_eq!(format!("{:.2}", Degrees(45.678)), "45.68°");
+/// assert_eq!(format!("{:>10.3}", Radians(1.0)), "  1.000rad");
+/// assert_eq!(format!("{:*<+8}", Degrees(-5)), "-5°*****");
+/// assert_eq!(format!("{:07.1}", Degrees(-5.0)), "-005.0°");
+/// ```
 impl<T: Display> Display for Angle<T> {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
         match *self {
-            Radians(ref v) => write!(f, "{}rad", v),
-            Degrees(ref v) => write!(f, "{}°", v),
+            Radians(ref v) => write_with_suffix(f, v, "rad"),
+            Degrees(ref v) => write_with_suffix(f, v, "°"),
         }
     }
 }
 
+/// Write `v` followed by `suffix`, following the formatting flags of `f` as
+/// described for the `Display` impl of [`Angle`].
+pub(crate) fn write_with_suffix<T: Display>(
+    f: &mut Formatter,
+    v: &T,
+    suffix: &str,
+) -> Result<(), Error> {
+    let (precision, plus) = (f.precision(), f.sign_plus());
+    let width = f.width().unwrap_or(0);
+    let suffix_len = suffix.chars().count();
+
+    if f.sign_aware_zero_pad() {
+        let width = width.saturating_sub(suffix_len);
+        write_value(f, v, precision, plus, width)?;
+        return f.write_str(suffix);
+    }
+
+    let mut len = CharCount(suffix_len);
+    if width > 0 {
+        write_value(&mut len, v, precision, plus, 0)?;
+    }
+    let pad = width.saturating_sub(len.0);
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, pad),
+        Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+        _ => (pad, 0),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write_value(f, v, precision, plus, 0)?;
+    f.write_str(suffix)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Write `v` with the given `precision` and sign flag, padded with zeros
+/// after its sign to `zeros` characters.
+fn write_value<W: Write, T: Display>(
+    w: &mut W,
+    v: &T,
+    precision: Option<usize>,
+    plus: bool,
+    zeros: usize,
+) -> Result<(), Error> {
+    match (precision, plus) {
+        (Some(p), true) => write!(w, "{:+0z$.p$}", v, z = zeros, p = p),
+        (Some(p), false) => write!(w, "{:0z$.p$}", v, z = zeros, p = p),
+        (None, true) => write!(w, "{:+0z$}", v, z = zeros),
+        (None, false) => write!(w, "{:0z$}", v, z = zeros),
+    }
+}
+
+/// A writer which only counts the characters written to it.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result<(), Error> {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
 unsafe impl<T: Send> Send for Angle<T> {}
 
 /// Compute the arcsine of a number. Return value is in the range of
 /// [-π/2, π/2] rad or `None` if the number is outside the range [-1, 1].
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
 pub fn asin<T: Float>(value: T) -> Option<Angle<T>> {
     let value = value.asin();
@@ -430,7 +1911,7 @@ pub fn asin<T: Float>(value: T) -> Option<Angle<T>> {
 
 /// Compute the arccosine of a number. Return value is in the range of
 /// [0, π] rad or `None` if the number is outside the range [-1, 1].
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
 pub fn acos<T: Float>(value: T) -> Option<Angle<T>> {
     let value = value.acos();
@@ -443,22 +1924,82 @@ pub fn acos<T: Float>(value: T) -> Option<Angle<T>> {
 
 /// Compute the arctangent of a number. Return value is in the range of
 /// [-π/2, π/2] rad.
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
 pub fn atan<T: Float>(value: T) -> Angle<T> {
     Radians(value.atan())
 }
 
-/// Compute the four quadrant arctangent of `y` and `x`.
-#[cfg(feature = "std")]
+/// Compute the four quadrant arctangent of `y` and `x`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn atan2<T: Float>(y: T, x: T) -> Angle<T> {
+    Radians(y.atan2(x))
+}
+
+/// Compute the inverse hyperbolic sine of a number, as an angle in radians.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn asinh<T: Float>(value: T) -> Angle<T> {
+    Radians(value.asinh())
+}
+
+/// Compute the inverse hyperbolic cosine of a number, as an angle in radians.
+/// Return value is non-negative or `None` if the number is less than 1.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn acosh<T: Float>(value: T) -> Option<Angle<T>> {
+    let value = value.acosh();
+    if value.is_nan() {
+        None
+    } else {
+        Some(Radians(value))
+    }
+}
+
+/// Compute the inverse hyperbolic tangent of a number, as an angle in
+/// radians. Return value is infinite for ±1 or `None` if the number is
+/// outside the range [-1, 1].
+#[cfg(any(feature = "std", feature = "libm"))]
+#[inline]
+pub fn atanh<T: Float>(value: T) -> Option<Angle<T>> {
+    let value = value.atanh();
+    if value.is_nan() {
+        None
+    } else {
+        Some(Radians(value))
+    }
+}
+
+/// Compute the Gudermannian function of a number, atan(sinh(x)), which
+/// relates the circular and hyperbolic functions without complex numbers.
+/// It is the latitude at the isometric latitude `x`, or at the northing `x`
+/// of the Mercator projection on the unit sphere. Return value is in the
+/// range of [-π/2, π/2] rad.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let latitude = gudermannian(1.0f64);
+/// assert!((latitude.in_radians() - 0.865_769_483).abs() < 1.0e-9);
+/// assert!((latitude.sin() - 1.0f64.tanh()).abs() < 1.0e-10);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn atan2<T: Float>(y: T, x: T) -> Angle<T> {
-    Radians(y.atan2(x))
+pub fn gudermannian<T: Float>(value: T) -> Angle<T> {
+    Radians(value.sinh().atan())
 }
 
 /// Compute the approximate mean of a list of angles by averaging the
 /// Cartesian coordinates of the angles on the unit circle. Return the
-/// normalized angle.
+/// normalized angle in radians.
+///
+/// Returns `None` if the list is empty, or if the unit vectors cancel out to
+/// a mean resultant length below √ε of `T`, about 1.5e-8 for `f64`, where the
+/// direction of the mean is meaningless. A single NaN among the angles also
+/// gives `None`; use [`mean_angle_ignore_nan`](fn.mean_angle_ignore_nan.html)
+/// to skip them.
 ///
 /// # Examples
 ///
@@ -466,44 +2007,129 @@ pub fn atan2<T: Float>(y: T, x: T) -> Angle<T> {
 /// # use ang::*;
 /// let angles = [Degrees(270.0f64), Degrees(360.0), Degrees(90.0)];
 ///
-/// let mu = mean_angle(&angles);
+/// let mu = mean_angle(&angles).unwrap();
 /// assert!(mu.min_dist(Radians(0.0)).in_radians() < 1.0e-10);
+///
+/// assert_eq!(mean_angle(&[Degrees(0.0f64), Degrees(180.0)]), None);
+/// assert_eq!(mean_angle::<f64, _>(&[]), None);
 /// ```
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "libm"))]
 #[inline]
-pub fn mean_angle<'a, T, I>(angles: I) -> Angle<T>
+pub fn mean_angle<'a, T, I>(angles: I) -> Option<Angle<T>>
 where
     T: 'a + Float,
     I: IntoIterator<Item = &'a Angle<T>>,
 {
-    let mut x = T::zero();
-    let mut y = T::zero();
-    let mut n = 0;
-
-    for angle in angles {
-        let (sin, cos) = angle.sin_cos();
-
-        x = x + cos;
-        y = y + sin;
-        n += 1;
-    }
-
-    let n = cast(n).unwrap();
-    let a = (y / n).atan2(x / n);
-
-    Radians(a).normalized()
+    stats::mean(angles)
 }
 
 // re-exports
 pub use Angle::{Degrees, Radians};
 
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use arc::bounding_arc;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use arc::ArcRange;
+pub use bam::{Bam16, Bam32};
+pub use bearing::Bearing;
+pub use compass::Compass;
+pub use ddm::Ddm;
+#[cfg(feature = "decimal")]
+pub use decimal::DecimalAngle;
+pub use delta::{AngleDelta, RotationDirection};
+#[cfg(all(feature = "rand", any(feature = "std", feature = "libm")))]
+pub use distribution::{VonMises, WrappedNormal};
+pub use dms::Dms;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use ext::unwrap_angles;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use ext::AngleSliceExt;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use ext::Unwrapped;
+pub use ext::{AngleIteratorExt, CircularDiffs};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use filter::ComplementaryFilter;
+pub use format::BufferTooSmall;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use histogram::AngleHistogram;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use histogram::CircularHistogram;
+pub use hms::Hms;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use integrator::AngleIntegrator;
+pub use nmea::NmeaCoordinate;
+pub use parse::ParseAngleError;
+pub use pi::PiFraction;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use quant::{AngleQuantizer, QuantRange};
+#[cfg(feature = "uom")]
+pub use quantity::AngleOutOfRange;
+#[cfg(feature = "rational")]
+pub use rational::RationalAngle;
+#[cfg(not(feature = "no-panic"))]
+pub use raw::pack_radians;
+pub use raw::{checked_pack_radians, PackedRadians, RawAngle, RAW_DEGREES, RAW_RADIANS};
+#[cfg(feature = "std")]
+pub use rose::{RoseOptions, RoseOrientation, RoseScale};
+pub use sweep::SweepArc;
+pub use units::{Gon, MilSystem};
+pub use velocity::AngularVelocity;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use astro::{angular_diameter, distance_from_angle, linear_size_from_angle, parallax_distance};
+#[cfg(all(feature = "geo", any(feature = "std", feature = "libm")))]
+pub use gis::{bearing_between, geodesic_bearing_between};
+#[cfg(all(feature = "embedded-graphics", any(feature = "std", feature = "libm")))]
+pub use graphics::gauge_needle_points;
+#[cfg(feature = "embedded-graphics")]
+pub use graphics::to_eg_arc;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use imu::{
+    pitch_roll_from_accel, pitch_roll_from_accel_with_tolerance, tilt_compensated_heading,
+    MagCalibration,
+};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use odometry::{dead_reckon, dead_reckon_arc};
+#[cfg(all(any(feature = "std", feature = "libm"), not(feature = "no-panic")))]
+pub use optics::brewster_angle;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use optics::{checked_brewster_angle, critical_angle, refract};
+#[cfg(all(feature = "rand", any(feature = "std", feature = "libm")))]
+pub use random::{sample_cone, AngleRandomWalk, UniformAngle, WalkBoundary};
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use resample::resample;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use solar::{geometric_solar_position, solar_position, SolarPosition};
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use sort::{sort_circular, sort_circular_by_key};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use spacing::Linspace;
+pub use spacing::Steps;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use sphere::{
+    along_track_angle, central_angle, cross_track_angle, destination, initial_bearing,
+};
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use spline::{CatmullRomAngles, CatmullRomEnds};
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use stats::circular_autocorrelation;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use stats::{
+    circular_correlation, circular_variance, circular_variance_ignore_nan, mean_and_resultant,
+    mean_angle_ignore_nan, mean_resultant_length, median_angle, phase_difference_stats,
+    phase_locking_value, rayleigh_test, turning_sum, weighted_mean_angle, CircularStats,
+    RayleighResult,
+};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use turn::{cos_turn, sin_turn};
+
 #[cfg(test)]
 #[allow(deprecated)]
 mod tests {
     use core::f64::consts::PI;
     use hamcrest2::{assert_that, close_to, prelude::*};
     use num_traits::cast::cast;
-    use quickcheck::{quickcheck, Arbitrary, Gen};
+    use quickcheck::quickcheck;
 
     #[cfg(feature = "std")]
     use num_traits::Float;
@@ -513,7 +2139,8 @@ mod tests {
     #[test]
     fn test_angle_conversions() {
         fn prop(angle: Angle) -> bool {
-            are_close(angle.in_radians(), Degrees(angle.in_degrees()).in_radians())
+            let tol = tolerance(&[angle]);
+            (angle.in_radians() - Degrees(angle.in_degrees()).in_radians()).abs() <= tol
         }
         quickcheck(prop as fn(Angle) -> bool);
     }
@@ -524,12 +2151,12 @@ mod tests {
             match a {
                 Radians(v) => {
                     let div_res = {
-                        let mut a1 = a.clone();
+                        let mut a1 = a;
                         a1 /= x;
                         a1.in_radians() == v / x
                     };
                     let mult_res = {
-                        let mut a1 = a.clone();
+                        let mut a1 = a;
                         a1 *= x;
                         a1.in_radians() == v * x
                     };
@@ -540,12 +2167,12 @@ mod tests {
                 }
                 Degrees(v) => {
                     let div_res = {
-                        let mut a1 = a.clone();
+                        let mut a1 = a;
                         a1 *= x;
                         a1.in_degrees() == v * x
                     };
                     let mult_res = {
-                        let mut a1 = a.clone();
+                        let mut a1 = a;
                         a1 /= x;
                         a1.in_degrees() == v / x
                     };
@@ -560,40 +2187,41 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "strict-algebra"))]
     fn test_angle_math_additive() {
         fn prop(a: Angle, b: Angle) -> bool {
             if let (Radians(x), Radians(y)) = (a, b) {
                 let add_res = {
-                    let mut a1 = a.clone();
+                    let mut a1 = a;
                     a1 += b;
                     a1.in_radians() == x + y
                 };
                 let sub_res = {
-                    let mut a1 = a.clone();
+                    let mut a1 = a;
                     a1 -= b;
                     a1.in_radians() == x - y
                 };
                 (a + b).in_radians() == x + y && (a - b).in_radians() == x - y && add_res && sub_res
             } else if let (Degrees(x), Degrees(y)) = (a, b) {
                 let add_res = {
-                    let mut a1 = a.clone();
+                    let mut a1 = a;
                     a1 += b;
                     a1.in_degrees() == x + y
                 };
                 let sub_res = {
-                    let mut a1 = a.clone();
+                    let mut a1 = a;
                     a1 -= b;
                     a1.in_degrees() == x - y
                 };
                 (a + b).in_degrees() == x + y && (a - b).in_degrees() == x - y && add_res && sub_res
             } else {
                 let add_res = {
-                    let mut a1 = a.clone();
+                    let mut a1 = a;
                     a1 += b;
                     a1.in_radians() == a.in_radians() + b.in_radians()
                 };
                 let sub_res = {
-                    let mut a1 = a.clone();
+                    let mut a1 = a;
                     a1 -= b;
                     a1.in_radians() == a.in_radians() - b.in_radians()
                 };
@@ -610,13 +2238,442 @@ mod tests {
             let rad = v.in_radians();
             let deg = v.in_degrees();
 
-            0.0 <= rad
-                && rad < 2.0 * PI
-                && 0.0 <= deg
-                && deg < 360.0
-                && are_close(rad.cos(), angle.cos())
+            (0.0..2.0 * PI).contains(&rad)
+                && (0.0..360.0).contains(&deg)
+                && (rad.cos() - angle.cos()).abs() <= tolerance(&[angle])
+        }
+        quickcheck(prop as fn(Angle) -> bool);
+    }
+
+    #[test]
+    fn test_angle_signed_normalization() {
+        fn prop(angle: Angle) -> bool {
+            let v = angle.normalized_signed();
+            let rad = v.in_radians();
+            let deg = v.in_degrees();
+
+            rad > -PI
+                && rad <= PI
+                && deg > -180.0
+                && deg <= 180.0
+                && (rad.cos() - angle.cos()).abs() <= tolerance(&[angle])
         }
         quickcheck(prop as fn(Angle) -> bool);
+
+        assert_eq!(Degrees(180.0).normalized_signed(), Degrees(180.0));
+        assert_eq!(Degrees(-180.0).normalized_signed(), Degrees(180.0));
+        assert_eq!(Degrees(540.0).normalized_signed(), Degrees(180.0));
+        assert_eq!(Degrees(-10.0).normalized_signed(), Degrees(-10.0));
+        assert_eq!(Degrees(190.0).normalized_signed(), Degrees(-170.0));
+        assert_eq!(Radians(PI).normalized_signed(), Radians(PI));
+        assert_eq!(Radians(-PI).normalized_signed(), Radians(PI));
+        assert_eq!(Radians(-0.5).normalized_signed(), Radians(-0.5));
+
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(Degrees(-180i16).normalized_signed(), Degrees(180));
+            assert_eq!(Degrees(350i16).normalized_signed(), Degrees(-10));
+        }
+        assert_eq!(Degrees(-90i8).checked_normalized_signed(), None);
+    }
+
+    #[test]
+    fn test_angle_normalize_around() {
+        fn prop(angle: Angle, center: Angle) -> bool {
+            let v = angle.normalize_around(center);
+            let d = v.in_radians() - center.in_radians();
+
+            v.unit() == angle.unit()
+                && (-PI - 1.0e-9..PI + 1.0e-9).contains(&d)
+                && v.min_dist(angle).in_radians() <= tolerance(&[angle, center])
+        }
+        quickcheck(prop as fn(Angle, Angle) -> bool);
+
+        let center = Degrees(135.0);
+        assert_eq!(Degrees(-45.0).normalize_around(center), Degrees(-45.0));
+        assert_eq!(Degrees(315.0).normalize_around(center), Degrees(-45.0));
+        assert_eq!(Degrees(314.0).normalize_around(center), Degrees(314.0));
+        assert_eq!(Degrees(-46.0).normalize_around(center), Degrees(314.0));
+        assert_eq!(Degrees(1000.0).normalize_around(center), Degrees(280.0));
+        assert_eq!(Degrees(0.0).normalize_around(Degrees(0.0)), Degrees(0.0));
+        assert_eq!(
+            Degrees(180.0).normalize_around(Degrees(0.0)),
+            Degrees(-180.0)
+        );
+        assert_that!(
+            Radians(0.0).normalize_around(Degrees(270.0)).in_radians(),
+            close_to(2.0 * PI, 1.0e-12)
+        );
+
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(Degrees(-400i32).normalize_around(Degrees(90)), Degrees(-40));
+            assert_eq!(Degrees(270i32).normalize_around(Degrees(90)), Degrees(-90));
+            assert_eq!(Degrees(10u16).normalize_around(Degrees(500)), Degrees(370));
+            assert_eq!(Degrees(679u16).normalize_around(Degrees(500)), Degrees(679));
+        }
+    }
+
+    #[test]
+    fn test_angle_signed_distance() {
+        fn prop(a: Angle, b: Angle) -> bool {
+            let d = a.signed_dist(b);
+            let rad = d.in_radians();
+
+            let tol = tolerance(&[a, b]);
+            rad > -PI - 1.0e-9
+                && rad <= PI + 1.0e-9
+                && (rad.abs() - a.min_dist(b).in_radians()).abs() <= tol
+                && (b + AngleDelta::new(d))
+                    .normalized()
+                    .min_dist(a.normalized())
+                    .in_radians()
+                    <= tol
+        }
+        quickcheck(prop as fn(Angle, Angle) -> bool);
+
+        assert_eq!(Degrees(350.0).signed_dist(Degrees(10.0)), Degrees(-20.0));
+        assert_eq!(Degrees(10.0).signed_dist(Degrees(350.0)), Degrees(20.0));
+        assert_eq!(Degrees(-370.0).signed_dist(Degrees(730.0)), Degrees(-20.0));
+        assert_eq!(Degrees(0.0).signed_dist(Degrees(180.0)), Degrees(180.0));
+        assert_eq!(Degrees(180.0).signed_dist(Degrees(0.0)), Degrees(180.0));
+        assert_that!(
+            Radians(0.5).signed_dist(Degrees(90.0)).in_radians(),
+            close_to(0.5 - PI / 2.0, 1.0e-12)
+        );
+    }
+
+    #[test]
+    fn test_reciprocal_trigonometry() {
+        for i in -36..=36 {
+            let alpha = Degrees(i as f64 * 10.0 + 5.0);
+            let (sin, cos) = alpha.sin_cos();
+            assert_that!(alpha.sec() * cos, close_to(1.0, 1.0e-12));
+            assert_that!(alpha.csc() * sin, close_to(1.0, 1.0e-12));
+            assert_that!(alpha.cot(), close_to(cos / sin, 1.0e-12));
+            let tan = alpha.tan();
+            assert_that!(alpha.sec().powi(2) - tan * tan, close_to(1.0, 1.0e-9));
+        }
+
+        assert_eq!(Radians(0.0).sec(), 1.0);
+        assert_eq!(Radians(0.0).csc(), f64::INFINITY);
+        assert_eq!(Radians(-0.0).csc(), f64::NEG_INFINITY);
+        assert_eq!(Radians(0.0).cot(), f64::INFINITY);
+        assert!(Degrees(90.0).sec().abs() > 1.0e15);
+        assert!(Degrees(90.0f32).sec().abs() > 1.0e7);
+        assert!(Degrees(180.0).cot().abs() > 1.0e15);
+        assert!(Radians(f64::NAN).sec().is_nan());
+    }
+
+    #[test]
+    fn test_hyperbolic() {
+        for i in -20..=20 {
+            let alpha = Radians(i as f64 * 0.25);
+            let (sinh, cosh) = (alpha.sinh(), alpha.cosh());
+            assert_that!(cosh * cosh - sinh * sinh, close_to(1.0, 1.0e-9));
+            assert_that!(alpha.tanh(), close_to(sinh / cosh, 1.0e-12));
+            assert_that!(
+                asinh(sinh).in_radians(),
+                close_to(alpha.in_radians(), 1.0e-12)
+            );
+            assert_that!(
+                acosh(cosh).unwrap().in_radians(),
+                close_to(alpha.in_radians().abs(), 1.0e-7)
+            );
+            assert_that!(
+                atanh(alpha.tanh()).unwrap().in_radians(),
+                close_to(alpha.in_radians(), 1.0e-9)
+            );
+        }
+        assert_that!(
+            Degrees(90.0).sinh(),
+            close_to(2.301_298_902_307_295, 1.0e-12)
+        );
+        assert_eq!(acosh(0.5), None);
+        assert_eq!(atanh(1.5), None);
+        assert_eq!(atanh(1.0), Some(Radians(f64::INFINITY)));
+    }
+
+    #[test]
+    fn test_gudermannian() {
+        // gd(π/4) and the latitude at the Mercator northing of π.
+        assert_that!(
+            gudermannian(PI / 4.0).in_radians(),
+            close_to(0.715_234_148_446_539_7, 1.0e-12)
+        );
+        assert_that!(
+            gudermannian(PI).in_degrees(),
+            close_to(85.051_128_779_806_6, 1.0e-9)
+        );
+        assert_eq!(gudermannian(0.0), Radians(0.0));
+        assert_eq!(gudermannian(f64::INFINITY), Radians(PI / 2.0));
+        assert_eq!(gudermannian(f64::NEG_INFINITY), Radians(-PI / 2.0));
+        assert_that!(
+            Degrees(85.051_128_779_806_6).inverse_gudermannian(),
+            close_to(PI, 1.0e-9)
+        );
+
+        for i in -89..=89 {
+            let latitude = Degrees(i as f64);
+            let back = gudermannian(latitude.inverse_gudermannian());
+            assert_that!(back.in_degrees(), close_to(i as f64, 1.0e-9));
+        }
+    }
+
+    #[test]
+    fn test_sinc() {
+        assert_eq!(Radians(0.0).sinc(), 1.0);
+        assert_eq!(Radians(-0.0).sinc(), 1.0);
+        assert_eq!(Degrees(0.0f32).sinc(), 1.0);
+        assert_eq!(Radians(0.0).sinc_pi(), 1.0);
+        assert_eq!(Radians(0.0f32).sinc_pi(), 1.0);
+        assert_eq!(Radians(f64::INFINITY).sinc(), 0.0);
+        assert_eq!(Radians(f64::NEG_INFINITY).sinc_pi(), 0.0);
+        assert!(Radians(f64::NAN).sinc().is_nan());
+        assert!(Radians(f64::NAN).sinc_pi().is_nan());
+
+        for &x in &[1.0e-300, 1.0e-20, 1.0e-8, 1.0e-5, 1.0e-4] {
+            for &x in &[x, -x] {
+                assert_that!(Radians(x).sinc(), close_to(1.0 - x * x / 6.0, 1.0e-16));
+                let pi_x = PI * x;
+                assert_that!(
+                    Radians(x).sinc_pi(),
+                    close_to(1.0 - pi_x * pi_x / 6.0, 1.0e-16)
+                );
+            }
+        }
+        assert_that!(Radians(1.0e-3f32).sinc() as f64, close_to(1.0, 1.0e-6));
+        assert_that!(
+            Radians(1.0e-2f32).sinc_pi() as f64,
+            close_to(0.999_835_5, 1.0e-7)
+        );
+
+        for i in 1..=1000 {
+            let x = i as f64 * 0.0123;
+            for &x in &[x, -x] {
+                assert_that!(Radians(x).sinc(), close_to(x.sin() / x, 1.0e-15));
+                let pi_x = PI * x;
+                assert_that!(Radians(x).sinc_pi(), close_to(pi_x.sin() / pi_x, 1.0e-14));
+            }
+        }
+        for i in 1..=10 {
+            assert_eq!(Radians(i as f64).sinc_pi(), 0.0);
+            assert_that!(Radians(PI * i as f64).sinc(), close_to(0.0, 1.0e-15));
+        }
+        assert_that!(Degrees(90.0).sinc(), close_to(2.0 / PI, 1.0e-15));
+    }
+
+    #[test]
+    fn test_half_and_double_angles() {
+        assert_eq!(Degrees(90.0).halved(), Degrees(45.0));
+        assert_eq!(Radians(PI).halved(), Radians(PI / 2.0));
+        assert_eq!(Degrees(7).halved(), Degrees(3));
+        assert_eq!(Degrees(-45.0).doubled(), Degrees(-90.0));
+        assert_eq!(Degrees(200u16).doubled(), Degrees(400));
+        assert!(matches!(Radians(1.0).doubled(), Radians(_)));
+
+        // All four quadrants, unnormalized angles and the neighbourhoods of
+        // zero and π.
+        let mut degrees = vec![0.0, 1.0e-9, -1.0e-9, 179.999_999, 180.000_001, -179.999_999];
+        degrees.extend((-16..=16).map(|i| i as f64 * 45.0 + 10.0));
+        for &x in &degrees {
+            let alpha = Degrees(x);
+            let h = (x / 2.0).to_radians();
+            assert_that!(alpha.sin_half(), close_to(h.sin(), 1.0e-15));
+            assert_that!(alpha.cos_half(), close_to(h.cos(), 1.0e-15));
+            let tan = h.tan();
+            assert_that!(
+                alpha.tan_half(),
+                close_to(tan, 1.0e-12 * tan.abs().max(1.0))
+            );
+        }
+
+        // The half-angle formula loses everything near zero.
+        let tiny = Radians(1.0e-10);
+        assert_eq!(tiny.sin_half(), 5.0e-11);
+        assert_eq!(((1.0 - tiny.cos()) / 2.0).sqrt(), 0.0);
+        assert_eq!(tiny.tan_half(), 5.0e-11);
+        // And sin(x) / (1 + cos(x)) near π.
+        let near_pi = Degrees(180.0 - 1.0e-6);
+        let (sin, cos) = near_pi.sin_cos();
+        let expected = (near_pi.in_radians() / 2.0).tan();
+        assert_that!(near_pi.tan_half(), close_to(expected, 1.0e-9 * expected));
+        assert!((sin / (1.0 + cos) - expected).abs() > 1.0e-6 * expected);
+        assert!(Radians(PI).tan_half() > 1.0e15);
+    }
+
+    #[test]
+    fn test_angle_ratio() {
+        assert_eq!(Degrees(90.0) / Degrees(30.0), 3.0);
+        assert_eq!(Degrees(0.3) / Degrees(0.1), 0.3 / 0.1);
+        assert_eq!(Radians(PI) / Radians(PI / 4.0), 4.0);
+        assert_eq!(Degrees(90) / Degrees(30), 3);
+        assert_eq!(Degrees(100) / Degrees(30), 3);
+        assert_that!(Degrees(90.0) / Radians(PI), close_to(0.5, 1.0e-15));
+        assert_that!(Radians(PI / 2.0) / Degrees(30.0), close_to(3.0, 1.0e-15));
+        assert!((Degrees(1.0) / Degrees(0.0)).is_infinite());
+
+        assert_eq!(Degrees(100.0).div_euclid(Degrees(30.0)), 3.0);
+        assert_eq!(Degrees(-100.0).div_euclid(Degrees(30.0)), -4.0);
+        assert_eq!(Degrees(-100.0).rem_euclid(Degrees(30.0)), Degrees(20.0));
+        assert_eq!(Degrees(100.0).rem_euclid(Degrees(-30.0)), Degrees(10.0));
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(Degrees(725).div_euclid(Degrees(360)), 2);
+            assert_eq!(Degrees(725).rem_euclid(Degrees(360)), Degrees(5));
+            assert_eq!(Degrees(-5).rem_euclid(Degrees(360)), Degrees(355));
+        }
+
+        let alpha = Degrees(400.0);
+        let turns = alpha.div_euclid(Radians(2.0 * PI));
+        let rest = alpha.rem_euclid(Radians(2.0 * PI));
+        assert_eq!(turns, 1.0);
+        assert!(matches!(rest, Degrees(_)));
+        assert_that!(rest.in_degrees(), close_to(40.0, 1.0e-12));
+        let rest = Radians(7.0).rem_euclid(Degrees(180.0));
+        assert_that!(rest.in_radians(), close_to(7.0 - 2.0 * PI, 1.0e-12));
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-algebra"))]
+    fn test_angle_sum() {
+        let empty: [Angle; 0] = [];
+        assert!(matches!(empty.iter().sum::<Angle>(), Radians(v) if v == 0.0));
+        assert_eq!(
+            vec![Degrees(30.0)].into_iter().sum::<Angle>(),
+            Degrees(30.0)
+        );
+
+        let degrees = [Degrees(10.0), Degrees(20.0), Degrees(350.0)];
+        let total: Angle = degrees.iter().sum();
+        assert!(matches!(total, Degrees(v) if v == 380.0));
+        let total: Angle<i32> = [Degrees(100), Degrees(-30)].iter().sum();
+        assert_eq!(total, Degrees(70));
+
+        let mixed = [Degrees(90.0), Radians(PI), Degrees(90.0)];
+        let total: Angle = mixed.iter().sum();
+        assert!(matches!(total, Radians(_)));
+        assert_that!(total.in_radians(), close_to(2.0 * PI, 1.0e-12));
+        let total: Angle = mixed.iter().copied().sum();
+        assert_that!(total.in_degrees(), close_to(360.0, 1.0e-12));
+    }
+
+    #[test]
+    fn test_wrapping_arithmetic() {
+        fn prop(a: Angle, b: Angle) -> bool {
+            let sum = a.wrapping_add(b);
+            let difference = a.wrapping_sub(b);
+            [sum, difference]
+                .iter()
+                .all(|v| v.unit() == a.unit() && (0.0..2.0 * PI).contains(&v.in_radians()))
+                && sum.min_dist(a + AngleDelta::new(b)).in_radians() <= tolerance(&[a, b])
+                && difference.min_dist(a - AngleDelta::new(b)).in_radians() <= tolerance(&[a, b])
+        }
+        quickcheck(prop as fn(Angle, Angle) -> bool);
+
+        assert_eq!(Degrees(350.0).wrapping_add(Degrees(20.0)), Degrees(10.0));
+        assert_eq!(Degrees(350.0).wrapping_add(Degrees(10.0)), Degrees(0.0));
+        assert_eq!(Degrees(1.0e6).wrapping_add(Degrees(-1.0e6)), Degrees(0.0));
+        assert_eq!(Degrees(10.0).wrapping_sub(Degrees(20.0)), Degrees(350.0));
+        assert_eq!(Degrees(10.0).wrapping_sub(Degrees(3610.0)), Degrees(0.0));
+        // A sum which rounds up to the full turn.
+        let below = Degrees(359.999_999_999_999_94);
+        assert_eq!(below.wrapping_add(Degrees(3.0e-14)), Degrees(0.0));
+        assert!(Degrees(0.0).wrapping_sub(Degrees(1.0e-15)).in_degrees() < 360.0);
+        let v = Radians(1.0).wrapping_add(Degrees(720.0));
+        assert!(matches!(v, Radians(_)));
+        assert_that!(v.in_radians(), close_to(1.0, 1.0e-12));
+
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(
+                Degrees(i32::MAX).wrapping_add(Degrees(i32::MAX)),
+                Degrees(254)
+            );
+            assert_eq!(
+                Degrees(i32::MIN).wrapping_sub(Degrees(i32::MAX)),
+                Degrees(105)
+            );
+            assert_eq!(Degrees(350u16).wrapping_add(Degrees(u16::MAX)), Degrees(5));
+            assert_eq!(Degrees(0u16).wrapping_sub(Degrees(1)), Degrees(359));
+        }
+    }
+
+    #[test]
+    fn test_mixed_scalars() {
+        assert_eq!(Degrees(30.0f64) * 2.0f32, Degrees(60.0));
+        assert_eq!(2.0f32 * Radians(1.5f64), Radians(3.0));
+        assert_eq!(Degrees(30.0f64) / 4u8, Degrees(7.5));
+        assert_eq!(Degrees(30.0f64) * -3i32, Degrees(-90.0));
+        assert_eq!(-3i16 * Degrees(30.0f64), Degrees(-90.0));
+        assert_eq!(Degrees(30.0f32) * 2.0f64, Degrees(60.0));
+        assert_eq!(Degrees(30.0f32) * 0.1f64, Degrees(30.0 * 0.1f32));
+        assert_eq!(0.5f64 * Degrees(30.0f32), Degrees(15.0));
+        assert_eq!(Degrees(30.0f32) / 3u16, Degrees(10.0));
+        assert_eq!(Degrees(1.0f32) * 1.0e300f64, Degrees(f32::INFINITY));
+
+        let mut alpha = Degrees(10.0f64);
+        alpha *= 3u32;
+        alpha /= 2.0f32;
+        assert_eq!(alpha, Degrees(15.0));
+        let mut beta = Radians(1.0f32);
+        beta *= 4i8;
+        assert!(matches!(beta, Radians(v) if v == 4.0));
+
+        assert_eq!(Degrees(30.0f64).scale(2u64), Degrees(60.0));
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(Degrees(30i32).scale(2.9f64), Degrees(60));
+            assert_eq!(Degrees(30i32).scale(-1i64), Degrees(-30));
+        }
+        assert_eq!(Radians(2.0f32).checked_scale(0.5f64), Some(Radians(1.0)));
+        assert_eq!(Degrees(1u8).checked_scale(-1i32), None);
+        assert_eq!(Degrees(1i64).checked_scale(f32::INFINITY), None);
+    }
+
+    macro_rules! check_binary(
+        ($op:tt, $a:expr, $b:expr) => ({
+            let (a, b) = ($a, $b);
+            let expected = a $op b;
+            for actual in [a $op &b, &a $op b, &a $op &b] {
+                assert_eq!(actual.unit(), expected.unit());
+                assert_eq!(actual.value(), expected.value());
+            }
+        });
+    );
+
+    const REFERENCE_OPERANDS: [(Angle, Angle); 4] = [
+        (Degrees(30.0), Degrees(45.0)),
+        (Radians(1.0), Radians(-2.0)),
+        (Degrees(30.0), Radians(1.0)),
+        (Radians(1.0), Degrees(30.0)),
+    ];
+
+    #[test]
+    fn test_reference_operators() {
+        for &(a, _) in &REFERENCE_OPERANDS {
+            check_binary!(*, a, 2.5f64);
+            check_binary!(/, a, 4.0f64);
+            assert_eq!(-&a, -a);
+        }
+        check_binary!(*, Degrees(30i16), 3);
+        check_binary!(/, Degrees(30u32), 7);
+        assert!(matches!(-&Degrees(5i8), Degrees(-5)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-algebra"))]
+    fn test_reference_additive_operators() {
+        for &(a, b) in &REFERENCE_OPERANDS {
+            check_binary!(+, a, b);
+            check_binary!(-, a, b);
+        }
+        check_binary!(+, Degrees(30), Degrees(45));
+        check_binary!(-, Radians(3u8), Radians(1));
+
+        let angles = [Degrees(10.0), Degrees(20.0), Degrees(30.0)];
+        let total = angles.iter().fold(Degrees(0.0), |acc, a| acc + a);
+        assert!(matches!(total, Degrees(v) if v == 60.0));
     }
 
     #[test]
@@ -641,24 +2698,555 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_angle_non_finite() {
+        use core::num::FpCategory;
+
+        let values = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+        for &v in &values {
+            for &alpha in &[Radians(v), Degrees(v)] {
+                assert_eq!(alpha.is_nan(), v.is_nan());
+                assert_eq!(alpha.is_infinite(), v.is_infinite());
+                assert!(!alpha.is_finite());
+                assert_eq!(alpha.classify(), v.classify());
+
+                let normalized = alpha.normalized();
+                assert_eq!(normalized.unit(), alpha.unit());
+                assert!(normalized.is_nan(), "{}", alpha);
+                assert!(alpha.normalized_signed().is_nan(), "{}", alpha);
+                assert!(alpha.min_dist(Degrees(10.0)).is_nan(), "{}", alpha);
+                assert!(Radians(1.0).min_dist(alpha).is_nan(), "{}", alpha);
+            }
+        }
+        assert!(Degrees(f32::NAN).is_nan());
+        assert!(Radians(f32::NEG_INFINITY).is_infinite());
+
+        // The stored value is classified without conversion.
+        assert!(Degrees(f64::MAX).is_finite());
+        assert!(Radians(f64::MAX).is_finite());
+        assert!(Radians(Degrees(f64::MAX).in_radians()).is_finite());
+        assert!(Degrees(Radians(f64::MAX).in_degrees()).is_infinite());
+        assert_eq!(Radians(0.0f32).classify(), FpCategory::Zero);
+        assert_eq!(Degrees(1.0e-40f32).classify(), FpCategory::Subnormal);
+        assert_eq!(Degrees(1.0f32).classify(), FpCategory::Normal);
+    }
+
+    #[test]
+    fn test_angle_dot() {
+        assert_that!(Degrees(0.0).dot(Degrees(90.0)), close_to(0.0, 1.0e-12));
+        assert_that!(
+            Degrees(45.0).dot(Radians(-PI / 4.0)),
+            close_to(0.0, 1.0e-12)
+        );
+        assert_that!(Degrees(30.0).dot(Degrees(210.0)), close_to(-1.0, 1.0e-12));
+        assert_that!(Degrees(0.0).dot(Degrees(60.0)), close_to(0.5, 1.0e-12));
+
+        assert_that!(Degrees(0.0).perp_dot(Degrees(90.0)), close_to(1.0, 1.0e-12));
+        assert_that!(
+            Degrees(0.0).perp_dot(Degrees(-90.0)),
+            close_to(-1.0, 1.0e-12)
+        );
+        assert_that!(
+            Degrees(355.0).perp_dot(Degrees(5.0)),
+            close_to(10f64.to_radians().sin(), 1.0e-12)
+        );
+        assert_that!(
+            Degrees(5.0).perp_dot(Degrees(355.0)),
+            close_to(-10f64.to_radians().sin(), 1.0e-12)
+        );
+
+        assert_that!(
+            Degrees(90.0).project(3.0, Degrees(0.0)),
+            close_to(0.0, 1.0e-12)
+        );
+        assert_that!(
+            Degrees(180.0).project(3.0, Degrees(0.0)),
+            close_to(-3.0, 1.0e-12)
+        );
+
+        fn prop(a: Angle, b: Angle) -> bool {
+            let (dot, perp) = (a.dot(b), a.perp_dot(b));
+            are_close(dot * dot + perp * perp, 1.0) && are_close(perp, -b.perp_dot(a))
+        }
+        quickcheck(prop as fn(Angle, Angle) -> bool);
+    }
+
     #[test]
     pub fn test_mean_angle() {
         assert_that!(
-            mean_angle(&[Degrees(90.0)]).in_degrees(),
+            mean_angle(&[Degrees(90.0)]).unwrap().in_degrees(),
             close_to(90.0, 0.000001)
         );
         assert_that!(
-            mean_angle(&[Degrees(90.0), Degrees(90.0)]).in_degrees(),
+            mean_angle(&[Degrees(90.0), Degrees(90.0)])
+                .unwrap()
+                .in_degrees(),
             close_to(90.0, 0.000001)
         );
         assert_that!(
-            mean_angle(&[Degrees(90.0), Degrees(180.0), Degrees(270.0)]).in_degrees(),
+            mean_angle(&[Degrees(90.0), Degrees(180.0), Degrees(270.0)])
+                .unwrap()
+                .in_degrees(),
             close_to(180.0, 0.000001)
         );
         assert_that!(
-            mean_angle(&[Degrees(20.0), Degrees(350.0)]).in_degrees(),
+            mean_angle(&[Degrees(20.0), Degrees(350.0)])
+                .unwrap()
+                .in_degrees(),
             close_to(5.0, 0.000001)
         );
+        assert!(matches!(mean_angle(&[Degrees(20.0)]), Some(Radians(_))));
+    }
+
+    #[test]
+    fn test_mean_angle_degenerate() {
+        assert_eq!(mean_angle::<f64, _>(&[]), None);
+        assert_eq!(mean_angle(&[Degrees(0.0), Degrees(180.0)]), None);
+        assert_eq!(mean_angle(&[Radians(1.0), Radians(1.0 + PI)]), None);
+        assert_eq!(
+            mean_angle(&[Degrees(0.0), Degrees(120.0), Degrees(240.0)]),
+            None
+        );
+        assert_eq!(mean_angle(&[Degrees(0.0f32), Degrees(180.0)]), None);
+        assert_eq!(mean_angle(&[Degrees(10.0), Degrees(f64::NAN)]), None);
+        assert_eq!(mean_angle(&[Degrees(f64::INFINITY)]), None);
+
+        let mu = mean_angle(&[Degrees(0.0), Degrees(179.0)]).unwrap();
+        assert_that!(mu.in_degrees(), close_to(89.5, 1.0e-10));
+    }
+
+    #[test]
+    fn test_angle_map() {
+        assert_eq!(Degrees(1.5f64).map(|v| v as f32), Degrees(1.5f32));
+        assert_eq!(Radians(0.25f64).map(|v| v as f32), Radians(0.25f32));
+
+        fn prop(angle: Angle) -> bool {
+            let same = angle.map(|v| v);
+            match (angle, same) {
+                (Radians(a), Radians(b)) | (Degrees(a), Degrees(b)) => a == b || a.is_nan(),
+                _ => false,
+            }
+        }
+        quickcheck(prop as fn(Angle) -> bool);
+    }
+
+    #[test]
+    fn test_angle_map_in_unit() {
+        assert!(matches!(
+            Radians(PI).map_in_degrees(|v| v.min(90.0)),
+            Radians(v) if are_close(v, PI / 2.0)
+        ));
+        assert!(matches!(
+            Degrees(270.0).map_in_radians(|v| v - PI),
+            Degrees(v) if are_close(v, 90.0)
+        ));
+
+        fn prop(angle: Angle) -> bool {
+            let radians = angle.map_in_radians(|v| v);
+            let degrees = angle.map_in_degrees(|v| v);
+            let tol = tolerance(&[angle]);
+            (radians.in_radians() - angle.in_radians()).abs() <= tol
+                && (degrees.in_radians() - angle.in_radians()).abs() <= tol
+                && core::mem::discriminant(&radians) == core::mem::discriminant(&angle)
+                && core::mem::discriminant(&degrees) == core::mem::discriminant(&angle)
+        }
+        quickcheck(prop as fn(Angle) -> bool);
+    }
+
+    #[test]
+    fn test_angle_parts() {
+        fn prop(angle: Angle) -> bool {
+            let (value, unit) = angle.into_parts();
+            let back = Angle::from_parts(value, unit);
+            back.unit() == angle.unit() && back.value() == angle.value()
+        }
+        quickcheck(prop as fn(Angle) -> bool);
+
+        assert_eq!(Degrees(180.0).into_parts(), (180.0, AngleUnit::Degrees));
+        assert_eq!(Radians(2i32).into_parts(), (2, AngleUnit::Radians));
+        assert_eq!(AngleUnit::Radians.full_turn_in::<f64>(), 2.0 * PI);
+        assert_eq!(AngleUnit::Degrees.full_turn_in::<f32>(), 360.0);
+    }
+
+    #[test]
+    fn test_angle_value_does_not_convert() {
+        let alpha = Radians(PI);
+        assert_eq!(alpha.value(), PI);
+        assert!(are_close(alpha.in_degrees(), 180.0));
+
+        let beta = Degrees(90.0);
+        assert_eq!(beta.value(), 90.0);
+        assert!(are_close(beta.in_radians(), PI / 2.0));
+    }
+
+    #[test]
+    fn test_angle_checked() {
+        assert_eq!(Radians(1i8).checked_in_degrees(), Some(57));
+        assert_eq!(Radians(100i8).checked_in_degrees(), None);
+        assert_eq!(Degrees(100i8).checked_in_degrees(), Some(100));
+        assert_eq!(Degrees(100i8).checked_in_radians(), Some(1));
+        assert_eq!(Degrees(90u8).checked_in_radians(), Some(1));
+        assert_eq!(
+            Radians(3.0f32).checked_in_degrees(),
+            Some(3.0f32.to_degrees())
+        );
+        assert!(matches!(Degrees(f64::NAN).checked_in_radians(), Some(v) if v.is_nan()));
+
+        assert_eq!(AngleUnit::Radians.checked_full_turn_in::<i8>(), Some(6));
+        assert_eq!(AngleUnit::Degrees.checked_full_turn_in::<i8>(), None);
+        assert_eq!(AngleUnit::Degrees.checked_full_turn_in::<u8>(), None);
+
+        assert_eq!(Degrees(-90i8).checked_normalized(), None);
+        assert_eq!(Radians(-1i8).checked_normalized(), Some(Radians(5)));
+        assert_eq!(Degrees(-90i32).checked_normalized(), Some(Degrees(270)));
+
+        fn prop(angle: Angle) -> bool {
+            angle.checked_in_radians() == Some(angle.in_radians())
+                && angle.checked_in_degrees() == Some(angle.in_degrees())
+                && angle.checked_normalized() == Some(angle.normalized())
+        }
+        quickcheck(prop as fn(Angle) -> bool);
+    }
+
+    #[cfg(feature = "no-panic")]
+    #[test]
+    fn test_angle_no_panic() {
+        // Floating point values overflow to infinity and fail as NaN.
+        assert_eq!(Radians(-2.0e38f32).in_degrees(), f32::NEG_INFINITY);
+        assert_eq!(AngleUnit::Degrees.full_turn_in::<f32>(), 360.0);
+        assert_eq!(Angle::<f64>::half(), Degrees(180.0));
+        assert!(Degrees(f64::NAN).normalized().value().is_nan());
+
+        // Integer values go through the checked and saturating methods.
+        assert_eq!(Radians(100i8).checked_in_degrees(), None);
+        assert_eq!(Radians(100i8).saturating_in_degrees(), i8::MAX);
+        assert_eq!(Radians(-100i8).saturating_in_degrees(), i8::MIN);
+        assert_eq!(Radians(i64::MAX).checked_in_degrees(), None);
+        assert_eq!(Radians(u128::MAX).saturating_in_degrees(), u128::MAX);
+        assert_eq!(Degrees(90i8).in_radians(), 1);
+        assert_eq!(AngleUnit::Degrees.checked_full_turn_in::<u8>(), None);
+        assert_eq!(Degrees(-90i8).checked_normalized(), None);
+        assert_eq!(Radians(-1i8).checked_normalized(), Some(Radians(5)));
+
+        // Comparing integer angles in different units cannot overflow.
+        assert!(Radians(100i8) > Degrees(5i8));
+        assert!(Radians(-100i8) < Degrees(-5i8));
+        assert_eq!(
+            Radians(u128::MAX).cmp(&Degrees(u128::MAX)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Radians(i64::MIN).partial_cmp(&Degrees(0)),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn test_angle_cast_into() {
+        assert_eq!(
+            Degrees(0.1f32).cast_into::<f64>(),
+            Some(Degrees(0.1f32 as f64))
+        );
+        assert_eq!(Radians(2.5f64).cast_into::<f32>(), Some(Radians(2.5f32)));
+        assert_eq!(Degrees(-90i32).cast_into::<i8>(), Some(Degrees(-90i8)));
+        assert_eq!(Degrees(360i32).cast_into::<i8>(), None);
+        assert_eq!(Radians(-1.0f64).cast_into::<u32>(), None);
+        assert_eq!(Degrees(f64::NAN).cast_into::<i32>(), None);
+        assert!(matches!(
+            Radians(f64::NAN).cast_into::<f32>(),
+            Some(Radians(v)) if v.is_nan()
+        ));
+
+        fn prop(angle: Angle<f32>) -> bool {
+            let wide = angle.cast_into::<f64>().unwrap();
+            wide.unit() == angle.unit() && wide.value() == angle.value() as f64
+        }
+        quickcheck(prop as fn(Angle<f32>) -> bool);
+    }
+
+    #[test]
+    fn test_angle_lossy_cast_into() {
+        assert_eq!(
+            Degrees(1.0e6f64).lossy_cast_into::<i16>(),
+            Degrees(i16::MAX)
+        );
+        assert_eq!(
+            Radians(-1.0e6f64).lossy_cast_into::<i16>(),
+            Radians(i16::MIN)
+        );
+        assert_eq!(Degrees(-5i32).lossy_cast_into::<u8>(), Degrees(0u8));
+        assert_eq!(Degrees(f64::NAN).lossy_cast_into::<i32>(), Degrees(0));
+        assert_eq!(
+            Degrees(1.0e300f64).lossy_cast_into::<f32>(),
+            Degrees(f32::INFINITY)
+        );
+        assert_eq!(Degrees(45.9f64).lossy_cast_into::<i32>(), Degrees(45));
+    }
+
+    #[test]
+    fn test_angle_widening_from() {
+        assert_eq!(Angle::<f64>::from(Degrees(0.1f32)), Degrees(0.1f32 as f64));
+        assert_eq!(
+            Angle::<f64>::from(Degrees(i32::MAX)),
+            Degrees(i32::MAX as f64)
+        );
+        assert_eq!(Angle::<i64>::from(Radians(-3i8)), Radians(-3i64));
+        let alpha: Angle<f32> = Degrees(255u8).into();
+        assert_eq!(alpha, Degrees(255.0));
+    }
+
+    #[test]
+    fn test_angle_display() {
+        assert_eq!(Degrees(45.5).to_string(), "45.5°");
+        assert_eq!(Radians(-1.25f32).to_string(), "-1.25rad");
+        assert_eq!(Degrees(7u8).to_string(), "7°");
+
+        // Precision.
+        assert_eq!(format!("{:.2}", Degrees(45.678)), "45.68°");
+        assert_eq!(format!("{:.0}", Radians(2.5)), "2rad");
+        assert_eq!(format!("{:.3}", Degrees(-0.0)), "-0.000°");
+        assert_eq!(format!("{:.1}", Radians(f64::NAN)), "NaNrad");
+
+        // Width, fill and alignment count the unit.
+        assert_eq!(format!("{:>10.3}", Radians(1.0)), "  1.000rad");
+        assert_eq!(format!("{:10.3}", Radians(1.0)), "  1.000rad");
+        assert_eq!(format!("{:<10.3}", Radians(-1.0)), "-1.000rad ");
+        assert_eq!(format!("{:^11.3}", Radians(-1.0)), " -1.000rad ");
+        assert_eq!(format!("{:_^10}", Degrees(-12)), "___-12°___");
+        assert_eq!(format!("{:8}", Degrees(-12.5)), "  -12.5°");
+        assert_eq!(format!("{:2}", Degrees(-12.5)), "-12.5°");
+        assert_eq!(format!("{:>4.1}", Degrees(0.25)), "0.2°");
+
+        // Sign.
+        assert_eq!(format!("{:+}", Degrees(5)), "+5°");
+        assert_eq!(format!("{:+.1}", Radians(-0.25)), "-0.2rad");
+        assert_eq!(format!("{:>+8.1}", Degrees(3.0)), "   +3.0°");
+
+        // Zeros after the sign.
+        assert_eq!(format!("{:07.1}", Degrees(-5.0)), "-005.0°");
+        assert_eq!(format!("{:+08.2}", Radians(1.5)), "+1.50rad");
+        assert_eq!(format!("{:+010.2}", Radians(1.5)), "+001.50rad");
+        assert_eq!(format!("{:05}", Degrees(42u16)), "0042°");
+    }
+
+    #[test]
+    fn test_angle_approx_eq() {
+        use approx::{abs_diff_eq, relative_eq, ulps_eq};
+
+        let a = 0.5;
+        let b = a + 1.0e-5;
+        let angles = |v: f64| [Radians(v), Degrees(v.to_degrees())];
+        for &x in &angles(a) {
+            for &y in &angles(b) {
+                assert!(!abs_diff_eq!(x, y, epsilon = 1.0e-6), "{} {}", x, y);
+                assert!(abs_diff_eq!(x, y, epsilon = 1.0e-4), "{} {}", x, y);
+                assert!(!relative_eq!(x, y, epsilon = 1.0e-6, max_relative = 1.0e-6));
+                assert!(relative_eq!(x, y, epsilon = 1.0e-4, max_relative = 1.0e-6));
+                assert!(!ulps_eq!(x, y, epsilon = 1.0e-6, max_ulps = 4));
+                assert!(ulps_eq!(x, y, epsilon = 1.0e-4, max_ulps = 4));
+            }
+        }
+        for &x in &angles(a) {
+            for &y in &angles(a) {
+                assert!(abs_diff_eq!(x, y), "{} {}", x, y);
+                assert!(relative_eq!(x, y), "{} {}", x, y);
+                assert!(ulps_eq!(x, y), "{} {}", x, y);
+            }
+        }
+
+        // A difference of 10⁻⁵° is within an epsilon of 10⁻⁶ rad.
+        assert!(abs_diff_eq!(
+            Degrees(30.0),
+            Degrees(30.00001),
+            epsilon = 1.0e-6
+        ));
+        assert!(abs_diff_eq!(
+            Degrees(10.0f32),
+            Radians(0.17453),
+            epsilon = 1.0e-4
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_angle_serde() {
+        let cases = [
+            (Degrees(45.0f64), r#"{"Degrees":45.0}"#),
+            (Radians(-1.5), r#"{"Radians":-1.5}"#),
+            (Degrees(-0.0), r#"{"Degrees":-0.0}"#),
+            (Radians(1.0e300), r#"{"Radians":1e+300}"#),
+        ];
+        for &(angle, json) in &cases {
+            assert_eq!(serde_json::to_string(&angle).unwrap(), json);
+            let back: Angle = serde_json::from_str(json).unwrap();
+            assert_eq!(back.unit(), angle.unit());
+            assert_eq!(back.value().to_bits(), angle.value().to_bits());
+        }
+
+        let angle = Degrees(-90i32);
+        let json = serde_json::to_string(&angle).unwrap();
+        assert_eq!(json, r#"{"Degrees":-90}"#);
+        assert!(matches!(
+            serde_json::from_str(&json).unwrap(),
+            Degrees(-90i32)
+        ));
+        let angle = Radians(0.1f32);
+        let back: Angle<f32> =
+            serde_json::from_str(&serde_json::to_string(&angle).unwrap()).unwrap();
+        assert!(matches!(back, Radians(v) if v == 0.1));
+
+        assert_eq!(
+            serde_json::to_string(&AngleUnit::Degrees).unwrap(),
+            r#""Degrees""#
+        );
+        assert_eq!(
+            serde_json::from_str::<AngleUnit>(r#""Radians""#).unwrap(),
+            AngleUnit::Radians
+        );
+
+        // JSON cannot hold non-finite values.
+        for &v in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let json = serde_json::to_string(&Degrees(v)).unwrap();
+            assert_eq!(json, r#"{"Degrees":null}"#);
+            assert!(serde_json::from_str::<Angle>(&json).is_err());
+        }
+
+        assert!(serde_json::from_str::<Angle>(r#"{"Gradians":45.0}"#).is_err());
+        assert!(serde_json::from_str::<Angle>("45.0").is_err());
+        assert!(serde_json::from_str::<Angle<u8>>(r#"{"Degrees":-1}"#).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_angle_serde_binary() {
+        let nan = f64::from_bits(0x7ff8_0000_dead_beef);
+        for &angle in &[
+            Degrees(nan),
+            Radians(-f64::NAN),
+            Degrees(f64::INFINITY),
+            Radians(f64::NEG_INFINITY),
+            Degrees(-0.0),
+            Radians(-1.5),
+        ] {
+            let bytes = postcard::to_allocvec(&angle).unwrap();
+            let back: Angle = postcard::from_bytes(&bytes).unwrap();
+            assert_eq!(back.unit(), angle.unit());
+            assert_eq!(back.value().to_bits(), angle.value().to_bits());
+        }
+
+        for &angle in &[
+            Degrees(f32::NAN),
+            Radians(f32::INFINITY),
+            Degrees(f32::NEG_INFINITY),
+        ] {
+            let bytes = postcard::to_allocvec(&angle).unwrap();
+            let back: Angle<f32> = postcard::from_bytes(&bytes).unwrap();
+            assert_eq!(back.unit(), angle.unit());
+            assert_eq!(back.value().to_bits(), angle.value().to_bits());
+        }
+
+        let bytes = postcard::to_allocvec(&Degrees(-90i32)).unwrap();
+        assert!(matches!(
+            postcard::from_bytes(&bytes).unwrap(),
+            Degrees(-90i32)
+        ));
+    }
+
+    #[test]
+    fn test_angle_total_cmp() {
+        use core::cmp::Ordering::*;
+
+        let nan = f64::NAN;
+        let inf = f64::INFINITY;
+        assert_eq!(Degrees(nan).total_cmp(&Degrees(1.0)), Greater);
+        assert_eq!(Radians(-nan).total_cmp(&Degrees(-inf)), Less);
+        assert_eq!(Degrees(nan).total_cmp(&Degrees(nan)), Equal);
+        assert_eq!(Degrees(-0.0).total_cmp(&Degrees(0.0)), Less);
+        assert_eq!(Radians(-0.0).total_cmp(&Radians(0.0)), Less);
+        assert_eq!(Radians(inf).total_cmp(&Degrees(f64::MAX)), Greater);
+        assert_eq!(Degrees(-inf).total_cmp(&Radians(-1.0e300)), Less);
+
+        // Equal in degrees, ordered by unit and then by stored value.
+        assert_eq!(Radians(0.0).total_cmp(&Degrees(0.0)), Less);
+        assert_eq!(Degrees(inf).total_cmp(&Radians(inf)), Greater);
+        assert_eq!(Degrees(180.0).total_cmp(&Radians(PI)), Greater);
+        assert_eq!(Radians(PI).total_cmp(&Radians(PI)), Equal);
+        assert_eq!(Radians(2.0f32).total_cmp(&Degrees(90.0)), Greater);
+
+        let angles = [
+            Degrees(90.0),
+            Radians(nan),
+            Degrees(-0.0),
+            Radians(0.0),
+            Degrees(0.0),
+            Radians(-inf),
+            Degrees(-nan),
+            Radians(PI),
+            Degrees(180.0),
+            Radians(-0.0),
+            Degrees(inf),
+        ];
+        let key = |a: &Angle| (a.unit(), a.value().to_bits());
+        let mut expected = angles;
+        expected.sort_by(Angle::total_cmp);
+        assert_eq!(key(&expected[0]), key(&Degrees(-nan)));
+        assert_eq!(key(&expected[1]), key(&Radians(-inf)));
+        assert_eq!(key(&expected[2]), key(&Radians(-0.0)));
+        assert_eq!(key(&expected[10]), key(&Radians(nan)));
+        for i in 0..angles.len() {
+            let mut sorted = angles;
+            sorted.rotate_left(i);
+            sorted.reverse();
+            sorted.sort_by(Angle::total_cmp);
+            assert!(sorted.iter().map(key).eq(expected.iter().map(key)));
+        }
+
+        assert_eq!(Degrees(10.0).max_by_total(Radians(1.0)), Radians(1.0));
+        assert_eq!(Degrees(10.0).min_by_total(Radians(1.0)), Degrees(10.0));
+        assert!(Degrees(1.0).max_by_total(Degrees(nan)).value().is_nan());
+        assert_eq!(Degrees(1.0).min_by_total(Degrees(nan)), Degrees(1.0));
+        let max = Degrees(0.0).max_by_total(Degrees(-0.0));
+        assert!(max.value().is_sign_positive());
+        let min = Degrees(0.0).min_by_total(Degrees(-0.0));
+        assert!(min.value().is_sign_negative());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_angle_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+
+        fn hash_of<T: Hash>(value: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let pairs = [
+            (Degrees(0i64), Radians(0i64)),
+            (Degrees(180), Radians(3)),
+            (Degrees(-360), Radians(-6)),
+            (Degrees(5730), Radians(100)),
+            (Degrees(42), Degrees(42)),
+        ];
+        for &(a, b) in &pairs {
+            assert_eq!(a, b);
+            assert_eq!(hash_of(a), hash_of(b), "{} and {}", a, b);
+        }
+        assert_eq!(hash_of(Radians(7u16)), hash_of(Radians(7u16)));
+
+        let mut map = HashMap::new();
+        map.insert(Degrees(180i64), "half");
+        map.insert(Radians(6), "full");
+        assert_eq!(map.get(&Radians(3)), Some(&"half"));
+        assert_eq!(map.get(&Degrees(360)), Some(&"full"));
+        assert_eq!(map.get(&Radians(1)), None);
+
+        // Inserting an equal key in the other unit replaces the value.
+        map.insert(Radians(3), "straight");
+        assert_eq!(map.len(), 2);
+        assert_eq!(map[&Degrees(180)], "straight");
     }
 
     #[cfg(feature = "std")]
@@ -666,14 +3254,12 @@ mod tests {
         (a - b).abs() < cast(1.0e-10).unwrap()
     }
 
-    impl<T: Arbitrary> Arbitrary for Angle<T> {
-        fn arbitrary<G: Gen>(g: &mut G) -> Self {
-            let v = Arbitrary::arbitrary(g);
-            if bool::arbitrary(g) {
-                Radians(v)
-            } else {
-                Degrees(v)
-            }
-        }
+    /// Compute the tolerance for results derived from the given angles. The
+    /// error of reducing a value by whole turns grows with its magnitude, so
+    /// the tolerance does too.
+    fn tolerance(angles: &[Angle]) -> f64 {
+        angles.iter().fold(1.0e-10, |tol, angle| {
+            tol.max(angle.in_radians().abs() * 1.0e-14)
+        })
     }
 }
@@ -0,0 +1,274 @@
+//! Reading and writing coordinates in NMEA 0183 sentences.
+
+use core::fmt::{Display, Error, Formatter};
+
+use crate::ddm::split_minutes;
+use crate::{Angle, Degrees, ParseAngleError};
+
+impl Angle<f64> {
+    /// Parse a latitude from the `ddmm.mmmm` field and the `N`/`S` hemisphere
+    /// indicator of an NMEA sentence, yielding signed decimal degrees.
+    ///
+    /// Missing leading zeros in the degrees are accepted, so `512.5` is read
+    /// as 5° 12.5'. An empty field, as sent by receivers without a fix, is
+    /// reported as `ParseAngleError::Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let lat = Angle::parse_nmea_lat("4916.45", 'N').unwrap();
+    /// assert!((lat.in_degrees() - 49.274167).abs() < 1.0e-6);
+    /// ```
+    pub fn parse_nmea_lat(field: &str, hemisphere: char) -> Result<Angle<f64>, ParseAngleError> {
+        parse_nmea(field, hemisphere, 90, ('N', 'S'))
+    }
+
+    /// Parse a longitude from the `dddmm.mmmm` field and the `E`/`W` hemisphere
+    /// indicator of an NMEA sentence, yielding signed decimal degrees.
+    ///
+    /// Follows the same rules as [`parse_nmea_lat`](#method.parse_nmea_lat).
+    pub fn parse_nmea_lon(field: &str, hemisphere: char) -> Result<Angle<f64>, ParseAngleError> {
+        parse_nmea(field, hemisphere, 180, ('E', 'W'))
+    }
+
+    /// Format the angle as an NMEA latitude, writing the `ddmm.mmmm` field and
+    /// the hemisphere indicator separated by a comma, e.g. `4916.4500,N`.
+    ///
+    /// The minutes are rounded to `decimals` places (at most 9), carrying
+    /// into the degrees where needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let lat = Degrees(-33.798900f64);
+    /// assert_eq!(lat.display_nmea_lat(4).to_string(), "3347.9340,S");
+    /// ```
+    pub fn display_nmea_lat(self, decimals: usize) -> NmeaCoordinate {
+        NmeaCoordinate {
+            degrees: self.in_degrees(),
+            decimals: decimals.min(9),
+            width: 2,
+            hemispheres: ('N', 'S'),
+        }
+    }
+
+    /// Format the angle as an NMEA longitude, writing the `dddmm.mmmm` field
+    /// and the hemisphere indicator separated by a comma, e.g. `12311.1200,W`.
+    ///
+    /// Follows the same rules as [`display_nmea_lat`](#method.display_nmea_lat).
+    pub fn display_nmea_lon(self, decimals: usize) -> NmeaCoordinate {
+        NmeaCoordinate {
+            degrees: self.in_degrees(),
+            decimals: decimals.min(9),
+            width: 3,
+            hemispheres: ('E', 'W'),
+        }
+    }
+}
+
+/// Helper struct for writing a coordinate in an NMEA sentence.
+///
+/// This `struct` is created by the [`display_nmea_lat`] and [`display_nmea_lon`]
+/// methods on [`Angle`]. See their documentation for more.
+///
+/// [`display_nmea_lat`]: enum.Angle.html#method.display_nmea_lat
+/// [`display_nmea_lon`]: enum.Angle.html#method.display_nmea_lon
+/// [`Angle`]: enum.Angle.html
+#[derive(Copy, Clone, Debug)]
+pub struct NmeaCoordinate {
+    degrees: f64,
+    decimals: usize,
+    width: usize,
+    hemispheres: (char, char),
+}
+
+impl Display for NmeaCoordinate {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        let (positive, negative) = self.hemispheres;
+        let hemisphere = if self.degrees < 0.0 {
+            negative
+        } else {
+            positive
+        };
+
+        let (degrees, minutes, scale) = split_minutes(self.degrees, self.decimals);
+
+        write!(f, "{:0w$}{:02}", degrees, minutes / scale, w = self.width)?;
+        if self.decimals > 0 {
+            write!(f, ".{:0d$}", minutes % scale, d = self.decimals)?;
+        }
+        write!(f, ",{}", hemisphere)
+    }
+}
+
+fn parse_nmea(
+    field: &str,
+    hemisphere: char,
+    max_degrees: u32,
+    (positive, negative): (char, char),
+) -> Result<Angle<f64>, ParseAngleError> {
+    if field.is_empty() {
+        return Err(ParseAngleError::Empty);
+    }
+
+    let sign = if hemisphere == positive {
+        1.0
+    } else if hemisphere == negative {
+        -1.0
+    } else {
+        return Err(ParseAngleError::InvalidHemisphere(hemisphere));
+    };
+
+    let point = field.find('.').unwrap_or(field.len());
+    let (int, frac) = field.split_at(point);
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if int.is_empty() || !is_digits(int) || !is_digits(frac.get(1..).unwrap_or("")) {
+        return Err(ParseAngleError::InvalidNumber);
+    }
+
+    // The last two integer digits are the whole minutes, everything before
+    // them is degrees.
+    let split = point.saturating_sub(2);
+    let degrees: u32 = match split {
+        0 => 0,
+        _ => field[..split]
+            .parse()
+            .map_err(|_| ParseAngleError::InvalidNumber)?,
+    };
+    let minutes: f64 = field[split..]
+        .parse()
+        .map_err(|_| ParseAngleError::InvalidNumber)?;
+
+    if minutes >= 60.0 {
+        return Err(ParseAngleError::MinutesOutOfRange);
+    }
+
+    let value = f64::from(degrees) + minutes / 60.0;
+    if value > f64::from(max_degrees) {
+        return Err(ParseAngleError::OutOfRange);
+    }
+
+    Ok(Degrees(sign * value))
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_parse_nmea() {
+        // $GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47
+        assert_that!(
+            Angle::parse_nmea_lat("4807.038", 'N').unwrap().in_degrees(),
+            close_to(48.1173, 0.000001)
+        );
+        assert_that!(
+            Angle::parse_nmea_lon("01131.000", 'E')
+                .unwrap()
+                .in_degrees(),
+            close_to(11.516667, 0.000001)
+        );
+
+        // $GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E*68
+        assert_that!(
+            Angle::parse_nmea_lat("4916.45", 'N').unwrap().in_degrees(),
+            close_to(49.274167, 0.000001)
+        );
+        assert_that!(
+            Angle::parse_nmea_lon("12311.12", 'W').unwrap().in_degrees(),
+            close_to(-123.185333, 0.000001)
+        );
+
+        assert_that!(
+            Angle::parse_nmea_lat("3347.9340", 'S')
+                .unwrap()
+                .in_degrees(),
+            close_to(-33.7989, 0.000001)
+        );
+    }
+
+    #[test]
+    fn test_parse_nmea_leading_zeros() {
+        assert_eq!(
+            Angle::parse_nmea_lon("1131.000", 'E'),
+            Angle::parse_nmea_lon("01131.000", 'E')
+        );
+        assert_that!(
+            Angle::parse_nmea_lat("512.5", 'N').unwrap().in_degrees(),
+            close_to(5.208333, 0.000001)
+        );
+        assert_that!(
+            Angle::parse_nmea_lat("7.5", 'S').unwrap().in_degrees(),
+            close_to(-0.125, 0.000001)
+        );
+        assert_that!(
+            Angle::parse_nmea_lat("4807", 'N').unwrap().in_degrees(),
+            close_to(48.116667, 0.000001)
+        );
+    }
+
+    #[test]
+    fn test_parse_nmea_errors() {
+        use ParseAngleError::*;
+
+        assert_eq!(Angle::parse_nmea_lat("", 'N'), Err(Empty));
+        assert_eq!(Angle::parse_nmea_lon("", ' '), Err(Empty));
+        assert_eq!(
+            Angle::parse_nmea_lat("4875.000", 'N'),
+            Err(MinutesOutOfRange)
+        );
+        assert_eq!(
+            Angle::parse_nmea_lat("4807.038", 'E'),
+            Err(InvalidHemisphere('E'))
+        );
+        assert_eq!(Angle::parse_nmea_lat("9100.000", 'N'), Err(OutOfRange));
+        assert_eq!(Angle::parse_nmea_lon("18000.001", 'W'), Err(OutOfRange));
+        assert_eq!(Angle::parse_nmea_lat("-4807.038", 'N'), Err(InvalidNumber));
+        assert_eq!(Angle::parse_nmea_lat("48o7.038", 'N'), Err(InvalidNumber));
+        assert_eq!(Angle::parse_nmea_lat(".038", 'N'), Err(InvalidNumber));
+        assert_eq!(Angle::parse_nmea_lat("4807.0.3", 'N'), Err(InvalidNumber));
+    }
+
+    #[test]
+    fn test_display_nmea() {
+        assert_eq!(
+            Degrees(48.1173).display_nmea_lat(3).to_string(),
+            "4807.038,N"
+        );
+        assert_eq!(
+            Degrees(11.516_666_667).display_nmea_lon(3).to_string(),
+            "01131.000,E"
+        );
+        assert_eq!(
+            Degrees(-123.185_333_333).display_nmea_lon(2).to_string(),
+            "12311.12,W"
+        );
+        assert_eq!(
+            Degrees(5.208_333_333).display_nmea_lat(0).to_string(),
+            "0512,N"
+        );
+        // 59.99999' rounds up into the next degree.
+        assert_eq!(
+            Degrees(48.999_999_9).display_nmea_lat(3).to_string(),
+            "4900.000,N"
+        );
+    }
+
+    #[test]
+    fn test_nmea_round_trip() {
+        for &(field, hemisphere) in &[("4807.0380", 'N'), ("3347.9340", 'S'), ("0000.0001", 'N')] {
+            let lat = Angle::parse_nmea_lat(field, hemisphere).unwrap();
+            let formatted = lat.display_nmea_lat(4).to_string();
+            assert_eq!(formatted, format!("{},{}", field, hemisphere));
+        }
+        for &(field, hemisphere) in &[("12311.1200", 'W'), ("00001.5000", 'E')] {
+            let lon = Angle::parse_nmea_lon(field, hemisphere).unwrap();
+            let formatted = lon.display_nmea_lon(4).to_string();
+            assert_eq!(formatted, format!("{},{}", field, hemisphere));
+        }
+    }
+}
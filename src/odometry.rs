@@ -0,0 +1,139 @@
+//! Planar dead reckoning.
+
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, AngleDelta};
+
+/// Advance the position (`x`, `y`) by `distance` along a straight line in the
+/// direction of `heading`, measured counter-clockwise from the positive x
+/// axis. A negative distance moves backwards.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let (x, y) = dead_reckon(1.0f64, 1.0, Degrees(90.0), 2.0);
+/// assert!((x - 1.0).abs() < 1.0e-10);
+/// assert!((y - 3.0).abs() < 1.0e-10);
+/// ```
+#[inline]
+pub fn dead_reckon<T: Float>(x: T, y: T, heading: Angle<T>, distance: T) -> (T, T) {
+    let (sin, cos) = heading.sin_cos();
+    (x + distance * cos, y + distance * sin)
+}
+
+/// Advance the pose (`x`, `y`, `heading`) by `distance` along a circular arc
+/// over which the heading turns by `heading_change`, as when a wheeled
+/// vehicle drives at a constant curvature. Positive changes turn
+/// counter-clockwise.
+///
+/// The position moves along the chord of the arc, which points halfway
+/// through the turn and is shorter than the travelled `distance`. For tiny
+/// heading changes the result approaches that of
+/// [`dead_reckon`](fn.dead_reckon.html). The returned heading is `heading +
+/// heading_change`, without normalization.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use std::f64::consts::PI;
+///
+/// // A quarter circle of radius 1, turning left from the positive x axis.
+/// let (x, y, heading) = dead_reckon_arc(0.0, 0.0, Degrees(0.0), PI / 2.0, Degrees(90.0));
+/// assert!((x - 1.0).abs() < 1.0e-10);
+/// assert!((y - 1.0).abs() < 1.0e-10);
+/// assert_eq!(heading, Degrees(90.0));
+/// ```
+pub fn dead_reckon_arc<T: Float>(
+    x: T,
+    y: T,
+    heading: Angle<T>,
+    distance: T,
+    heading_change: Angle<T>,
+) -> (T, T, Angle<T>) {
+    let half = heading_change.in_radians() / (T::one() + T::one());
+
+    // The chord is distance·sin(h)/h for half the turn h, which is 0/0 when
+    // there is no turn; fall back to its series below where the next term no
+    // longer matters.
+    let ratio = if half.abs() < T::epsilon().sqrt().sqrt() {
+        let six: T = cast(6).unwrap();
+        T::one() - half * half / six
+    } else {
+        half.sin() / half
+    };
+
+    let (x, y) = dead_reckon(
+        x,
+        y,
+        heading + AngleDelta::new(heading_change / (T::one() + T::one())),
+        distance * ratio,
+    );
+    (x, y, heading + AngleDelta::new(heading_change))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_dead_reckon() {
+        let cases = [
+            (0.0, (2.0, 0.0)),
+            (90.0, (0.0, 2.0)),
+            (180.0, (-2.0, 0.0)),
+            (270.0, (0.0, -2.0)),
+            (-90.0, (0.0, -2.0)),
+        ];
+        for &(heading, (dx, dy)) in &cases {
+            let (x, y) = dead_reckon(1.0, -1.0, Degrees(heading), 2.0);
+            assert_that!(x, close_to(1.0 + dx, 1.0e-10));
+            assert_that!(y, close_to(-1.0 + dy, 1.0e-10));
+        }
+
+        let (x, y) = dead_reckon(0.0, 0.0, Radians(PI / 4.0), -(2.0f64.sqrt()));
+        assert_that!(x, close_to(-1.0, 1.0e-10));
+        assert_that!(y, close_to(-1.0, 1.0e-10));
+    }
+
+    #[test]
+    fn test_dead_reckon_arc() {
+        // A quarter circle of radius 2 turning right from north ends 2 units
+        // east and 2 units north of where it started.
+        let (x, y, heading) = dead_reckon_arc(1.0, 1.0, Degrees(90.0), PI, Degrees(-90.0));
+        assert_that!(x, close_to(3.0, 1.0e-10));
+        assert_that!(y, close_to(3.0, 1.0e-10));
+        assert_eq!(heading, Degrees(0.0));
+
+        // A full circle returns to where it started.
+        let (x, y, heading) = dead_reckon_arc(1.0, 1.0, Radians(0.3), 5.0, Radians(2.0 * PI));
+        assert_that!(x, close_to(1.0, 1.0e-10));
+        assert_that!(y, close_to(1.0, 1.0e-10));
+        assert_that!(heading.in_radians(), close_to(0.3 + 2.0 * PI, 1.0e-10));
+
+        // A half circle of radius 1 ends 2 units to the side.
+        let (x, y, _) = dead_reckon_arc(0.0, 0.0, Degrees(0.0), PI, Degrees(180.0));
+        assert_that!(x, close_to(0.0, 1.0e-10));
+        assert_that!(y, close_to(2.0, 1.0e-10));
+    }
+
+    #[test]
+    fn test_dead_reckon_arc_tiny_turns() {
+        let (x, y, heading) = dead_reckon_arc(1.0, 2.0, Degrees(30.0), 10.0, Degrees(0.0));
+        let (sx, sy) = dead_reckon(1.0, 2.0, Degrees(30.0), 10.0);
+        assert_eq!((x, y), (sx, sy));
+        assert_eq!(heading, Degrees(30.0));
+
+        for &change in &[1.0e-12, -1.0e-9, 1.0e-6, 1.0e-4, 2.0e-4] {
+            let (x, y, _) = dead_reckon_arc(1.0, 2.0, Radians(0.5), 10.0, Radians(change));
+            let (sx, sy) = dead_reckon(1.0, 2.0, Radians(0.5 + change / 2.0), 10.0);
+            assert!(x.is_finite() && y.is_finite());
+            assert_that!(x, close_to(sx, 1.0e-6));
+            assert_that!(y, close_to(sy, 1.0e-6));
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Refraction of light at the boundary between two media.
+
+use num_traits::Float;
+
+use crate::{Angle, Radians};
+
+/// Compute the angle of the transmitted ray when a ray with the given angle of
+/// `incidence` passes from a medium with refractive index `n1` into a medium
+/// with refractive index `n2`, following Snell's law.
+///
+/// Both angles are measured from the surface normal, and the sign of the
+/// incidence is preserved so the ray stays in the plane of incidence. Return
+/// value is in the range of [-π/2, π/2] rad, or `None` on total internal
+/// reflection or if either index is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let theta = refract(Degrees(30.0f64), 1.0, 1.5).unwrap();
+/// assert!((theta.in_degrees() - 19.47).abs() < 0.01);
+/// ```
+#[inline]
+pub fn refract<T: Float>(incidence: Angle<T>, n1: T, n2: T) -> Option<Angle<T>> {
+    if !(n1 > T::zero() && n2 > T::zero()) {
+        return None;
+    }
+
+    let sin = incidence.sin() * n1 / n2;
+    if sin.abs() > T::one() {
+        None
+    } else {
+        Some(Radians(sin.asin()))
+    }
+}
+
+/// Compute the critical angle beyond which a ray passing from a medium with
+/// refractive index `n1` into a medium with refractive index `n2` is totally
+/// internally reflected.
+///
+/// Returns `None` if `n1 <= n2`, where total internal reflection cannot occur,
+/// or if either index is not positive.
+#[inline]
+pub fn critical_angle<T: Float>(n1: T, n2: T) -> Option<Angle<T>> {
+    if !(n1 > n2 && n2 > T::zero()) {
+        None
+    } else {
+        Some(Radians((n2 / n1).asin()))
+    }
+}
+
+/// Compute Brewster's angle, the angle of incidence at which light polarized
+/// in the plane of incidence is perfectly transmitted from a medium with
+/// refractive index `n1` into a medium with refractive index `n2`.
+///
+/// # Panics
+///
+/// Panics if either index is not positive. See [`checked_brewster_angle`].
+/// The function is not available with the `no-panic` feature.
+#[cfg(not(feature = "no-panic"))]
+#[inline]
+pub fn brewster_angle<T: Float>(n1: T, n2: T) -> Angle<T> {
+    checked_brewster_angle(n1, n2).expect("refractive indices must be positive")
+}
+
+/// Compute Brewster's angle as [`brewster_angle`] does, or return `None` if
+/// either index is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let theta = checked_brewster_angle(1.0f64, 1.5).unwrap();
+/// assert!((theta.in_degrees() - 56.31).abs() < 0.01);
+/// assert!(checked_brewster_angle(1.0f64, 0.0).is_none());
+/// ```
+#[inline]
+pub fn checked_brewster_angle<T: Float>(n1: T, n2: T) -> Option<Angle<T>> {
+    if !(n1 > T::zero() && n2 > T::zero()) {
+        None
+    } else {
+        Some(Radians(n2.atan2(n1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_refract() {
+        assert_that!(
+            refract(Degrees(30.0), 1.0, 1.5).unwrap().in_degrees(),
+            close_to(19.4712, 0.0001)
+        );
+        assert_that!(
+            refract(Degrees(-30.0), 1.0, 1.5).unwrap().in_degrees(),
+            close_to(-19.4712, 0.0001)
+        );
+        assert_that!(
+            refract(Degrees(0.0), 1.0, 1.5).unwrap().in_degrees(),
+            close_to(0.0, 0.000001)
+        );
+        assert!(refract(Degrees(30.0), 0.0, 1.5).is_none());
+        assert!(refract(Degrees(30.0), 1.0, -1.5).is_none());
+    }
+
+    #[test]
+    fn test_total_internal_reflection() {
+        let critical = critical_angle(1.5, 1.0).unwrap();
+        assert_that!(critical.in_degrees(), close_to(41.8103, 0.0001));
+
+        assert!(refract(critical - AngleDelta::new(Degrees(0.1)), 1.5, 1.0).is_some());
+        assert!(refract(critical + AngleDelta::new(Degrees(0.1)), 1.5, 1.0).is_none());
+        assert!(refract(Degrees(-60.0), 1.5, 1.0).is_none());
+
+        assert!(critical_angle(1.0, 1.5).is_none());
+        assert!(critical_angle(1.5, 1.5).is_none());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    fn test_brewster_angle() {
+        assert_that!(
+            brewster_angle(1.0, 1.5).in_degrees(),
+            close_to(56.3099, 0.0001)
+        );
+        assert_that!(
+            (brewster_angle(1.0, 1.5) + AngleDelta::new(brewster_angle(1.5, 1.0))).in_degrees(),
+            close_to(90.0, 0.000001)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    #[should_panic]
+    fn test_brewster_angle_invalid() {
+        brewster_angle(0.0, 1.5);
+    }
+
+    #[test]
+    fn test_checked_brewster_angle() {
+        assert_that!(
+            checked_brewster_angle(1.0, 1.5).unwrap().in_degrees(),
+            close_to(56.3099, 0.0001)
+        );
+        assert_that!(
+            checked_brewster_angle(1.0f32, 1.0).unwrap().in_degrees(),
+            close_to(45.0, 0.0001)
+        );
+        assert!(checked_brewster_angle(0.0, 1.5).is_none());
+        assert!(checked_brewster_angle(1.0, -1.5).is_none());
+        assert!(checked_brewster_angle(f64::NAN, 1.5).is_none());
+    }
+
+    #[test]
+    fn test_refract_symmetry() {
+        for i in -89..90 {
+            let theta = Degrees(i as f64);
+            let there = refract(theta, 1.0, 1.33).unwrap();
+            let back = refract(there, 1.33, 1.0).unwrap();
+            assert_that!(back.in_degrees(), close_to(theta.in_degrees(), 1.0e-9));
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Parsing angles from text.
+
+use core::fmt::{Display, Error, Formatter};
+use core::str::FromStr;
+
+use crate::{Angle, Degrees, Radians};
+
+/// An error which can be returned when parsing an angle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseAngleError {
+    /// The input was empty, as in NMEA sentences sent before the receiver has
+    /// a fix.
+    Empty,
+    /// The input is not a valid number.
+    InvalidNumber,
+    /// The minutes component is not in the range [0, 60).
+    MinutesOutOfRange,
+    /// The seconds component is not in the range [0, 60).
+    SecondsOutOfRange,
+    /// The hemisphere indicator is not one of the characters expected for the
+    /// coordinate.
+    InvalidHemisphere(char),
+    /// The value is outside the valid range for the coordinate.
+    OutOfRange,
+}
+
+impl Display for ParseAngleError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match *self {
+            ParseAngleError::Empty => write!(f, "cannot parse angle from empty string"),
+            ParseAngleError::InvalidNumber => write!(f, "invalid number in angle"),
+            ParseAngleError::MinutesOutOfRange => write!(f, "minutes must be less than 60"),
+            ParseAngleError::SecondsOutOfRange => write!(f, "seconds must be less than 60"),
+            ParseAngleError::InvalidHemisphere(c) => write!(f, "invalid hemisphere `{}`", c),
+            ParseAngleError::OutOfRange => write!(f, "angle out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAngleError {}
+
+/// The symbols accepted after each sexagesimal component, in order.
+const UNITS: [&[char]; 2] = [&['°'], &['\'', '′']];
+
+/// The symbols accepted after the degrees, minutes and seconds of
+/// [`Angle::from_dms_str`].
+const DMS_UNITS: [&[char]; 3] = [&['°', 'd'], &['\'', '′', 'm'], &['"', '″', 's']];
+
+/// Parse an angle from a string.
+///
+/// The following forms are accepted, with an optional leading sign:
+///
+/// * decimal radians with a `rad` suffix, as written by `Display`: `1.5rad`,
+/// * decimal degrees, with or without a degree sign: `45.5°`, `45.5`,
+/// * degrees and decimal minutes: `49° 30.254'`, `49°30.254′`.
+///
+/// Coordinates may carry a hemisphere letter (`N`, `E`, `S` or `W`) as a
+/// prefix or suffix instead of a sign, so `S 33° 47.934'` is a negative
+/// angle. The sign applies to the whole quantity: `-0° 30'` is -0.5°.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let alpha: Angle = "49° 30.254' N".parse().unwrap();
+/// assert!((alpha.in_degrees() - 49.504233).abs() < 1.0e-6);
+///
+/// let beta: Angle = "-1.5rad".parse().unwrap();
+/// assert_eq!(beta, Radians(-1.5));
+/// ```
+impl FromStr for Angle<f64> {
+    type Err = ParseAngleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseAngleError::Empty);
+        }
+
+        let (s, hemisphere) = strip_hemisphere(s);
+        let (s, sign) = match s.chars().next() {
+            Some('-') => (s[1..].trim_start(), Some(-1.0)),
+            Some('+') => (s[1..].trim_start(), Some(1.0)),
+            _ => (s, None),
+        };
+        let sign = match (hemisphere, sign) {
+            (Some(_), Some(_)) => return Err(ParseAngleError::InvalidNumber),
+            (Some(sign), None) | (None, Some(sign)) => sign,
+            (None, None) => 1.0,
+        };
+
+        if let Some(radians) = s.strip_suffix("rad") {
+            return parse_number(radians.trim_end()).map(|v| Radians(sign * v));
+        }
+
+        parse_sexagesimal(s, &UNITS).map(|v| Degrees(sign * v))
+    }
+}
+
+impl Angle<f64> {
+    /// Parse an angle in degrees, minutes and seconds, in any of the forms
+    ///
+    /// * with symbols: `48°51′29.6″`, or `48°51'29.6"` with ASCII quotes,
+    /// * with letters: `48d51m29.6s`,
+    /// * separated by colons: `48:51:29.6`,
+    ///
+    /// with an optional leading sign, which applies to the whole quantity:
+    /// `-0°30'` is -0.5°. The seconds, and the minutes, may be left out, and
+    /// whitespace between the components is ignored. A last component
+    /// without a symbol is taken to be the next unit.
+    ///
+    /// Returns `ParseAngleError::MinutesOutOfRange` or
+    /// `ParseAngleError::SecondsOutOfRange` if a component is not in the range
+    /// [0, 60), and `ParseAngleError::InvalidNumber` if the string is not in
+    /// one of these forms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Angle::from_dms_str("48°51'29.6\"").unwrap();
+    /// assert!((alpha.in_degrees() - 48.858222).abs() < 1.0e-6);
+    /// assert_eq!(Angle::from_dms_str("48d51m29.6s"), Ok(alpha));
+    /// assert_eq!(Angle::from_dms_str("48:51:29.6"), Ok(alpha));
+    ///
+    /// assert_eq!(Angle::from_dms_str("-0:30"), Ok(Degrees(-0.5)));
+    /// ```
+    pub fn from_dms_str(s: &str) -> Result<Angle<f64>, ParseAngleError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseAngleError::Empty);
+        }
+
+        let (s, sign) = match s.chars().next() {
+            Some('-') => (s[1..].trim_start(), -1.0),
+            Some('+') => (s[1..].trim_start(), 1.0),
+            _ => (s, 1.0),
+        };
+
+        let value = if s.contains(':') {
+            let mut value = 0.0;
+            for (unit, field) in s.split(':').enumerate() {
+                if unit >= DMS_UNITS.len() {
+                    return Err(ParseAngleError::InvalidNumber);
+                }
+                value += sexagesimal_component(parse_number(field.trim())?, unit)?;
+            }
+            value
+        } else {
+            parse_sexagesimal(s, &DMS_UNITS)?
+        };
+
+        Ok(Degrees(sign * value))
+    }
+}
+
+/// Parse unsigned sexagesimal components, each followed by one of the
+/// symbols of its unit in `units`, into a value in the first unit.
+fn parse_sexagesimal(s: &str, units: &[&[char]]) -> Result<f64, ParseAngleError> {
+    let mut value = 0.0;
+    let mut next_unit = 0;
+    let mut rest = s;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number = parse_number(&rest[..end])?;
+        rest = rest[end..].trim_start();
+
+        // A component without a symbol is taken to be the next unit.
+        let symbol = rest.chars().next().and_then(|c| {
+            units
+                .iter()
+                .position(|symbols| symbols.contains(&c))
+                .map(|unit| (unit, c.len_utf8()))
+        });
+        let unit = match symbol {
+            Some((unit, len)) => {
+                rest = rest[len..].trim_start();
+                unit
+            }
+            None => next_unit,
+        };
+
+        if unit < next_unit || unit >= units.len() {
+            return Err(ParseAngleError::InvalidNumber);
+        }
+        value += sexagesimal_component(number, unit)?;
+        next_unit = unit + 1;
+    }
+    Ok(value)
+}
+
+/// Check the range of a component of `unit`, 0 for degrees, 1 for minutes and
+/// 2 for seconds, and return its value in degrees.
+fn sexagesimal_component(number: f64, unit: usize) -> Result<f64, ParseAngleError> {
+    match unit {
+        1 if number >= 60.0 => Err(ParseAngleError::MinutesOutOfRange),
+        2 if number >= 60.0 => Err(ParseAngleError::SecondsOutOfRange),
+        _ => Ok(number / [1.0, 60.0, 3600.0][unit]),
+    }
+}
+
+/// Strip a hemisphere letter from either end of the string, returning the sign
+/// it implies.
+fn strip_hemisphere(s: &str) -> (&str, Option<f64>) {
+    let sign = |c| match c {
+        'N' | 'E' => Some(1.0),
+        'S' | 'W' => Some(-1.0),
+        _ => None,
+    };
+
+    if let Some(c) = s.chars().next_back() {
+        if let Some(sign) = sign(c) {
+            return (s[..s.len() - c.len_utf8()].trim_end(), Some(sign));
+        }
+    }
+    if let Some(c) = s.chars().next() {
+        if let Some(sign) = sign(c) {
+            return (s[c.len_utf8()..].trim_start(), Some(sign));
+        }
+    }
+    (s, None)
+}
+
+/// Parse an unsigned decimal number.
+fn parse_number(s: &str) -> Result<f64, ParseAngleError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return Err(ParseAngleError::InvalidNumber);
+    }
+    s.parse().map_err(|_| ParseAngleError::InvalidNumber)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_angle() {
+        assert_eq!("45".parse(), Ok(Degrees(45.0)));
+        assert_eq!("45.5°".parse(), Ok(Degrees(45.5)));
+        assert_eq!(" +45.5° ".parse(), Ok(Degrees(45.5)));
+        assert_eq!("-1.5rad".parse(), Ok(Radians(-1.5)));
+        assert_eq!("0.25 rad".parse(), Ok(Radians(0.25)));
+        assert_eq!("-0° 30'".parse(), Ok(Degrees(-0.5)));
+        assert_eq!("12 30".parse(), Ok(Degrees(12.5)));
+        assert_eq!("W 12° 30'".parse(), Ok(Degrees(-12.5)));
+        assert_eq!("12° 30' E".parse(), Ok(Degrees(12.5)));
+
+        for &angle in &[Degrees(12.25), Degrees(-0.5), Radians(3.5), Radians(-0.125)] {
+            assert_eq!(angle.to_string().parse(), Ok(angle));
+        }
+    }
+
+    #[test]
+    fn test_parse_dms() {
+        let cases = [
+            "12°30'36\"",
+            "12° 30′ 36″",
+            " +12°30'36 ",
+            "12d30m36s",
+            "12d 30m 36",
+            "12:30:36",
+            "12 : 30 : 36.0",
+        ];
+        for &s in &cases {
+            assert_eq!(Angle::from_dms_str(s), Ok(Degrees(12.51)), "{}", s);
+        }
+
+        // The sign applies to the whole quantity.
+        for &s in &["-12°30'36\"", "-12d30m36s", "-12:30:36", "- 12:30:36"] {
+            assert_eq!(Angle::from_dms_str(s), Ok(Degrees(-12.51)), "{}", s);
+        }
+        for &s in &["-0°30'", "-0d30m", "-0:30", "-0 30"] {
+            assert_eq!(Angle::from_dms_str(s), Ok(Degrees(-0.5)), "{}", s);
+        }
+        assert_eq!(Angle::from_dms_str("-0:0:36"), Ok(Degrees(-0.01)));
+
+        // Missing seconds, and minutes.
+        assert_eq!(Angle::from_dms_str("10°15'"), Ok(Degrees(10.25)));
+        assert_eq!(Angle::from_dms_str("10d15m"), Ok(Degrees(10.25)));
+        assert_eq!(Angle::from_dms_str("10:15"), Ok(Degrees(10.25)));
+        assert_eq!(Angle::from_dms_str("10° 15"), Ok(Degrees(10.25)));
+        assert_eq!(Angle::from_dms_str("10°"), Ok(Degrees(10.0)));
+        assert_eq!(Angle::from_dms_str("10.5"), Ok(Degrees(10.5)));
+        assert_eq!(Angle::from_dms_str("10° 36\""), Ok(Degrees(10.01)));
+    }
+
+    #[test]
+    fn test_parse_dms_errors() {
+        use ParseAngleError::*;
+
+        assert_eq!(Angle::from_dms_str(" "), Err(Empty));
+        assert_eq!(Angle::from_dms_str("10°60'"), Err(MinutesOutOfRange));
+        assert_eq!(Angle::from_dms_str("10:60"), Err(MinutesOutOfRange));
+        assert_eq!(Angle::from_dms_str("10d0m60s"), Err(SecondsOutOfRange));
+        assert_eq!(Angle::from_dms_str("10:0:60"), Err(SecondsOutOfRange));
+        assert_eq!(Angle::from_dms_str("10:0:0:0"), Err(InvalidNumber));
+        assert_eq!(Angle::from_dms_str("10::30"), Err(InvalidNumber));
+        assert_eq!(Angle::from_dms_str("10:-30"), Err(InvalidNumber));
+        assert_eq!(Angle::from_dms_str("30' 10°"), Err(InvalidNumber));
+        assert_eq!(Angle::from_dms_str("10° 20' 30\" 40"), Err(InvalidNumber));
+        assert_eq!(Angle::from_dms_str("--10°"), Err(InvalidNumber));
+        assert_eq!(Angle::from_dms_str("10°30'N"), Err(InvalidNumber));
+        assert_eq!(Angle::from_dms_str("1.5rad"), Err(InvalidNumber));
+    }
+
+    #[test]
+    fn test_parse_angle_errors() {
+        use ParseAngleError::*;
+
+        assert_eq!("".parse::<Angle>(), Err(Empty));
+        assert_eq!("   ".parse::<Angle>(), Err(Empty));
+        assert_eq!("abc".parse::<Angle>(), Err(InvalidNumber));
+        assert_eq!("--45".parse::<Angle>(), Err(InvalidNumber));
+        assert_eq!("-45° N".parse::<Angle>(), Err(InvalidNumber));
+        assert_eq!("45° 1.2.3'".parse::<Angle>(), Err(InvalidNumber));
+        assert_eq!("30' 45°".parse::<Angle>(), Err(InvalidNumber));
+        assert_eq!("45° 60'".parse::<Angle>(), Err(MinutesOutOfRange));
+        assert_eq!("45° 30' 10".parse::<Angle>(), Err(InvalidNumber));
+        assert_eq!("rad".parse::<Angle>(), Err(InvalidNumber));
+    }
+}
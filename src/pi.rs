@@ -0,0 +1,185 @@
+//! Display of angles as fractions of π.
+
+use core::f64::consts::PI;
+use core::fmt::{Display, Error, Formatter};
+use num_traits::cast::{cast, NumCast};
+
+use crate::{units::round, Angle, Radians};
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Format the angle in radians as a simple multiple of π, such as `π/2`,
+    /// `-3π/4` or `2π`, if it is one within a tolerance of 10⁻⁹ rad and with
+    /// a denominator of at most 24, and as a number of radians otherwise.
+    /// The smallest such denominator is used, so the fraction is always
+    /// reduced, and zero is `0`.
+    ///
+    /// [`PiFraction::tolerance`](struct.PiFraction.html#method.tolerance) and
+    /// [`PiFraction::max_denominator`](struct.PiFraction.html#method.max_denominator)
+    /// change the limits; the tolerance may need widening for angles
+    /// computed in `f32`. The formatter flags apply to the numeric form only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::<f64>::quarter().display_pi().to_string(), "π/2");
+    /// assert_eq!(Degrees(-135.0).display_pi().to_string(), "-3π/4");
+    /// assert_eq!(Radians(1.0).display_pi().to_string(), "1rad");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64`.
+    pub fn display_pi(self) -> PiFraction {
+        PiFraction {
+            radians: self.map(|v| cast::<T, f64>(v).unwrap()).in_radians(),
+            tolerance: 1.0e-9,
+            max_denominator: 24,
+        }
+    }
+}
+
+/// Helper struct for formatting an angle as a fraction of π.
+///
+/// This `struct` is created by the [`display_pi`] method on [`Angle`]. See its
+/// documentation for more.
+///
+/// [`display_pi`]: enum.Angle.html#method.display_pi
+/// [`Angle`]: enum.Angle.html
+#[derive(Copy, Clone, Debug)]
+pub struct PiFraction {
+    radians: f64,
+    tolerance: f64,
+    max_denominator: u32,
+}
+
+impl PiFraction {
+    /// Set the largest difference in radians between the angle and a
+    /// multiple of π for it to be written as one.
+    #[inline]
+    pub fn tolerance(self, tolerance: f64) -> PiFraction {
+        PiFraction { tolerance, ..self }
+    }
+
+    /// Set the largest denominator of the fractions of π that are tried,
+    /// where 1 only allows whole multiples.
+    #[inline]
+    pub fn max_denominator(self, max_denominator: u32) -> PiFraction {
+        PiFraction {
+            max_denominator,
+            ..self
+        }
+    }
+
+    /// Find the fraction of π with the smallest denominator within the
+    /// tolerance of the angle.
+    fn fraction(&self) -> Option<(i64, u32)> {
+        let turns = self.radians / PI;
+        (1..=self.max_denominator).find_map(|d| {
+            let n = round(turns * d as f64);
+            let error = (self.radians - n as f64 * PI / d as f64).abs();
+            if error <= self.tolerance {
+                Some((n, d))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl Display for PiFraction {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        match self.fraction() {
+            Some((0, _)) => write!(f, "0"),
+            Some((n, d)) => {
+                match n {
+                    1 => write!(f, "π")?,
+                    -1 => write!(f, "-π")?,
+                    _ => write!(f, "{}π", n)?,
+                }
+                if d > 1 {
+                    write!(f, "/{}", d)?;
+                }
+                Ok(())
+            }
+            None => Radians(self.radians).fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_6, PI};
+
+    use crate::*;
+
+    #[test]
+    fn test_display_pi_constants() {
+        assert_eq!(Angle::<f64>::eighth().display_pi().to_string(), "π/4");
+        assert_eq!(Angle::<f64>::quarter().display_pi().to_string(), "π/2");
+        assert_eq!(Angle::<f64>::half().display_pi().to_string(), "π");
+        assert_eq!(Angle::<f64>::full().display_pi().to_string(), "2π");
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(Angle::<i32>::full().display_pi().to_string(), "2π");
+        assert_eq!(Radians(0.0).display_pi().to_string(), "0");
+        assert_eq!(Radians(-0.0).display_pi().to_string(), "0");
+        assert_eq!(Radians(FRAC_PI_6).display_pi().to_string(), "π/6");
+        assert_eq!(Radians(2.0 * FRAC_PI_3).display_pi().to_string(), "2π/3");
+        assert_eq!(Radians(3.0 * PI / 4.0).display_pi().to_string(), "3π/4");
+        assert_eq!(Radians(5.0 * PI / 24.0).display_pi().to_string(), "5π/24");
+        assert_eq!(Radians(7.0 * PI).display_pi().to_string(), "7π");
+        assert_eq!(Degrees(7.5).display_pi().to_string(), "π/24");
+        assert_eq!(Degrees(330.0).display_pi().to_string(), "11π/6");
+    }
+
+    #[test]
+    fn test_display_pi_negative() {
+        assert_eq!(Radians(-PI).display_pi().to_string(), "-π");
+        assert_eq!(Radians(-FRAC_PI_2).display_pi().to_string(), "-π/2");
+        assert_eq!(Degrees(-240.0).display_pi().to_string(), "-4π/3");
+        assert_eq!(Degrees(-720.0).display_pi().to_string(), "-4π");
+        assert_eq!((-Angle::<f64>::quarter()).display_pi().to_string(), "-π/2");
+    }
+
+    #[test]
+    fn test_display_pi_near_misses() {
+        assert_eq!(Radians(1.0).display_pi().to_string(), "1rad");
+        assert_eq!(
+            Radians(FRAC_PI_2 + 1.0e-6).display_pi().to_string(),
+            (FRAC_PI_2 + 1.0e-6).to_string() + "rad"
+        );
+        assert_eq!(
+            format!("{:.3}", Radians(PI - 1.0e-8).display_pi()),
+            "3.142rad"
+        );
+        // A denominator beyond the limit.
+        assert_eq!(
+            format!("{:.4}", Radians(PI / 25.0).display_pi()),
+            "0.1257rad"
+        );
+        // f32 values need a wider tolerance.
+        let alpha = Radians(core::f32::consts::FRAC_PI_4);
+        assert_eq!(format!("{:.4}", alpha.display_pi()), "0.7854rad");
+        assert_eq!(alpha.display_pi().tolerance(1.0e-6).to_string(), "π/4");
+    }
+
+    #[test]
+    fn test_display_pi_limits() {
+        let alpha = Radians(PI / 25.0).display_pi();
+        assert_eq!(alpha.max_denominator(25).to_string(), "π/25");
+        assert_eq!(alpha.max_denominator(100).to_string(), "π/25");
+
+        let alpha = Radians(FRAC_PI_2).display_pi().max_denominator(1);
+        assert_eq!(format!("{:.2}", alpha), "1.57rad");
+        let alpha = Radians(3.0 * PI).display_pi().max_denominator(1);
+        assert_eq!(alpha.to_string(), "3π");
+
+        // A wide tolerance snaps to the simplest nearby fraction.
+        let alpha = Radians(FRAC_PI_2 + 0.01).display_pi();
+        assert_eq!(alpha.tolerance(0.02).to_string(), "π/2");
+        assert_eq!(
+            alpha.tolerance(0.001).max_denominator(1000).to_string(),
+            "72π/143"
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! Quantization of angles into fixed-width integer codes.
+
+use core::f64::consts::PI;
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, Radians};
+
+/// The range of angles a quantizer decodes to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QuantRange {
+    /// Codes are unsigned and decode into the range of [0, 2π) rad.
+    Unsigned,
+    /// Codes are two's complement and decode into the range of [-π, π) rad.
+    Signed,
+}
+
+/// Quantizes angles into codes of a fixed number of bits, spreading the
+/// codes evenly over a full turn.
+///
+/// Both ranges share the same codes for the same angles, since a signed
+/// code is the two's complement of the unsigned one. An angle of π rad is
+/// encoded as the most negative code in the signed range and decodes to
+/// -π rad, so no angle is covered twice.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let q = AngleQuantizer::new(8, QuantRange::Signed).unwrap();
+/// assert_eq!(q.quantize(Degrees(-90.0f64)), 0xc0);
+///
+/// let alpha: Angle = q.dequantize(0xc0);
+/// assert!((alpha.in_degrees() + 90.0).abs() < 1.0e-10);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AngleQuantizer {
+    bits: u8,
+    range: QuantRange,
+}
+
+impl AngleQuantizer {
+    /// Create a quantizer producing codes of `bits` bits.
+    ///
+    /// Returns `None` if `bits` is not in the range of [1, 32].
+    #[inline]
+    pub fn new(bits: u8, range: QuantRange) -> Option<AngleQuantizer> {
+        if (1..=32).contains(&bits) {
+            Some(AngleQuantizer { bits, range })
+        } else {
+            None
+        }
+    }
+
+    /// Yield the width of the codes in bits.
+    #[inline]
+    pub fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    /// Yield the range the codes decode into.
+    #[inline]
+    pub fn range(&self) -> QuantRange {
+        self.range
+    }
+
+    /// Encode the angle as the code of the nearest quantization step.
+    ///
+    /// Angles outside the range wrap around, and non-finite angles are
+    /// encoded as zero.
+    pub fn quantize<T: Float>(&self, angle: Angle<T>) -> u32 {
+        let turns = cast::<T, f64>(angle.in_radians()).unwrap() / (2.0 * PI);
+        let turns = turns - turns.floor();
+        let code = (turns * self.codes() as f64).round();
+        if code.is_finite() {
+            (code as u64 & (self.codes() - 1)) as u32
+        } else {
+            0
+        }
+    }
+
+    /// Decode the angle of a code. Bits above the width of the codes are
+    /// ignored.
+    pub fn dequantize<T: Float>(&self, code: u32) -> Angle<T> {
+        let code = u64::from(code) & (self.codes() - 1);
+        let steps = match self.range {
+            QuantRange::Signed if code >= self.codes() / 2 => code as i64 - self.codes() as i64,
+            _ => code as i64,
+        };
+        Radians(cast(steps as f64 * self.step_in_radians()).unwrap())
+    }
+
+    /// Yield the angle between two adjacent codes.
+    #[inline]
+    pub fn step<T: Float>(&self) -> Angle<T> {
+        Radians(cast(self.step_in_radians()).unwrap())
+    }
+
+    /// Yield the largest distance between an angle and the decoded angle of
+    /// its code, which is half a step.
+    #[inline]
+    pub fn max_error<T: Float>(&self) -> Angle<T> {
+        Radians(cast(self.step_in_radians() / 2.0).unwrap())
+    }
+
+    #[inline]
+    fn codes(&self) -> u64 {
+        1 << self.bits
+    }
+
+    #[inline]
+    fn step_in_radians(&self) -> f64 {
+        2.0 * PI / self.codes() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use quickcheck::quickcheck;
+
+    use crate::*;
+
+    #[test]
+    fn test_quantizer_bits() {
+        assert!(AngleQuantizer::new(0, QuantRange::Unsigned).is_none());
+        assert!(AngleQuantizer::new(33, QuantRange::Signed).is_none());
+        assert_eq!(
+            AngleQuantizer::new(1, QuantRange::Unsigned).unwrap().bits(),
+            1
+        );
+        assert_eq!(
+            AngleQuantizer::new(32, QuantRange::Signed).unwrap().bits(),
+            32
+        );
+    }
+
+    #[test]
+    fn test_quantizer_exhaustive_round_trip() {
+        for &bits in &[8, 12] {
+            for &range in &[QuantRange::Unsigned, QuantRange::Signed] {
+                let q = AngleQuantizer::new(bits, range).unwrap();
+                let bound = q.max_error::<f64>().in_radians() * (1.0 - 1.0e-9);
+                for code in 0..1u32 << bits {
+                    let center = q.dequantize::<f64>(code).in_radians();
+                    assert_eq!(q.quantize(Radians(center)), code);
+                    assert_eq!(q.quantize(Radians(center - bound)), code);
+                    assert_eq!(q.quantize(Radians(center + bound)), code);
+                    assert_eq!(q.quantize(Radians(center + 2.0 * PI)), code);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_quantizer_error_bound() {
+        fn prop(angle: Angle, bits: u8) -> bool {
+            let q = AngleQuantizer::new(bits % 32 + 1, QuantRange::Signed).unwrap();
+            let back: Angle = q.dequantize(q.quantize(angle));
+            let error = back.min_dist(angle).in_radians();
+            error <= q.max_error::<f64>().in_radians() + 1.0e-9 * angle.in_radians().abs().max(1.0)
+        }
+        quickcheck(prop as fn(Angle, u8) -> bool);
+    }
+
+    #[test]
+    fn test_quantizer_signed_range() {
+        let q = AngleQuantizer::new(8, QuantRange::Signed).unwrap();
+        assert_eq!(q.quantize(Radians(-PI)), 0x80);
+        assert_eq!(q.quantize(Radians(PI)), 0x80);
+        assert_eq!(q.quantize(Radians(0.0)), 0);
+        assert_that!(
+            q.dequantize::<f64>(0x80).in_radians(),
+            close_to(-PI, 1.0e-12)
+        );
+        assert_that!(
+            q.dequantize::<f64>(0x7f).in_radians(),
+            close_to(PI - q.step::<f64>().in_radians(), 1.0e-12)
+        );
+        assert_that!(
+            q.dequantize::<f64>(0xff).in_degrees(),
+            close_to(-1.40625, 1.0e-12)
+        );
+
+        let u = AngleQuantizer::new(8, QuantRange::Unsigned).unwrap();
+        assert_that!(
+            u.dequantize::<f64>(0x80).in_radians(),
+            close_to(PI, 1.0e-12)
+        );
+        assert_that!(
+            u.dequantize::<f64>(0xff).in_degrees(),
+            close_to(358.59375, 1.0e-12)
+        );
+        for code in 0..=255 {
+            assert_eq!(u.quantize(q.dequantize::<f64>(code)), code);
+        }
+    }
+
+    #[test]
+    fn test_quantizer_step() {
+        for bits in 1..=32 {
+            let q = AngleQuantizer::new(bits, QuantRange::Unsigned).unwrap();
+            let step = q.step::<f64>().in_radians();
+            assert_that!(step * (1u64 << bits) as f64, close_to(2.0 * PI, 1.0e-12));
+            assert_eq!(q.max_error::<f64>().in_radians(), step / 2.0);
+            assert_that!(q.dequantize::<f64>(1).in_radians(), close_to(step, 1.0e-15));
+        }
+        let q = AngleQuantizer::new(12, QuantRange::Unsigned).unwrap();
+        assert_that!(q.step::<f32>().in_degrees(), close_to(0.087890625, 1.0e-6));
+        assert_eq!(q.quantize(Radians(f64::NAN)), 0);
+        assert_eq!(q.dequantize::<f64>(0x1000), Radians(0.0));
+    }
+}
@@ -0,0 +1,150 @@
+//! Interoperability with the angle quantities of the `uom` crate.
+
+use core::convert::TryFrom;
+use core::fmt::{Display, Error, Formatter};
+use uom::si::angle::radian;
+
+use crate::{Angle, Radians};
+
+/// The error returned when an angle does not fit into the storage type it is
+/// converted to.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use core::convert::TryFrom;
+/// use uom::si::angle::radian;
+/// use uom::si::f64::{Angle as Quantity, Length};
+/// use uom::si::length::meter;
+///
+/// assert_eq!(Angle::<u8>::try_from(Quantity::new::<radian>(-1.0)), Err(AngleOutOfRange));
+///
+/// fn arc_length(radius: Length, angle: Quantity) -> Length {
+///     radius * angle.get::<radian>()
+/// }
+///
+/// let arc = arc_length(Length::new::<meter>(2.0), Degrees(90.0).into());
+/// assert!((arc.get::<meter>() - core::f64::consts::PI).abs() < 1.0e-12);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AngleOutOfRange;
+
+impl Display for AngleOutOfRange {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "angle out of range of the target type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AngleOutOfRange {}
+
+macro_rules! uom_from {
+    ($($storage:ident),*) => {$(
+        impl From<uom::si::$storage::Angle> for Angle<$storage> {
+            #[inline]
+            fn from(quantity: uom::si::$storage::Angle) -> Self {
+                Radians(quantity.get::<radian>())
+            }
+        }
+
+        impl From<Angle<$storage>> for uom::si::$storage::Angle {
+            #[inline]
+            fn from(angle: Angle<$storage>) -> Self {
+                uom::si::$storage::Angle::new::<radian>(angle.in_radians())
+            }
+        }
+    )*};
+}
+
+uom_from!(f32, f64);
+
+macro_rules! uom_try_from {
+    ($storage:ident => $($t:ty),*) => {$(
+        impl TryFrom<uom::si::$storage::Angle> for Angle<$t> {
+            type Error = AngleOutOfRange;
+
+            #[inline]
+            fn try_from(quantity: uom::si::$storage::Angle) -> Result<Self, Self::Error> {
+                Radians(quantity.get::<radian>())
+                    .cast_into()
+                    .ok_or(AngleOutOfRange)
+            }
+        }
+
+        impl TryFrom<Angle<$t>> for uom::si::$storage::Angle {
+            type Error = AngleOutOfRange;
+
+            #[inline]
+            fn try_from(angle: Angle<$t>) -> Result<Self, Self::Error> {
+                let radians = angle
+                    .cast_into::<$storage>()
+                    .and_then(Angle::checked_in_radians)
+                    .ok_or(AngleOutOfRange)?;
+                Ok(uom::si::$storage::Angle::new::<radian>(radians))
+            }
+        }
+    )*};
+}
+
+uom_try_from!(f32 => f64, i8, i16, i32, i64, u8, u16, u32, u64);
+uom_try_from!(f64 => f32, i8, i16, i32, i64, u8, u16, u32, u64);
+
+#[cfg(test)]
+mod tests {
+    use core::convert::TryFrom;
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use uom::si::angle::{degree, radian};
+    use uom::si::f64::{Angle as Quantity, Length};
+    use uom::si::length::meter;
+
+    use crate::*;
+
+    #[test]
+    fn test_uom_round_trip() {
+        for &v in &[0.0, 0.1, -2.5, PI, 1.0e300] {
+            let quantity = Quantity::new::<radian>(v);
+            assert_eq!(Angle::from(quantity), Radians(v));
+            assert_eq!(Quantity::from(Radians(v)), quantity);
+        }
+        let quantity = uom::si::f32::Angle::new::<radian>(0.1);
+        assert_eq!(Angle::from(quantity), Radians(0.1f32));
+        assert_eq!(uom::si::f32::Angle::from(Radians(0.1f32)), quantity);
+    }
+
+    #[test]
+    fn test_uom_degrees() {
+        for &v in &[0.0, 30.0, -90.0, 725.0] {
+            let quantity = Quantity::from(Degrees(v));
+            assert_that!(quantity.get::<degree>(), close_to(v, 1.0e-10));
+            assert_that!(Angle::from(quantity).in_degrees(), close_to(v, 1.0e-10));
+        }
+    }
+
+    #[test]
+    fn test_uom_try_from() {
+        let quantity = Quantity::new::<radian>(3.0);
+        assert_eq!(Angle::<i16>::try_from(quantity), Ok(Radians(3)));
+        assert_eq!(Angle::<f32>::try_from(quantity), Ok(Radians(3.0f32)));
+        let quantity = Quantity::new::<radian>(-1.0);
+        assert_eq!(Angle::<u8>::try_from(quantity), Err(AngleOutOfRange));
+
+        let quantity = Quantity::try_from(Degrees(180i16)).unwrap();
+        assert_that!(quantity.get::<radian>(), close_to(PI, 1.0e-12));
+        let quantity = uom::si::f32::Angle::try_from(Radians(2.5f64)).unwrap();
+        assert_eq!(quantity.get::<radian>(), 2.5f32);
+    }
+
+    fn arc_length(radius: Length, angle: Quantity) -> Length {
+        radius * angle.get::<radian>()
+    }
+
+    #[test]
+    fn test_uom_formula() {
+        let arc = arc_length(Length::new::<meter>(2.0), Degrees(90.0).into());
+        assert_that!(arc.get::<meter>(), close_to(PI, 1.0e-12));
+        let arc = arc_length(Length::new::<meter>(1.0), Radians(0.5).into());
+        assert_that!(arc.get::<meter>(), close_to(0.5, 1.0e-15));
+    }
+}
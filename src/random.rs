@@ -0,0 +1,468 @@
+//! Random sampling of angles.
+
+use core::f64::consts::PI;
+use num_traits::{cast::cast, Float};
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::{Rng, SeedableRng};
+
+use crate::{Angle, AngleDelta, AngleUnit, ArcRange, Radians};
+
+/// Draw a direction uniformly from the circle, as an angle in the range of
+/// [0, 2π) rad.
+impl<T: Float + SampleUniform> Distribution<Angle<T>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle<T> {
+        Radians(rng.gen_range(T::zero()..cast(2.0 * PI).unwrap()))
+    }
+}
+
+/// The sampler behind `rng.gen_range(low..high)` for angles.
+///
+/// Values are drawn uniformly between the bounds, in the unit of `low`; a
+/// bound in the other unit is converted first. Ranges are linear in the
+/// value and do not wrap around the circle, so an empty or inverted range
+/// like `Degrees(350.0)..Degrees(10.0)` panics just as it does for floats.
+/// Use an [`ArcRange`](struct.ArcRange.html) to sample across the 0°/360°
+/// seam.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use rand::Rng;
+///
+/// let mut rng = rand::thread_rng();
+/// let alpha = rng.gen_range(Degrees(0.0)..Degrees(90.0));
+/// assert!(alpha >= Degrees(0.0) && alpha < Degrees(90.0));
+///
+/// let beta = rng.gen_range(Degrees(0.0)..=Radians(1.0));
+/// assert!(matches!(beta, Degrees(_)) && beta <= Radians(1.0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UniformAngle<T> {
+    inner: UniformFloat<T>,
+    unit: AngleUnit,
+}
+
+impl<T: Float + SampleUniform> UniformSampler for UniformAngle<T>
+where
+    UniformFloat<T>: UniformSampler<X = T>,
+{
+    type X = Angle<T>;
+
+    #[inline]
+    fn new<B1, B2>(low: B1, high: B2) -> UniformAngle<T>
+    where
+        B1: SampleBorrow<Angle<T>> + Sized,
+        B2: SampleBorrow<Angle<T>> + Sized,
+    {
+        let unit = low.borrow().unit();
+        UniformAngle {
+            inner: UniformFloat::new(low.borrow().value(), high.borrow().value_in(unit)),
+            unit,
+        }
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> UniformAngle<T>
+    where
+        B1: SampleBorrow<Angle<T>> + Sized,
+        B2: SampleBorrow<Angle<T>> + Sized,
+    {
+        let unit = low.borrow().unit();
+        UniformAngle {
+            inner: UniformFloat::new_inclusive(low.borrow().value(), high.borrow().value_in(unit)),
+            unit,
+        }
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle<T> {
+        Angle::from_parts(self.inner.sample(rng), self.unit)
+    }
+}
+
+impl<T: Float + SampleUniform> SampleUniform for Angle<T>
+where
+    UniformFloat<T>: UniformSampler<X = T>,
+{
+    type Sampler = UniformAngle<T>;
+}
+
+/// Draw angles uniformly along the arc, normalized and in the unit of the
+/// arc. An arc of zero length always yields its start.
+impl<T: Float + SampleUniform> Distribution<Angle<T>> for ArcRange<T> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle<T> {
+        let (length, unit) = self.length().into_parts();
+        if length > T::zero() {
+            let offset = rng.gen_range(T::zero()..length);
+            (self.start() + AngleDelta::new(Angle::from_parts(offset, unit))).normalized()
+        } else {
+            self.start()
+        }
+    }
+}
+
+/// Draw an angle uniformly from the cone extending `half_width` to either
+/// side of `center`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let mut rng = rand::thread_rng();
+/// let alpha = sample_cone(Degrees(90.0f64), Degrees(15.0), &mut rng);
+///
+/// assert!(alpha.min_dist(Degrees(90.0)).in_degrees() <= 15.0 + 1.0e-10);
+/// ```
+#[inline]
+pub fn sample_cone<T, R>(center: Angle<T>, half_width: Angle<T>, rng: &mut R) -> Angle<T>
+where
+    T: Float + SampleUniform,
+    R: Rng + ?Sized,
+{
+    ArcRange::centered(center, half_width).sample(rng)
+}
+
+/// How an [`AngleRandomWalk`](struct.AngleRandomWalk.html) keeps to an arc.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WalkBoundary<T = f64> {
+    /// Steps past either end of the arc bounce back off it.
+    Reflecting(ArcRange<T>),
+    /// Steps past either end of the arc continue from its other end.
+    Wrapping(ArcRange<T>),
+}
+
+/// An iterator over a random walk around the circle, for simulating noisy
+/// headings.
+///
+/// Each step is drawn from a normal distribution with the given standard
+/// deviation around a constant drift, and yields the new angle, normalized
+/// and in the unit of the initial angle. The walk never ends. A walk starting
+/// outside of its boundary arc is folded onto the arc by the first step.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use rand::rngs::StdRng;
+///
+/// let boundary = WalkBoundary::Reflecting(ArcRange::new(Degrees(-30.0), Degrees(30.0)));
+/// let walk = AngleRandomWalk::<f64, StdRng>::seeded(Degrees(0.0), Degrees(5.0), 42)
+///     .with_boundary(boundary);
+///
+/// for alpha in walk.take(1000) {
+///     assert!(alpha.min_dist(Degrees(0.0)).in_degrees() <= 30.0 + 1.0e-9);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AngleRandomWalk<T, R> {
+    angle: Angle<T>,
+    sigma: T,
+    drift: T,
+    boundary: Option<WalkBoundary<T>>,
+    rng: R,
+}
+
+impl<T: Float, R: Rng> AngleRandomWalk<T, R> {
+    /// Create a walk from `initial` with steps of the standard deviation
+    /// `sigma`, drawing from `rng`, without drift or a boundary.
+    #[inline]
+    pub fn new(initial: Angle<T>, sigma: Angle<T>, rng: R) -> AngleRandomWalk<T, R> {
+        AngleRandomWalk {
+            angle: initial.normalized(),
+            sigma: sigma.value_in(initial.unit()).abs(),
+            drift: T::zero(),
+            boundary: None,
+            rng,
+        }
+    }
+
+    /// Add a constant `drift` to every step.
+    #[inline]
+    pub fn with_drift(mut self, drift: Angle<T>) -> AngleRandomWalk<T, R> {
+        self.drift = drift.value_in(self.angle.unit());
+        self
+    }
+
+    /// Keep the walk to an arc.
+    #[inline]
+    pub fn with_boundary(mut self, boundary: WalkBoundary<T>) -> AngleRandomWalk<T, R> {
+        self.boundary = Some(boundary);
+        self
+    }
+
+    /// Yield the current angle of the walk.
+    #[inline]
+    pub fn angle(&self) -> Angle<T> {
+        self.angle
+    }
+}
+
+impl<T: Float, R: Rng + SeedableRng> AngleRandomWalk<T, R> {
+    /// Create a walk as [`new`](#method.new) does, drawing from a generator
+    /// seeded with `seed`, so that the same seed always gives the same walk.
+    #[inline]
+    pub fn seeded(initial: Angle<T>, sigma: Angle<T>, seed: u64) -> AngleRandomWalk<T, R> {
+        AngleRandomWalk::new(initial, sigma, R::seed_from_u64(seed))
+    }
+}
+
+impl<T: Float, R: Rng> Iterator for AngleRandomWalk<T, R> {
+    type Item = Angle<T>;
+
+    fn next(&mut self) -> Option<Angle<T>> {
+        let step = self.drift + self.sigma * standard_normal(&mut self.rng);
+        let unit = self.angle.unit();
+        let value = self.angle.value() + step;
+
+        self.angle = match self.boundary {
+            None => Angle::from_parts(value, unit).normalized(),
+            Some(WalkBoundary::Wrapping(arc)) | Some(WalkBoundary::Reflecting(arc)) => {
+                let start = arc.start().value_in(unit);
+                let length = arc.length().value_in(unit);
+                let offset = Angle::from_parts(self.angle.value() - start, unit)
+                    .normalized()
+                    .value()
+                    + step;
+
+                let offset = if length <= T::zero() {
+                    T::zero()
+                } else if let Some(WalkBoundary::Wrapping(_)) = self.boundary {
+                    rem_euclid(offset, length)
+                } else {
+                    let folded = rem_euclid(offset, length + length);
+                    if folded > length {
+                        length + length - folded
+                    } else {
+                        folded
+                    }
+                };
+                Angle::from_parts(start + offset, unit).normalized()
+            }
+        };
+        Some(self.angle)
+    }
+}
+
+#[inline]
+fn rem_euclid<T: Float>(v: T, m: T) -> T {
+    let r = v % m;
+    if r < T::zero() {
+        r + m
+    } else {
+        r
+    }
+}
+
+/// Draw from the standard normal distribution with the Box–Muller transform.
+pub(crate) fn standard_normal<T: Float, R: Rng + ?Sized>(rng: &mut R) -> T {
+    // The first uniform is in (0, 1] to keep clear of the logarithm of zero.
+    let u: f64 = 1.0 - rng.gen::<f64>();
+    let v: f64 = rng.gen();
+    cast((-2.0 * Float::ln(u)).sqrt() * Float::cos(2.0 * PI * v)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use rand::distributions::Standard;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::*;
+
+    #[test]
+    fn test_sample_standard() {
+        let mut rng = StdRng::seed_from_u64(0x616e67);
+        let samples: Vec<Angle> = (&mut rng).sample_iter(Standard).take(100_000).collect();
+        assert!(samples.iter().all(|&alpha| matches!(alpha, Radians(_))));
+        assert!(samples
+            .iter()
+            .all(|&alpha| alpha >= Radians(0.0) && alpha < Radians(2.0 * PI)));
+
+        // The moments of the uniform distribution on [0, 2π) and of the
+        // directions it gives.
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(|a| a.in_radians()).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|a| (a.in_radians() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        assert_that!(mean, close_to(PI, 0.02));
+        assert_that!(variance, close_to(PI * PI / 3.0, 0.05));
+        let x = samples.iter().map(|a| a.cos()).sum::<f64>() / n;
+        let y = samples.iter().map(|a| a.sin()).sum::<f64>() / n;
+        assert_that!(x.hypot(y), close_to(0.0, 0.01));
+
+        let alpha: Angle<f32> = rng.gen();
+        assert!(alpha >= Radians(0.0) && alpha < Radians(2.0 * core::f32::consts::PI));
+    }
+
+    #[test]
+    fn test_gen_range() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let samples: Vec<Angle> = (0..100_000)
+            .map(|_| rng.gen_range(Degrees(10.0)..Degrees(70.0)))
+            .collect();
+        assert!(samples.iter().all(|&alpha| matches!(alpha, Degrees(_))));
+        assert!(samples
+            .iter()
+            .all(|&alpha| alpha >= Degrees(10.0) && alpha < Degrees(70.0)));
+        let n = samples.len() as f64;
+        let mean = samples.iter().map(|a| a.in_degrees()).sum::<f64>() / n;
+        let variance = samples
+            .iter()
+            .map(|a| (a.in_degrees() - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        assert_that!(mean, close_to(40.0, 0.2));
+        assert_that!(variance, close_to(300.0, 3.0));
+
+        for _ in 0..1000 {
+            let alpha = rng.gen_range(Radians(0.5)..=Degrees(45.0));
+            assert!(matches!(alpha, Radians(_)));
+            assert!(alpha >= Radians(0.5) && alpha <= Degrees(45.0));
+            let beta = rng.gen_range(Degrees(-90.0f32)..Radians(0.0));
+            assert!(matches!(beta, Degrees(_)));
+            assert!(beta >= Degrees(-90.0) && beta < Degrees(0.0));
+        }
+        assert_eq!(rng.gen_range(Degrees(5.0)..=Degrees(5.0)), Degrees(5.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_range_empty() {
+        let mut rng = StdRng::seed_from_u64(0);
+        rng.gen_range(Degrees(10.0)..Degrees(10.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gen_range_inverted() {
+        let mut rng = StdRng::seed_from_u64(0);
+        rng.gen_range(Degrees(350.0)..Degrees(10.0));
+    }
+
+    #[test]
+    fn test_sample_arc() {
+        let mut rng = StdRng::seed_from_u64(0x616e67);
+        let arc = ArcRange::new(Degrees(340.0), Degrees(30.0));
+
+        let samples: Vec<Angle> = (&mut rng).sample_iter(arc).take(10_000).collect();
+        assert!(samples.iter().all(|&alpha| arc.contains(alpha)));
+        assert!(samples.iter().all(|&alpha| matches!(alpha, Degrees(_))));
+        assert!(samples
+            .iter()
+            .any(|&alpha| alpha.min_dist(arc.start()).in_degrees() < 0.5));
+        assert!(samples
+            .iter()
+            .any(|&alpha| alpha.min_dist(arc.end()).in_degrees() < 0.5));
+
+        let mu = mean_angle(&samples).unwrap();
+        assert_that!(mu.min_dist(arc.midpoint()).in_degrees(), close_to(0.0, 0.5));
+    }
+
+    #[test]
+    fn test_sample_degenerate_arcs() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let arc = ArcRange::new(Radians(1.0), Radians(1.0));
+        for _ in 0..100 {
+            assert_eq!(rng.sample(arc), Radians(1.0));
+        }
+
+        let arc = ArcRange::full(Degrees(0.0f32));
+        let samples: Vec<_> = (&mut rng).sample_iter(arc).take(1000).collect();
+        assert!(samples
+            .iter()
+            .all(|&a| a >= Degrees(0.0) && a < Degrees(360.0)));
+        assert!(samples.iter().any(|&a| a < Degrees(10.0)));
+        assert!(samples.iter().any(|&a| a > Degrees(350.0)));
+    }
+
+    #[test]
+    fn test_sample_cone() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let alpha = sample_cone(Degrees(0.0), Degrees(15.0), &mut rng);
+            assert!(alpha.min_dist(Degrees(0.0)).in_degrees() <= 15.0 + 1.0e-10);
+        }
+    }
+
+    #[test]
+    fn test_random_walk_without_noise() {
+        let walk = AngleRandomWalk::<f64, StdRng>::seeded(Degrees(350.0), Degrees(0.0), 1);
+        assert!(walk.take(10).all(|alpha| alpha == Degrees(350.0)));
+
+        let walk = AngleRandomWalk::<f64, StdRng>::seeded(Degrees(350.0), Degrees(0.0), 1)
+            .with_drift(Degrees(5.0));
+        let angles: Vec<_> = walk.take(4).collect();
+        assert_eq!(
+            angles,
+            [Degrees(355.0), Degrees(0.0), Degrees(5.0), Degrees(10.0)]
+        );
+
+        let walk = AngleRandomWalk::<f64, StdRng>::seeded(Degrees(0.0), Degrees(0.0), 1)
+            .with_drift(Degrees(-25.0))
+            .with_boundary(WalkBoundary::Wrapping(ArcRange::new(
+                Degrees(-30.0),
+                Degrees(30.0),
+            )));
+        let angles: Vec<_> = walk.take(3).map(|alpha| alpha.in_degrees()).collect();
+        assert_eq!(angles, [335.0, 10.0, 345.0]);
+    }
+
+    #[test]
+    fn test_random_walk_sigma() {
+        let sigma = 3.0;
+        let mut walk = AngleRandomWalk::<f64, StdRng>::seeded(Radians(0.0), Degrees(sigma), 7);
+        assert!(matches!(walk.angle(), Radians(_)));
+
+        let mut previous = walk.angle();
+        let mut steps = Vec::new();
+        for alpha in walk.by_ref().take(20_000) {
+            steps.push(previous.shortest_delta_to(alpha).angle().in_degrees());
+            previous = alpha;
+        }
+        let n = steps.len() as f64;
+        let mean = steps.iter().sum::<f64>() / n;
+        let std_dev = (steps.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n).sqrt();
+        assert_that!(mean, close_to(0.0, 0.1));
+        assert_that!(std_dev, close_to(sigma, 0.05));
+
+        let a: Vec<_> = AngleRandomWalk::<f64, StdRng>::seeded(Degrees(0.0), Degrees(1.0), 3)
+            .take(10)
+            .collect();
+        let b: Vec<_> = AngleRandomWalk::<f64, StdRng>::seeded(Degrees(0.0), Degrees(1.0), 3)
+            .take(10)
+            .collect();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_random_walk_reflecting() {
+        let arc = ArcRange::new(Degrees(340.0), Degrees(20.0));
+        let walk = AngleRandomWalk::<f64, StdRng>::seeded(Degrees(0.0), Degrees(15.0), 11)
+            .with_drift(Degrees(2.0))
+            .with_boundary(WalkBoundary::Reflecting(arc));
+        let angles: Vec<_> = walk.take(10_000).collect();
+        assert!(angles.iter().all(|&alpha| arc.contains(alpha)));
+        assert!(angles
+            .iter()
+            .any(|&alpha| alpha.min_dist(arc.start()).in_degrees() < 1.0));
+        assert!(angles
+            .iter()
+            .any(|&alpha| alpha.min_dist(arc.end()).in_degrees() < 1.0));
+
+        let arc = ArcRange::new(Radians(1.0f32), Radians(1.0));
+        let mut walk =
+            AngleRandomWalk::new(Radians(1.0f32), Radians(0.5), StdRng::seed_from_u64(0))
+                .with_boundary(WalkBoundary::Reflecting(arc));
+        assert_eq!(walk.next(), Some(Radians(1.0)));
+    }
+}
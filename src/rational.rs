@@ -0,0 +1,225 @@
+//! Support for angles of `num_rational::Ratio`.
+
+use core::cmp::Ordering;
+use core::ops::{Div, Mul};
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
+
+use crate::{Angle, Degrees, Radians};
+
+/// Exact methods on angles of rational numbers, such as `Ratio<i64>` and
+/// `BigRational`.
+///
+/// `Ratio` does not implement `NumCast`, which the generic methods and
+/// operators of [`Angle`](enum.Angle.html) rely on, so this trait provides
+/// the equivalents for rational angles. Since π is irrational, an angle in
+/// radians can never be converted exactly into degrees or the other way
+/// around. These methods therefore return `None` when given angles in
+/// different units, and [`to_f64`](#tymethod.to_f64) is the explicit, lossy,
+/// way to combine them.
+///
+/// Multiplying and dividing by a rational uses the usual operators for
+/// `Ratio<i64>`, and the assigning operators for `BigRational`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// use num_rational::Ratio;
+///
+/// let third = Degrees(Ratio::new(1i64, 3));
+/// let alpha = third * Ratio::from(3);
+/// assert_eq!(alpha.into_parts().0, Ratio::from(1));
+///
+/// let beta = Degrees(Ratio::from(360)).checked_add(&third).unwrap();
+/// let gamma = beta.normalized().unwrap();
+/// assert_eq!(gamma.to_string(), "1/3°");
+///
+/// assert!(third.checked_add(&Radians(Ratio::from(1))).is_none());
+/// ```
+pub trait RationalAngle: Sized {
+    /// Add two angles in the same unit, or return `None` if the units differ.
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    /// Subtract two angles in the same unit, or return `None` if the units
+    /// differ.
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+
+    /// Compare two angles in the same unit, or return `None` if the units
+    /// differ.
+    fn checked_cmp(&self, other: &Self) -> Option<Ordering>;
+
+    /// Create a new angle by normalizing the value into the range of
+    /// [0, 360)°, or return `None` if the angle is in radians, where a full
+    /// turn is irrational.
+    fn normalized(&self) -> Option<Self>;
+
+    /// Convert the angle to the nearest angle of `f64`, keeping the unit it
+    /// is stored in. This loses the exactness of the rational value.
+    ///
+    /// Returns `None` if the value cannot be represented in an `f64`.
+    fn to_f64(&self) -> Option<Angle<f64>>;
+}
+
+impl<T> RationalAngle for Angle<Ratio<T>>
+where
+    T: Clone + Integer + FromPrimitive,
+    Ratio<T>: ToPrimitive,
+{
+    #[inline]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        match (self, rhs) {
+            (Radians(a), Radians(b)) => Some(Radians(a + b)),
+            (Degrees(a), Degrees(b)) => Some(Degrees(a + b)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        match (self, rhs) {
+            (Radians(a), Radians(b)) => Some(Radians(a - b)),
+            (Degrees(a), Degrees(b)) => Some(Degrees(a - b)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn checked_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Radians(a), Radians(b)) | (Degrees(a), Degrees(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+
+    fn normalized(&self) -> Option<Self> {
+        match self {
+            Radians(_) => None,
+            Degrees(v) => {
+                let full = Ratio::from_integer(T::from_u16(360)?);
+                let v = v % &full;
+                Some(Degrees(if v < Ratio::zero() { v + full } else { v }))
+            }
+        }
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<Angle<f64>> {
+        match self {
+            Radians(v) => v.to_f64().map(Radians),
+            Degrees(v) => v.to_f64().map(Degrees),
+        }
+    }
+}
+
+impl<T: Clone + Integer> Mul<Angle<Ratio<T>>> for Ratio<T> {
+    type Output = Angle<Ratio<T>>;
+    #[inline]
+    fn mul(self, rhs: Angle<Ratio<T>>) -> Self::Output {
+        match rhs {
+            Radians(v) => Radians(self * v),
+            Degrees(v) => Degrees(self * v),
+        }
+    }
+}
+
+impl<T: Clone + Integer> Div<Angle<Ratio<T>>> for Ratio<T> {
+    type Output = Angle<Ratio<T>>;
+    #[inline]
+    fn div(self, rhs: Angle<Ratio<T>>) -> Self::Output {
+        match rhs {
+            Radians(v) => Radians(self / v),
+            Degrees(v) => Degrees(self / v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use num_rational::{BigRational, Ratio};
+
+    use crate::*;
+
+    fn r(numer: i64, denom: i64) -> Ratio<i64> {
+        Ratio::new(numer, denom)
+    }
+
+    fn big(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(numer.into(), denom.into())
+    }
+
+    #[test]
+    fn test_rational_arithmetic() {
+        let third = Degrees(r(1, 3));
+        assert_eq!(
+            (third * r(3, 1)).into_parts(),
+            (r(1, 1), AngleUnit::Degrees)
+        );
+        assert_eq!(
+            (r(3, 1) * third).into_parts(),
+            (r(1, 1), AngleUnit::Degrees)
+        );
+        assert_eq!((third / r(2, 1)).into_parts().0, r(1, 6));
+        assert_eq!((-third).into_parts().0, r(-1, 3));
+
+        let sum = third
+            .checked_add(&third)
+            .unwrap()
+            .checked_add(&third)
+            .unwrap();
+        assert_eq!(sum.into_parts().0, r(1, 1));
+        let difference = sum.checked_sub(&Degrees(r(1, 6))).unwrap();
+        assert_eq!(difference.into_parts().0, r(5, 6));
+        let radians = Radians(r(1, 2)).checked_add(&Radians(r(1, 4))).unwrap();
+        assert_eq!(radians.into_parts(), (r(3, 4), AngleUnit::Radians));
+
+        let mut alpha = Degrees(big(1, 3));
+        alpha *= big(3, 1);
+        assert_eq!(alpha.into_parts().0, big(1, 1));
+        let beta = big(1, 2) * Degrees(big(2, 3));
+        assert_eq!(beta.into_parts().0, big(1, 3));
+    }
+
+    #[test]
+    fn test_rational_normalized() {
+        let alpha = Degrees(r(361, 1)).normalized().unwrap();
+        assert_eq!(alpha.into_parts().0, r(1, 1));
+        let beta = Degrees(r(-1, 3)).normalized().unwrap();
+        assert_eq!(beta.into_parts().0, r(1079, 3));
+        let gamma = Degrees(big(7201, 20)).normalized().unwrap();
+        assert_eq!(gamma.into_parts().0, big(1, 20));
+        assert!(Radians(r(7, 1)).normalized().is_none());
+    }
+
+    #[test]
+    fn test_rational_ordering() {
+        assert_eq!(
+            Degrees(r(359, 2)).checked_cmp(&Degrees(r(179, 1))),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Degrees(big(358, 2)).checked_cmp(&Degrees(big(179, 1))),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(Degrees(r(1, 1)).checked_cmp(&Radians(r(1, 1))), None);
+        assert!(Degrees(r(1, 1)).checked_sub(&Radians(r(1, 1))).is_none());
+    }
+
+    #[test]
+    fn test_rational_display() {
+        assert_eq!(Degrees(r(1, 3)).to_string(), "1/3°");
+        assert_eq!(Degrees(r(-4, 2)).to_string(), "-2°");
+        assert_eq!(Radians(big(3, 4)).to_string(), "3/4rad");
+    }
+
+    #[test]
+    fn test_rational_to_f64() {
+        let alpha = Degrees(r(1, 3)).to_f64().unwrap();
+        assert_eq!(alpha, Degrees(1.0 / 3.0));
+        let beta = Radians(big(1, 4)).to_f64().unwrap();
+        assert_eq!(beta, Radians(0.25));
+        assert!((alpha + AngleDelta::new(beta)).in_degrees() > 14.6);
+    }
+}
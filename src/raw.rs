@@ -0,0 +1,362 @@
+//! Raw forms of angles with a stable memory layout.
+
+use num_traits::cast::NumCast;
+
+use crate::{Angle, Degrees, Radians};
+
+/// The unit byte of a [`RawAngle`] in radians.
+pub const RAW_RADIANS: u8 = 0;
+
+/// The unit byte of a [`RawAngle`] in degrees.
+pub const RAW_DEGREES: u8 = 1;
+
+/// An angle with a stable memory layout, for buffers shared with a GPU, a
+/// DMA controller or another language.
+///
+/// The value comes first, followed by the unit as a byte, [`RAW_RADIANS`] or
+/// [`RAW_DEGREES`], and padding up to the alignment of `T`. The layout of
+/// [`Angle`] itself is not guaranteed. Because of the padding, this is not
+/// `bytemuck::Pod`; see [`PackedRadians`] for that.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let raw = Degrees(45.0f32).to_raw();
+/// assert_eq!((raw.value, raw.unit), (45.0, RAW_DEGREES));
+/// assert_eq!(Angle::from_raw(raw), Some(Degrees(45.0)));
+/// assert_eq!(core::mem::size_of::<RawAngle<f32>>(), 8);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct RawAngle<T> {
+    /// The value of the angle.
+    pub value: T,
+    /// The unit of the angle, [`RAW_RADIANS`] or [`RAW_DEGREES`].
+    pub unit: u8,
+}
+
+impl<T> Angle<T> {
+    /// Convert the angle to its raw form.
+    #[inline]
+    pub fn to_raw(self) -> RawAngle<T> {
+        match self {
+            Radians(value) => RawAngle {
+                value,
+                unit: RAW_RADIANS,
+            },
+            Degrees(value) => RawAngle {
+                value,
+                unit: RAW_DEGREES,
+            },
+        }
+    }
+
+    /// Convert an angle from its raw form, or return `None` if its unit byte
+    /// is neither [`RAW_RADIANS`] nor [`RAW_DEGREES`].
+    #[inline]
+    pub fn from_raw(raw: RawAngle<T>) -> Option<Angle<T>> {
+        match raw.unit {
+            RAW_RADIANS => Some(Radians(raw.value)),
+            RAW_DEGREES => Some(Degrees(raw.value)),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Copy> Angle<T> {
+    /// Convert `angles` to their raw form in `raw`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths. See
+    /// [`checked_to_raw_slice`](#method.checked_to_raw_slice). The method is
+    /// not available with the `no-panic` feature.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn to_raw_slice(angles: &[Angle<T>], raw: &mut [RawAngle<T>]) {
+        Angle::checked_to_raw_slice(angles, raw).expect("slices of different lengths")
+    }
+
+    /// Convert `angles` to their raw form in `raw`, or return `None` without
+    /// converting any if the slices have different lengths.
+    pub fn checked_to_raw_slice(angles: &[Angle<T>], raw: &mut [RawAngle<T>]) -> Option<()> {
+        if angles.len() != raw.len() {
+            return None;
+        }
+        for (raw, angle) in raw.iter_mut().zip(angles) {
+            *raw = angle.to_raw();
+        }
+        Some(())
+    }
+
+    /// Convert `raw` angles into `angles`, or return the index of the first
+    /// one with an invalid unit byte, leaving the angles from there on
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let raw = [Radians(1.0).to_raw(), RawAngle { value: 2.0, unit: 7 }];
+    /// let mut angles = [Radians(0.0); 2];
+    /// assert_eq!(Angle::from_raw_slice(&raw, &mut angles), Err(1));
+    /// assert_eq!(angles[0], Radians(1.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths. See
+    /// [`checked_from_raw_slice`](#method.checked_from_raw_slice). The method
+    /// is not available with the `no-panic` feature.
+    #[cfg(not(feature = "no-panic"))]
+    pub fn from_raw_slice(raw: &[RawAngle<T>], angles: &mut [Angle<T>]) -> Result<(), usize> {
+        Angle::checked_from_raw_slice(raw, angles).expect("slices of different lengths")
+    }
+
+    /// Convert `raw` angles into `angles` as
+    /// [`from_raw_slice`](#method.from_raw_slice) does, or return `None`
+    /// without converting any if the slices have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let raw = [Radians(1.0).to_raw(), Degrees(2.0).to_raw()];
+    /// let mut angles = [Radians(0.0); 2];
+    /// assert_eq!(Angle::checked_from_raw_slice(&raw, &mut angles), Some(Ok(())));
+    /// assert_eq!(Angle::checked_from_raw_slice(&raw, &mut angles[..1]), None);
+    /// ```
+    pub fn checked_from_raw_slice(
+        raw: &[RawAngle<T>],
+        angles: &mut [Angle<T>],
+    ) -> Option<Result<(), usize>> {
+        if angles.len() != raw.len() {
+            return None;
+        }
+        for (i, (angle, &raw)) in angles.iter_mut().zip(raw).enumerate() {
+            if let Some(converted) = Angle::from_raw(raw) {
+                *angle = converted;
+            } else {
+                return Some(Err(i));
+            }
+        }
+        Some(Ok(()))
+    }
+}
+
+/// An angle in radians with the layout of `T`, for buffers which hold plain
+/// numbers, such as GPU uniforms.
+///
+/// With the `bytemuck` feature, this is `bytemuck::Pod` and
+/// `bytemuck::Zeroable` whenever `T` is, so that slices of it can be cast to
+/// and from bytes or slices of `T` without copying.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let packed = Degrees(180.0f32).to_packed_radians();
+/// assert_eq!(packed.0, core::f32::consts::PI);
+/// assert_eq!(Angle::from(packed), Radians(core::f32::consts::PI));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(transparent)]
+pub struct PackedRadians<T>(pub T);
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Convert the angle to radians packed as a plain `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn to_packed_radians(self) -> PackedRadians<T> {
+        PackedRadians(self.in_radians())
+    }
+}
+
+impl<T> From<PackedRadians<T>> for Angle<T> {
+    #[inline]
+    fn from(packed: PackedRadians<T>) -> Self {
+        Radians(packed.0)
+    }
+}
+
+// SAFETY: `PackedRadians` is a transparent wrapper around `T`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for PackedRadians<T> {}
+
+// SAFETY: `PackedRadians` is a transparent wrapper around `T`.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for PackedRadians<T> {}
+
+/// Convert `angles` to radians packed as plain numbers in `packed`, ready to
+/// be cast with `bytemuck::cast_slice`.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths, or as
+/// [`Angle::to_packed_radians`] does. See [`checked_pack_radians`]. The
+/// function is not available with the `no-panic` feature.
+#[cfg(not(feature = "no-panic"))]
+pub fn pack_radians<T: Copy + NumCast>(angles: &[Angle<T>], packed: &mut [PackedRadians<T>]) {
+    checked_pack_radians(angles, packed).expect("slices of different lengths")
+}
+
+/// Convert `angles` to radians packed as plain numbers in `packed` as
+/// [`pack_radians`] does, or return `None` without converting any if the
+/// slices have different lengths.
+///
+/// # Panics
+///
+/// Panics as [`Angle::to_packed_radians`] does.
+pub fn checked_pack_radians<T: Copy + NumCast>(
+    angles: &[Angle<T>],
+    packed: &mut [PackedRadians<T>],
+) -> Option<()> {
+    if angles.len() != packed.len() {
+        return None;
+    }
+    for (packed, angle) in packed.iter_mut().zip(angles) {
+        *packed = angle.to_packed_radians();
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{align_of, size_of};
+
+    use crate::*;
+
+    #[test]
+    fn test_raw_layout() {
+        assert_eq!(size_of::<RawAngle<f32>>(), 8);
+        assert_eq!(align_of::<RawAngle<f32>>(), 4);
+        assert_eq!(size_of::<RawAngle<f64>>(), 16);
+        assert_eq!(align_of::<RawAngle<f64>>(), 8);
+        assert_eq!(size_of::<RawAngle<i16>>(), 4);
+        assert_eq!(size_of::<RawAngle<u8>>(), 2);
+
+        let raw = Degrees(-1.5f32).to_raw();
+        let base = &raw as *const RawAngle<f32> as usize;
+        assert_eq!(&raw.value as *const f32 as usize, base);
+        assert_eq!(&raw.unit as *const u8 as usize, base + 4);
+    }
+
+    #[test]
+    fn test_raw_round_trip() {
+        for &angle in &[Radians(1.5f32), Degrees(-30.0), Radians(f32::NAN)] {
+            let raw = angle.to_raw();
+            let back = Angle::from_raw(raw).unwrap();
+            assert_eq!(back.unit(), angle.unit());
+            assert_eq!(back.value().to_bits(), angle.value().to_bits());
+        }
+        assert_eq!(Radians(3i32).to_raw(), RawAngle { value: 3, unit: 0 });
+        assert_eq!(Degrees(3i32).to_raw(), RawAngle { value: 3, unit: 1 });
+        assert_eq!(RawAngle::<f64>::default().unit, RAW_RADIANS);
+
+        for unit in 2..=u8::MAX {
+            assert_eq!(Angle::from_raw(RawAngle { value: 1.0, unit }), None);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    fn test_raw_slices() {
+        let angles = [Radians(0.25f32), Degrees(90.0), Degrees(-0.0), Radians(7.0)];
+        let mut raw = [RawAngle::default(); 4];
+        Angle::to_raw_slice(&angles, &mut raw);
+        assert_eq!(
+            raw[1],
+            RawAngle {
+                value: 90.0,
+                unit: RAW_DEGREES
+            }
+        );
+
+        let mut back = [Radians(0.0); 4];
+        assert_eq!(Angle::from_raw_slice(&raw, &mut back), Ok(()));
+        assert_eq!(back, angles);
+
+        raw[2].unit = 2;
+        let mut back = [Radians(9.0); 4];
+        assert_eq!(Angle::from_raw_slice(&raw, &mut back), Err(2));
+        assert_eq!(back[..2], angles[..2]);
+        assert_eq!(back[2..], [Radians(9.0); 2]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    fn test_packed_radians() {
+        assert_eq!(size_of::<PackedRadians<f32>>(), 4);
+        assert_eq!(align_of::<PackedRadians<f64>>(), align_of::<f64>());
+
+        let angles = [Radians(0.5f32), Degrees(90.0), Degrees(-180.0)];
+        let mut packed = [PackedRadians::default(); 3];
+        pack_radians(&angles, &mut packed);
+        assert_eq!(
+            packed,
+            [
+                PackedRadians(0.5),
+                PackedRadians(core::f32::consts::FRAC_PI_2),
+                PackedRadians(-core::f32::consts::PI)
+            ]
+        );
+        assert_eq!(Angle::from(PackedRadians(2i32)), Radians(2));
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_packed_radians_bytemuck() {
+        let angles = [Radians(0.5f32), Degrees(90.0), Radians(-1.0)];
+        let mut packed = [PackedRadians::default(); 3];
+        assert_eq!(checked_pack_radians(&angles, &mut packed), Some(()));
+
+        let values: &[f32] = bytemuck::cast_slice(&packed);
+        assert_eq!(values, &[0.5, core::f32::consts::FRAC_PI_2, -1.0]);
+        let bytes: &[u8] = bytemuck::cast_slice(&packed);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(&bytes[..4], &0.5f32.to_ne_bytes());
+
+        let back: &[PackedRadians<f32>] = bytemuck::cast_slice(bytes);
+        assert_eq!(back, &packed);
+        let zeroed: PackedRadians<f64> = bytemuck::Zeroable::zeroed();
+        assert_eq!(Angle::from(zeroed), Radians(0.0));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    #[should_panic(expected = "different lengths")]
+    fn test_raw_slices_lengths() {
+        let mut raw = [RawAngle::default(); 2];
+        Angle::to_raw_slice(&[Radians(1.0f32)], &mut raw);
+    }
+
+    #[test]
+    fn test_checked_raw_slices() {
+        let angles = [Radians(0.25f32), Degrees(90.0), Degrees(-180.0)];
+        let mut raw = [RawAngle::default(); 3];
+        assert_eq!(Angle::checked_to_raw_slice(&angles, &mut raw), Some(()));
+        assert_eq!(raw[2], Degrees(-180.0).to_raw());
+
+        let mut back = [Radians(0.0); 3];
+        assert_eq!(Angle::checked_from_raw_slice(&raw, &mut back), Some(Ok(())));
+        assert_eq!(back, angles);
+        raw[1].unit = 7;
+        assert_eq!(Angle::checked_from_raw_slice(&raw, &mut back), Some(Err(1)));
+
+        let mut packed = [PackedRadians::default(); 3];
+        assert_eq!(checked_pack_radians(&angles, &mut packed), Some(()));
+        assert_eq!(packed[2], PackedRadians(-core::f32::consts::PI));
+
+        let mut short = [RawAngle::default(); 2];
+        assert_eq!(Angle::checked_to_raw_slice(&angles, &mut short), None);
+        assert_eq!(short, [RawAngle::default(); 2]);
+        let mut short = [Radians(0.0f32); 2];
+        assert_eq!(Angle::checked_from_raw_slice(&raw, &mut short), None);
+        let mut short = [PackedRadians::default(); 4];
+        assert_eq!(checked_pack_radians(&angles, &mut short), None);
+    }
+}
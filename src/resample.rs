@@ -0,0 +1,176 @@
+//! Resampling of angular time series.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::Float;
+
+use crate::stats::wrap_signed;
+use crate::Angle;
+
+/// Resample a series of `(time, angle)` samples onto a uniform grid of times
+/// `t₀`, `t₀ + dt`, … up to the time of the last sample.
+///
+/// The angle at each time is interpolated along the shortest path between
+/// the samples before and after it, in the unit of the sample before it.
+/// Interpolated angles are not normalized.
+///
+/// When `max_gap` is given, samples further apart than it are not
+/// interpolated between: times within such a gap take the angle of the
+/// nearest sample instead, or of the earlier one if both are equally near.
+///
+/// Returns `None` if the sample times are not strictly increasing and
+/// finite, if `dt` is not positive and finite, or if the grid has more
+/// points than `T` can count exactly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let samples = [(0.0, Degrees(350.0f64)), (2.0, Degrees(10.0))];
+/// let resampled = resample(&samples, 0.5, None).unwrap();
+///
+/// assert_eq!(resampled.len(), 5);
+/// assert_eq!(resampled[2].0, 1.0);
+/// assert!(resampled[2].1.min_dist(Degrees(0.0)).in_degrees() < 1.0e-10);
+/// ```
+pub fn resample<T: Float>(
+    samples: &[(T, Angle<T>)],
+    dt: T,
+    max_gap: Option<T>,
+) -> Option<Vec<(T, Angle<T>)>> {
+    let increasing = |w: &[(T, Angle<T>)]| w[0].0.partial_cmp(&w[1].0) == Some(Ordering::Less);
+    if !dt.is_finite() || dt <= T::zero() || !samples.windows(2).all(increasing) {
+        return None;
+    }
+
+    let (first, last) = match (samples.first(), samples.last()) {
+        (Some(first), Some(last)) => (first.0, last.0),
+        _ => return Some(Vec::new()),
+    };
+    // The loop below counts steps in `T`, so it only ends if the count is
+    // finite and every increment changes it.
+    let steps = ((last - first) / dt).floor();
+    if !steps.is_finite() || steps + T::one() == steps {
+        return None;
+    }
+
+    let mut resampled = Vec::new();
+    let mut segment = 0;
+    let mut step = T::zero();
+    loop {
+        let t = first + step * dt;
+        if t > last {
+            break;
+        }
+
+        while segment + 2 < samples.len() && samples[segment + 1].0 <= t {
+            segment += 1;
+        }
+        let (t0, a) = samples[segment];
+        let (t1, b) = *samples.get(segment + 1).unwrap_or(&samples[segment]);
+
+        let angle = if t1 <= t0 || t <= t0 {
+            a
+        } else if max_gap.is_some_and(|gap| t1 - t0 > gap) {
+            if t1 - t < t - t0 {
+                b
+            } else {
+                a
+            }
+        } else {
+            let fraction = (t - t0) / (t1 - t0);
+            a.map_in_radians(|v| v + wrap_signed(b.in_radians() - v) * fraction)
+        };
+        resampled.push((t, angle));
+        step = step + T::one();
+    }
+
+    Some(resampled)
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_resample_uniform() {
+        let samples: Vec<_> = (0..8)
+            .map(|i| (i as f64 * 0.25, Degrees(i as f64 * 40.0)))
+            .collect();
+        let resampled = resample(&samples, 0.25, None).unwrap();
+        assert_eq!(resampled.len(), samples.len());
+        for (&(t, alpha), &(u, beta)) in samples.iter().zip(&resampled) {
+            assert_eq!(t, u);
+            assert_that!(alpha.min_dist(beta).in_degrees(), close_to(0.0, 1.0e-9));
+        }
+    }
+
+    #[test]
+    fn test_resample_seam() {
+        let samples = [
+            (0.0, Degrees(340.0)),
+            (1.0, Degrees(20.0)),
+            (1.5, Radians(1.0)),
+        ];
+        let resampled = resample(&samples, 0.25, None).unwrap();
+        assert_eq!(resampled.len(), 7);
+
+        let expected = [340.0, 350.0, 0.0, 10.0, 20.0];
+        for (&(_, alpha), &degrees) in resampled.iter().zip(&expected) {
+            assert!(matches!(alpha, Degrees(_)));
+            assert_that!(
+                alpha.min_dist(Degrees(degrees)).in_degrees(),
+                close_to(0.0, 1.0e-9)
+            );
+        }
+        let midway = (20.0 + 1.0f64.to_degrees()) / 2.0;
+        assert_that!(resampled[5].1.in_degrees(), close_to(midway, 1.0e-9));
+        assert_that!(resampled[6].1.in_radians(), close_to(1.0, 1.0e-9));
+    }
+
+    #[test]
+    fn test_resample_gaps() {
+        let samples = [
+            (0.0, Degrees(0.0)),
+            (1.0, Degrees(10.0)),
+            (5.0, Degrees(90.0)),
+        ];
+        let resampled = resample(&samples, 1.0, Some(2.0)).unwrap();
+        let degrees: Vec<_> = resampled.iter().map(|&(_, a)| a.in_degrees()).collect();
+        assert_eq!(degrees, [0.0, 10.0, 10.0, 10.0, 90.0, 90.0]);
+
+        let resampled = resample(&samples, 1.0, None).unwrap();
+        assert_that!(resampled[3].1.in_degrees(), close_to(50.0, 1.0e-9));
+    }
+
+    #[test]
+    fn test_resample_edge_cases() {
+        let samples = [(1.0, Degrees(10.0)), (2.0, Degrees(20.0))];
+        assert_eq!(
+            resample(&samples, 5.0, None).unwrap(),
+            [(1.0, Degrees(10.0))]
+        );
+        assert_eq!(resample::<f64>(&[], 1.0, None).unwrap(), []);
+        assert_eq!(
+            resample(&samples[..1], 1.0, None).unwrap(),
+            [(1.0, Degrees(10.0))]
+        );
+
+        assert!(resample(&samples, 0.0, None).is_none());
+        assert!(resample(&samples, f64::NAN, None).is_none());
+        assert!(resample(&samples, f64::INFINITY, None).is_none());
+        assert!(resample(&samples, 1.0e-300, None).is_none());
+        let unbounded = [(1.0, Degrees(10.0)), (f64::INFINITY, Degrees(20.0))];
+        assert!(resample(&unbounded, 1.0, None).is_none());
+        let unbounded = [(f64::NEG_INFINITY, Degrees(10.0)), (1.0, Degrees(20.0))];
+        assert!(resample(&unbounded, 1.0, None).is_none());
+        let coarse = [(0.0f32, Degrees(10.0f32)), (2.0e7, Degrees(20.0))];
+        assert!(resample(&coarse, 1.0, None).is_none());
+        let unordered = [(2.0, Degrees(10.0)), (1.0, Degrees(20.0))];
+        assert!(resample(&unordered, 1.0, None).is_none());
+        let repeated = [(1.0, Degrees(10.0)), (1.0, Degrees(20.0))];
+        assert!(resample(&repeated, 1.0, None).is_none());
+    }
+}
@@ -0,0 +1,356 @@
+//! Rendering of circular histograms as SVG rose diagrams.
+
+use std::fmt::Write;
+
+use num_traits::Float;
+
+use crate::CircularHistogram;
+
+/// How the radius of each wedge of a rose diagram grows with its count.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoseScale {
+    /// The radius is proportional to the count.
+    Linear,
+    /// The radius is proportional to the square root of the count, so that
+    /// the area of each wedge is proportional to the count.
+    SquareRoot,
+}
+
+/// How the angles of a rose diagram are laid out.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoseOrientation {
+    /// Zero at the top and increasing clockwise, for compass bearings.
+    Compass,
+    /// Zero to the right and increasing counter-clockwise, as for the angles
+    /// elsewhere in this crate.
+    Math,
+}
+
+/// Options for [`CircularHistogram::to_svg`](struct.CircularHistogram.html#method.to_svg).
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoseOptions {
+    /// The width and height of the diagram in pixels.
+    pub size: u32,
+    /// How the radius of each wedge grows with its count.
+    pub scale: RoseScale,
+    /// How the angles are laid out.
+    pub orientation: RoseOrientation,
+    /// The fill color of the wedges.
+    pub fill: String,
+    /// The color of the wedge outlines, the outer circle and the ticks.
+    pub stroke: String,
+    /// The color of the tick labels.
+    pub text: String,
+    /// The color of the mean direction arrow, which is only drawn if set.
+    pub mean_arrow: Option<String>,
+}
+
+impl Default for RoseOptions {
+    fn default() -> Self {
+        RoseOptions {
+            size: 200,
+            scale: RoseScale::Linear,
+            orientation: RoseOrientation::Compass,
+            fill: "steelblue".to_string(),
+            stroke: "black".to_string(),
+            text: "black".to_string(),
+            mean_arrow: None,
+        }
+    }
+}
+
+impl<T: Float> CircularHistogram<T> {
+    /// Render the histogram as a self-contained SVG rose diagram, with one
+    /// wedge per bin and ticks labelling the four cardinal directions.
+    ///
+    /// The longest wedge reaches the outer circle. The mean direction arrow
+    /// points along the circular mean of the angles added, and its length is
+    /// their resultant length relative to the outer circle. The output only
+    /// depends on the histogram and the options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let mut histogram = CircularHistogram::new(8, Degrees(-22.5f64)).unwrap();
+    /// histogram.extend(&[Degrees(0.0), Degrees(10.0), Degrees(90.0)]);
+    ///
+    /// let options = RoseOptions {
+    ///     scale: RoseScale::SquareRoot,
+    ///     mean_arrow: Some("red".to_string()),
+    ///     ..RoseOptions::default()
+    /// };
+    /// let svg = histogram.to_svg(options);
+    /// assert!(svg.starts_with("<svg"));
+    /// assert_eq!(svg.matches("<path").count(), 2);
+    /// ```
+    pub fn to_svg(&self, options: RoseOptions) -> String {
+        let size = f64::from(options.size);
+        let center = size / 2.0;
+        let radius = 0.4 * size;
+        let point = |angle: f64, r: f64| {
+            let (sin, cos) = angle.sin_cos();
+            match options.orientation {
+                RoseOrientation::Compass => (center + r * sin, center - r * cos),
+                RoseOrientation::Math => (center + r * cos, center - r * sin),
+            }
+        };
+        let sweep = match options.orientation {
+            RoseOrientation::Compass => 1,
+            RoseOrientation::Math => 0,
+        };
+        let extent = |count: u64| match options.scale {
+            RoseScale::Linear => count as f64,
+            RoseScale::SquareRoot => (count as f64).sqrt(),
+        };
+
+        let mut svg = String::new();
+        let _ = write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{0}" viewBox="0 0 {0} {0}">"#,
+            options.size
+        );
+        svg.push('\n');
+
+        let max = self.counts().iter().copied().max().unwrap_or(0);
+        let width = self.bin_width().in_radians().to_f64().unwrap();
+        for (i, &count) in self.counts().iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let r = radius * extent(count) / extent(max);
+            if self.bins() == 1 {
+                let _ = writeln!(
+                    svg,
+                    r#"<circle cx="{}" cy="{}" r="{}" fill="{}" stroke="{}"/>"#,
+                    num(center),
+                    num(center),
+                    num(r),
+                    escape(&options.fill),
+                    escape(&options.stroke)
+                );
+                continue;
+            }
+
+            let start = self.bin_start(i).unwrap().in_radians().to_f64().unwrap();
+            let (x0, y0) = point(start, r);
+            let (x1, y1) = point(start + width, r);
+            let _ = writeln!(
+                svg,
+                r#"<path d="M{} {} L{} {} A{} {} 0 0 {} {} {} Z" fill="{}" stroke="{}"/>"#,
+                num(center),
+                num(center),
+                num(x0),
+                num(y0),
+                num(r),
+                num(r),
+                sweep,
+                num(x1),
+                num(y1),
+                escape(&options.fill),
+                escape(&options.stroke)
+            );
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="{}"/>"#,
+            num(center),
+            num(center),
+            num(radius),
+            escape(&options.stroke)
+        );
+
+        let labels = match options.orientation {
+            RoseOrientation::Compass => ["N", "E", "S", "W"],
+            RoseOrientation::Math => ["0°", "90°", "180°", "270°"],
+        };
+        for (i, label) in labels.iter().enumerate() {
+            let angle = i as f64 * core::f64::consts::FRAC_PI_2;
+            let (x0, y0) = point(angle, radius);
+            let (x1, y1) = point(angle, 1.05 * radius);
+            let (x, y) = point(angle, 1.15 * radius);
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}"/>"#,
+                num(x0),
+                num(y0),
+                num(x1),
+                num(y1),
+                escape(&options.stroke)
+            );
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" fill="{}" font-size="{}" text-anchor="middle" dominant-baseline="middle">{}</text>"#,
+                num(x),
+                num(y),
+                escape(&options.text),
+                num(0.06 * size),
+                label
+            );
+        }
+
+        if let (Some(color), Some(mean)) = (&options.mean_arrow, self.stats().mean()) {
+            let length = self.stats().resultant_length().to_f64().unwrap();
+            let angle = mean.in_radians().to_f64().unwrap();
+            let (x, y) = point(angle, length * radius);
+            let (xl, yl) = point(angle - 0.15, (length * radius - 0.05 * size).max(0.0));
+            let (xr, yr) = point(angle + 0.15, (length * radius - 0.05 * size).max(0.0));
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="2"/>"#,
+                num(center),
+                num(center),
+                num(x),
+                num(y),
+                escape(color)
+            );
+            let _ = writeln!(
+                svg,
+                r#"<polygon points="{},{} {},{} {},{}" fill="{}"/>"#,
+                num(x),
+                num(y),
+                num(xl),
+                num(yl),
+                num(xr),
+                num(yr),
+                escape(color)
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Round a coordinate to two decimal places for output, turning a negative
+/// zero positive.
+fn num(v: f64) -> f64 {
+    (v * 100.0).round() / 100.0 + 0.0
+}
+
+/// Escape a string for use within an XML attribute.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// Check that the SVG is a single, well-nested `svg` element whose
+    /// attribute values are quoted and free of unescaped markup.
+    fn assert_well_formed(svg: &str) {
+        let mut stack = Vec::new();
+        let mut rest = svg.trim();
+        assert!(rest.starts_with("<svg "));
+        while let Some(start) = rest.find('<') {
+            assert!(!rest[..start].contains('>'));
+            let end = start + rest[start..].find('>').expect("unterminated tag");
+            let tag = &rest[start + 1..end];
+            assert!(!tag.contains('<'));
+            assert_eq!(
+                tag.matches('"').count() % 2,
+                0,
+                "unbalanced quotes in {}",
+                tag
+            );
+
+            if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(stack.pop(), Some(name));
+            } else {
+                let name = tag.split_whitespace().next().unwrap();
+                if !tag.ends_with('/') {
+                    stack.push(name);
+                }
+            }
+            rest = &rest[end + 1..];
+            if stack.is_empty() {
+                assert!(rest.trim().is_empty());
+            }
+        }
+        assert!(stack.is_empty());
+    }
+
+    fn histogram() -> CircularHistogram {
+        let mut histogram = CircularHistogram::new(8, Degrees(-22.5)).unwrap();
+        histogram.extend(&[
+            Degrees(0.0),
+            Degrees(5.0),
+            Degrees(350.0),
+            Degrees(15.0),
+            Degrees(40.0),
+            Degrees(50.0),
+            Degrees(90.0),
+            Degrees(200.0),
+        ]);
+        histogram
+    }
+
+    #[test]
+    fn test_rose_golden() {
+        let options = RoseOptions {
+            mean_arrow: Some("crimson".to_string()),
+            ..RoseOptions::default()
+        };
+        let svg = histogram().to_svg(options);
+        assert_well_formed(&svg);
+        assert_eq!(svg, include_str!("../testdata/rose.svg"));
+    }
+
+    #[test]
+    fn test_rose_options() {
+        let histogram = histogram();
+        let svg = histogram.to_svg(RoseOptions::default());
+        assert_well_formed(&svg);
+        assert_eq!(svg, histogram.to_svg(RoseOptions::default()));
+        assert_eq!(svg.matches("<path").count(), 4);
+        assert!(svg.contains(">N</text>") && svg.contains(">W</text>"));
+        assert!(!svg.contains("<polygon"));
+
+        let options = RoseOptions {
+            size: 300,
+            scale: RoseScale::SquareRoot,
+            orientation: RoseOrientation::Math,
+            fill: "#a&b".to_string(),
+            stroke: "\"gray\"".to_string(),
+            ..RoseOptions::default()
+        };
+        let svg = histogram.to_svg(options);
+        assert_well_formed(&svg);
+        assert!(svg.contains(r#"width="300""#));
+        assert!(svg.contains(">90°</text>"));
+        assert!(svg.contains(r##"fill="#a&amp;b""##));
+        assert!(svg.contains(r#"stroke="&quot;gray&quot;""#));
+        assert_ne!(svg, histogram.to_svg(RoseOptions::default()));
+    }
+
+    #[test]
+    fn test_rose_degenerate() {
+        let empty = CircularHistogram::<f64>::new(8, Degrees(0.0)).unwrap();
+        let options = RoseOptions {
+            mean_arrow: Some("red".to_string()),
+            ..RoseOptions::default()
+        };
+        let svg = empty.to_svg(options.clone());
+        assert_well_formed(&svg);
+        assert!(!svg.contains("<path") && !svg.contains("<polygon"));
+
+        let mut single = CircularHistogram::new(1, Degrees(0.0)).unwrap();
+        single.add(Degrees(10.0));
+        let svg = single.to_svg(options);
+        assert_well_formed(&svg);
+        assert_eq!(svg.matches("<circle").count(), 2);
+    }
+}
@@ -0,0 +1,166 @@
+//! Saturating arithmetic and conversions for integer angles.
+
+use core::f64::consts::PI;
+use num_traits::{
+    bounds::Bounded,
+    cast::{cast, NumCast},
+    ops::saturating::{SaturatingAdd, SaturatingMul, SaturatingSub},
+    Zero,
+};
+
+use crate::{Angle, AngleUnit, Degrees, Radians};
+
+impl<T: Copy + NumCast + Bounded + Zero> Angle<T> {
+    /// Yield the value encoded in radians, saturating to the minimum or
+    /// maximum value of `T` where [`in_radians`](#method.in_radians) would
+    /// panic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(30000i16).saturating_in_radians(), 523);
+    /// assert_eq!(Radians(-1.0e300f64).saturating_in_radians(), -1.0e300);
+    /// ```
+    #[inline]
+    pub fn saturating_in_radians(self) -> T {
+        self.saturating_in_unit(AngleUnit::Radians)
+    }
+
+    /// Yield the value encoded in degrees, saturating to the minimum or
+    /// maximum value of `T` where [`in_degrees`](#method.in_degrees) would
+    /// panic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Radians(1000i16).saturating_in_degrees(), i16::MAX);
+    /// assert_eq!(Radians(-1000i16).saturating_in_degrees(), i16::MIN);
+    /// ```
+    #[inline]
+    pub fn saturating_in_degrees(self) -> T {
+        self.saturating_in_unit(AngleUnit::Degrees)
+    }
+
+    /// Add `rhs`, keeping the unit of this angle and saturating at the
+    /// bounds of `T` instead of overflowing. `rhs` is converted to the unit
+    /// of this angle with saturation as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(30000i16).saturating_add(Degrees(5000)), Degrees(i16::MAX));
+    /// ```
+    #[inline]
+    pub fn saturating_add(self, rhs: Angle<T>) -> Self
+    where
+        T: SaturatingAdd,
+    {
+        let (v, unit) = self.into_parts();
+        Angle::from_parts(v.saturating_add(&rhs.saturating_in_unit(unit)), unit)
+    }
+
+    /// Subtract `rhs`, keeping the unit of this angle and saturating at the
+    /// bounds of `T` as [`saturating_add`](#method.saturating_add) does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(-30000i16).saturating_sub(Degrees(5000)), Degrees(i16::MIN));
+    /// assert_eq!(Degrees(10u16).saturating_sub(Degrees(20)), Degrees(0));
+    /// ```
+    #[inline]
+    pub fn saturating_sub(self, rhs: Angle<T>) -> Self
+    where
+        T: SaturatingSub,
+    {
+        let (v, unit) = self.into_parts();
+        Angle::from_parts(v.saturating_sub(&rhs.saturating_in_unit(unit)), unit)
+    }
+
+    /// Multiply the value of the angle by `rhs`, keeping its unit and
+    /// saturating at the bounds of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(1000i16).saturating_mul(-40), Degrees(i16::MIN));
+    /// ```
+    #[inline]
+    pub fn saturating_mul(self, rhs: T) -> Self
+    where
+        T: SaturatingMul,
+    {
+        self.map(|v| v.saturating_mul(&rhs))
+    }
+
+    /// Yield the value of the angle in `unit`, saturating at the bounds of
+    /// `T`.
+    fn saturating_in_unit(self, unit: AngleUnit) -> T {
+        match (self, unit) {
+            (Radians(v), AngleUnit::Radians) | (Degrees(v), AngleUnit::Degrees) => v,
+            _ => {
+                let v = cast::<T, f64>(self.value()).unwrap_or(0.0);
+                let converted = match unit {
+                    AngleUnit::Radians => Radians(v / 180.0 * PI),
+                    AngleUnit::Degrees => Degrees(v / PI * 180.0),
+                };
+                converted.lossy_cast_into().value()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_saturating_conversions() {
+        assert_eq!(Radians(i16::MAX).saturating_in_degrees(), i16::MAX);
+        assert_eq!(Radians(i16::MIN).saturating_in_degrees(), i16::MIN);
+        assert_eq!(Radians(500i16).saturating_in_degrees(), 28647);
+        assert_eq!(Radians(600i16).saturating_in_degrees(), i16::MAX);
+        assert_eq!(Degrees(i16::MAX).saturating_in_radians(), 571);
+        assert_eq!(Degrees(i16::MIN).saturating_in_radians(), -571);
+        assert_eq!(Radians(7i16).saturating_in_radians(), 7);
+        assert_eq!(Degrees(7i16).saturating_in_degrees(), 7);
+        assert_eq!(Radians(100u8).saturating_in_degrees(), u8::MAX);
+        assert_eq!(
+            Radians(-1.0f32).saturating_in_degrees(),
+            -1.0f32.to_degrees()
+        );
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        let max = Degrees(i16::MAX);
+        let min = Degrees(i16::MIN);
+        assert_eq!(max.saturating_add(Degrees(1)), max);
+        assert_eq!(max.saturating_add(max), max);
+        assert_eq!(min.saturating_add(min), min);
+        assert_eq!(min.saturating_sub(Degrees(1)), min);
+        assert_eq!(max.saturating_sub(min), max);
+        assert_eq!(min.saturating_sub(max), min);
+        assert_eq!(max.saturating_add(min), Degrees(-1));
+        assert_eq!(Degrees(100i16).saturating_sub(Degrees(30)), Degrees(70));
+
+        assert_eq!(max.saturating_mul(2), max);
+        assert_eq!(min.saturating_mul(2), min);
+        assert_eq!(min.saturating_mul(-1), max);
+        assert_eq!(Degrees(-3i16).saturating_mul(7), Degrees(-21));
+
+        // The other operand is converted with saturation and keeps the unit.
+        assert_eq!(Degrees(0i16).saturating_add(Radians(600)), max);
+        assert_eq!(Degrees(-1i16).saturating_sub(Radians(600)), min);
+        assert!(matches!(
+            Radians(1i16).saturating_add(Degrees(180)),
+            Radians(4)
+        ));
+        assert!(matches!(Degrees(1i16).saturating_mul(3), Degrees(3)));
+    }
+}
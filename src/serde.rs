@@ -0,0 +1,212 @@
+//! Helpers to serialize angles as plain numbers in a fixed unit, for use with
+//! `#[serde(with = "...")]`.
+//!
+//! An [`Angle`](../enum.Angle.html) is serialized with its unit by default.
+//! A field annotated with one of these modules is serialized as its value
+//! converted to the unit of the module instead, and deserialized into that
+//! variant, whichever unit the code holds it in.
+//!
+//! A glob import of this crate brings this module into scope, so that
+//! `serde::` paths next to it are ambiguous and need to be written
+//! `::serde::` to name the serde crate.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use ang::*;
+//! # use std::f64::consts::PI;
+//! #[derive(::serde::Serialize, ::serde::Deserialize)]
+//! struct Mount {
+//!     #[serde(with = "ang::serde::degrees")]
+//!     tilt: Angle,
+//!     #[serde(default, with = "ang::serde::degrees_option")]
+//!     pan: Option<Angle>,
+//! }
+//!
+//! let mount = Mount { tilt: Radians(PI), pan: None };
+//! let json = serde_json::to_string(&mount).unwrap();
+//! assert_eq!(json, r#"{"tilt":180.0,"pan":null}"#);
+//!
+//! let mount: Mount = serde_json::from_str(r#"{"tilt":90.0}"#).unwrap();
+//! assert_eq!(mount.tilt, Degrees(90.0));
+//! assert_eq!(mount.pan, None);
+//! ```
+
+macro_rules! serde_unit(
+    ($name:ident, $option:ident, $unit:literal, $variant:ident, $checked:ident) => (
+        #[doc = concat!("Serialize an angle as its value in ", $unit, ".")]
+        pub mod $name {
+            use ::serde::{ser::Error, Deserialize, Deserializer, Serialize, Serializer};
+            use num_traits::cast::NumCast;
+
+            use crate::{Angle, $variant};
+
+            #[doc = concat!(
+                "Serialize `angle` as its value in ", $unit, ", failing if it cannot be ",
+                "represented in `T`."
+            )]
+            pub fn serialize<T, S>(angle: &Angle<T>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Copy + NumCast + Serialize,
+                S: Serializer,
+            {
+                angle
+                    .$checked()
+                    .ok_or_else(|| S::Error::custom(concat!("angle out of range in ", $unit)))?
+                    .serialize(serializer)
+            }
+
+            #[doc = concat!("Deserialize a value in ", $unit, " into an angle.")]
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Angle<T>, D::Error>
+            where
+                T: Deserialize<'de>,
+                D: Deserializer<'de>,
+            {
+                T::deserialize(deserializer).map($variant)
+            }
+        }
+
+        #[doc = concat!(
+            "Serialize an optional angle as its value in ", $unit, ", or none.\n\n",
+            "Add `#[serde(default)]` to the field to also accept a missing value."
+        )]
+        pub mod $option {
+            use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+            use num_traits::cast::NumCast;
+
+            use crate::{Angle, $variant};
+
+            #[doc = concat!(
+                "Serialize `angle` as its value in ", $unit, ", or none, failing if it ",
+                "cannot be represented in `T`."
+            )]
+            pub fn serialize<T, S>(angle: &Option<Angle<T>>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Copy + NumCast + Serialize,
+                S: Serializer,
+            {
+                match angle {
+                    Some(angle) => serializer.serialize_some(&Wrapper(angle)),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            #[doc = concat!("Deserialize an optional value in ", $unit, " into an angle.")]
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Angle<T>>, D::Error>
+            where
+                T: Deserialize<'de>,
+                D: Deserializer<'de>,
+            {
+                Option::<T>::deserialize(deserializer).map(|v| v.map($variant))
+            }
+
+            struct Wrapper<'a, T>(&'a Angle<T>);
+
+            impl<T: Copy + NumCast + Serialize> Serialize for Wrapper<'_, T> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::$name::serialize(self.0, serializer)
+                }
+            }
+        }
+    )
+);
+
+serde_unit!(
+    degrees,
+    degrees_option,
+    "degrees",
+    Degrees,
+    checked_in_degrees
+);
+serde_unit!(
+    radians,
+    radians_option,
+    "radians",
+    Radians,
+    checked_in_radians
+);
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+    struct Record {
+        #[serde(with = "crate::serde::degrees")]
+        heading: Angle,
+        #[serde(with = "crate::serde::radians")]
+        roll: Angle<f32>,
+        #[serde(default, with = "crate::serde::radians_option")]
+        pitch: Option<Angle>,
+        #[serde(default, with = "crate::serde::degrees_option")]
+        yaw: Option<Angle<i16>>,
+    }
+
+    #[test]
+    fn test_serde_with_units() {
+        let record = Record {
+            heading: Radians(PI),
+            roll: Degrees(90.0),
+            pitch: Some(Degrees(-45.0)),
+            yaw: Some(Radians(1)),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"heading":180.0,"roll":1.5707964,"pitch":-0.7853981633974483,"yaw":57}"#
+        );
+
+        let back: Record = serde_json::from_str(&json).unwrap();
+        assert!(matches!(back.heading, Degrees(_)));
+        assert_that!(back.heading.in_radians(), close_to(PI, 1.0e-12));
+        assert!(matches!(back.roll, Radians(_)));
+        assert_that!(back.roll.in_degrees() as f64, close_to(90.0, 1.0e-5));
+        assert!(matches!(back.pitch, Some(Radians(v)) if v == -PI / 4.0));
+        assert!(matches!(back.yaw, Some(Degrees(57))));
+    }
+
+    #[test]
+    fn test_serde_with_units_option() {
+        let record = Record {
+            heading: Degrees(10.0),
+            roll: Radians(0.5),
+            pitch: None,
+            yaw: None,
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"heading":10.0,"roll":0.5,"pitch":null,"yaw":null}"#
+        );
+        let back: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!((back.pitch, back.yaw), (None, None));
+
+        let back: Record = serde_json::from_str(r#"{"heading":1.0,"roll":2.0}"#).unwrap();
+        assert_eq!((back.pitch, back.yaw), (None, None));
+        assert!(matches!(back.roll, Radians(v) if v == 2.0));
+    }
+
+    #[test]
+    fn test_serde_with_units_errors() {
+        let record = Record {
+            heading: Degrees(0.0),
+            roll: Radians(0.0),
+            pitch: None,
+            yaw: Some(Radians(1000)),
+        };
+        let err = serde_json::to_string(&record).unwrap_err();
+        assert!(
+            err.to_string().contains("out of range in degrees"),
+            "{}",
+            err
+        );
+
+        assert!(
+            serde_json::from_str::<Record>(r#"{"heading":{"Degrees":1.0},"roll":0.0}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Record>(r#"{"heading":1.0,"roll":0.0,"yaw":1.5}"#).is_err());
+    }
+}
@@ -0,0 +1,164 @@
+//! Conversions between angles of inclination and slope ratios.
+
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, Radians};
+
+impl<T: Float> Angle<T> {
+    /// Create an angle of inclination from a percent grade, where a grade of
+    /// `100%` rises one unit for every unit of horizontal run.
+    ///
+    /// Return value is in the range of (-π/2, π/2) rad.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let alpha = Angle::from_percent_grade(100.0f64);
+    /// assert!((alpha.in_degrees() - 45.0).abs() < 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn from_percent_grade(percent: T) -> Angle<T> {
+        let hundred: T = cast(100.0).unwrap();
+        Radians((percent / hundred).atan())
+    }
+
+    /// Create an angle of inclination from a rise over a horizontal run.
+    ///
+    /// A negative `rise` yields a negative (downhill) angle, and a negative
+    /// `run` yields an angle beyond ±π/2 rad. A zero `run` is a vertical line
+    /// of ±π/2 rad. Returns `None` if both `rise` and `run` are zero.
+    #[inline]
+    pub fn from_rise_run(rise: T, run: T) -> Option<Angle<T>> {
+        if rise.is_zero() && run.is_zero() {
+            None
+        } else {
+            Some(Radians(rise.atan2(run)))
+        }
+    }
+
+    /// Compute the percent grade of the angle of inclination.
+    ///
+    /// A vertical angle (±π/2 rad) yields an infinite grade carrying the sign
+    /// of the rise. Since the grade only describes the line the angle lies
+    /// on, angles beyond ±π/2 rad wrap around with a period of π rad: an
+    /// inclination of 135° has a grade of `-100%`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert!((Degrees(45.0f64).to_percent_grade() - 100.0).abs() < 1.0e-10);
+    /// assert_eq!(Degrees(90.0f64).to_percent_grade(), f64::INFINITY);
+    /// ```
+    #[inline]
+    pub fn to_percent_grade(self) -> T {
+        let hundred: T = cast(100.0).unwrap();
+        self.slope() * hundred
+    }
+
+    /// Compute the rise over the given horizontal `run` for this angle of
+    /// inclination.
+    ///
+    /// Follows the same conventions as [`to_percent_grade`](#method.to_percent_grade)
+    /// for vertical angles and angles beyond ±π/2 rad.
+    #[inline]
+    pub fn to_rise_per(self, run: T) -> T {
+        self.slope() * run
+    }
+
+    /// The tangent of the angle, snapped to infinity when the angle is vertical
+    /// to within rounding error.
+    #[inline]
+    fn slope(self) -> T {
+        let (sin, cos) = self.sin_cos();
+        if cos.abs() <= T::epsilon() {
+            T::infinity().copysign(sin)
+        } else {
+            sin / cos
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use quickcheck::quickcheck;
+
+    use crate::*;
+
+    #[test]
+    fn test_percent_grade() {
+        assert_that!(
+            Angle::from_percent_grade(100.0).in_degrees(),
+            close_to(45.0, 0.000001)
+        );
+        assert_that!(
+            Angle::from_percent_grade(-100.0).in_degrees(),
+            close_to(-45.0, 0.000001)
+        );
+        assert_that!(Degrees(45.0).to_percent_grade(), close_to(100.0, 0.000001));
+        assert_that!(
+            Degrees(135.0).to_percent_grade(),
+            close_to(-100.0, 0.000001)
+        );
+
+        // For small angles the grade is very nearly linear in the angle.
+        for &deg in &[0.1, 0.5, 1.0, 2.0] {
+            assert_that!(
+                Degrees(deg).to_percent_grade(),
+                close_to(deg * 1.746, deg * 0.01)
+            );
+        }
+    }
+
+    #[test]
+    fn test_vertical_grade() {
+        assert_eq!(Degrees(90.0).to_percent_grade(), f64::INFINITY);
+        assert_eq!(Degrees(-90.0).to_percent_grade(), f64::NEG_INFINITY);
+        assert_eq!(Degrees(90.0f32).to_percent_grade(), f32::INFINITY);
+        assert_eq!(Degrees(270.0).to_rise_per(10.0), f64::NEG_INFINITY);
+
+        assert_that!(
+            Angle::from_rise_run(5.0, 0.0).unwrap().in_degrees(),
+            close_to(90.0, 0.000001)
+        );
+        assert_that!(
+            Angle::from_rise_run(-5.0, 0.0).unwrap().in_degrees(),
+            close_to(-90.0, 0.000001)
+        );
+        assert!(Angle::from_rise_run(0.0, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_rise_run() {
+        assert_that!(
+            Angle::from_rise_run(-1.0, 1.0).unwrap().in_degrees(),
+            close_to(-45.0, 0.000001)
+        );
+        assert_that!(Degrees(-45.0).to_rise_per(8.0), close_to(-8.0, 0.000001));
+        assert_that!(
+            Radians(0.25).to_rise_per(2.0),
+            close_to(2.0 * 0.25.tan(), 0.000001)
+        );
+    }
+
+    #[test]
+    fn test_grade_round_trip() {
+        fn prop(percent: f64) -> bool {
+            let percent = percent % 1.0e4;
+            let back = Angle::from_percent_grade(percent).to_percent_grade();
+            (back - percent).abs() <= 1.0e-9 * percent.abs().max(1.0)
+        }
+        quickcheck(prop as fn(f64) -> bool);
+
+        fn prop_rise_run(rise: f64, run: f64) -> bool {
+            if run <= 0.0 || !(rise / run).is_finite() || (rise / run).abs() > 1.0e4 {
+                return true;
+            }
+            let alpha = Angle::from_rise_run(rise, run).unwrap();
+            (alpha.to_rise_per(run) - rise).abs() <= 1.0e-9 * rise.abs().max(run)
+        }
+        quickcheck(prop_rise_run as fn(f64, f64) -> bool);
+    }
+}
@@ -0,0 +1,202 @@
+//! Position of the Sun in the sky.
+
+use num_traits::Float;
+
+use crate::{Angle, AngleDelta, Degrees, Radians};
+
+/// The apparent position of the Sun, as seen from a point on the Earth.
+///
+/// The azimuth is measured clockwise from north and normalized into the range
+/// of [0, 360)°. The elevation is measured up from the horizon and is negative
+/// while the Sun is below it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SolarPosition {
+    /// The direction of the Sun, clockwise from north.
+    pub azimuth: Angle<f64>,
+    /// The height of the Sun above the horizon.
+    pub elevation: Angle<f64>,
+}
+
+/// Compute the position of the Sun at the given time, in seconds since the
+/// Unix epoch, as seen from the given `latitude` and `longitude`. Longitudes
+/// are positive east of Greenwich.
+///
+/// The elevation includes the standard atmospheric refraction correction,
+/// which lifts the Sun by about half a degree at the horizon; see
+/// [`geometric_solar_position`](fn.geometric_solar_position.html) for the
+/// position without it.
+///
+/// This follows the NOAA solar calculator, which is based on the low-precision
+/// formulas of Meeus' *Astronomical Algorithms*, and is accurate to within
+/// about 0.3° between 1800 and 2100.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// // Greenwich, at the 2024 March equinox.
+/// let sun = solar_position(1_710_936_000, Degrees(51.4779), Degrees(0.0));
+/// assert!((sun.azimuth.in_degrees() - 177.7).abs() < 0.3);
+/// assert!((sun.elevation.in_degrees() - 38.7).abs() < 0.3);
+/// ```
+pub fn solar_position(
+    unix_seconds: i64,
+    latitude: Angle<f64>,
+    longitude: Angle<f64>,
+) -> SolarPosition {
+    let position = geometric_solar_position(unix_seconds, latitude, longitude);
+    let elevation = position.elevation.in_degrees();
+    SolarPosition {
+        elevation: Degrees(elevation + refraction(elevation)),
+        ..position
+    }
+}
+
+/// Compute the position of the Sun as
+/// [`solar_position`](fn.solar_position.html) does, but without correcting
+/// the elevation for atmospheric refraction.
+pub fn geometric_solar_position(
+    unix_seconds: i64,
+    latitude: Angle<f64>,
+    longitude: Angle<f64>,
+) -> SolarPosition {
+    let days = unix_seconds.div_euclid(86_400);
+    let seconds = unix_seconds.rem_euclid(86_400);
+
+    // Julian centuries since J2000.0.
+    let jd = days as f64 + seconds as f64 / 86_400.0 + 2_440_587.5;
+    let t = (jd - 2_451_545.0) / 36_525.0;
+
+    let mean_longitude = (280.46646 + t * (36000.76983 + t * 0.0003032)) % 360.0;
+    let mean_anomaly = 357.52911 + t * (35999.05029 - 0.0001537 * t);
+    let eccentricity = 0.016708634 - t * (0.000042037 + 0.0000001267 * t);
+
+    let m = mean_anomaly.to_radians();
+    let center = m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m).sin() * 0.000289;
+    let node = (125.04 - 1934.136 * t).to_radians();
+    let apparent_longitude =
+        (mean_longitude + center - 0.00569 - 0.00478 * node.sin()).to_radians();
+
+    let seconds_of_arc = 21.448 - t * (46.815 + t * (0.00059 - t * 0.001813));
+    let obliquity = 23.0 + (26.0 + seconds_of_arc / 60.0) / 60.0 + 0.00256 * node.cos();
+    let obliquity = obliquity.to_radians();
+    let declination = (obliquity.sin() * apparent_longitude.sin()).asin();
+
+    // The equation of time, in minutes.
+    let l = mean_longitude.to_radians();
+    let e = eccentricity;
+    let y = (obliquity / 2.0).tan().powi(2);
+    let equation_of_time = 4.0
+        * (y * (2.0 * l).sin() - 2.0 * e * m.sin() + 4.0 * e * y * m.sin() * (2.0 * l).cos()
+            - 0.5 * y * y * (4.0 * l).sin()
+            - 1.25 * e * e * (2.0 * m).sin())
+        .to_degrees();
+
+    let true_solar_time = seconds as f64 / 60.0 + equation_of_time + 4.0 * longitude.in_degrees();
+    let hour_angle = (true_solar_time / 4.0 - 180.0).to_radians();
+
+    let phi = latitude.in_radians();
+    let elevation = (phi.sin() * declination.sin()
+        + phi.cos() * declination.cos() * hour_angle.cos())
+    .clamp(-1.0, 1.0)
+    .asin();
+    let azimuth = Float::atan2(
+        hour_angle.sin(),
+        hour_angle.cos() * phi.sin() - declination.tan() * phi.cos(),
+    );
+
+    SolarPosition {
+        azimuth: (Degrees(180.0) + AngleDelta::new(Radians(azimuth))).normalized(),
+        elevation: Degrees(elevation.to_degrees()),
+    }
+}
+
+/// Estimate the atmospheric refraction in degrees for a body at the given
+/// geometric `elevation` in degrees, as the NOAA solar calculator does.
+fn refraction(elevation: f64) -> f64 {
+    let arcseconds = if elevation > 85.0 {
+        0.0
+    } else if elevation > 5.0 {
+        let t = elevation.to_radians().tan();
+        58.1 / t - 0.07 / t.powi(3) + 0.000086 / t.powi(5)
+    } else if elevation > -0.575 {
+        let e = elevation;
+        1735.0 + e * (-518.2 + e * (103.4 + e * (-12.79 + e * 0.711)))
+    } else {
+        -20.772 / elevation.to_radians().tan()
+    };
+    arcseconds / 3600.0
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    // Reference positions worked through the NOAA solar calculator
+    // spreadsheet, as (unix seconds, latitude, longitude, azimuth, elevation).
+    const CASES: [(i64, f64, f64, f64, f64); 5] = [
+        // The 2024 March equinox, at noon on the equator.
+        (1_710_936_000, 0.0, 0.0, 85.387, 88.171),
+        // Boulder, Colorado, around noon on the 2024 June solstice.
+        (1_718_992_800, 40.015, -105.2705, 136.550, 68.754),
+        // Sydney, around noon on the 2023 December solstice.
+        (1_703_124_000, -33.8688, 151.2093, 351.077, 79.454),
+        // Greenwich, on a September morning.
+        (1_598_952_600, 51.4779, -0.0015, 131.213, 36.810),
+        // Fairbanks, Alaska, on a January night.
+        (1_263_564_000, 64.8378, -147.7164, 71.489, -31.610),
+    ];
+
+    #[test]
+    fn test_solar_position() {
+        for &(time, latitude, longitude, azimuth, elevation) in &CASES {
+            let sun = solar_position(time, Degrees(latitude), Degrees(longitude));
+            assert_that!(sun.azimuth.in_degrees(), close_to(azimuth, 0.01));
+            assert_that!(sun.elevation.in_degrees(), close_to(elevation, 0.01));
+        }
+    }
+
+    #[test]
+    fn test_solar_position_units() {
+        let (time, latitude, longitude, azimuth, elevation) = CASES[2];
+        let sun = solar_position(
+            time,
+            Radians(latitude.to_radians()),
+            Radians(longitude.to_radians()),
+        );
+        assert_that!(sun.azimuth.in_degrees(), close_to(azimuth, 0.01));
+        assert_that!(sun.elevation.in_degrees(), close_to(elevation, 0.01));
+        assert!(sun.azimuth >= Degrees(0.0) && sun.azimuth < Degrees(360.0));
+    }
+
+    #[test]
+    fn test_solar_position_near_sunset() {
+        // Boulder, Colorado, around sunset on the 2024 June solstice.
+        let (latitude, longitude) = (Degrees(40.015), Degrees(-105.2705));
+        let cases = [
+            (1_718_935_200, 296.847, 4.901, 4.735),
+            (1_718_936_580, 300.442, 1.247, 0.871),
+            (1_718_936_940, 301.402, 0.384, -0.115),
+            (1_718_937_300, 302.373, -0.787, -1.090),
+        ];
+        for &(time, azimuth, elevation, geometric) in &cases {
+            let sun = solar_position(time, latitude, longitude);
+            assert_that!(sun.azimuth.in_degrees(), close_to(azimuth, 0.01));
+            assert_that!(sun.elevation.in_degrees(), close_to(elevation, 0.01));
+
+            let sun = geometric_solar_position(time, latitude, longitude);
+            assert_that!(sun.azimuth.in_degrees(), close_to(azimuth, 0.01));
+            assert_that!(sun.elevation.in_degrees(), close_to(geometric, 0.01));
+        }
+
+        // The refraction correction fades out high in the sky.
+        let (time, latitude, longitude, _, _) = CASES[0];
+        let apparent = solar_position(time, Degrees(latitude), Degrees(longitude));
+        let geometric = geometric_solar_position(time, Degrees(latitude), Degrees(longitude));
+        assert_eq!(apparent.elevation, geometric.elevation);
+    }
+}
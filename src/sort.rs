@@ -0,0 +1,207 @@
+//! Sorting of angles around the circle.
+
+use core::cmp::Ordering;
+use num_traits::Float;
+
+use crate::{Angle, RotationDirection};
+
+/// Sort angles in the order they are met when sweeping around the circle
+/// from `start` in the given direction, following the convention of this
+/// crate that counter-clockwise means increasing angles. An angle equal to
+/// `start` comes first.
+///
+/// [`RotationDirection::None`](enum.RotationDirection.html) sorts by the
+/// [`min_dist`](enum.Angle.html#method.min_dist) to `start` instead, nearest
+/// first, with counter-clockwise winning ties. NaN and infinite angles sort
+/// last. The sort is stable.
+///
+/// Compass bearings are measured clockwise, so sort them by their
+/// [`to_math_angle`](struct.Bearing.html#method.to_math_angle), with
+/// [`sort_circular_by_key`](fn.sort_circular_by_key.html).
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let mut angles = [Degrees(350.0f64), Degrees(10.0), Degrees(170.0)];
+///
+/// sort_circular(&mut angles, Degrees(0.0), RotationDirection::CounterClockwise);
+/// assert_eq!(angles, [Degrees(10.0), Degrees(170.0), Degrees(350.0)]);
+///
+/// sort_circular(&mut angles, Degrees(0.0), RotationDirection::Clockwise);
+/// assert_eq!(angles, [Degrees(350.0), Degrees(170.0), Degrees(10.0)]);
+/// ```
+#[inline]
+pub fn sort_circular<T: Float>(
+    angles: &mut [Angle<T>],
+    start: Angle<T>,
+    direction: RotationDirection,
+) {
+    sort_circular_by_key(angles, start, direction, |&angle| angle);
+}
+
+/// Sort items by an angle extracted from each with `key`, in the order of
+/// [`sort_circular`](fn.sort_circular.html).
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// struct Waypoint {
+///     name: &'static str,
+///     bearing: Bearing,
+/// }
+///
+/// let mut waypoints = [
+///     Waypoint { name: "harbour", bearing: Bearing::from_azimuth(350.0) },
+///     Waypoint { name: "lighthouse", bearing: Bearing::from_azimuth(10.0) },
+///     Waypoint { name: "reef", bearing: Bearing::from_azimuth(170.0) },
+/// ];
+///
+/// // Sweep clockwise on the compass from the current heading.
+/// let heading = Bearing::from_azimuth(0.0);
+/// sort_circular_by_key(
+///     &mut waypoints,
+///     heading.to_math_angle(),
+///     RotationDirection::Clockwise,
+///     |waypoint| waypoint.bearing.to_math_angle(),
+/// );
+///
+/// let names: Vec<_> = waypoints.iter().map(|waypoint| waypoint.name).collect();
+/// assert_eq!(names, ["lighthouse", "reef", "harbour"]);
+/// ```
+pub fn sort_circular_by_key<A, T, F>(
+    items: &mut [A],
+    start: Angle<T>,
+    direction: RotationDirection,
+    mut key: F,
+) where
+    T: Float,
+    F: FnMut(&A) -> Angle<T>,
+{
+    let start = start.in_radians();
+    let mut sort_key = |item: &A| {
+        let angle = key(item).in_radians();
+        let counter_clockwise = Angle::Radians(angle - start).normalized().value();
+        let clockwise = Angle::Radians(start - angle).normalized().value();
+        match direction {
+            RotationDirection::CounterClockwise => (counter_clockwise, T::zero()),
+            RotationDirection::Clockwise => (clockwise, T::zero()),
+            RotationDirection::None => (counter_clockwise.min(clockwise), counter_clockwise),
+        }
+    };
+
+    items.sort_by(|a, b| compare(sort_key(a), sort_key(b)));
+}
+
+/// Compare pairs of offsets lexicographically, with NaN after any number.
+#[inline]
+fn compare<T: Float>(a: (T, T), b: (T, T)) -> Ordering {
+    let order = |a: T, b: T| match (a.is_nan(), b.is_nan()) {
+        (false, false) => a.partial_cmp(&b).unwrap(),
+        (a, b) => a.cmp(&b),
+    };
+    order(a.0, b.0).then_with(|| order(a.1, b.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RotationDirection::{Clockwise, CounterClockwise};
+    use crate::*;
+
+    #[test]
+    fn test_sort_circular() {
+        let mut angles = [Degrees(170.0), Degrees(350.0), Degrees(10.0)];
+        sort_circular(&mut angles, Degrees(0.0), CounterClockwise);
+        assert_eq!(angles, [Degrees(10.0), Degrees(170.0), Degrees(350.0)]);
+        sort_circular(&mut angles, Degrees(0.0), Clockwise);
+        assert_eq!(angles, [Degrees(350.0), Degrees(170.0), Degrees(10.0)]);
+
+        // Unnormalized angles, mixed units and a start on one of the angles.
+        let mut angles = [Degrees(-10.0), Radians(0.5), Degrees(400.0), Degrees(90.0)];
+        sort_circular(&mut angles, Degrees(90.0), CounterClockwise);
+        assert_eq!(
+            angles,
+            [Degrees(90.0), Degrees(-10.0), Radians(0.5), Degrees(400.0)]
+        );
+        sort_circular(&mut angles, Degrees(90.0), Clockwise);
+        assert_eq!(
+            angles,
+            [Degrees(90.0), Degrees(400.0), Radians(0.5), Degrees(-10.0)]
+        );
+    }
+
+    #[test]
+    fn test_sort_circular_nearest() {
+        let mut angles = [
+            Radians(-2.0),
+            Radians(0.25),
+            Radians(3.0),
+            Radians(-0.25),
+            Radians(0.5),
+        ];
+        sort_circular(&mut angles, Radians(0.0), RotationDirection::None);
+        assert_eq!(
+            angles,
+            [
+                Radians(0.25),
+                Radians(-0.25),
+                Radians(0.5),
+                Radians(-2.0),
+                Radians(3.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sort_circular_nan() {
+        for &direction in &[Clockwise, CounterClockwise, RotationDirection::None] {
+            let mut angles = [
+                Degrees(f64::NAN),
+                Degrees(20.0),
+                Degrees(f64::INFINITY),
+                Degrees(10.0),
+            ];
+            sort_circular(&mut angles, Degrees(15.0), direction);
+            assert!(angles[..2].iter().all(|angle| angle.is_finite()));
+            assert!(angles[2].is_nan() && angles[3].value().is_infinite());
+        }
+    }
+
+    #[test]
+    fn test_sort_circular_by_key() {
+        #[derive(Debug, PartialEq)]
+        struct Waypoint {
+            id: u32,
+            bearing: Bearing,
+        }
+        let waypoint = |id, azimuth| Waypoint {
+            id,
+            bearing: Bearing::from_azimuth(azimuth),
+        };
+
+        let mut waypoints = [
+            waypoint(1, 350.0),
+            waypoint(2, 10.0),
+            waypoint(3, 170.0),
+            waypoint(4, 10.0),
+        ];
+        sort_circular_by_key(
+            &mut waypoints,
+            Bearing::from_azimuth(0.0).to_math_angle(),
+            Clockwise,
+            |w| w.bearing.to_math_angle(),
+        );
+        let ids: Vec<_> = waypoints.iter().map(|w| w.id).collect();
+        assert_eq!(ids, [2, 4, 3, 1]);
+
+        sort_circular_by_key(
+            &mut waypoints,
+            Bearing::from_azimuth(0.0).to_math_angle(),
+            CounterClockwise,
+            |w| w.bearing.to_math_angle(),
+        );
+        let ids: Vec<_> = waypoints.iter().map(|w| w.id).collect();
+        assert_eq!(ids, [1, 3, 2, 4]);
+    }
+}
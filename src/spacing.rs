@@ -0,0 +1,593 @@
+//! Iterators over evenly spaced angles.
+
+use core::iter::FusedIterator;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+use num_traits::{cast::cast, Num, NumCast};
+
+use crate::{normalize, Angle, AngleUnit, Convertible};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> Angle<T> {
+    /// Create an iterator over `n` evenly spaced angles from `start` towards
+    /// `end`, like `numpy.linspace`. If `inclusive` is `true` the last angle
+    /// is `end`, otherwise the angles stop one step short of it.
+    ///
+    /// The angles run straight from the value of `start` to that of `end`,
+    /// without wrapping around the circle, and are in the unit of `start`.
+    /// Each angle is computed as `start + i·step`, so no error accumulates
+    /// along the way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let angles: Vec<_> = Angle::linspace(Degrees(0.0f64), Degrees(90.0), 4, true).collect();
+    /// assert_eq!(angles, [Degrees(0.0), Degrees(30.0), Degrees(60.0), Degrees(90.0)]);
+    ///
+    /// let angles: Vec<_> = Angle::linspace(Degrees(0.0f64), Degrees(90.0), 3, false).collect();
+    /// assert_eq!(angles, [Degrees(0.0), Degrees(30.0), Degrees(60.0)]);
+    /// ```
+    #[inline]
+    pub fn linspace(start: Angle<T>, end: Angle<T>, n: usize, inclusive: bool) -> Linspace<T> {
+        let (start, unit) = start.into_parts();
+        let end = end.value_in(unit);
+        let intervals = if inclusive { n.saturating_sub(1) } else { n };
+        let step = if intervals == 0 {
+            T::zero()
+        } else {
+            (end - start) / cast(intervals).unwrap()
+        };
+
+        Linspace {
+            start,
+            step,
+            end: if inclusive { Some(end) } else { None },
+            unit,
+            n,
+            front: 0,
+            back: n,
+        }
+    }
+
+    /// Create an iterator over `n` evenly spaced angles around the full
+    /// circle, in radians from 0 up to but excluding 2π.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let compass: Vec<_> = Angle::<f64>::full_circle(4).map(Angle::in_degrees).collect();
+    /// assert_eq!(compass, [0.0, 90.0, 180.0, 270.0]);
+    /// ```
+    #[inline]
+    pub fn full_circle(n: usize) -> Linspace<T> {
+        let full = AngleUnit::Radians.full_turn_in();
+        Angle::linspace(Angle::Radians(T::zero()), Angle::Radians(full), n, false)
+    }
+}
+
+/// An iterator over evenly spaced angles.
+///
+/// This `struct` is created by [`Angle::linspace`] and
+/// [`Angle::full_circle`]. See their documentation for more.
+///
+/// [`Angle::linspace`]: enum.Angle.html#method.linspace
+/// [`Angle::full_circle`]: enum.Angle.html#method.full_circle
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Clone, Debug)]
+pub struct Linspace<T> {
+    start: T,
+    step: T,
+    end: Option<T>,
+    unit: AngleUnit,
+    n: usize,
+    front: usize,
+    back: usize,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> Linspace<T> {
+    /// Compute the `i`th angle, which is exactly the end for the last angle
+    /// of an inclusive range.
+    #[inline]
+    fn angle_at(&self, i: usize) -> Option<Angle<T>> {
+        let value = match self.end {
+            Some(end) if i > 0 && i + 1 == self.n => end,
+            _ => self.start + self.step * cast(i)?,
+        };
+        Some(Angle::from_parts(value, self.unit))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> Iterator for Linspace<T> {
+    type Item = Angle<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Angle<T>> {
+        if self.front < self.back {
+            self.front += 1;
+            self.angle_at(self.front - 1)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Angle<T>> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Angle<T>> {
+        self.next_back()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> DoubleEndedIterator for Linspace<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Angle<T>> {
+        if self.front < self.back {
+            self.back -= 1;
+            self.angle_at(self.back)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> ExactSizeIterator for Linspace<T> {}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl<T: Float> FusedIterator for Linspace<T> {}
+
+impl<T: Copy + Num + NumCast + PartialOrd> Angle<T> {
+    /// Create an iterator over `count` angles starting at `start`, each
+    /// `step` on from the last. A negative step sweeps clockwise.
+    ///
+    /// The angles are in the unit of `start`, converting `step` where
+    /// needed, and are computed as `start + i·step` so that no error
+    /// accumulates. Call [`normalized`](struct.Steps.html#method.normalized)
+    /// on the iterator to normalize each angle, for sweeps which go round
+    /// more than once.
+    ///
+    /// Returns `None` if the last angle, its offset from `start` or its
+    /// index cannot be represented in `T`. This is checked with an estimate
+    /// in an `f64`, so values within a small margin of the limits of `T` are
+    /// rejected too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let table: Vec<_> = Angle::steps(Degrees(0), Degrees(45), 8).unwrap().collect();
+    /// assert_eq!(table.len(), 8);
+    /// assert_eq!(table[7], Degrees(315));
+    ///
+    /// let sweep: Vec<_> = Angle::steps(Degrees(10.0), Degrees(-20.0), 3)
+    ///     .unwrap()
+    ///     .normalized()
+    ///     .collect();
+    /// assert_eq!(sweep, [Degrees(10.0), Degrees(350.0), Degrees(330.0)]);
+    ///
+    /// assert!(Angle::steps(Degrees(0u8), Degrees(1), 300).is_none());
+    /// ```
+    pub fn steps(start: Angle<T>, step: Angle<T>, count: usize) -> Option<Steps<T>> {
+        let (start, unit) = start.into_parts();
+        let step = step.checked_in_unit_of(unit)?;
+
+        if let Some(last) = count.checked_sub(1) {
+            let last = last as f64;
+            let offset = cast::<T, f64>(step)? * last;
+            let end = cast::<T, f64>(start)? + offset;
+            if !fits::<T>(last) || !fits::<T>(offset) || !fits::<T>(end) {
+                return None;
+            }
+        }
+
+        Some(Steps {
+            start,
+            step,
+            unit,
+            full_turn: None,
+            front: 0,
+            back: count,
+        })
+    }
+
+    /// Create an iterator over the angles from `start` towards `end`, each
+    /// `step` on from the last, as for [`steps`](#method.steps). The last
+    /// angle is the last one not beyond `end`, which is included if a whole
+    /// number of steps reaches it. A step pointing away from `end` yields
+    /// nothing.
+    ///
+    /// Returns `None` if `step` is zero or NaN, the number of steps does not
+    /// fit in a `usize`, or the distance from `start` to `end` or the angles
+    /// cannot be represented in `T`, as for [`steps`](#method.steps).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let angles: Vec<_> = Angle::steps_to(Degrees(0), Degrees(100), Degrees(30))
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(angles, [Degrees(0), Degrees(30), Degrees(60), Degrees(90)]);
+    ///
+    /// let angles: Vec<_> = Angle::steps_to(Degrees(90.0f64), Degrees(0.0), Degrees(-45.0))
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(angles, [Degrees(90.0), Degrees(45.0), Degrees(0.0)]);
+    /// ```
+    pub fn steps_to(start: Angle<T>, end: Angle<T>, step: Angle<T>) -> Option<Steps<T>> {
+        let unit = start.unit();
+        let (from, to, by) = (
+            start.value(),
+            end.checked_in_unit_of(unit)?,
+            step.checked_in_unit_of(unit)?,
+        );
+        let zero = T::zero();
+
+        let towards = if by > zero {
+            to >= from
+        } else if by < zero {
+            to <= from
+        } else {
+            return None;
+        };
+        let count = if towards {
+            if !fits::<T>(cast::<T, f64>(to)? - cast::<T, f64>(from)?) {
+                return None;
+            }
+            cast::<T, usize>((to - from) / by)?.checked_add(1)?
+        } else {
+            0
+        };
+
+        Angle::steps(start, step, count)
+    }
+}
+
+/// Check whether a value estimated in an `f64` can be represented in `T`,
+/// leaving a margin for the rounding of the estimate.
+#[inline]
+fn fits<T: NumCast>(v: f64) -> bool {
+    cast::<f64, T>(v * (1.0 + 1.0e-9)).is_some()
+}
+
+/// An iterator over angles a fixed step apart.
+///
+/// This `struct` is created by [`Angle::steps`], [`Angle::steps_to`] and
+/// [`ArcRange::iter_step`]. See their documentation for more.
+///
+/// [`Angle::steps`]: enum.Angle.html#method.steps
+/// [`Angle::steps_to`]: enum.Angle.html#method.steps_to
+/// [`ArcRange::iter_step`]: struct.ArcRange.html#method.iter_step
+#[derive(Clone, Debug)]
+pub struct Steps<T> {
+    start: T,
+    step: T,
+    unit: AngleUnit,
+    full_turn: Option<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<T: Copy + Num + NumCast + PartialOrd> Steps<T> {
+    /// Normalize each angle yielded into the range of [0, 2π) rad.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a full turn cannot be represented in `T`, as
+    /// [`Angle::normalized`](enum.Angle.html#method.normalized) does. See
+    /// [`checked_normalized`](#method.checked_normalized). With the
+    /// `no-panic` feature, `T` must be [`Convertible`] instead.
+    ///
+    /// [`Convertible`]: trait.Convertible.html
+    #[inline]
+    pub fn normalized(mut self) -> Steps<T>
+    where
+        T: Convertible,
+    {
+        self.full_turn = Some(self.unit.full_turn_in());
+        self
+    }
+
+    /// Normalize each angle yielded into the range of [0, 2π) rad, or return
+    /// `None` if a full turn cannot be represented in `T`.
+    #[inline]
+    pub fn checked_normalized(mut self) -> Option<Steps<T>> {
+        self.full_turn = Some(self.unit.checked_full_turn_in()?);
+        Some(self)
+    }
+
+    /// Compute the `i`th angle. The constructors check that every index and
+    /// angle up to the last can be represented in `T`.
+    #[inline]
+    fn angle_at(&self, i: usize) -> Angle<T> {
+        let v = self.start + self.step * cast(i).unwrap();
+        let v = match self.full_turn {
+            Some(full_turn) => normalize(v, full_turn),
+            None => v,
+        };
+        Angle::from_parts(v, self.unit)
+    }
+}
+
+impl<T: Copy + Num + NumCast + PartialOrd> Iterator for Steps<T> {
+    type Item = Angle<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Angle<T>> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.angle_at(self.front - 1))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Angle<T>> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Angle<T>> {
+        self.next_back()
+    }
+}
+
+impl<T: Copy + Num + NumCast + PartialOrd> DoubleEndedIterator for Steps<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Angle<T>> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.angle_at(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Copy + Num + NumCast + PartialOrd> ExactSizeIterator for Steps<T> {}
+
+impl<T: Copy + Num + NumCast + PartialOrd> FusedIterator for Steps<T> {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use core::f64::consts::PI;
+    #[cfg(any(feature = "std", feature = "libm"))]
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_linspace() {
+        let angles: Vec<_> = Angle::linspace(Degrees(10.0), Degrees(-20.0), 4, true).collect();
+        assert_eq!(
+            angles,
+            [Degrees(10.0), Degrees(0.0), Degrees(-10.0), Degrees(-20.0)]
+        );
+
+        // The end is converted to the unit of the start, and hit exactly.
+        let angles: Vec<_> = Angle::linspace(Radians(0.1), Degrees(100.0), 7, true).collect();
+        assert_eq!(angles.len(), 7);
+        assert_eq!(angles[0], Radians(0.1));
+        assert_eq!(angles[6], Radians(Degrees(100.0).in_radians()));
+        assert!(angles.iter().all(|angle| matches!(angle, Radians(_))));
+
+        let angles: Vec<_> = Angle::linspace(Degrees(0.0f32), Degrees(1.0), 10, false).collect();
+        assert_eq!(angles.len(), 10);
+        assert_that!(angles[9].in_degrees() as f64, close_to(0.9, 1.0e-6));
+
+        assert_eq!(
+            Angle::linspace(Degrees(0.0), Degrees(90.0), 0, true).count(),
+            0
+        );
+        assert_eq!(
+            Angle::linspace(Degrees(0.0), Degrees(90.0), 0, false).count(),
+            0
+        );
+        let single: Vec<_> = Angle::linspace(Degrees(5.0), Degrees(90.0), 1, true).collect();
+        assert_eq!(single, [Degrees(5.0)]);
+        let single: Vec<_> = Angle::linspace(Degrees(5.0), Degrees(90.0), 1, false).collect();
+        assert_eq!(single, [Degrees(5.0)]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_linspace_double_ended() {
+        let space = Angle::linspace(Degrees(0.0), Degrees(70.0), 8, true);
+        assert_eq!(space.len(), 8);
+        let forwards: Vec<_> = space.clone().collect();
+        let mut backwards: Vec<_> = space.clone().rev().collect();
+        backwards.reverse();
+        assert_eq!(forwards, backwards);
+
+        let mut space = space;
+        assert_eq!(space.next(), Some(Degrees(0.0)));
+        assert_eq!(space.next_back(), Some(Degrees(70.0)));
+        assert_eq!(space.len(), 6);
+        assert_eq!(space.nth(2), Some(Degrees(30.0)));
+        assert_eq!(space.next_back(), Some(Degrees(60.0)));
+        assert_eq!(space.nth(5), None);
+        assert_eq!(space.next_back(), None);
+        assert_eq!(space.len(), 0);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn test_full_circle() {
+        let angles: Vec<Angle> = Angle::full_circle(6).collect();
+        assert_eq!(angles.len(), 6);
+        assert_eq!(angles[0], Radians(0.0));
+        for (i, angle) in angles.iter().enumerate() {
+            assert_that!(angle.in_degrees(), close_to(i as f64 * 60.0, 1.0e-12));
+        }
+        assert_eq!(Angle::<f32>::full_circle(0).len(), 0);
+
+        // Computing each angle from the start keeps the error of the last
+        // of many bounded.
+        let n = 10_000;
+        let last = Angle::<f64>::full_circle(n).next_back().unwrap();
+        assert_that!(
+            last.in_radians(),
+            close_to(2.0 * PI * (n - 1) as f64 / n as f64, 1.0e-12)
+        );
+        for (i, angle) in Angle::<f32>::full_circle(n).enumerate() {
+            let expected = 2.0 * PI * i as f64 / n as f64;
+            assert_that!(angle.in_radians() as f64, close_to(expected, 1.0e-5));
+        }
+    }
+
+    #[test]
+    fn test_steps() {
+        let angles: Vec<_> = Angle::steps(Degrees(350), Degrees(5), 5).unwrap().collect();
+        assert_eq!(
+            angles,
+            [
+                Degrees(350),
+                Degrees(355),
+                Degrees(360),
+                Degrees(365),
+                Degrees(370)
+            ]
+        );
+        let angles: Vec<_> = Angle::steps(Degrees(350), Degrees(5), 5)
+            .unwrap()
+            .checked_normalized()
+            .unwrap()
+            .collect();
+        assert_eq!(
+            angles,
+            [
+                Degrees(350),
+                Degrees(355),
+                Degrees(0),
+                Degrees(5),
+                Degrees(10)
+            ]
+        );
+
+        // Clockwise sweeps.
+        let angles: Vec<_> = Angle::steps(Degrees(20.0), Degrees(-15.0), 4)
+            .unwrap()
+            .normalized()
+            .collect();
+        assert_eq!(
+            angles,
+            [Degrees(20.0), Degrees(5.0), Degrees(350.0), Degrees(335.0)]
+        );
+
+        // The step is converted to the unit of the start.
+        let angles: Vec<_> = Angle::steps(Degrees(0), Radians(1), 3).unwrap().collect();
+        assert_eq!(angles, [Degrees(0), Degrees(57), Degrees(114)]);
+
+        let mut steps = Angle::steps(Degrees(0u16), Degrees(10), 6).unwrap();
+        assert_eq!(steps.len(), 6);
+        assert_eq!(steps.next_back(), Some(Degrees(50)));
+        assert_eq!(steps.nth(1), Some(Degrees(10)));
+        assert_eq!(
+            steps.rev().collect::<Vec<_>>(),
+            [Degrees(40), Degrees(30), Degrees(20)]
+        );
+        assert_eq!(
+            Angle::steps(Degrees(0), Degrees(10), 0).unwrap().next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_steps_to() {
+        // A whole number of steps reaches the end.
+        let table: Vec<_> = Angle::steps_to(Degrees(0i32), Degrees(360), Degrees(15))
+            .unwrap()
+            .collect();
+        assert_eq!(table.len(), 25);
+        assert_eq!(table[24], Degrees(360));
+
+        // Otherwise the last angle stops short of the end, either way round.
+        let angles = Angle::steps_to(Degrees(10), Degrees(45), Degrees(10)).unwrap();
+        assert_eq!(angles.len(), 4);
+        assert_eq!(angles.last(), Some(Degrees(40)));
+        let angles = Angle::steps_to(Degrees(10), Degrees(-25), Degrees(-10)).unwrap();
+        assert_eq!(angles.len(), 4);
+        assert_eq!(angles.last(), Some(Degrees(-20)));
+        let angles = Angle::steps_to(Degrees(1.0), Degrees(0.0), Degrees(-0.25)).unwrap();
+        assert_eq!(angles.last(), Some(Degrees(0.0)));
+        let angles = Angle::steps_to(Degrees(0.0), Degrees(1.0), Degrees(0.3)).unwrap();
+        assert_eq!(angles.len(), 4);
+
+        // Steps pointing away from the end, or going nowhere.
+        assert_eq!(
+            Angle::steps_to(Degrees(10), Degrees(0), Degrees(5))
+                .unwrap()
+                .len(),
+            0
+        );
+        assert_eq!(
+            Angle::steps_to(Degrees(0), Degrees(10), Degrees(-5))
+                .unwrap()
+                .len(),
+            0
+        );
+        assert_eq!(
+            Angle::steps_to(Degrees(5u8), Degrees(5), Degrees(1))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(Angle::steps_to(Degrees(0), Degrees(10), Degrees(0)).is_none());
+        assert!(Angle::steps_to(Degrees(0.0), Degrees(10.0), Degrees(f64::NAN)).is_none());
+        assert!(Angle::steps_to(Degrees(0.0), Degrees(f64::INFINITY), Degrees(1.0)).is_none());
+    }
+
+    #[test]
+    fn test_steps_overflow() {
+        let steps = Angle::steps(Degrees(0u8), Degrees(1), 256).unwrap();
+        assert_eq!(steps.len(), 256);
+        assert_eq!(steps.last(), Some(Degrees(255)));
+        assert!(Angle::steps(Degrees(0u8), Degrees(1), 300).is_none());
+        assert!(Angle::steps(Degrees(0u8), Degrees(0), 300).is_none());
+        assert!(Angle::steps(Degrees(0i16), Degrees(100), 400).is_none());
+        // The offset of the last angle overflows, even though the angle
+        // itself would fit.
+        assert!(Angle::steps(Degrees(100i8), Degrees(-10), 20).is_none());
+
+        let steps = Angle::steps(Degrees(0i16), Degrees(100), 300).unwrap();
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(steps.clone().normalized().last(), Some(Degrees(20)));
+        let steps = steps.checked_normalized().unwrap();
+        assert_eq!(steps.last(), Some(Degrees(20)));
+        let steps = Angle::steps(Degrees(0i8), Degrees(10), 10).unwrap();
+        assert!(steps.checked_normalized().is_none());
+        let steps = Angle::steps(Degrees(0i64), Degrees(1 << 40), 1 << 22).unwrap();
+        assert_eq!(steps.last(), Some(Degrees(((1 << 22) - 1) << 40)));
+
+        assert!(Angle::steps_to(Degrees(-100i8), Degrees(100), Degrees(50)).is_none());
+        let steps = Angle::steps_to(Degrees(0i8), Degrees(-120), Degrees(i8::MIN)).unwrap();
+        assert_eq!(steps.collect::<Vec<_>>(), [Degrees(0)]);
+        let steps = Angle::steps_to(Degrees(0u8), Degrees(255), Degrees(1)).unwrap();
+        assert_eq!(steps.len(), 256);
+    }
+}
@@ -0,0 +1,412 @@
+//! Navigation along great circles of a sphere.
+//!
+//! Positions are given as a latitude and a longitude, and directions as
+//! bearings measured clockwise from north. Results are in the unit of the
+//! first latitude passed in.
+
+use core::f64::consts::PI;
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, AngleUnit, Radians};
+
+/// Compute the initial bearing of the great circle path from (`lat1`,
+/// `lon1`) to (`lat2`, `lon2`), measured clockwise from north and normalized
+/// into the range of [0, 360)° or [0, 2π) rad.
+///
+/// The bearing is meaningless between coincident or antipodal points, and
+/// is 0 between coincident points.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let bearing = initial_bearing(Degrees(0.0f64), Degrees(0.0), Degrees(0.0), Degrees(90.0));
+/// assert!((bearing.in_degrees() - 90.0).abs() < 1.0e-10);
+/// ```
+pub fn initial_bearing<T: Float>(
+    lat1: Angle<T>,
+    lon1: Angle<T>,
+    lat2: Angle<T>,
+    lon2: Angle<T>,
+) -> Angle<T> {
+    let (sin1, cos1) = lat1.sin_cos();
+    let (sin2, cos2) = lat2.sin_cos();
+    let (sin_dlon, cos_dlon) = lon1.delta_to(lon2).angle().sin_cos();
+
+    let y = sin_dlon * cos2;
+    let x = cos1 * sin2 - sin1 * cos2 * cos_dlon;
+    in_unit(Radians(y.atan2(x)).normalized(), lat1.unit())
+}
+
+/// Compute the central angle between (`lat1`, `lon1`) and (`lat2`, `lon2`),
+/// the angular distance along the great circle through both of them, in the
+/// range of [0, 180]° or [0, π] rad. Multiply its value in radians by the
+/// radius of the sphere to get the distance.
+///
+/// This uses the arctangent form of Vincenty's formula, which stays accurate
+/// for nearby as well as nearly antipodal points.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let delta = central_angle(Degrees(90.0f64), Degrees(0.0), Degrees(0.0), Degrees(45.0));
+/// assert!((delta.in_degrees() - 90.0).abs() < 1.0e-10);
+/// ```
+pub fn central_angle<T: Float>(
+    lat1: Angle<T>,
+    lon1: Angle<T>,
+    lat2: Angle<T>,
+    lon2: Angle<T>,
+) -> Angle<T> {
+    let (sin1, cos1) = lat1.sin_cos();
+    let (sin2, cos2) = lat2.sin_cos();
+    let (sin_dlon, cos_dlon) = lon1.delta_to(lon2).angle().sin_cos();
+
+    let y = (cos2 * sin_dlon).hypot(cos1 * sin2 - sin1 * cos2 * cos_dlon);
+    let x = sin1 * sin2 + cos1 * cos2 * cos_dlon;
+    in_unit(Radians(y.atan2(x)), lat1.unit())
+}
+
+/// Compute the position reached by travelling from (`lat`, `lon`) along the
+/// great circle with the initial `bearing`, over the angular distance
+/// `central_angle`. Return the latitude and the longitude, normalized into
+/// the range of (-180, 180]° or (-π, π] rad.
+///
+/// Paths may cross a pole or the antimeridian, and may be longer than half a
+/// turn. A negative central angle travels backwards.
+///
+/// Every direction leads south from the north pole, so a start exactly at a
+/// pole takes the bearing as seen from just off the pole on the meridian
+/// `lon`. From the north pole the path follows the meridian `lon + 180° -
+/// bearing`, and from the south pole the meridian `lon + bearing`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// // A quarter of the way around the equator towards the east.
+/// let (lat, lon) = destination(Degrees(0.0f64), Degrees(10.0), Degrees(90.0), Degrees(90.0));
+/// assert!(lat.in_degrees().abs() < 1.0e-10);
+/// assert!((lon.in_degrees() - 100.0).abs() < 1.0e-10);
+/// ```
+pub fn destination<T: Float>(
+    lat: Angle<T>,
+    lon: Angle<T>,
+    bearing: Angle<T>,
+    central_angle: Angle<T>,
+) -> (Angle<T>, Angle<T>) {
+    let (sin_lat, mut cos_lat) = lat.sin_cos();
+    // Latitudes of ±90° do not give a cosine of exactly zero in radians, and
+    // the remainder would pick an arbitrary meridian at the poles.
+    if cos_lat.abs() <= T::epsilon() {
+        cos_lat = T::zero();
+    }
+    let (sin_bearing, cos_bearing) = bearing.sin_cos();
+    let (sin_delta, cos_delta) = central_angle.sin_cos();
+
+    let z = sin_lat * cos_delta + cos_lat * sin_delta * cos_bearing;
+    let x = cos_lat * cos_delta - sin_lat * sin_delta * cos_bearing;
+    let y = sin_delta * sin_bearing;
+
+    let unit = lat.unit();
+    let lat = z.atan2(x.hypot(y));
+    let lon = signed(lon.in_radians() + y.atan2(x));
+    (in_unit(Radians(lat), unit), in_unit(Radians(lon), unit))
+}
+
+/// Compute the signed angular distance of (`lat`, `lon`) from the great
+/// circle through (`start_lat`, `start_lon`) and (`end_lat`, `end_lon`),
+/// which is positive to the left of the track when travelling from the start
+/// towards the end, and negative to the right. Multiply its value in radians
+/// by the radius of the sphere to get the distance.
+///
+/// The track is meaningless if the start and the end coincide, in which case
+/// it is taken to head north.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// // East along the equator, with the point 10° to the north on the left.
+/// let (start, end) = ((Degrees(0.0f64), Degrees(0.0)), (Degrees(0.0), Degrees(90.0)));
+/// let xt = cross_track_angle(start.0, start.1, end.0, end.1, Degrees(10.0), Degrees(45.0));
+/// assert!((xt.in_degrees() - 10.0).abs() < 1.0e-10);
+/// ```
+pub fn cross_track_angle<T: Float>(
+    start_lat: Angle<T>,
+    start_lon: Angle<T>,
+    end_lat: Angle<T>,
+    end_lon: Angle<T>,
+    lat: Angle<T>,
+    lon: Angle<T>,
+) -> Angle<T> {
+    let (sin_delta, cos_delta, sin_phi, cos_phi) =
+        track_frame(start_lat, start_lon, end_lat, end_lon, lat, lon);
+    let xt = (-sin_delta * sin_phi).atan2(cos_delta.hypot(sin_delta * cos_phi));
+    in_unit(Radians(xt), start_lat.unit())
+}
+
+/// Compute the signed angular distance from (`start_lat`, `start_lon`) to
+/// the closest point to (`lat`, `lon`) on the great circle through the start
+/// and (`end_lat`, `end_lon`), which is negative if that point lies behind
+/// the start. The result is in the range of [-180, 180]° or [-π, π] rad.
+///
+/// The track is meaningless if the start and the end coincide, in which case
+/// it is taken to head north. Every point of the track is equally close to
+/// the two poles of its great circle, 90° to either side, which makes the
+/// result meaningless there.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let (start, end) = ((Degrees(0.0f64), Degrees(0.0)), (Degrees(0.0), Degrees(90.0)));
+/// let at = along_track_angle(start.0, start.1, end.0, end.1, Degrees(10.0), Degrees(-20.0));
+/// assert!((at.in_degrees() + 20.0).abs() < 1.0e-10);
+/// ```
+pub fn along_track_angle<T: Float>(
+    start_lat: Angle<T>,
+    start_lon: Angle<T>,
+    end_lat: Angle<T>,
+    end_lon: Angle<T>,
+    lat: Angle<T>,
+    lon: Angle<T>,
+) -> Angle<T> {
+    let (sin_delta, cos_delta, _, cos_phi) =
+        track_frame(start_lat, start_lon, end_lat, end_lon, lat, lon);
+    in_unit(
+        Radians((sin_delta * cos_phi).atan2(cos_delta)),
+        start_lat.unit(),
+    )
+}
+
+/// Compute the sine and cosine of the central angle from the start to the
+/// point, and of the bearing to the point relative to the track.
+fn track_frame<T: Float>(
+    start_lat: Angle<T>,
+    start_lon: Angle<T>,
+    end_lat: Angle<T>,
+    end_lon: Angle<T>,
+    lat: Angle<T>,
+    lon: Angle<T>,
+) -> (T, T, T, T) {
+    let (sin_delta, cos_delta) = central_angle(start_lat, start_lon, lat, lon).sin_cos();
+    let track = initial_bearing(start_lat, start_lon, end_lat, end_lon);
+    let bearing = initial_bearing(start_lat, start_lon, lat, lon);
+    let (sin_phi, cos_phi) = track.delta_to(bearing).angle().sin_cos();
+    (sin_delta, cos_delta, sin_phi, cos_phi)
+}
+
+#[inline]
+fn in_unit<T: Float>(angle: Angle<T>, unit: AngleUnit) -> Angle<T> {
+    Angle::from_parts(angle.value_in(unit), unit)
+}
+
+/// Normalize `v` radians into the range of (-π, π].
+#[inline]
+fn signed<T: Float>(v: T) -> T {
+    let pi: T = cast(PI).unwrap();
+    let two_pi = pi + pi;
+    let r = v % two_pi;
+    if r <= -pi {
+        r + two_pi
+    } else if r > pi {
+        r - two_pi
+    } else {
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use quickcheck::quickcheck;
+
+    use crate::*;
+
+    fn assert_position(actual: (Angle, Angle), lat: f64, lon: f64) {
+        assert_that!(actual.0.in_degrees(), close_to(lat, 1.0e-9));
+        assert_that!(actual.1.in_degrees(), close_to(lon, 1.0e-9));
+    }
+
+    #[test]
+    fn test_initial_bearing_and_central_angle() {
+        // Between the Big Ben and the Statue of Liberty, on a sphere.
+        let (lat1, lon1) = (Degrees(51.5007), Degrees(-0.1246));
+        let (lat2, lon2) = (Degrees(40.6892), Degrees(-74.0445));
+        assert_that!(
+            initial_bearing(lat1, lon1, lat2, lon2).in_degrees(),
+            close_to(288.3_f64, 0.05)
+        );
+        assert_that!(
+            central_angle(lat1, lon1, lat2, lon2).in_radians() * 6371.0,
+            close_to(5574.8_f64, 0.1)
+        );
+
+        let cases = [
+            ((0.0, 0.0), (10.0, 0.0), 0.0, 10.0),
+            ((0.0, 0.0), (-10.0, 0.0), 180.0, 10.0),
+            ((0.0, 170.0), (0.0, -170.0), 90.0, 20.0),
+            ((0.0, 0.0), (0.0, -90.0), 270.0, 90.0),
+            ((30.0, 40.0), (30.0, 40.0), 0.0, 0.0),
+        ];
+        for &((lat1, lon1), (lat2, lon2), bearing, delta) in &cases {
+            let args = (Degrees(lat1), Degrees(lon1), Degrees(lat2), Degrees(lon2));
+            let b = initial_bearing(args.0, args.1, args.2, args.3);
+            let d = central_angle(args.0, args.1, args.2, args.3);
+            assert_that!(b.in_degrees(), close_to(bearing, 1.0e-9));
+            assert_that!(d.in_degrees(), close_to(delta, 1.0e-9));
+        }
+
+        let d = central_angle(Degrees(45.0), Degrees(0.0), Degrees(-45.0), Degrees(180.0));
+        assert_that!(d.in_degrees(), close_to(180.0, 1.0e-9));
+
+        let d = central_angle(Radians(0.5f64), Degrees(20.0), Degrees(10.0), Degrees(20.0));
+        assert!(matches!(d, Radians(_)));
+        assert!(matches!(
+            initial_bearing(Degrees(0.0f32), Radians(0.0), Radians(1.0), Radians(1.0)),
+            Degrees(_)
+        ));
+    }
+
+    #[test]
+    fn test_destination() {
+        // Due north only changes the latitude.
+        let north = destination(Degrees(10.0), Degrees(-30.0), Degrees(0.0), Degrees(25.0));
+        assert_position(north, 35.0, -30.0);
+
+        // A quarter circle east along the equator.
+        let east = destination(Degrees(0.0), Degrees(0.0), Degrees(90.0), Degrees(90.0));
+        assert_position(east, 0.0, 90.0);
+
+        // Across the antimeridian in both directions.
+        let east = destination(Degrees(0.0), Degrees(170.0), Degrees(90.0), Degrees(20.0));
+        assert_position(east, 0.0, -170.0);
+        let west = destination(Degrees(0.0), Degrees(-175.0), Degrees(270.0), Degrees(10.0));
+        assert_position(west, 0.0, 175.0);
+
+        // Over the north pole and down the other side.
+        let over = destination(Degrees(80.0), Degrees(20.0), Degrees(0.0), Degrees(30.0));
+        assert_position(over, 70.0, -160.0);
+
+        // A full turn comes back, and a negative distance travels backwards.
+        let full = destination(Degrees(12.0), Degrees(34.0), Degrees(56.0), Degrees(360.0));
+        assert_position(full, 12.0, 34.0);
+        let back = destination(Degrees(0.0), Degrees(0.0), Degrees(90.0), Degrees(-30.0));
+        assert_position(back, 0.0, -30.0);
+
+        // The longitude is normalized into (-180, 180].
+        let lon = destination(Degrees(0.0), Degrees(90.0), Degrees(90.0), Degrees(90.0)).1;
+        assert_eq!(lon, Degrees(180.0));
+        let lon = destination(Degrees(0.0), Degrees(900.0), Degrees(0.0), Degrees(0.0)).1;
+        assert_that!(lon.in_degrees(), close_to(180.0, 1.0e-9));
+
+        let (lat, lon) = destination(Radians(0.0f64), Degrees(0.0), Degrees(0.0), Degrees(10.0));
+        assert!(matches!(lat, Radians(_)));
+        assert!(matches!(lon, Radians(_)));
+    }
+
+    #[test]
+    fn test_destination_from_pole() {
+        let cases = [(0.0, -170.0), (90.0, 100.0), (180.0, 10.0), (270.0, -80.0)];
+        for &(bearing, lon) in &cases {
+            let north = destination(
+                Degrees(90.0),
+                Degrees(10.0),
+                Degrees(bearing),
+                Degrees(30.0),
+            );
+            assert_position(north, 60.0, lon);
+        }
+        let cases = [(0.0, 10.0), (90.0, 100.0), (180.0, -170.0), (270.0, -80.0)];
+        for &(bearing, lon) in &cases {
+            let south = destination(
+                Degrees(-90.0),
+                Degrees(10.0),
+                Degrees(bearing),
+                Degrees(30.0),
+            );
+            assert_position(south, -60.0, lon);
+        }
+
+        let (lat, lon) = destination(Degrees(90.0f32), Degrees(0.0), Degrees(90.0), Degrees(10.0));
+        assert_that!(lat.in_degrees() as f64, close_to(80.0, 1.0e-4));
+        assert_that!(lon.in_degrees() as f64, close_to(90.0, 1.0e-4));
+    }
+
+    #[test]
+    fn prop_destination_round_trip() {
+        fn prop(lat: f64, lon: f64, bearing: f64, delta: f64) -> bool {
+            let lat = Degrees(lat % 80.0);
+            let lon = Degrees(lon % 180.0);
+            let bearing = Degrees(bearing).normalized();
+            let delta = Degrees(1.0 + (delta % 170.0).abs());
+
+            let (lat2, lon2) = destination(lat, lon, bearing, delta);
+            let b = initial_bearing(lat, lon, lat2, lon2);
+            let d = central_angle(lat, lon, lat2, lon2);
+            b.min_dist(bearing).in_degrees() < 1.0e-6
+                && (d.in_degrees() - delta.in_degrees()).abs() < 1.0e-6
+        }
+        quickcheck(prop as fn(f64, f64, f64, f64) -> bool);
+    }
+
+    #[test]
+    fn test_cross_and_along_track() {
+        // The worked example of Chris Veness' "Movable Type" pages, whose
+        // cross-track distance is measured positive to the right instead.
+        let args = (
+            Degrees(53.3206),
+            Degrees(-1.7297),
+            Degrees(53.1887),
+            Degrees(0.1334),
+            Degrees(53.2611),
+            Degrees(-0.7972),
+        );
+        let xt = cross_track_angle(args.0, args.1, args.2, args.3, args.4, args.5);
+        let at = along_track_angle(args.0, args.1, args.2, args.3, args.4, args.5);
+        assert_that!(xt.in_radians() * 6_371_000.0, close_to(307.5_f64, 0.1));
+        assert_that!(at.in_radians() * 6_371.0, close_to(62.331_f64, 0.001));
+
+        // Heading north along the meridian 0, with points on both sides and
+        // before and after the start.
+        let (sin, cos) = (
+            10.0f64.to_radians().sin_cos(),
+            20.0f64.to_radians().sin_cos(),
+        );
+        let cases = [
+            ((0.0, -20.0), 20.0, 0.0),
+            ((0.0, 20.0), -20.0, 0.0),
+            ((-30.0, 0.0), 0.0, -30.0),
+            ((50.0, 0.0), 0.0, 50.0),
+            ((0.0, 0.0), 0.0, 0.0),
+            ((0.0, 60.0), -60.0, 0.0),
+            ((0.0, 180.0), 0.0, 180.0),
+            (
+                (10.0, -20.0),
+                (sin.1 * cos.0).asin().to_degrees(),
+                sin.0.atan2(sin.1 * cos.1).to_degrees(),
+            ),
+        ];
+        for &((lat, lon), cross, along) in &cases {
+            let args = (Degrees(0.0), Degrees(0.0), Degrees(40.0), Degrees(0.0));
+            let point = (Degrees(lat), Degrees(lon));
+            let xt = cross_track_angle(args.0, args.1, args.2, args.3, point.0, point.1);
+            let at = along_track_angle(args.0, args.1, args.2, args.3, point.0, point.1);
+            assert_that!(xt.in_degrees(), close_to(cross, 1.0e-9));
+            assert_that!(at.in_degrees(), close_to(along, 1.0e-9));
+        }
+
+        let xt = cross_track_angle(
+            Radians(0.0f64),
+            Degrees(0.0),
+            Degrees(0.0),
+            Degrees(90.0),
+            Degrees(-10.0),
+            Degrees(45.0),
+        );
+        assert!(matches!(xt, Radians(_)));
+        assert_that!(xt.in_degrees(), close_to(-10.0, 1.0e-9));
+    }
+}
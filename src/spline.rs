@@ -0,0 +1,226 @@
+//! Smooth interpolation through angular keyframes.
+
+use alloc::vec::Vec;
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, AngleUnit};
+
+/// How a [`CatmullRomAngles`](struct.CatmullRomAngles.html) spline treats its
+/// first and last controls.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CatmullRomEnds {
+    /// The first and last controls only shape the tangents at the ends, and
+    /// the spline runs from the second control to the second to last one.
+    Clamped,
+    /// The first and last controls are duplicated, and the spline runs
+    /// through all of the controls.
+    Duplicated,
+}
+
+/// A uniform Catmull–Rom spline through a sequence of angles, which passes
+/// through its controls with a continuous rate of turn.
+///
+/// The spline works on an unwrapped copy of the controls, so it does not
+/// jump at the seam between a full turn and zero. The sampled angles are in
+/// the unit of the first control, and are not normalized.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let controls = [Degrees(340.0f64), Degrees(350.0), Degrees(10.0), Degrees(20.0)];
+/// let spline = CatmullRomAngles::new(&controls, CatmullRomEnds::Clamped).unwrap();
+///
+/// assert_eq!(spline.segments(), 1);
+/// assert!((spline.sample(0.5).in_degrees() - 360.0).abs() < 1.0e-10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CatmullRomAngles<T = f64> {
+    points: Vec<T>,
+    unit: AngleUnit,
+}
+
+impl<T: Float> CatmullRomAngles<T> {
+    /// Create a spline through the `controls`, taking the shortest path
+    /// between each consecutive pair. Returns `None` if there are fewer than
+    /// four controls.
+    pub fn new(controls: &[Angle<T>], ends: CatmullRomEnds) -> Option<CatmullRomAngles<T>> {
+        let first = *controls.first()?;
+        let unit = first.unit();
+
+        let mut value = first.value();
+        let mut unwrapped = Vec::with_capacity(controls.len());
+        unwrapped.push(value);
+        for pair in controls.windows(2) {
+            value = value + pair[0].shortest_delta_to(pair[1]).angle().value_in(unit);
+            unwrapped.push(value);
+        }
+        CatmullRomAngles::from_unwrapped(&unwrapped, unit, ends)
+    }
+
+    /// Create a spline through the controls given as `values` in `unit`,
+    /// taking them as they are rather than along the shortest path. This
+    /// allows turns of more than half a turn between controls. Returns `None`
+    /// if there are fewer than four controls.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let values = [0.0f64, 270.0, 540.0, 810.0];
+    /// let spline = CatmullRomAngles::from_unwrapped(&values, AngleUnit::Degrees, CatmullRomEnds::Duplicated)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(spline.segments(), 3);
+    /// assert!((spline.sample(0.5).in_degrees() - 405.0).abs() < 1.0e-10);
+    /// ```
+    pub fn from_unwrapped(
+        values: &[T],
+        unit: AngleUnit,
+        ends: CatmullRomEnds,
+    ) -> Option<CatmullRomAngles<T>> {
+        if values.len() < 4 {
+            return None;
+        }
+
+        let points = match ends {
+            CatmullRomEnds::Clamped => values.to_vec(),
+            CatmullRomEnds::Duplicated => {
+                let mut points = Vec::with_capacity(values.len() + 2);
+                points.push(values[0]);
+                points.extend_from_slice(values);
+                points.push(values[values.len() - 1]);
+                points
+            }
+        };
+        Some(CatmullRomAngles { points, unit })
+    }
+
+    /// Yield the number of segments between consecutive controls the spline
+    /// runs through.
+    #[inline]
+    pub fn segments(&self) -> usize {
+        self.points.len() - 3
+    }
+
+    /// Sample the spline at `t` in the range of [0, 1] over its whole length,
+    /// where each segment takes an equal share of the range. Values of `t`
+    /// outside of the range are clamped into it.
+    pub fn sample(&self, t: T) -> Angle<T> {
+        let segments: T = cast(self.segments()).unwrap();
+        let s = t.max(T::zero()).min(T::one()) * segments;
+        let i = cast::<T, usize>(s.floor())
+            .unwrap_or(0)
+            .min(self.segments() - 1);
+        self.evaluate(i, s - cast(i).unwrap())
+    }
+
+    /// Sample segment `i` of the spline at `u` in the range of [0, 1], or
+    /// return `None` if there is no such segment. Values of `u` outside of
+    /// the range are clamped into it.
+    #[inline]
+    pub fn sample_segment(&self, i: usize, u: T) -> Option<Angle<T>> {
+        if i < self.segments() {
+            Some(self.evaluate(i, u.max(T::zero()).min(T::one())))
+        } else {
+            None
+        }
+    }
+
+    fn evaluate(&self, i: usize, u: T) -> Angle<T> {
+        let (p0, p1, p2, p3) = (
+            self.points[i],
+            self.points[i + 1],
+            self.points[i + 2],
+            self.points[i + 3],
+        );
+        let c = |v: f64| -> T { cast(v).unwrap() };
+
+        let a = c(2.0) * p1;
+        let b = p2 - p0;
+        let d = c(2.0) * p0 - c(5.0) * p1 + c(4.0) * p2 - p3;
+        let e = c(3.0) * (p1 - p2) + p3 - p0;
+        let v = (a + u * (b + u * (d + u * e))) / c(2.0);
+        Angle::from_parts(v, self.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_catmull_rom_controls() {
+        let controls = [
+            Degrees(0.0),
+            Degrees(30.0),
+            Degrees(80.0),
+            Degrees(90.0),
+            Degrees(170.0),
+        ];
+
+        let spline = CatmullRomAngles::new(&controls, CatmullRomEnds::Duplicated).unwrap();
+        assert_eq!(spline.segments(), 4);
+        for (i, &alpha) in controls.iter().enumerate() {
+            assert_eq!(spline.sample(i as f64 / 4.0), alpha);
+        }
+        assert_eq!(spline.sample_segment(1, 0.0), Some(Degrees(30.0)));
+        assert_eq!(spline.sample_segment(1, 1.0), Some(Degrees(80.0)));
+        assert_eq!(spline.sample_segment(4, 0.0), None);
+        assert_eq!(spline.sample(-1.0), Degrees(0.0));
+        assert_eq!(spline.sample(2.0), Degrees(170.0));
+
+        let spline = CatmullRomAngles::new(&controls, CatmullRomEnds::Clamped).unwrap();
+        assert_eq!(spline.segments(), 2);
+        assert_eq!(spline.sample(0.0), Degrees(30.0));
+        assert_eq!(spline.sample(0.5), Degrees(80.0));
+        assert_eq!(spline.sample(1.0), Degrees(90.0));
+
+        assert!(CatmullRomAngles::new(&controls[..3], CatmullRomEnds::Duplicated).is_none());
+        assert!(CatmullRomAngles::<f64>::new(&[], CatmullRomEnds::Clamped).is_none());
+    }
+
+    #[test]
+    fn test_catmull_rom_smooth_joints() {
+        let controls = [
+            Radians(0.0),
+            Radians(0.5),
+            Radians(0.7),
+            Radians(1.8),
+            Radians(1.0),
+            Radians(1.2),
+        ];
+        let spline = CatmullRomAngles::new(&controls, CatmullRomEnds::Duplicated).unwrap();
+
+        let h = 1.0e-6;
+        for i in 0..spline.segments() - 1 {
+            let end = spline.sample_segment(i, 1.0).unwrap().in_radians();
+            let start = spline.sample_segment(i + 1, 0.0).unwrap().in_radians();
+            assert_that!(end, close_to(start, 1.0e-12));
+
+            let before = (end - spline.sample_segment(i, 1.0 - h).unwrap().in_radians()) / h;
+            let after = (spline.sample_segment(i + 1, h).unwrap().in_radians() - start) / h;
+            assert_that!(before, close_to(after, 1.0e-5));
+        }
+    }
+
+    #[test]
+    fn test_catmull_rom_seam() {
+        let controls = [Degrees(340.0), Degrees(350.0), Degrees(10.0), Degrees(20.0)];
+        let spline = CatmullRomAngles::new(&controls, CatmullRomEnds::Duplicated).unwrap();
+        for i in 0..=30 {
+            let alpha = spline.sample(i as f64 / 30.0);
+            assert!(matches!(alpha, Degrees(_)));
+            assert!(alpha.in_degrees() >= 340.0 - 1.0e-9 && alpha.in_degrees() <= 380.0 + 1.0e-9);
+        }
+        assert_that!(spline.sample(0.5).in_degrees(), close_to(360.0, 1.0e-9));
+        assert_that!(spline.sample(1.0).in_degrees(), close_to(380.0, 1.0e-9));
+
+        let controls = [Radians(0.0), Degrees(-10.0), Degrees(-20.0), Degrees(-30.0)];
+        let spline = CatmullRomAngles::new(&controls, CatmullRomEnds::Clamped).unwrap();
+        assert!(matches!(spline.sample(0.5), Radians(_)));
+        assert_that!(spline.sample(0.5).in_degrees(), close_to(-15.0, 1.0e-9));
+    }
+}
@@ -0,0 +1,1309 @@
+//! Statistics of angles treated as directions on the unit circle.
+
+use core::borrow::Borrow;
+use core::f64::consts::PI;
+use core::iter::FromIterator;
+use num_traits::{cast::cast, Float};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Angle, Radians};
+
+/// Compute the circular variance of a list of angles, defined as one minus
+/// the length of the mean of their unit vectors. Return value is in the
+/// range of [0, 1], where 0 means all angles are identical.
+///
+/// A single NaN among the angles makes the variance NaN; use
+/// [`circular_variance_ignore_nan`](fn.circular_variance_ignore_nan.html) to
+/// skip them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let angles = [Degrees(10.0f64), Degrees(10.0), Degrees(10.0)];
+/// assert!(circular_variance(&angles).abs() < 1.0e-10);
+///
+/// let angles = [Degrees(0.0f64), Degrees(90.0), Degrees(180.0), Degrees(270.0)];
+/// assert!((circular_variance(&angles) - 1.0).abs() < 1.0e-10);
+/// ```
+#[inline]
+pub fn circular_variance<'a, T, I>(angles: I) -> T
+where
+    T: 'a + Float,
+    I: IntoIterator<Item = &'a Angle<T>>,
+{
+    variance(angles)
+}
+
+/// Compute the mean resultant length R of a list of angles, the length of
+/// the mean of their unit vectors. Return value is in the range of [0, 1],
+/// where 1 means all angles are identical and values near 0 mean they are
+/// too dispersed for their mean to be meaningful. An empty list gives 0.
+///
+/// A single NaN among the angles makes the length NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let angles = [Degrees(10.0f64), Degrees(10.0), Degrees(10.0)];
+/// assert!((mean_resultant_length(&angles) - 1.0).abs() < 1.0e-10);
+///
+/// let angles = [Degrees(0.0f64), Degrees(90.0), Degrees(180.0), Degrees(270.0)];
+/// assert!(mean_resultant_length(&angles) < 1.0e-10);
+/// ```
+#[inline]
+pub fn mean_resultant_length<'a, T, I>(angles: I) -> T
+where
+    T: 'a + Float,
+    I: IntoIterator<Item = &'a Angle<T>>,
+{
+    mean_and_resultant(angles).1
+}
+
+/// Compute both the mean of a list of angles, as
+/// [`mean_angle`](fn.mean_angle.html) does, and their
+/// [`mean_resultant_length`](fn.mean_resultant_length.html) in a single pass
+/// over the angles.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let angles = [Degrees(350.0f64), Degrees(10.0)];
+///
+/// let (mu, r) = mean_and_resultant(&angles);
+/// assert!(mu.unwrap().min_dist(Degrees(0.0)).in_degrees() < 1.0e-10);
+/// assert!((r - Degrees(10.0f64).cos()).abs() < 1.0e-10);
+/// ```
+#[inline]
+pub fn mean_and_resultant<'a, T, I>(angles: I) -> (Option<Angle<T>>, T)
+where
+    T: 'a + Float,
+    I: IntoIterator<Item = &'a Angle<T>>,
+{
+    let (x, y, n) = resultant(angles);
+    let n: T = cast(n).unwrap();
+    (mean_of_resultant(x, y, n), resultant_length(x, y, n))
+}
+
+/// The result of a [`rayleigh_test`](fn.rayleigh_test.html).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayleighResult<T = f64> {
+    /// The Rayleigh statistic Z = nR², for the mean resultant length R.
+    pub z: T,
+    /// The approximate probability of a Z at least this large if the angles
+    /// were drawn from a uniform distribution.
+    pub p_value: T,
+    /// The number of angles tested.
+    pub n: usize,
+}
+
+/// Test whether a list of angles is significantly non-uniform, against the
+/// alternative of a single preferred direction. Return `None` if there are
+/// fewer than two angles or if any of them is NaN or infinite.
+///
+/// The p-value uses the approximation of Greenwood and Durand with its
+/// correction for small samples,
+///
+/// p ≈ e^(-Z) (1 + (2Z - Z²) / 4n - (24Z - 132Z² + 76Z³ - 9Z⁴) / 288n²),
+///
+/// clamped into [0, 1]. It agrees with tabulated critical values to within
+/// 0.001 from n = 10 on and 0.002 at n = 5, but it is only a rough guide for
+/// smaller samples. The test does not detect distributions with several
+/// preferred directions, such as axial data, whose unit vectors cancel out.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let winds = [
+///     Degrees(40.0f64), Degrees(55.0), Degrees(60.0), Degrees(70.0),
+///     Degrees(75.0), Degrees(80.0), Degrees(95.0), Degrees(230.0),
+/// ];
+///
+/// let result = rayleigh_test(&winds).unwrap();
+/// assert_eq!(result.n, 8);
+/// assert!(result.p_value < 0.05);
+/// ```
+pub fn rayleigh_test<'a, T, I>(angles: I) -> Option<RayleighResult<T>>
+where
+    T: 'a + Float,
+    I: IntoIterator<Item = &'a Angle<T>>,
+{
+    let (x, y, n) = resultant(angles);
+    if n < 2 {
+        return None;
+    }
+
+    let n_t: T = cast(n).unwrap();
+    let r = resultant_length(x, y, n_t);
+    let z = n_t * r * r;
+    if !z.is_finite() {
+        return None;
+    }
+
+    let c = |v: f64| -> T { cast(v).unwrap() };
+    let (z2, z3, z4) = (z * z, z * z * z, z * z * z * z);
+    let correction = T::one() + (c(2.0) * z - z2) / (c(4.0) * n_t)
+        - (c(24.0) * z - c(132.0) * z2 + c(76.0) * z3 - c(9.0) * z4) / (c(288.0) * n_t * n_t);
+    let p_value = ((-z).exp() * correction).max(T::zero()).min(T::one());
+
+    Some(RayleighResult { z, p_value, n })
+}
+
+/// Compute the mean of the finite angles in a list as
+/// [`mean_angle`](fn.mean_angle.html) does, skipping angles which are NaN or
+/// infinite. Return the mean together with the number of angles it was
+/// computed from, or `None` if none of them were finite.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let angles = [Degrees(350.0f64), Degrees(f64::NAN), Degrees(10.0)];
+///
+/// let (mu, count) = mean_angle_ignore_nan(&angles).unwrap();
+/// assert!(mu.min_dist(Degrees(0.0)).in_degrees() < 1.0e-10);
+/// assert_eq!(count, 2);
+/// ```
+#[inline]
+pub fn mean_angle_ignore_nan<'a, T, I>(angles: I) -> Option<(Angle<T>, usize)>
+where
+    T: 'a + Float,
+    I: IntoIterator<Item = &'a Angle<T>>,
+{
+    let stats = checked_stats(angles);
+    stats.mean().map(|mu| (mu, stats.count()))
+}
+
+/// Compute the weighted circular mean of a list of angles paired with their
+/// weights, by averaging the unit vectors of the angles scaled by their
+/// weights. Return the normalized mean in radians.
+///
+/// Returns `None` if the list is empty, any weight is negative or NaN, the
+/// total weight is zero, or the weighted unit vectors cancel out to a mean
+/// resultant length below √ε of `T`, about 1.5e-8 for `f64`, where the
+/// direction of the mean is meaningless.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let readings = [(Degrees(350.0f64), 1.0), (Degrees(10.0), 1.0), (Degrees(90.0), 0.0)];
+///
+/// let mu = weighted_mean_angle(readings.iter().copied()).unwrap();
+/// assert!(mu.min_dist(Degrees(0.0)).in_degrees() < 1.0e-10);
+///
+/// let opposite = [(Degrees(0.0f64), 1.0), (Degrees(180.0), 1.0)];
+/// assert_eq!(weighted_mean_angle(opposite.iter().copied()), None);
+/// ```
+pub fn weighted_mean_angle<T, I>(pairs: I) -> Option<Angle<T>>
+where
+    T: Float,
+    I: IntoIterator<Item = (Angle<T>, T)>,
+{
+    let mut stats = CircularStats::new();
+    for (angle, weight) in pairs {
+        stats.push_weighted(angle, weight);
+        if stats.skipped() > 0 {
+            return None;
+        }
+    }
+    stats.mean()
+}
+
+/// Compute the circular median of a list of angles, the direction minimizing
+/// the sum of the [`min_dist`](enum.Angle.html#method.min_dist) to all
+/// angles. Return the normalized median in radians, or `None` if the list is
+/// empty or contains an angle which is NaN or infinite.
+///
+/// The median is searched among the angles themselves, taking O(n²) time.
+/// Where several angles minimize the sum, the one with the smallest
+/// normalized value wins. For an even number of angles, the sum is usually
+/// minimized by a whole arc between two central angles, and the median is
+/// the midpoint of the shorter arc between the winner and the nearest other
+/// angle minimizing the sum, again preferring the smallest normalized value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let headings = [Degrees(350.0f64), Degrees(10.0)];
+/// let median = median_angle(&headings).unwrap();
+/// assert!(median.min_dist(Degrees(0.0)).in_degrees() < 1.0e-10);
+///
+/// // An outlier does not pull the median away.
+/// let headings = [Degrees(5.0f64), Degrees(10.0), Degrees(15.0), Degrees(100.0)];
+/// assert!((median_angle(&headings).unwrap().in_degrees() - 12.5).abs() < 1.0e-10);
+/// ```
+pub fn median_angle<T: Float>(angles: &[Angle<T>]) -> Option<Angle<T>> {
+    if angles.is_empty() || angles.iter().any(|angle| !angle.is_finite()) {
+        return None;
+    }
+
+    let cost = |theta: Angle<T>| {
+        angles.iter().fold(T::zero(), |sum, &angle| {
+            sum + theta.min_dist(angle).in_radians()
+        })
+    };
+    let candidates = || {
+        angles
+            .iter()
+            .map(|angle| Radians(angle.in_radians()).normalized())
+    };
+
+    let n: T = cast(angles.len()).unwrap();
+    let tolerance = T::epsilon() * n * cast(16.0).unwrap();
+    let min_cost = candidates().map(cost).fold(T::infinity(), T::min);
+    let is_minimal = |theta: Angle<T>| cost(theta) <= min_cost + tolerance;
+
+    let first = candidates().filter(|&theta| is_minimal(theta)).fold(
+        None,
+        |first: Option<Angle<T>>, theta| match first {
+            Some(first) if first.in_radians() <= theta.in_radians() => Some(first),
+            _ => Some(theta),
+        },
+    )?;
+    if angles.len() % 2 == 1 {
+        return Some(first);
+    }
+
+    let nearest = candidates()
+        .filter(|&theta| first.min_dist(theta).in_radians() > tolerance && is_minimal(theta))
+        .fold(None, |nearest: Option<Angle<T>>, theta| match nearest {
+            Some(nearest)
+                if (first.min_dist(nearest), nearest.in_radians())
+                    <= (first.min_dist(theta), theta.in_radians()) =>
+            {
+                Some(nearest)
+            }
+            _ => Some(theta),
+        });
+    let two: T = cast(2.0).unwrap();
+    match nearest {
+        Some(other) => {
+            let offset = wrap_signed(other.in_radians() - first.in_radians());
+            let midpoint = Radians(first.in_radians() + offset / two).normalized();
+            Some(if is_minimal(midpoint) {
+                midpoint
+            } else {
+                first
+            })
+        }
+        None => Some(first),
+    }
+}
+
+/// Compute the circular variance of the finite angles in a list as
+/// [`circular_variance`](fn.circular_variance.html) does, skipping angles
+/// which are NaN or infinite. Return the variance together with the number
+/// of angles it was computed from, or `None` if none of them were finite.
+#[inline]
+pub fn circular_variance_ignore_nan<'a, T, I>(angles: I) -> Option<(T, usize)>
+where
+    T: 'a + Float,
+    I: IntoIterator<Item = &'a Angle<T>>,
+{
+    let stats = checked_stats(angles);
+    if stats.count() == 0 {
+        None
+    } else {
+        Some((stats.variance(), stats.count()))
+    }
+}
+
+/// Compute the total signed rotation along a sequence of angles, summing the
+/// shortest signed difference between each consecutive pair. Counter-clockwise
+/// turns are positive.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let headings = [Degrees(350.0f64), Degrees(10.0), Degrees(5.0)];
+/// assert!((turning_sum(&headings).in_degrees() - 15.0).abs() < 1.0e-10);
+/// ```
+#[inline]
+pub fn turning_sum<'a, T, I>(angles: I) -> Angle<T>
+where
+    T: 'a + Float,
+    I: IntoIterator<Item = &'a Angle<T>>,
+{
+    turning(angles)
+}
+
+/// Compute the phase-locking value of two equally sampled series of phases,
+/// the length of the mean of the unit vectors of their pairwise differences.
+/// Return value is in the range of [0, 1], where 1 means the phases differ by
+/// a constant, or `None` if the series are empty or differ in length.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let a = [Degrees(0.0f64), Degrees(90.0), Degrees(180.0)];
+/// let b = [Degrees(330.0f64), Degrees(60.0), Degrees(150.0)];
+/// assert!((phase_locking_value(&a, &b).unwrap() - 1.0).abs() < 1.0e-10);
+/// ```
+#[inline]
+pub fn phase_locking_value<T: Float>(a: &[Angle<T>], b: &[Angle<T>]) -> Option<T> {
+    phase_difference_stats(a, b).map(|stats| stats.resultant_length())
+}
+
+/// Compute the statistics of the pairwise differences `a[i] - b[i]` of two
+/// equally sampled series of phases, or `None` if the series are empty or
+/// differ in length.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let a = [Degrees(10.0f64), Degrees(100.0), Degrees(190.0)];
+/// let b = [Degrees(350.0f64), Degrees(80.0), Degrees(170.0)];
+///
+/// let stats = phase_difference_stats(&a, &b).unwrap();
+/// assert!(stats.mean().unwrap().min_dist(Degrees(20.0)).in_degrees() < 1.0e-10);
+/// ```
+pub fn phase_difference_stats<T: Float>(
+    a: &[Angle<T>],
+    b: &[Angle<T>],
+) -> Option<CircularStats<T>> {
+    if a.is_empty() || a.len() != b.len() {
+        return None;
+    }
+
+    let mut stats = CircularStats::new();
+    for (alpha, beta) in a.iter().zip(b) {
+        stats.push(Radians(wrap_signed(alpha.in_radians() - beta.in_radians())));
+    }
+    Some(stats)
+}
+
+/// Compute the circular correlation coefficient of two equally sampled series
+/// of angles, as defined by Jammalamadaka and SenGupta. Return value is in the
+/// range of [-1, 1], where 1 means one series is the other rotated by a
+/// constant angle and -1 means it is the other mirrored.
+///
+/// Returns `None` if the series are empty or differ in length, or if either
+/// series has no defined mean or no spread about its mean.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let a = [Degrees(10.0f64), Degrees(40.0), Degrees(20.0), Degrees(70.0)];
+/// let b = [Degrees(100.0f64), Degrees(130.0), Degrees(110.0), Degrees(160.0)];
+/// assert!((circular_correlation(&a, &b).unwrap() - 1.0).abs() < 1.0e-10);
+/// ```
+pub fn circular_correlation<T: Float>(a: &[Angle<T>], b: &[Angle<T>]) -> Option<T> {
+    if a.is_empty() || a.len() != b.len() {
+        return None;
+    }
+
+    let (mean_a, mean_b) = (mean(a)?.in_radians(), mean(b)?.in_radians());
+    let (mut sab, mut saa, mut sbb) = (T::zero(), T::zero(), T::zero());
+    for (alpha, beta) in a.iter().zip(b) {
+        let x = (alpha.in_radians() - mean_a).sin();
+        let y = (beta.in_radians() - mean_b).sin();
+        sab = sab + x * y;
+        saa = saa + x * x;
+        sbb = sbb + y * y;
+    }
+
+    let denominator = (saa * sbb).sqrt();
+    if denominator > T::zero() {
+        Some((sab / denominator).max(-T::one()).min(T::one()))
+    } else {
+        None
+    }
+}
+
+/// Compute the circular autocorrelation of a series of angles for the lags
+/// from 0 up to `max_lag`, the [`circular_correlation`](fn.circular_correlation.html)
+/// of the series with itself shifted by each lag.
+///
+/// The correlation at lag 0 is exactly 1. The output stops before the first
+/// lag leaving fewer than two pairs of angles, or whose correlation is
+/// undefined, so it never contains NaN. An empty series gives an empty
+/// output.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// // A heading weaving from side to side every four samples.
+/// let headings: Vec<_> = (0..40).map(|i| Degrees([0.0f64, 30.0, 0.0, -30.0][i % 4])).collect();
+/// let r = circular_autocorrelation(&headings, 4);
+///
+/// assert_eq!(r[0], 1.0);
+/// assert!(r[2] < -0.99 && r[4] > 0.99);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn circular_autocorrelation<T: Float>(angles: &[Angle<T>], max_lag: usize) -> Vec<T> {
+    let mut r = Vec::new();
+    if angles.is_empty() {
+        return r;
+    }
+
+    r.push(T::one());
+    for lag in 1..=max_lag.min(angles.len().saturating_sub(2)) {
+        let n = angles.len() - lag;
+        match circular_correlation(&angles[..n], &angles[lag..]) {
+            Some(rho) => r.push(rho),
+            None => break,
+        }
+    }
+    r
+}
+
+/// Statistics of angles treated as directions on the unit circle, accumulated
+/// one angle at a time from the sum of their unit vectors.
+///
+/// The statistics take constant memory however many angles are added, so
+/// they suit streams too large to collect. Statistics accumulated separately,
+/// for example on several threads, can be combined with
+/// [`merge`](#method.merge).
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let mut stats = CircularStats::new();
+/// stats.push(Degrees(350.0f64));
+/// stats.push(Degrees(10.0));
+///
+/// assert_eq!(stats.count(), 2);
+/// assert!(stats.mean().unwrap().min_dist(Degrees(0.0)).in_degrees() < 1.0e-10);
+/// assert!(stats.variance() < 0.1);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CircularStats<T = f64> {
+    x: T,
+    y: T,
+    weight: T,
+    count: usize,
+    skipped: usize,
+}
+
+impl<T: Float> CircularStats<T> {
+    /// Create empty statistics.
+    #[inline]
+    pub fn new() -> CircularStats<T> {
+        CircularStats {
+            x: T::zero(),
+            y: T::zero(),
+            weight: T::zero(),
+            count: 0,
+            skipped: 0,
+        }
+    }
+
+    /// Add an angle to the statistics.
+    #[inline]
+    pub fn push(&mut self, angle: Angle<T>) {
+        let (sin, cos) = angle.sin_cos();
+        self.x = self.x + cos;
+        self.y = self.y + sin;
+        self.weight = self.weight + T::one();
+        self.count += 1;
+    }
+
+    /// Add an angle to the statistics with the given weight, as
+    /// [`weighted_mean_angle`](../fn.weighted_mean_angle.html) does. An angle
+    /// with a negative or NaN weight is counted as skipped instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let mut stats = CircularStats::new();
+    /// stats.push_weighted(Degrees(0.0f64), 1.0);
+    /// stats.push_weighted(Degrees(90.0), 3.0);
+    ///
+    /// assert!((stats.mean().unwrap().in_radians() - 3.0f64.atan()).abs() < 1.0e-12);
+    /// ```
+    #[inline]
+    pub fn push_weighted(&mut self, angle: Angle<T>, weight: T) {
+        if weight.is_nan() || weight < T::zero() {
+            self.skipped += 1;
+        } else {
+            let (sin, cos) = angle.sin_cos();
+            self.x = self.x + weight * cos;
+            self.y = self.y + weight * sin;
+            self.weight = self.weight + weight;
+            self.count += 1;
+        }
+    }
+
+    /// Add the statistics of `other` to these, giving the statistics of the
+    /// angles added to either.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let mut left: CircularStats = [Degrees(350.0), Degrees(355.0)].iter().collect();
+    /// let right: CircularStats = [Degrees(5.0), Degrees(10.0)].iter().collect();
+    /// left.merge(right);
+    ///
+    /// assert_eq!(left.count(), 4);
+    /// assert!(left.mean().unwrap().min_dist(Degrees(0.0)).in_degrees() < 1.0e-10);
+    /// ```
+    #[inline]
+    pub fn merge(&mut self, other: CircularStats<T>) {
+        self.x = self.x + other.x;
+        self.y = self.y + other.y;
+        self.weight = self.weight + other.weight;
+        self.count += other.count;
+        self.skipped += other.skipped;
+    }
+
+    /// Add an angle to the statistics if it is finite, or count it as
+    /// skipped if it is NaN or infinite. Unlike [`push`](#method.push), this
+    /// keeps a single bad reading from making every statistic NaN.
+    #[inline]
+    pub fn push_checked(&mut self, angle: Angle<T>) {
+        if angle.value().is_finite() {
+            self.push(angle);
+        } else {
+            self.skipped += 1;
+        }
+    }
+
+    /// Yield the number of angles added.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Yield the number of angles skipped by
+    /// [`push_checked`](#method.push_checked) and
+    /// [`push_weighted`](#method.push_weighted).
+    #[inline]
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Compute the normalized circular mean of the angles, or `None` if no
+    /// angles were added or the mean is undefined as for
+    /// [`mean_angle`](../fn.mean_angle.html).
+    #[inline]
+    pub fn mean(&self) -> Option<Angle<T>> {
+        mean_of_resultant(self.x, self.y, self.weight)
+    }
+
+    /// Compute the length of the mean of the unit vectors of the angles,
+    /// weighted by their weights. Return value is in the range of [0, 1],
+    /// where 1 means all angles are identical, and is 0 if no angles or only
+    /// angles without weight were added.
+    #[inline]
+    pub fn resultant_length(&self) -> T {
+        resultant_length(self.x, self.y, self.weight)
+    }
+
+    /// Compute the circular variance of the angles, one minus the
+    /// [`resultant_length`](#method.resultant_length).
+    #[inline]
+    pub fn variance(&self) -> T {
+        T::one() - self.resultant_length()
+    }
+
+    /// Compute the circular standard deviation of the angles in radians,
+    /// √(-2 ln R) for the resultant length R.
+    #[inline]
+    pub fn std_dev(&self) -> T {
+        let two = T::one() + T::one();
+        (-two * self.resultant_length().ln()).sqrt()
+    }
+}
+
+impl<T: Float> Default for CircularStats<T> {
+    #[inline]
+    fn default() -> Self {
+        CircularStats::new()
+    }
+}
+
+impl<T: Float, A: Borrow<Angle<T>>> Extend<A> for CircularStats<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = A>>(&mut self, angles: I) {
+        for angle in angles {
+            self.push(*angle.borrow());
+        }
+    }
+}
+
+impl<T: Float, A: Borrow<Angle<T>>> FromIterator<A> for CircularStats<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = A>>(angles: I) -> Self {
+        let mut stats = CircularStats::new();
+        stats.extend(angles);
+        stats
+    }
+}
+
+// The functions below are the implementations shared by the public functions
+// and the extension traits, which also accept iterators of owned angles.
+
+/// See [`mean_angle`](../fn.mean_angle.html).
+#[inline]
+pub(crate) fn mean<T, I>(angles: I) -> Option<Angle<T>>
+where
+    T: Float,
+    I: IntoIterator,
+    I::Item: Borrow<Angle<T>>,
+{
+    let (x, y, n) = resultant(angles);
+    mean_of_resultant(x, y, cast(n).unwrap())
+}
+
+/// Compute the normalized direction of a sum of unit vectors scaled by
+/// weights adding up to `weight`, or `None` if it is undefined.
+#[inline]
+fn mean_of_resultant<T: Float>(x: T, y: T, weight: T) -> Option<Angle<T>> {
+    if weight > T::zero() && !is_degenerate((x / weight).hypot(y / weight)) {
+        Some(Radians(y.atan2(x)).normalized())
+    } else {
+        None
+    }
+}
+
+/// Compute the length of a sum of unit vectors scaled by weights adding up
+/// to `weight`, divided by that weight and clamped into [0, 1], or 0 if there
+/// is no weight. NaN is passed through rather than clamped.
+#[inline]
+fn resultant_length<T: Float>(x: T, y: T, weight: T) -> T {
+    if weight > T::zero() {
+        let r = (x / weight).hypot(y / weight);
+        if r > T::one() {
+            T::one()
+        } else {
+            r
+        }
+    } else {
+        T::zero()
+    }
+}
+
+/// Accumulate the statistics of the finite angles, skipping the others.
+#[inline]
+fn checked_stats<'a, T, I>(angles: I) -> CircularStats<T>
+where
+    T: 'a + Float,
+    I: IntoIterator<Item = &'a Angle<T>>,
+{
+    let mut stats = CircularStats::new();
+    for &angle in angles {
+        stats.push_checked(angle);
+    }
+    stats
+}
+
+/// See [`circular_variance`](../fn.circular_variance.html).
+#[inline]
+pub(crate) fn variance<T, I>(angles: I) -> T
+where
+    T: Float,
+    I: IntoIterator,
+    I::Item: Borrow<Angle<T>>,
+{
+    let (x, y, n) = resultant(angles);
+    let n: T = cast(n).unwrap();
+    T::one() - (x / n).hypot(y / n)
+}
+
+/// See [`turning_sum`](../fn.turning_sum.html).
+#[inline]
+pub(crate) fn turning<T, I>(angles: I) -> Angle<T>
+where
+    T: Float,
+    I: IntoIterator,
+    I::Item: Borrow<Angle<T>>,
+{
+    let mut angles = angles.into_iter();
+    let mut sum = T::zero();
+
+    if let Some(first) = angles.next() {
+        let mut prev = first.borrow().in_radians();
+        for angle in angles {
+            let angle = angle.borrow().in_radians();
+            sum = sum + wrap_signed(angle - prev);
+            prev = angle;
+        }
+    }
+
+    Radians(sum)
+}
+
+/// Check whether a mean resultant length is too short for the direction of
+/// the mean to be meaningful, or NaN.
+#[inline]
+pub(crate) fn is_degenerate<T: Float>(resultant_length: T) -> bool {
+    resultant_length.is_nan() || resultant_length <= T::epsilon().sqrt()
+}
+
+/// Sum the unit vectors of a list of angles, returning the sums of their
+/// cosines and sines and the number of angles.
+#[inline]
+pub(crate) fn resultant<T, I>(angles: I) -> (T, T, usize)
+where
+    T: Float,
+    I: IntoIterator,
+    I::Item: Borrow<Angle<T>>,
+{
+    let mut x = T::zero();
+    let mut y = T::zero();
+    let mut n = 0;
+
+    for angle in angles {
+        let (sin, cos) = angle.borrow().sin_cos();
+
+        x = x + cos;
+        y = y + sin;
+        n += 1;
+    }
+
+    (x, y, n)
+}
+
+/// Wrap a value in radians into the range of (-π, π].
+#[inline]
+pub(crate) fn wrap_signed<T: Float>(v: T) -> T {
+    let pi: T = cast(PI).unwrap();
+    let two_pi: T = cast(2.0 * PI).unwrap();
+
+    let v = v % two_pi;
+    if v > pi {
+        v - two_pi
+    } else if v <= -pi {
+        v + two_pi
+    } else {
+        v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::*;
+
+    #[test]
+    fn test_circular_variance() {
+        assert_that!(
+            circular_variance(&[Degrees(45.0), Degrees(45.0)]),
+            close_to(0.0, 0.000001)
+        );
+        assert_that!(
+            circular_variance(&[Degrees(0.0), Degrees(180.0)]),
+            close_to(1.0, 0.000001)
+        );
+        assert_that!(
+            circular_variance(&[Degrees(0.0), Degrees(90.0)]),
+            close_to(1.0 - 0.5f64.sqrt(), 0.000001)
+        );
+    }
+
+    #[test]
+    fn test_mean_resultant_length() {
+        assert_eq!(mean_resultant_length::<f64, _>(&[]), 0.0);
+        assert_eq!(mean_and_resultant::<f64, _>(&[]), (None, 0.0));
+        assert!(mean_resultant_length(&[Degrees(f64::NAN)]).is_nan());
+
+        // Identical angles never exceed one despite rounding.
+        for &angle in &[Degrees(10.0), Radians(1.0), Degrees(-123.4), Radians(7.0)] {
+            let r = mean_resultant_length(&[angle; 7]);
+            assert!(r <= 1.0);
+            assert_that!(r, close_to(1.0, 1.0e-12));
+        }
+
+        // Evenly spread angles cancel out.
+        let spread: Vec<Angle> = (0..36).map(|i| Degrees(i as f64 * 10.0 + 3.0)).collect();
+        assert_that!(mean_resultant_length(&spread), close_to(0.0, 1.0e-12));
+        let (mu, r) = mean_and_resultant(&spread);
+        assert_eq!(mu, None);
+        assert_that!(r, close_to(0.0, 1.0e-12));
+
+        // Reference values from the norm and direction of the mean unit
+        // vector, the definition scipy.stats.directional_stats uses.
+        let angles: Vec<Angle> = [43.0, 45.0, 52.0, 61.0, 75.0, 88.0, 88.0, 279.0, 357.0]
+            .iter()
+            .map(|&v| Degrees(v))
+            .collect();
+        let (mu, r) = mean_and_resultant(&angles);
+        assert_that!(r, close_to(0.710_990_998_863_177_3, 1.0e-12));
+        assert_that!(
+            mu.unwrap().in_degrees(),
+            close_to(51.050_177_108_549_97, 1.0e-10)
+        );
+        assert_eq!(mean_resultant_length(&angles), r);
+        assert_eq!(
+            r,
+            angles.iter().collect::<CircularStats>().resultant_length()
+        );
+    }
+
+    #[test]
+    fn test_rayleigh_test() {
+        assert_eq!(rayleigh_test::<f64, _>(&[]), None);
+        assert_eq!(rayleigh_test(&[Degrees(10.0)]), None);
+
+        // Ten angles split evenly either side of zero with R = 0.7.
+        let theta = 0.7f64.acos();
+        let angles: Vec<Angle> = (0..10)
+            .map(|i| Radians(if i % 2 == 0 { theta } else { -theta }))
+            .collect();
+        let result = rayleigh_test(&angles).unwrap();
+        assert_eq!(result.n, 10);
+        assert_that!(result.z, close_to(4.9, 1.0e-12));
+        assert!(result.p_value > 0.001 && result.p_value < 0.01);
+
+        // Evenly spread angles are as uniform as can be.
+        let spread: Vec<Angle> = (0..12).map(|i| Degrees(i as f64 * 30.0)).collect();
+        let result = rayleigh_test(&spread).unwrap();
+        assert_that!(result.z, close_to(0.0, 1.0e-12));
+        assert_that!(result.p_value, close_to(1.0, 1.0e-12));
+
+        // Identical angles leave no doubt, and the p-value stays in range.
+        let result = rayleigh_test(&[Degrees(30.0f32); 3]).unwrap();
+        assert_that!(result.z, close_to(3.0, 1.0e-5));
+        assert!(result.p_value >= 0.0 && result.p_value <= 1.0);
+
+        // Non-finite angles have no direction to test.
+        for &bad in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let angles = [Degrees(10.0), Degrees(bad), Degrees(20.0)];
+            assert_eq!(rayleigh_test(&angles), None);
+        }
+    }
+
+    #[test]
+    fn test_rayleigh_test_critical_values() {
+        // Critical values of Z from the table in Zar, Biostatistical
+        // Analysis, as (n, Z at p = 0.05, Z at p = 0.01).
+        let table = [(10, 2.910, 4.290), (30, 2.968, 4.509), (100, 2.986, 4.574)];
+        for &(n, z05, z01) in &table {
+            for &(z, p) in &[(z05, 0.05), (z01, 0.01)] {
+                // Angles split evenly either side of zero with this Z.
+                let theta = (z / n as f64).sqrt().acos();
+                let angles: Vec<Angle> = (0..n)
+                    .map(|i| Radians(if i % 2 == 0 { theta } else { -theta }))
+                    .collect();
+
+                let result = rayleigh_test(&angles).unwrap();
+                assert_eq!(result.n, n);
+                assert_that!(result.z, close_to(z, 1.0e-9));
+                assert_that!(result.p_value, close_to(p, 1.0e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn test_circular_stats() {
+        let stats = CircularStats::<f64>::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.resultant_length(), 0.0);
+
+        let angles = [Degrees(270.0), Degrees(360.0), Degrees(90.0), Degrees(20.0)];
+        let stats: CircularStats = angles.iter().collect();
+        assert_eq!(stats.count(), 4);
+        assert_that!(
+            stats
+                .mean()
+                .unwrap()
+                .min_dist(mean_angle(&angles).unwrap())
+                .in_degrees(),
+            close_to(0.0, 1.0e-10)
+        );
+        assert_that!(
+            stats.variance(),
+            close_to(circular_variance(&angles), 1.0e-12)
+        );
+
+        let stats: CircularStats = [Degrees(10.0); 3].iter().collect();
+        assert_that!(stats.resultant_length(), close_to(1.0, 1.0e-12));
+        assert_that!(stats.std_dev(), close_to(0.0, 1.0e-6));
+    }
+
+    #[test]
+    fn test_circular_stats_matches_mean_angle() {
+        let mut rng = StdRng::seed_from_u64(0x616363);
+        let angles: Vec<Angle> = (0..1000)
+            .map(|_| Degrees(rng.gen_range(-60.0..150.0)))
+            .collect();
+
+        let mut stats = CircularStats::new();
+        for &angle in &angles {
+            stats.push(angle);
+        }
+        assert_eq!(stats.mean(), mean_angle(&angles));
+        assert_eq!(stats.variance(), circular_variance(&angles));
+
+        let mut weighted = CircularStats::new();
+        for &angle in &angles {
+            weighted.push_weighted(angle, 1.0);
+        }
+        assert_eq!(weighted, stats);
+    }
+
+    #[test]
+    fn test_circular_stats_merge() {
+        let mut rng = StdRng::seed_from_u64(0x6d7267);
+        let pairs: Vec<(Angle, f64)> = (0..1000)
+            .map(|_| (Radians(rng.gen_range(0.0..2.0)), rng.gen_range(0.0..5.0)))
+            .collect();
+
+        let mut whole = CircularStats::new();
+        let mut left = CircularStats::new();
+        let mut right = CircularStats::new();
+        for (i, &(angle, weight)) in pairs.iter().enumerate() {
+            whole.push_weighted(angle, weight);
+            if i < 400 {
+                left.push_weighted(angle, weight);
+            } else {
+                right.push_weighted(angle, weight);
+            }
+        }
+        left.merge(right);
+        assert_eq!(left.count(), whole.count());
+        assert_that!(
+            left.mean()
+                .unwrap()
+                .min_dist(whole.mean().unwrap())
+                .in_radians(),
+            close_to(0.0, 1.0e-12)
+        );
+        assert_that!(
+            left.resultant_length(),
+            close_to(whole.resultant_length(), 1.0e-12)
+        );
+        assert_that!(
+            left.mean().unwrap().in_radians(),
+            close_to(weighted_mean_angle(pairs).unwrap().in_radians(), 1.0e-12)
+        );
+
+        let mut empty = CircularStats::new();
+        empty.merge(whole);
+        assert_eq!(empty, whole);
+    }
+
+    #[test]
+    fn test_circular_stats_push_weighted() {
+        let mut stats = CircularStats::new();
+        stats.push_weighted(Degrees(10.0), 0.0);
+        assert_eq!(stats.count(), 1);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.resultant_length(), 0.0);
+
+        stats.push_weighted(Degrees(20.0), -1.0);
+        stats.push_weighted(Degrees(30.0), f64::NAN);
+        assert_eq!(stats.count(), 1);
+        assert_eq!(stats.skipped(), 2);
+
+        stats.push_weighted(Degrees(40.0), 0.5);
+        assert_that!(stats.mean().unwrap().in_degrees(), close_to(40.0, 1.0e-10));
+        assert_that!(stats.resultant_length(), close_to(1.0, 1.0e-12));
+
+        // The statistics can be accumulated on other threads.
+        fn assert_send<S: Send>(_: &S) {}
+        assert_send(&stats);
+        let handle = std::thread::spawn(move || {
+            stats.push(Degrees(40.0));
+            stats
+        });
+        assert_eq!(handle.join().unwrap().count(), 3);
+    }
+
+    #[test]
+    fn test_phase_locking_value() {
+        let a: Vec<_> = (0..100).map(|i| Radians(i as f64 * 0.37)).collect();
+        assert_that!(phase_locking_value(&a, &a).unwrap(), close_to(1.0, 1.0e-12));
+
+        let b: Vec<_> = a
+            .iter()
+            .map(|&alpha| alpha - AngleDelta::new(Degrees(30.0)))
+            .collect();
+        assert_that!(phase_locking_value(&a, &b).unwrap(), close_to(1.0, 1.0e-12));
+        let stats = phase_difference_stats(&a, &b).unwrap();
+        assert_eq!(stats.count(), 100);
+        assert_that!(
+            stats.mean().unwrap().min_dist(Degrees(30.0)).in_degrees(),
+            close_to(0.0, 1.0e-9)
+        );
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut random = || -> Vec<Angle> {
+            (0..10_000)
+                .map(|_| Radians(rng.gen_range(0.0..2.0 * PI)))
+                .collect()
+        };
+        let (a, b) = (random(), random());
+        assert!(phase_locking_value(&a, &b).unwrap() < 0.05);
+
+        assert_eq!(phase_locking_value(&a[..2], &b[..3]), None);
+        assert_eq!(phase_locking_value::<f64>(&[], &[]), None);
+        assert!(phase_difference_stats(&a[..1], &b[..0]).is_none());
+    }
+
+    #[test]
+    fn test_turning_sum() {
+        assert_that!(
+            turning_sum(&[Degrees(350.0), Degrees(10.0), Degrees(30.0)]).in_degrees(),
+            close_to(40.0, 0.000001)
+        );
+        assert_that!(
+            turning_sum(&[Degrees(10.0), Degrees(350.0), Degrees(180.0)]).in_degrees(),
+            close_to(-190.0, 0.000001)
+        );
+
+        // Four quarter turns make a full revolution.
+        let quarters = [
+            Degrees(0.0),
+            Degrees(90.0),
+            Degrees(180.0),
+            Degrees(270.0),
+            Degrees(0.0),
+        ];
+        assert_that!(
+            turning_sum(&quarters).in_degrees(),
+            close_to(360.0, 0.000001)
+        );
+
+        assert_eq!(turning_sum(&[Degrees(10.0)]), Radians(0.0));
+        assert_eq!(turning_sum(&[] as &[Angle]), Radians(0.0));
+    }
+
+    #[test]
+    fn test_circular_correlation() {
+        let a = [Degrees(350.0), Degrees(20.0), Degrees(5.0), Degrees(40.0)];
+        let rotated: Vec<_> = a
+            .iter()
+            .map(|&alpha| alpha + AngleDelta::new(Degrees(123.0)))
+            .collect();
+        let mirrored: Vec<_> = a
+            .iter()
+            .map(|&alpha| alpha.delta_to(Degrees(50.0)).angle())
+            .collect();
+        assert_that!(
+            circular_correlation(&a, &rotated).unwrap(),
+            close_to(1.0, 1.0e-12)
+        );
+        assert_that!(
+            circular_correlation(&a, &mirrored).unwrap(),
+            close_to(-1.0, 1.0e-12)
+        );
+
+        assert_eq!(circular_correlation(&a, &a[..3]), None);
+        assert_eq!(circular_correlation::<f64>(&[], &[]), None);
+        assert_eq!(circular_correlation(&a, &[Degrees(7.0); 4]), None);
+    }
+
+    #[test]
+    fn test_circular_autocorrelation() {
+        // A heading completing a full turn every 50 samples, turning faster
+        // through some directions than others.
+        let mut rng = StdRng::seed_from_u64(50);
+        let headings: Vec<_> = (0..500)
+            .map(|i| {
+                let phase = 2.0 * PI * i as f64 / 50.0;
+                Radians(phase + 0.8 * phase.sin() + rng.gen_range(-0.05..0.05))
+            })
+            .collect();
+        let r = circular_autocorrelation(&headings, 80);
+        assert_eq!(r.len(), 81);
+        assert_eq!(r[0], 1.0);
+        assert!(r[50] > 0.95);
+        for lag in (1..=80).filter(|lag| !(45..=55).contains(lag)) {
+            assert!(r[lag] < r[50], "lag {}", lag);
+        }
+
+        let noise: Vec<_> = (0..2000).map(|_| Radians(rng.gen_range(-PI..PI))).collect();
+        let r = circular_autocorrelation(&noise, 20);
+        assert_eq!(r[0], 1.0);
+        assert!(r[1..].iter().all(|rho| rho.abs() < 0.1));
+    }
+
+    #[test]
+    fn test_circular_autocorrelation_truncates() {
+        let angles = [Degrees(0.0), Degrees(10.0), Degrees(30.0), Degrees(20.0)];
+        assert_eq!(circular_autocorrelation(&angles, 10).len(), 3);
+        assert_eq!(circular_autocorrelation(&angles[..1], 10), [1.0]);
+        assert!(circular_autocorrelation::<f64>(&[], 10).is_empty());
+
+        // The correlation of a constant stretch is undefined.
+        let angles = [Degrees(0.0), Degrees(0.0), Degrees(0.0), Degrees(10.0)];
+        assert_eq!(circular_autocorrelation(&angles, 2), [1.0]);
+    }
+
+    #[test]
+    fn test_weighted_mean_angle() {
+        let angles = [Degrees(270.0), Degrees(360.0), Degrees(90.0), Degrees(20.0)];
+        for &w in &[1.0, 0.25, 1.0e6] {
+            let mu = weighted_mean_angle(angles.iter().map(|&a| (a, w))).unwrap();
+            assert!(matches!(mu, Radians(_)));
+            assert_that!(
+                mu.min_dist(mean_angle(&angles).unwrap()).in_degrees(),
+                close_to(0.0, 1.0e-10)
+            );
+        }
+
+        // A sample without weight does not move the mean.
+        let pairs = [
+            (Degrees(10.0), 2.0),
+            (Degrees(200.0), 0.0),
+            (Degrees(50.0), 2.0),
+        ];
+        let mu = weighted_mean_angle(pairs.iter().copied()).unwrap();
+        assert_that!(mu.in_degrees(), close_to(30.0, 1.0e-10));
+
+        // Weights pull the mean toward the heavier sample.
+        let pairs = [(Degrees(0.0), 1.0), (Degrees(90.0), 3.0)];
+        let mu = weighted_mean_angle(pairs.iter().copied()).unwrap();
+        assert_that!(mu.in_radians(), close_to(3.0f64.atan(), 1.0e-12));
+    }
+
+    #[test]
+    fn test_weighted_mean_angle_degenerate() {
+        let none = |pairs: &[(Angle, f64)]| weighted_mean_angle(pairs.iter().copied()).is_none();
+        assert!(none(&[]));
+        assert!(none(&[(Degrees(0.0), 1.0), (Degrees(180.0), 1.0)]));
+        assert!(none(&[(Radians(0.3), 2.5), (Radians(0.3 + PI), 2.5)]));
+        assert!(none(&[(Degrees(0.0), 0.0), (Degrees(90.0), 0.0)]));
+        assert!(none(&[(Degrees(0.0), 1.0), (Degrees(90.0), -1.0)]));
+        assert!(none(&[(Degrees(0.0), 1.0), (Degrees(90.0), f64::NAN)]));
+        assert!(none(&[(Degrees(f64::NAN), 1.0)]));
+        assert!(!none(&[
+            (Degrees(0.0), 1.0),
+            (Degrees(180.0), 1.0 + 1.0e-6)
+        ]));
+    }
+
+    /// The minimum of the sum of distances to all angles, searched on a fine
+    /// grid.
+    fn brute_force_median_cost(angles: &[Angle]) -> f64 {
+        (0..36_000)
+            .map(|i| {
+                let theta = Degrees(i as f64 / 100.0);
+                angles
+                    .iter()
+                    .map(|&a| theta.min_dist(a).in_radians())
+                    .sum::<f64>()
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_median_angle() {
+        let median = median_angle(&[Degrees(350.0), Degrees(10.0)]).unwrap();
+        assert!(matches!(median, Radians(_)));
+        assert_that!(
+            median.min_dist(Degrees(0.0)).in_degrees(),
+            close_to(0.0, 1.0e-10)
+        );
+
+        let median = median_angle(&[Degrees(-10.0), Degrees(730.0)]).unwrap();
+        assert_that!(
+            median.min_dist(Degrees(0.0)).in_degrees(),
+            close_to(0.0, 1.0e-10)
+        );
+
+        let median = median_angle(&[Degrees(350.0), Degrees(10.0), Degrees(20.0)]).unwrap();
+        assert_that!(median.in_degrees(), close_to(10.0, 1.0e-10));
+        assert_eq!(median_angle(&[Degrees(123.0)]), Some(Degrees(123.0)));
+
+        // Ties are broken toward the smallest normalized angle.
+        let square = [Degrees(270.0), Degrees(0.0), Degrees(180.0), Degrees(90.0)];
+        let median = median_angle(&square).unwrap();
+        assert_that!(median.in_degrees(), close_to(45.0, 1.0e-10));
+        let median = median_angle(&square[1..]).unwrap();
+        assert_that!(median.in_degrees(), close_to(90.0, 1.0e-10));
+        let median = median_angle(&[Degrees(0.0), Degrees(120.0), Degrees(240.0)]).unwrap();
+        assert_that!(median.in_degrees(), close_to(0.0, 1.0e-10));
+
+        assert_eq!(median_angle::<f64>(&[]), None);
+        assert_eq!(median_angle(&[Degrees(0.0), Degrees(f64::NAN)]), None);
+        assert_eq!(median_angle(&[Degrees(f64::INFINITY)]), None);
+    }
+
+    #[test]
+    fn test_median_angle_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0x6d6564);
+        for n in 1..=12 {
+            for _ in 0..3 {
+                let center = rng.gen_range(0.0..360.0);
+                let angles: Vec<Angle> = (0..n)
+                    .map(|_| {
+                        Degrees(
+                            center
+                                + rng.gen_range(-90.0..90.0)
+                                + 360.0 * rng.gen_range(-2..3) as f64,
+                        )
+                    })
+                    .collect();
+                let median = median_angle(&angles).unwrap();
+                let cost: f64 = angles
+                    .iter()
+                    .map(|&a| median.min_dist(a).in_radians())
+                    .sum();
+                assert!(cost <= brute_force_median_cost(&angles) + 1.0e-12);
+                assert!((0.0..2.0 * PI).contains(&median.in_radians()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_ignore_nan() {
+        let angles = [
+            Degrees(f64::NAN),
+            Degrees(350.0),
+            Degrees(20.0),
+            Degrees(f64::INFINITY),
+            Degrees(30.0),
+            Degrees(f64::NAN),
+        ];
+        let finite: Vec<_> = angles
+            .iter()
+            .copied()
+            .filter(|alpha| alpha.value().is_finite())
+            .collect();
+        assert_eq!(mean_angle(&angles), None);
+        assert!(circular_variance(&angles).is_nan());
+
+        let (mu, count) = mean_angle_ignore_nan(&angles).unwrap();
+        assert_eq!(count, 3);
+        assert_that!(
+            mu.min_dist(mean_angle(&finite).unwrap()).in_degrees(),
+            close_to(0.0, 1.0e-10)
+        );
+        let (variance, count) = circular_variance_ignore_nan(&angles).unwrap();
+        assert_eq!(count, 3);
+        assert_that!(variance, close_to(circular_variance(&finite), 1.0e-12));
+
+        let nans = [Radians(f64::NAN); 3];
+        assert_eq!(mean_angle_ignore_nan(&nans), None);
+        assert_eq!(circular_variance_ignore_nan(&nans), None);
+        assert_eq!(mean_angle_ignore_nan(&[] as &[Angle]), None);
+    }
+
+    #[test]
+    fn test_circular_stats_push_checked() {
+        let mut stats = CircularStats::new();
+        for &v in &[10.0, f64::NAN, 20.0, f64::NEG_INFINITY, f64::NAN, 30.0] {
+            stats.push_checked(Degrees(v));
+        }
+        assert_eq!(stats.count(), 3);
+        assert_eq!(stats.skipped(), 3);
+        assert_that!(stats.mean().unwrap().in_degrees(), close_to(20.0, 1.0e-10));
+
+        stats.push(Degrees(f64::NAN));
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.skipped(), 3);
+        assert_eq!(stats.mean(), None);
+    }
+}
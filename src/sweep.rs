@@ -0,0 +1,97 @@
+//! Signed sweeps of the circle.
+
+use num_traits::{cast::NumCast, Num};
+
+use crate::{Angle, Convertible};
+
+/// An arc of the circle sweeping from its start by a signed angle, which is
+/// counter-clockwise when positive and may exceed a full turn.
+///
+/// Unlike [`ArcRange`](struct.ArcRange.html), neither the start nor the sweep
+/// is normalized, which keeps the direction and number of turns of animated
+/// arcs such as progress rings and gauges.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// let arc = SweepArc::new(Degrees(90.0f64), Degrees(-120.0));
+///
+/// assert_eq!(arc.end(), Degrees(-30.0));
+/// assert!(arc.is_clockwise());
+/// ```
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SweepArc<T = f64> {
+    start: Angle<T>,
+    sweep: Angle<T>,
+}
+
+impl<T: Copy + Num + NumCast + PartialOrd> SweepArc<T> {
+    /// Create the arc sweeping from `start` by `sweep`.
+    #[inline]
+    pub fn new(start: Angle<T>, sweep: Angle<T>) -> SweepArc<T> {
+        SweepArc { start, sweep }
+    }
+
+    /// Yield the angle the arc starts at.
+    #[inline]
+    pub fn start(&self) -> Angle<T> {
+        self.start
+    }
+
+    /// Yield the signed angle the arc sweeps through.
+    #[inline]
+    pub fn sweep(&self) -> Angle<T> {
+        self.sweep
+    }
+
+    /// Yield the angle the arc ends at, without normalization, in the unit of
+    /// its start.
+    #[inline]
+    pub fn end(&self) -> Angle<T>
+    where
+        T: Convertible,
+    {
+        let unit = self.start.unit();
+        self.start.map(|v| v + self.sweep.in_unit_of(unit))
+    }
+
+    /// Return `true` if the arc sweeps clockwise, through decreasing angles.
+    #[inline]
+    pub fn is_clockwise(&self) -> bool {
+        self.sweep.value() < T::zero()
+    }
+}
+
+impl<T: Copy + NumCast + PartialEq> PartialEq for SweepArc<T> {
+    #[inline]
+    fn eq(&self, other: &SweepArc<T>) -> bool {
+        self.start == other.start && self.sweep == other.sweep
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_sweep_arc() {
+        let arc = SweepArc::new(Degrees(350.0), Degrees(450.0));
+        assert_eq!(arc.start(), Degrees(350.0));
+        assert_eq!(arc.sweep(), Degrees(450.0));
+        assert_eq!(arc.end(), Degrees(800.0));
+        assert!(!arc.is_clockwise());
+
+        let arc = SweepArc::new(Degrees(10.0), Radians(-PI));
+        assert_that!(arc.end().in_degrees(), close_to(-170.0, 1.0e-12));
+        assert!(matches!(arc.end(), Degrees(_)));
+        assert!(arc.is_clockwise());
+
+        let arc = SweepArc::from(ArcRange::new(Degrees(-10.0), Degrees(20.0)));
+        assert_eq!(arc, SweepArc::new(Degrees(350.0), Degrees(30.0)));
+    }
+}
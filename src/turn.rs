@@ -0,0 +1,168 @@
+//! Trigonometry of angles given as fractions of a turn.
+
+use core::f64::consts::FRAC_PI_2;
+use num_traits::{cast::cast, Float};
+
+use crate::{Angle, Degrees, Radians};
+
+/// Compute the sine of `x` turns, sin(2π·x).
+///
+/// The argument is reduced exactly to within an eighth of a turn of a
+/// multiple of a quarter turn before it is converted to radians, so large
+/// values of `x` keep their full accuracy and multiples of a quarter turn
+/// give exactly 0 or ±1.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// assert_eq!(sin_turn(0.5f64), 0.0);
+/// assert_eq!(sin_turn(1.0e15f64 + 0.25), 1.0);
+/// ```
+#[inline]
+pub fn sin_turn<T: Float>(x: T) -> T {
+    sin_cos_quadrant(x, quarter()).0
+}
+
+/// Compute the cosine of `x` turns, cos(2π·x), with the same accuracy as
+/// [`sin_turn`](fn.sin_turn.html).
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// assert_eq!(cos_turn(0.25f64), 0.0);
+/// assert_eq!(cos_turn(-1.0e15f64), 1.0);
+/// ```
+#[inline]
+pub fn cos_turn<T: Float>(x: T) -> T {
+    sin_cos_quadrant(x, quarter()).1
+}
+
+impl<T: Float> Angle<T> {
+    /// Compute the sine and cosine of the angle, reducing it in the unit it
+    /// is stored in as [`sin_turn`](fn.sin_turn.html) does.
+    ///
+    /// This is exact for the reduction of angles in degrees, so multiples of
+    /// 90° give exactly 0 or ±1 and large angles keep their accuracy. Angles
+    /// in radians cannot be reduced exactly and give the same result as
+    /// [`sin_cos`](#method.sin_cos).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(180.0f64).sin_cos_turn_exactish(), (0.0, -1.0));
+    /// assert_eq!(Degrees(3.6e15f64 + 90.0).sin_cos_turn_exactish().0, 1.0);
+    /// ```
+    #[inline]
+    pub fn sin_cos_turn_exactish(self) -> (T, T) {
+        match self {
+            Degrees(v) => sin_cos_quadrant(v, cast(90).unwrap()),
+            Radians(_) => self.sin_cos(),
+        }
+    }
+}
+
+#[inline]
+fn quarter<T: Float>() -> T {
+    cast(0.25).unwrap()
+}
+
+/// Compute the sine and cosine of `v`, measured in a unit in which a quarter
+/// turn is `quarter`.
+fn sin_cos_quadrant<T: Float>(v: T, quarter: T) -> (T, T) {
+    if !v.is_finite() {
+        return (T::nan(), T::nan());
+    }
+
+    // Both the remainder and the offset from the nearest multiple of a
+    // quarter turn are exact, leaving only the final scaling to round.
+    let four: T = cast(4).unwrap();
+    let r = v % (four * quarter);
+    let k = (r / quarter).round();
+    let theta = (r - k * quarter) * (cast::<f64, T>(FRAC_PI_2).unwrap() / quarter);
+    let (sin, cos) = theta.sin_cos();
+
+    match cast::<T, i32>(k).unwrap().rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_1_SQRT_2, PI};
+    use hamcrest2::{assert_that, close_to, prelude::*};
+    use quickcheck::quickcheck;
+
+    use crate::*;
+
+    #[test]
+    fn test_turn_quadrants() {
+        for i in -8i32..=8 {
+            let x = i as f64 / 4.0;
+            let expected =
+                [(0.0, 1.0), (1.0, 0.0), (0.0, -1.0), (-1.0, 0.0)][i.rem_euclid(4) as usize];
+            assert_eq!((sin_turn(x), cos_turn(x)), expected);
+            assert_eq!(Degrees(i as f64 * 90.0).sin_cos_turn_exactish(), expected);
+        }
+        assert_eq!(sin_turn(1.0e15 + 0.25), 1.0);
+        assert_eq!(cos_turn(1.0e15 + 0.25), 0.0);
+        assert_eq!(sin_turn(-1.0e15 - 0.5), 0.0);
+        assert_eq!(sin_turn(0.25f32), 1.0);
+        assert_eq!(cos_turn(1.0e6f32 + 0.5), -1.0);
+    }
+
+    #[test]
+    fn test_turn_large_arguments() {
+        // References computed to 50 digits.
+        let cases = [
+            (1.0e9 + 0.1, 0.5877853734854867, 0.8090169063230551),
+            (1.0e9 + 0.3, 0.9510566088783399, -0.3090167094330538),
+            (123_456.789, -0.9701265964835402, 0.24259923082166293),
+            (-987_654.321, -0.9021339593783018, -0.431456045659843),
+            (1.0e15 + 0.375, FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+        ];
+        for &(x, sin, cos) in &cases {
+            assert_that!(sin_turn(x), close_to(sin, 1.0e-15));
+            assert_that!(cos_turn(x), close_to(cos, 1.0e-15));
+        }
+        // The naive path loses everything at this size.
+        assert!(((2.0 * PI * (1.0e15 + 0.375)).sin() - FRAC_1_SQRT_2).abs() > 0.1);
+
+        let cases = [
+            (1.0e10 + 30.0, -0.766044443118978, 0.6427876096865394),
+            (123_456_789.123, -0.15855442773716208, -0.9873502384893321),
+            (-3_600_000_045.5, -0.7132504491541816, 0.7009092642998509),
+        ];
+        for &(degrees, sin, cos) in &cases {
+            let (s, c) = Degrees(degrees).sin_cos_turn_exactish();
+            assert_that!(s, close_to(sin, 1.0e-15));
+            assert_that!(c, close_to(cos, 1.0e-15));
+        }
+    }
+
+    #[test]
+    fn test_turn_non_finite() {
+        assert!(sin_turn(f64::NAN).is_nan());
+        assert!(cos_turn(f64::INFINITY).is_nan());
+        assert!(Degrees(f64::NEG_INFINITY)
+            .sin_cos_turn_exactish()
+            .0
+            .is_nan());
+        assert_eq!(Radians(1.0).sin_cos_turn_exactish(), Radians(1.0).sin_cos());
+    }
+
+    #[test]
+    fn prop_turn_matches_radians() {
+        fn prop(x: f64) -> bool {
+            let x = x % 1.0e6;
+            let (sin, cos) = (2.0 * PI * x).sin_cos();
+            (sin_turn(x) - sin).abs() < 1.0e-8 && (cos_turn(x) - cos).abs() < 1.0e-8
+        }
+        quickcheck(prop as fn(f64) -> bool);
+    }
+}
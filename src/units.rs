@@ -0,0 +1,672 @@
+//! Conversions from and to further units of angle.
+//!
+//! Angles are only ever stored in radians or degrees. The other units are
+//! views onto them, constructing an angle in whichever of the two converts
+//! exactly from the unit and converting the stored value on the way out.
+
+use core::f64::consts::PI;
+use core::fmt::{Display, Error, Formatter};
+use num_traits::cast::{cast, NumCast};
+
+use crate::{degrees_f64, unwrap_cast, write_with_suffix, Angle, Convertible, Degrees, Radians};
+
+/// A convention for angular mils.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MilSystem {
+    /// The NATO mil, with 6400 mils in a full circle.
+    Nato6400,
+    /// The mil of the former Warsaw Pact, with 6000 mils in a full circle.
+    Warsaw6000,
+    /// The milliradian, with 2000π mils in a full circle.
+    Milliradian,
+}
+
+impl Default for MilSystem {
+    #[inline]
+    fn default() -> Self {
+        MilSystem::Nato6400
+    }
+}
+
+impl<T: Copy + Convertible> Angle<T> {
+    /// Create an angle from a value in turns, or revolutions, with one turn
+    /// in a full circle. The angle is stored in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_turns(0.25f64).in_degrees(), 90.0);
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(Angle::from_turns(-2i32), Degrees(-720));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn from_turns(turns: T) -> Angle<T> {
+        Degrees(scale(turns, 360.0, 1.0))
+    }
+
+    /// Yield the value encoded in turns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// # use std::f64::consts::PI;
+    /// assert_eq!(Degrees(-450.0f64).in_turns(), -1.25);
+    /// assert_eq!(Radians(PI).in_turns(), 0.5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn in_turns(self) -> T {
+        match self {
+            Radians(v) => from_radians(v, 0.5),
+            Degrees(v) => scale(v, 1.0, 360.0),
+        }
+    }
+
+    /// Create an angle from a value in gradians, or gons, with 400 gon in a
+    /// full circle. The angle is stored in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_gradians(100.0f64), Degrees(90.0));
+    /// assert_eq!(Angle::from_gradians(450.0f64).normalized().in_gradians(), 50.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn from_gradians(gradians: T) -> Angle<T> {
+        Degrees(scale(gradians, 360.0, 400.0))
+    }
+
+    /// Yield the value encoded in gradians.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn in_gradians(self) -> T {
+        match self {
+            Radians(v) => from_radians(v, 200.0),
+            Degrees(v) => scale(v, 400.0, 360.0),
+        }
+    }
+
+    /// Format the angle in gradians followed by `gon`, e.g. `50gon`. The
+    /// formatting flags apply as for [`Angle`](enum.Angle.html) itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(45.0f64).display_gradians().to_string(), "50gon");
+    /// assert_eq!(format!("{:.1}", Degrees(-90.0f64).display_gradians()), "-100.0gon");
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(format!("{:>7}", Degrees(180).display_gradians()), " 200gon");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn display_gradians(self) -> Gon<T> {
+        Gon(self.in_gradians())
+    }
+
+    /// Create an angle from a value in arcminutes, with 60′ in a degree. The
+    /// angle is stored in degrees, so integer values truncate to whole
+    /// degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_arcmin(90.0f64), Degrees(1.5));
+    /// # #[cfg(not(feature = "no-panic"))]
+    /// assert_eq!(Angle::from_arcmin(90i32), Degrees(1));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn from_arcmin(arcmin: T) -> Angle<T> {
+        Degrees(scale(arcmin, 1.0, 60.0))
+    }
+
+    /// Create an angle from a value in arcseconds, with 3600″ in a degree.
+    /// The angle is stored in degrees, so integer values truncate to whole
+    /// degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_arcsec(5400.0f64), Degrees(1.5));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn from_arcsec(arcsec: T) -> Angle<T> {
+        Degrees(scale(arcsec, 1.0, 3600.0))
+    }
+
+    /// Yield the value encoded in arcminutes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn in_arcmin(self) -> T {
+        match self {
+            Radians(v) => from_radians(v, 10_800.0),
+            Degrees(v) => scale(v, 60.0, 1.0),
+        }
+    }
+
+    /// Yield the value encoded in arcseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// # use std::f64::consts::PI;
+    /// assert_eq!(Radians(PI).in_arcsec(), 648_000.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn in_arcsec(self) -> T {
+        match self {
+            Radians(v) => from_radians(v, 648_000.0),
+            Degrees(v) => scale(v, 3600.0, 1.0),
+        }
+    }
+
+    /// Create an angle from a value in NATO mils, with 6400 mils in a full
+    /// circle. The angle is stored in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_mils(1600.0f64), Degrees(90.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn from_mils(mils: T) -> Angle<T> {
+        Angle::from_mils_in(mils, MilSystem::Nato6400)
+    }
+
+    /// Create an angle from a value in the mils of `system`. The angle is
+    /// stored in radians for milliradians, and in degrees otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_mils_in(1500.0f64, MilSystem::Warsaw6000), Degrees(90.0));
+    /// assert_eq!(Angle::from_mils_in(250.0f64, MilSystem::Milliradian), Radians(0.25));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn from_mils_in(mils: T, system: MilSystem) -> Angle<T> {
+        match system {
+            MilSystem::Nato6400 => Degrees(scale(mils, 360.0, 6400.0)),
+            MilSystem::Warsaw6000 => Degrees(scale(mils, 360.0, 6000.0)),
+            MilSystem::Milliradian => Radians(scale(mils, 1.0, 1000.0)),
+        }
+    }
+
+    /// Yield the value encoded in NATO mils.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn in_mils(self) -> T {
+        self.in_mils_in(MilSystem::Nato6400)
+    }
+
+    /// Yield the value encoded in the mils of `system`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn in_mils_in(self, system: MilSystem) -> T {
+        let full = match system {
+            MilSystem::Nato6400 => 6400.0,
+            MilSystem::Warsaw6000 => 6000.0,
+            MilSystem::Milliradian => 2000.0 * PI,
+        };
+        match (self, system) {
+            (Radians(v), MilSystem::Milliradian) => scale(v, 1000.0, 1.0),
+            (Radians(v), _) => from_radians(v, full / 2.0),
+            (Degrees(v), _) => scale(v, full, 360.0),
+        }
+    }
+
+    /// Create an angle from a value in milliradians. The angle is stored in
+    /// radians.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::from_mrad(250.0f64), Radians(0.25));
+    /// assert_eq!(Radians(0.25f64).in_mrad(), 250.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn from_mrad(mrad: T) -> Angle<T> {
+        Angle::from_mils_in(mrad, MilSystem::Milliradian)
+    }
+
+    /// Yield the value encoded in milliradians. Angles in radians are scaled
+    /// directly, without a detour through degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64` or the
+    /// result cannot be represented in `T`.
+    #[inline]
+    pub fn in_mrad(self) -> T {
+        self.in_mils_in(MilSystem::Milliradian)
+    }
+
+    /// Create an angle from a value in semicircles, as stored in the FIT files
+    /// of Garmin devices, with 2³¹ semicircles in half a turn. The angle is
+    /// stored in degrees.
+    ///
+    /// Every `i32` converts into an `f64` angle which converts back exactly
+    /// with [`to_semicircles`](#method.to_semicircles).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Angle::<f64>::from_semicircles(1 << 30), Degrees(90.0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result cannot be represented in `T`.
+    #[inline]
+    pub fn from_semicircles(semicircles: i32) -> Angle<T> {
+        Angle::from_semicircles_i64(semicircles.into())
+    }
+
+    /// Create an angle from a value in semicircles as
+    /// [`from_semicircles`](#method.from_semicircles) does, without limiting
+    /// it to a single turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result cannot be represented in `T`.
+    #[inline]
+    pub fn from_semicircles_i64(semicircles: i64) -> Angle<T> {
+        let degrees = semicircles as f64 * 180.0 / SEMICIRCLE;
+        Degrees(unwrap_cast(cast(degrees)))
+    }
+}
+
+impl<T: Copy + NumCast> Angle<T> {
+    /// Yield the value encoded in semicircles, rounded to the nearest one and
+    /// wrapped into the range of an `i32`, i.e. [-180, 180)°. NaN gives zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// assert_eq!(Degrees(-90.0f64).to_semicircles(), -(1 << 30));
+    /// assert_eq!(Degrees(180.0f64).to_semicircles(), i32::MIN);
+    /// assert_eq!(Degrees(270.0f64).to_semicircles(), -(1 << 30));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64`.
+    #[inline]
+    pub fn to_semicircles(self) -> i32 {
+        let degrees = degrees_f64(self).unwrap() % 360.0;
+        // Wrapping around the turn is wrapping around the range of an `i32`.
+        round(degrees / 180.0 * SEMICIRCLE) as i32
+    }
+
+    /// Yield the value encoded in semicircles, rounded to the nearest one,
+    /// without wrapping it into a single turn. Values outside of the range
+    /// of an `i64` saturate, and NaN gives zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value cannot be converted through an `f64`.
+    #[inline]
+    pub fn to_semicircles_i64(self) -> i64 {
+        round(degrees_f64(self).unwrap() / 180.0 * SEMICIRCLE)
+    }
+}
+
+/// The number of semicircles in half a turn.
+const SEMICIRCLE: f64 = 2_147_483_648.0;
+
+/// Round `v` to the nearest integer, away from zero on ties, saturating.
+#[inline]
+pub(crate) fn round(v: f64) -> i64 {
+    // Floats this large are whole numbers already, and `as` saturates them.
+    if v.abs() >= 4_503_599_627_370_496.0 {
+        return v as i64;
+    }
+    let whole = v as i64;
+    let fract = v - whole as f64;
+    if fract >= 0.5 {
+        whole + 1
+    } else if fract <= -0.5 {
+        whole - 1
+    } else {
+        whole
+    }
+}
+
+/// Helper struct for formatting an angle in gradians.
+///
+/// This `struct` is created by the [`display_gradians`] method on [`Angle`].
+/// See its documentation for more.
+///
+/// [`display_gradians`]: enum.Angle.html#method.display_gradians
+/// [`Angle`]: enum.Angle.html
+#[derive(Copy, Clone, Debug)]
+pub struct Gon<T>(T);
+
+impl<T: Display> Display for Gon<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write_with_suffix(f, &self.0, "gon")
+    }
+}
+
+/// Convert `v` rad to the unit with `half_turn` in half a turn, through an
+/// `f64`. Dividing by π first keeps multiples of π exact.
+#[inline]
+fn from_radians<T: Convertible>(v: T, half_turn: f64) -> T {
+    unwrap_cast(cast(cast::<T, f64>(v).unwrap() / PI * half_turn))
+}
+
+/// Compute `v * mul / div` through an `f64`.
+#[inline]
+fn scale<T: Convertible>(v: T, mul: f64, div: f64) -> T {
+    unwrap_cast(cast(cast::<T, f64>(v).unwrap() * mul / div))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::PI;
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_turns() {
+        let cases = [
+            (0.0, 0.0),
+            (0.25, 90.0),
+            (-0.5, -180.0),
+            (1.0, 360.0),
+            (2.75, 990.0),
+            (-3.125, -1125.0),
+        ];
+        for &(turns, degrees) in &cases {
+            let alpha = Angle::from_turns(turns);
+            assert_eq!(alpha, Degrees(degrees));
+            assert_eq!(alpha.in_turns(), turns);
+            assert_eq!(Degrees(degrees).in_turns(), turns);
+            let radians = Radians(alpha.in_radians());
+            assert_that!(radians.in_radians(), close_to(turns * 2.0 * PI, 1.0e-12));
+            assert_that!(radians.in_turns(), close_to(turns, 1.0e-15));
+        }
+
+        assert_eq!(Angle::from_turns(1.25).normalized().in_turns(), 0.25);
+        assert_eq!(Angle::from_turns(-0.25).normalized().in_turns(), 0.75);
+        assert_eq!(
+            Angle::from_turns(0.125) + AngleDelta::new(Angle::from_turns(0.25)),
+            Angle::from_turns(0.375)
+        );
+        assert_eq!(Angle::from_turns(0.1f32).in_degrees(), 36.0);
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(Angle::from_turns(3u16), Degrees(1080));
+            assert_eq!(Degrees(900i32).in_turns(), 2);
+        }
+    }
+
+    #[test]
+    fn test_gradians() {
+        let cases = [
+            (0.0, 0.0),
+            (50.0, 45.0),
+            (100.0, 90.0),
+            (200.0, 180.0),
+            (-300.0, -270.0),
+            (400.0, 360.0),
+            (1234.5, 1111.05),
+        ];
+        for &(gradians, degrees) in &cases {
+            assert_eq!(Angle::from_gradians(gradians), Degrees(degrees));
+            assert_eq!(Degrees(degrees).in_gradians(), gradians);
+            let radians = Radians(Degrees(degrees).in_radians());
+            assert_that!(radians.in_gradians(), close_to(gradians, 1.0e-12));
+        }
+        assert_eq!(Radians(PI).in_gradians(), 200.0);
+
+        assert_eq!(Angle::from_gradians(450.0).normalized().in_gradians(), 50.0);
+        assert_eq!(
+            Angle::from_gradians(-100.0).normalized().in_gradians(),
+            300.0
+        );
+        assert_eq!(Angle::from_gradians(100.0f32).in_degrees(), 90.0);
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(Angle::from_gradians(200i32), Degrees(180));
+            assert_eq!(Degrees(-90i16).in_gradians(), -100);
+        }
+    }
+
+    #[test]
+    fn test_display_gradians() {
+        assert_eq!(Degrees(90.0).display_gradians().to_string(), "100gon");
+        assert_eq!(Radians(PI).display_gradians().to_string(), "200gon");
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(Degrees(-9i32).display_gradians().to_string(), "-10gon");
+        assert_eq!(
+            format!("{:+.2}", Degrees(1.0).display_gradians()),
+            "+1.11gon"
+        );
+        assert_eq!(
+            format!("{:<9.1}|", Degrees(45.0).display_gradians()),
+            "50.0gon  |"
+        );
+        assert_eq!(
+            format!("{:08.1}", Degrees(-45.0).display_gradians()),
+            "-50.0gon"
+        );
+        assert_eq!(
+            format!("{:09.1}", Degrees(-45.0).display_gradians()),
+            "-050.0gon"
+        );
+    }
+
+    #[test]
+    fn test_arcmin_arcsec() {
+        assert_eq!(Angle::from_arcmin(30.0), Degrees(0.5));
+        assert_eq!(Angle::from_arcmin(-150.0), Degrees(-2.5));
+        assert_eq!(Angle::from_arcsec(1800.0), Degrees(0.5));
+        assert_eq!(Degrees(2.5).in_arcmin(), 150.0);
+        assert_eq!(Degrees(-0.25).in_arcsec(), -900.0);
+        assert_eq!(Radians(PI).in_arcmin(), 10_800.0);
+        assert_eq!(Radians(PI).in_arcsec(), 648_000.0);
+        assert_eq!(Radians(-PI / 2.0).in_arcsec(), -324_000.0);
+
+        // Sub-arcsecond values survive a round trip through radians.
+        for &arcsec in &[1.0e-3, 0.25, 0.123_456_789, -7.5e-6] {
+            let alpha = Radians(Angle::from_arcsec(arcsec).in_radians());
+            let error = (alpha.in_arcsec() - arcsec).abs() / arcsec.abs();
+            assert!(error <= 2.0 * f64::EPSILON, "{} {}", arcsec, error);
+        }
+        let mas = Angle::from_arcsec(1.0e-3f32);
+        assert_that!(mas.in_arcsec() as f64, close_to(1.0e-3, 1.0e-10));
+
+        // Integer angles stored in degrees truncate to whole degrees.
+        #[cfg(not(feature = "no-panic"))]
+        {
+            assert_eq!(Angle::from_arcmin(90i32), Degrees(1));
+            assert_eq!(Angle::from_arcsec(3599i32), Degrees(0));
+            assert_eq!(Angle::from_arcsec(-7200i64), Degrees(-2));
+            assert_eq!(Degrees(3i32).in_arcsec(), 10_800);
+            assert_eq!(Radians(1i32).in_arcmin(), 3437);
+        }
+    }
+
+    #[test]
+    fn test_mils() {
+        assert_eq!(MilSystem::default(), MilSystem::Nato6400);
+        let cases = [
+            (1600.0, 1500.0, 90.0),
+            (-3200.0, -3000.0, -180.0),
+            (6400.0, 6000.0, 360.0),
+            (8000.0, 7500.0, 450.0),
+            (1.0, 0.9375, 0.05625),
+        ];
+        for &(nato, warsaw, degrees) in &cases {
+            assert_eq!(Angle::from_mils(nato), Degrees(degrees));
+            assert_eq!(
+                Angle::from_mils_in(warsaw, MilSystem::Warsaw6000),
+                Degrees(degrees)
+            );
+            assert_eq!(Degrees(degrees).in_mils(), nato);
+            assert_eq!(Degrees(degrees).in_mils_in(MilSystem::Warsaw6000), warsaw);
+            let radians = Radians(Degrees(degrees).in_radians());
+            assert_that!(radians.in_mils(), close_to(nato, 1.0e-9));
+            assert_that!(
+                radians.in_mils_in(MilSystem::Warsaw6000),
+                close_to(warsaw, 1.0e-9)
+            );
+        }
+        assert_eq!(Radians(PI).in_mils(), 3200.0);
+        assert_eq!(Angle::from_mils(8000.0).normalized().in_mils(), 1600.0);
+
+        for &v in &[0.0, 0.1, -1.5, 1234.5678] {
+            let alpha = Radians(v);
+            let mrad = alpha.in_mils_in(MilSystem::Milliradian);
+            assert_eq!(mrad, alpha.in_radians() * 1000.0);
+            assert_eq!(Angle::from_mils_in(mrad, MilSystem::Milliradian), alpha);
+            assert_that!(
+                Degrees(alpha.in_degrees()).in_mils_in(MilSystem::Milliradian),
+                close_to(mrad, 1.0e-9)
+            );
+        }
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(Angle::from_mils(3200i32), Degrees(180));
+    }
+
+    #[test]
+    fn test_semicircles() {
+        // Positions near Garmin's headquarters and the Greenwich meridian.
+        let cases = [
+            (463_565_365, 38.8556),
+            (-1_130_997_317, -94.7991),
+            (614_154_076, 51.4778),
+            (-17_896, -0.0015),
+            (1 << 30, 90.0),
+            (i32::MIN, -180.0),
+        ];
+        for &(semicircles, degrees) in &cases {
+            let alpha: Angle = Angle::from_semicircles(semicircles);
+            assert_that!(alpha.in_degrees(), close_to(degrees, 1.0e-7));
+            assert_eq!(Degrees(degrees).to_semicircles(), semicircles);
+            assert_eq!(Radians(alpha.in_radians()).to_semicircles(), semicircles);
+        }
+
+        for &semicircles in &[i32::MIN, i32::MIN + 1, -1, 0, 1, 123_456_789, i32::MAX] {
+            let alpha: Angle = Angle::from_semicircles(semicircles);
+            assert_eq!(alpha.to_semicircles(), semicircles);
+            assert_eq!(alpha.to_semicircles_i64(), semicircles as i64);
+        }
+
+        // Half a semicircle rounds away from zero.
+        let half = 90.0 / 2_147_483_648.0;
+        assert_eq!(Degrees(half).to_semicircles(), 1);
+        assert_eq!(Degrees(-half).to_semicircles(), -1);
+        assert_eq!(Degrees(half * 0.99).to_semicircles(), 0);
+
+        // Whole turns wrap around the range of an `i32`.
+        assert_eq!(Degrees(180.0).to_semicircles(), i32::MIN);
+        assert_eq!(Degrees(-180.0).to_semicircles(), i32::MIN);
+        assert_eq!(Degrees(540.0 + 45.0).to_semicircles(), -(3 << 29));
+        assert_eq!(Degrees(-720.0 - 90.0).to_semicircles(), -(1 << 30));
+        assert_eq!(Degrees(f64::NAN).to_semicircles(), 0);
+
+        assert_eq!(Degrees(540.0).to_semicircles_i64(), 3 << 31);
+        assert_eq!(Degrees(f64::INFINITY).to_semicircles_i64(), i64::MAX);
+        let alpha: Angle = Angle::from_semicircles_i64(-(5 << 30));
+        assert_eq!(alpha, Degrees(-450.0));
+        assert_eq!(Angle::<f32>::from_semicircles(1 << 29), Degrees(45.0));
+    }
+
+    #[test]
+    fn test_mrad() {
+        for &v in &[0.0, 0.1, -0.1, 1.0, 1234.5, -6283.185] {
+            let alpha = Angle::from_mrad(v);
+            assert_eq!(alpha, Radians(v / 1000.0));
+            assert_eq!(alpha.in_mrad(), v);
+            assert_that!(Degrees(alpha.in_degrees()).in_mrad(), close_to(v, 1.0e-9));
+        }
+        assert_eq!(Radians(PI).in_mrad(), PI * 1000.0);
+        assert_that!(Degrees(180.0).in_mrad(), close_to(PI * 1000.0, 1.0e-9));
+
+        // A tenth of a milliradian keeps the precision of an `f32`.
+        let alpha = Angle::from_mrad(0.1f32);
+        assert_eq!(alpha, Radians(0.1 / 1000.0));
+        assert_eq!(alpha.in_mrad(), 0.1);
+        assert!((Radians(1.0e-4f32).in_mrad() - 0.1).abs() <= f32::EPSILON * 0.1);
+        #[cfg(not(feature = "no-panic"))]
+        assert_eq!(Angle::from_mrad(3000i32), Radians(3));
+    }
+}
@@ -0,0 +1,108 @@
+//! Interoperability with the two-dimensional vectors of the `glam` crate.
+
+use glam::{Mat2, Vec2};
+
+use crate::{atan2, Angle};
+
+impl Angle<f32> {
+    /// Compute the direction of a vector, measured counterclockwise from the
+    /// positive x-axis. Return value is in the range of [-π, π] rad or `None`
+    /// for the zero vector, whose direction is undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// use glam::Vec2;
+    ///
+    /// assert_eq!(Angle::from_vec2(Vec2::Y), Some(Radians(core::f32::consts::FRAC_PI_2)));
+    /// assert_eq!(Angle::from_vec2(Vec2::ZERO), None);
+    /// ```
+    #[inline]
+    pub fn from_vec2(v: Vec2) -> Option<Angle<f32>> {
+        if v == Vec2::ZERO {
+            None
+        } else {
+            Some(atan2(v.y, v.x))
+        }
+    }
+
+    /// Compute the unit vector pointing in the direction of the angle,
+    /// measured counterclockwise from the positive x-axis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ang::*;
+    /// let v = Degrees(90.0f32).to_vec2();
+    /// assert!(v.abs_diff_eq(glam::Vec2::Y, 1.0e-6));
+    /// ```
+    #[inline]
+    pub fn to_vec2(self) -> Vec2 {
+        let (sin, cos) = self.sin_cos();
+        Vec2::new(cos, sin)
+    }
+
+    /// Rotate a vector counterclockwise by the angle.
+    #[inline]
+    pub fn rotate_vec2(self, v: Vec2) -> Vec2 {
+        let (sin, cos) = self.sin_cos();
+        Vec2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+    }
+}
+
+/// Build the matrix rotating vectors counterclockwise by the angle.
+impl From<Angle<f32>> for Mat2 {
+    #[inline]
+    fn from(angle: Angle<f32>) -> Mat2 {
+        let (sin, cos) = angle.sin_cos();
+        Mat2::from_cols(Vec2::new(cos, sin), Vec2::new(-sin, cos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{Mat2, Vec2};
+    use hamcrest2::{assert_that, close_to, prelude::*};
+
+    use crate::*;
+
+    #[test]
+    fn test_vec2_cardinal_directions() {
+        let cases = [
+            (Vec2::X, 0.0f32),
+            (Vec2::Y, 90.0),
+            (Vec2::NEG_X, 180.0),
+            (Vec2::NEG_Y, -90.0),
+        ];
+        for &(v, degrees) in &cases {
+            let angle = Angle::from_vec2(v * 3.0).unwrap();
+            assert_that!(angle.in_degrees(), close_to(degrees, 1.0e-4));
+            assert!(Degrees(degrees).to_vec2().abs_diff_eq(v, 1.0e-6));
+        }
+    }
+
+    #[test]
+    fn test_vec2_zero() {
+        assert_eq!(Angle::from_vec2(Vec2::ZERO), None);
+        assert_eq!(Angle::from_vec2(Vec2::new(-0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_vec2_rotation() {
+        let v = Vec2::new(2.0, -0.5);
+        for &degrees in &[0.0f32, 30.0, 90.0, -135.0, 400.0] {
+            let angle = Degrees(degrees);
+            let rotated = angle.rotate_vec2(v);
+            assert!((Mat2::from(angle) * v).abs_diff_eq(rotated, 1.0e-5));
+            assert_that!(rotated.length(), close_to(v.length(), 1.0e-5));
+            let delta = Angle::from_vec2(v)
+                .unwrap()
+                .delta_to(Angle::from_vec2(rotated).unwrap());
+            assert_that!(
+                delta.angle().normalized().in_degrees(),
+                close_to(Degrees(degrees).normalized().in_degrees(), 1.0e-3)
+            );
+        }
+    }
+}
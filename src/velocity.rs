@@ -0,0 +1,116 @@
+//! Angular velocities.
+
+use core::ops::Mul;
+use core::time::Duration;
+use num_traits::cast::{cast, NumCast};
+
+use crate::{Angle, Convertible, Degrees, Radians};
+
+/// An angular velocity, the angle turned per second.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ang::*;
+/// # use std::time::Duration;
+/// let spin = AngularVelocity::degrees_per_second(90.0f64);
+/// assert_eq!(spin.over(Duration::from_millis(500)), Degrees(45.0));
+/// ```
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AngularVelocity<T = f64> {
+    per_second: Angle<T>,
+}
+
+impl<T> AngularVelocity<T> {
+    /// Create an angular velocity turning the given angle every second.
+    #[inline]
+    pub fn per_second(angle: Angle<T>) -> AngularVelocity<T> {
+        AngularVelocity { per_second: angle }
+    }
+
+    /// Create an angular velocity from a value in radians per second.
+    #[inline]
+    pub fn radians_per_second(value: T) -> AngularVelocity<T> {
+        AngularVelocity::per_second(Radians(value))
+    }
+
+    /// Create an angular velocity from a value in degrees per second.
+    #[inline]
+    pub fn degrees_per_second(value: T) -> AngularVelocity<T> {
+        AngularVelocity::per_second(Degrees(value))
+    }
+
+    /// Yield the angle turned every second.
+    #[inline]
+    pub fn angle_per_second(self) -> Angle<T> {
+        self.per_second
+    }
+}
+
+impl<T: Copy + NumCast> AngularVelocity<T> {
+    /// Yield the value in radians per second.
+    #[inline]
+    pub fn in_radians_per_second(self) -> T {
+        self.per_second.in_radians()
+    }
+
+    /// Yield the value in degrees per second.
+    #[inline]
+    pub fn in_degrees_per_second(self) -> T
+    where
+        T: Convertible,
+    {
+        self.per_second.in_degrees()
+    }
+}
+
+impl<T: Copy + NumCast + Mul<Output = T>> AngularVelocity<T> {
+    /// Compute the angle turned over the duration `dt`, in the unit the
+    /// velocity is stored in.
+    #[inline]
+    pub fn over(self, dt: Duration) -> Angle<T> {
+        self.per_second * cast::<f64, T>(dt.as_secs_f64()).unwrap()
+    }
+}
+
+impl<T: Copy + NumCast + PartialEq> PartialEq for AngularVelocity<T> {
+    #[inline]
+    fn eq(&self, other: &AngularVelocity<T>) -> bool {
+        self.per_second == other.per_second
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use crate::*;
+
+    #[test]
+    fn test_angular_velocity() {
+        let spin = AngularVelocity::radians_per_second(2.0);
+        assert_eq!(spin.angle_per_second(), Radians(2.0));
+        assert_eq!(spin.in_radians_per_second(), 2.0);
+        assert_eq!(spin.over(Duration::from_millis(250)), Radians(0.5));
+        assert_eq!(spin.over(Duration::from_secs(0)), Radians(0.0));
+
+        let spin = AngularVelocity::per_second(Degrees(-90.0f32));
+        assert_eq!(spin.over(Duration::from_secs(2)), Degrees(-180.0));
+        assert_eq!(spin, AngularVelocity::degrees_per_second(-90.0));
+        assert_eq!(spin.in_degrees_per_second(), -90.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_angular_velocity_serde() {
+        let spin = AngularVelocity::degrees_per_second(-90.0);
+        let json = serde_json::to_string(&spin).unwrap();
+        assert_eq!(json, r#"{"per_second":{"Degrees":-90.0}}"#);
+        assert_eq!(
+            serde_json::from_str::<AngularVelocity>(&json).unwrap(),
+            spin
+        );
+        assert!(serde_json::from_str::<AngularVelocity>(r#"{"per_second":1.0}"#).is_err());
+    }
+}
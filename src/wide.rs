@@ -0,0 +1,239 @@
+//! Checked operations on angles of integers wider than the mantissa of an
+//! `f64`.
+//!
+//! The conversions between units go through `f64`, which silently drops the
+//! low bits of integers above 2⁵³. The methods here operate on integers
+//! directly when both operands are in the same unit, and otherwise refuse to
+//! convert values which do not survive the trip through `f64`.
+
+use core::cmp::Ordering;
+use core::f64::consts::PI;
+
+use crate::{Angle, AngleDelta, Degrees, Radians};
+
+/// The largest magnitude below which every integer is exact in an `f64`.
+const MAX_EXACT: f64 = (1u64 << 53) as f64;
+
+macro_rules! wide_integer(
+    ($($t:ident),*) => ($(
+        impl Angle<$t> {
+            /// Yield the value encoded in radians, or `None` if the value or
+            /// the result of the conversion is not exactly representable in
+            /// an `f64`. Otherwise the result is the same as
+            /// [`in_radians`](#method.in_radians).
+            pub fn try_in_radians(self) -> Option<$t> {
+                match self {
+                    Radians(v) => Some(v),
+                    Degrees(v) => to_integer(v.to_exact()? / 180.0 * PI),
+                }
+            }
+
+            /// Yield the value encoded in degrees, or `None` if the value or
+            /// the result of the conversion is not exactly representable in
+            /// an `f64`. Otherwise the result is the same as
+            /// [`in_degrees`](#method.in_degrees).
+            pub fn try_in_degrees(self) -> Option<$t> {
+                match self {
+                    Radians(v) => to_integer(v.to_exact()? / PI * 180.0),
+                    Degrees(v) => Some(v),
+                }
+            }
+
+            /// Compare two angles.
+            ///
+            /// Angles in the same unit are compared exactly. Angles in
+            /// different units are compared in degrees through an `f64`,
+            /// returning `None` if either value is not exactly representable
+            /// in an `f64`.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use ang::*;
+            /// # use std::cmp::Ordering;
+            /// let big = 1i128 << 63;
+            /// assert_eq!(Degrees(big).try_cmp(&Degrees(big + 1)), Some(Ordering::Less));
+            /// assert_eq!(Radians(1i128).try_cmp(&Degrees(57)), Some(Ordering::Greater));
+            /// assert_eq!(Radians(1i128).try_cmp(&Degrees(big)), None);
+            /// ```
+            pub fn try_cmp(&self, other: &Angle<$t>) -> Option<Ordering> {
+                match (*self, *other) {
+                    (Radians(a), Radians(b)) | (Degrees(a), Degrees(b)) => Some(a.cmp(&b)),
+                    (Radians(a), Degrees(b)) => {
+                        (a.to_exact()? / PI * 180.0).partial_cmp(&b.to_exact()?)
+                    }
+                    (Degrees(a), Radians(b)) => {
+                        a.to_exact()?.partial_cmp(&(b.to_exact()? / PI * 180.0))
+                    }
+                }
+            }
+
+            /// Compute the minimal unsigned distance between two angles as a
+            /// delta in the unit of `self`.
+            ///
+            /// The distance between two angles in degrees is computed exactly.
+            /// Otherwise it is computed through an `f64`, returning `None` if
+            /// either value is not exactly representable in an `f64`.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            /// # use ang::*;
+            /// let big = (1i128 << 63) * 360 + 355;
+            /// let distance = Degrees(big).try_min_dist(Degrees(5));
+            /// assert_eq!(distance, Some(AngleDelta::new(Degrees(10))));
+            /// assert_eq!(Degrees(big).try_min_dist(Radians(0)), None);
+            /// ```
+            pub fn try_min_dist(self, other: Angle<$t>) -> Option<AngleDelta<$t>> {
+                if let (Some(Degrees(a)), Some(Degrees(b))) =
+                    (self.checked_normalized(), other.checked_normalized())
+                {
+                    let d = if a > b { a - b } else { b - a };
+                    return Some(AngleDelta::new(Degrees(if d > 180 { 360 - d } else { d })));
+                }
+
+                let a = self.value().to_exact()?;
+                let b = other.value().to_exact()?;
+                let (a, b) = match (self, other) {
+                    (Degrees(_), Radians(_)) => (a, b / PI * 180.0),
+                    (Radians(_), Degrees(_)) => (a, b / 180.0 * PI),
+                    _ => (a, b),
+                };
+                let full = self.unit().full_turn_in::<f64>();
+                let d = (if a > b { a - b } else { b - a }) % full;
+                let d = if d > full / 2.0 { full - d } else { d };
+                Some(AngleDelta::new(Angle::from_parts(d as $t, self.unit())))
+            }
+        }
+
+        impl ToExact for $t {
+            #[inline]
+            fn to_exact(self) -> Option<f64> {
+                let v = self as f64;
+                if (-MAX_EXACT..=MAX_EXACT).contains(&v) && v as $t == self {
+                    Some(v)
+                } else {
+                    None
+                }
+            }
+        }
+    )*);
+);
+
+wide_integer!(i64, u64, i128, u128);
+
+/// Conversion of an integer into an `f64`, if it is exactly representable.
+trait ToExact {
+    fn to_exact(self) -> Option<f64>;
+}
+
+/// Truncate a converted value into an integer, like `NumCast`, provided it
+/// is small enough to have been computed exactly.
+#[inline]
+fn to_integer<T: num_traits::NumCast>(v: f64) -> Option<T> {
+    if (-MAX_EXACT..=MAX_EXACT).contains(&v) {
+        num_traits::cast(v)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use crate::*;
+
+    /// Values far beyond the 53 bits of precision of an `f64`.
+    const MICRO: i128 = 1 << 63;
+    const BIG: i128 = MICRO * 1_000_000;
+
+    #[test]
+    #[cfg(not(feature = "no-panic"))]
+    fn test_wide_normalized() {
+        assert_eq!(Degrees(BIG).normalized(), Degrees(BIG % 360));
+        assert_eq!(Degrees(BIG + 1).normalized(), Degrees(BIG % 360 + 1));
+        assert_eq!(Degrees(-BIG).normalized(), Degrees(360 - BIG % 360));
+        assert_eq!(Degrees(u128::MAX).normalized(), Degrees(u128::MAX % 360));
+        assert_eq!(
+            Degrees(i64::MIN).normalized(),
+            Degrees(i64::MIN % 360 + 360)
+        );
+    }
+
+    #[test]
+    fn test_wide_checked_normalized() {
+        assert_eq!(Degrees(BIG).checked_normalized(), Some(Degrees(BIG % 360)));
+        assert_eq!(
+            Degrees(-BIG).checked_normalized(),
+            Some(Degrees(360 - BIG % 360))
+        );
+        assert_eq!(
+            Degrees(u128::MAX).checked_normalized(),
+            Some(Degrees(u128::MAX % 360))
+        );
+        assert_eq!(
+            Degrees(i64::MIN).checked_normalized_signed(),
+            Some(Degrees(-8))
+        );
+    }
+
+    #[test]
+    fn test_wide_comparison() {
+        assert_ne!(Degrees(MICRO), Degrees(MICRO + 1));
+        assert!(Degrees(MICRO) < Degrees(MICRO + 1));
+        assert_eq!(
+            Degrees(BIG).try_cmp(&Degrees(BIG - 1)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(Radians(BIG).try_cmp(&Radians(BIG)), Some(Ordering::Equal));
+
+        assert_eq!(
+            Radians(2i64).try_cmp(&Degrees(114)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(Radians(2i64).try_cmp(&Degrees(115)), Some(Ordering::Less));
+        assert_eq!(Degrees(MICRO).try_cmp(&Radians(0)), None);
+        assert_eq!(Radians(0u64).try_cmp(&Degrees(u64::MAX)), None);
+    }
+
+    #[test]
+    fn test_wide_min_dist() {
+        assert_eq!(
+            Degrees(BIG + 1).try_min_dist(Degrees(BIG - 1)),
+            Some(AngleDelta::new(Degrees(2)))
+        );
+        assert_eq!(
+            Degrees(BIG).try_min_dist(Degrees(BIG)),
+            Some(AngleDelta::new(Degrees(0)))
+        );
+        assert_eq!(
+            Degrees(MICRO * 360 + 180).try_min_dist(Degrees(-MICRO * 360)),
+            Some(AngleDelta::new(Degrees(180)))
+        );
+        assert_eq!(
+            Degrees(350i64).try_min_dist(Radians(0)),
+            Some(AngleDelta::new(Degrees(10)))
+        );
+        assert_eq!(Radians(BIG).try_min_dist(Radians(BIG)), None);
+        assert_eq!(Degrees(BIG).try_min_dist(Radians(0)), None);
+    }
+
+    #[test]
+    fn test_wide_conversions() {
+        assert_eq!(Degrees(180i128).try_in_radians(), Some(3));
+        assert_eq!(Radians(3i128).try_in_degrees(), Some(171));
+        assert_eq!(Degrees(BIG).try_in_degrees(), Some(BIG));
+        assert_eq!(Radians(BIG).try_in_radians(), Some(BIG));
+
+        let exact = 1i128 << 53;
+        assert_eq!(
+            Degrees(exact).try_in_radians(),
+            Some(Degrees(exact).in_radians())
+        );
+        assert_eq!(Degrees(exact + 1).try_in_radians(), None);
+        assert_eq!(Degrees(BIG).try_in_radians(), None);
+        assert_eq!(Radians(exact).try_in_degrees(), None);
+        assert_eq!(Radians(u64::MAX).try_in_degrees(), None);
+    }
+}
@@ -0,0 +1,44 @@
+//! Calls the float APIs from a `no_std` crate, to catch any of them coming
+//! to depend on `std` again. Run without `std` with
+//! `cargo test --no-default-features --features libm --test no_std`.
+
+#![no_std]
+#![cfg(any(feature = "std", feature = "libm"))]
+
+use ang::{acos, asin, atan, atan2, mean_angle, Degrees, Radians};
+
+fn near(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1.0e-10
+}
+
+#[test]
+fn test_mean_angle() {
+    let angles = [Degrees(340.0f64), Degrees(10.0), Degrees(40.0)];
+    let mu = mean_angle(&angles).unwrap();
+    assert!(mu.min_dist(Degrees(10.0)).in_degrees() < 1.0e-10);
+
+    assert_eq!(mean_angle(&[Degrees(0.0f64), Degrees(180.0)]), None);
+    assert_eq!(mean_angle::<f32, _>(&[]), None);
+}
+
+#[test]
+fn test_min_dist() {
+    assert!(near(
+        Degrees(345.0f64).min_dist(Degrees(15.0)).in_degrees(),
+        30.0
+    ));
+    assert!(near(
+        Radians(0.5f64).min_dist(Degrees(270.0)).in_radians(),
+        0.5 + core::f64::consts::FRAC_PI_2
+    ));
+}
+
+#[test]
+fn test_inverse_trig() {
+    assert!(near(asin(0.5f64).unwrap().in_degrees(), 30.0));
+    assert!(near(acos(0.5f64).unwrap().in_degrees(), 60.0));
+    assert_eq!(asin(2.0f64), None);
+    assert_eq!(acos(-2.0f32), None);
+    assert!(near(atan(1.0f64).in_degrees(), 45.0));
+    assert!(near(atan2(-1.0f64, -1.0).in_degrees(), -135.0));
+}
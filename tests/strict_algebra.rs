@@ -0,0 +1,13 @@
+//! Check that the `strict-algebra` feature rejects combining two absolute
+//! angles, and still accepts rotating them by deltas.
+#![cfg(feature = "strict-algebra")]
+
+#[test]
+fn strict_algebra() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/delta-algebra.rs");
+    cases.compile_fail("tests/ui/add-absolute.rs");
+    cases.compile_fail("tests/ui/add-assign-absolute.rs");
+    cases.compile_fail("tests/ui/sum-absolute.rs");
+    cases.compile_fail("tests/ui/sub-absolute-is-delta.rs");
+}
@@ -0,0 +1,5 @@
+use ang::Degrees;
+
+fn main() {
+    let _ = Degrees(10.0) + Degrees(20.0);
+}
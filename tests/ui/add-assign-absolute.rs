@@ -0,0 +1,6 @@
+use ang::Degrees;
+
+fn main() {
+    let mut heading = Degrees(10.0);
+    heading += Degrees(20.0);
+}
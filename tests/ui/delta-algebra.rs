@@ -0,0 +1,22 @@
+use ang::{Angle, AngleDelta, Degrees};
+
+fn main() {
+    let heading = Degrees(350.0);
+    let turn: AngleDelta = Degrees(20.0) - heading;
+    assert_eq!(turn, AngleDelta::new(Degrees(-330.0)));
+
+    let turn = turn + AngleDelta::new(Degrees(360.0));
+    assert_eq!(heading + turn, Degrees(380.0));
+    assert_eq!((heading + turn * 2.0).normalized(), Degrees(50.0));
+    assert_eq!(heading - turn / 2.0, Degrees(335.0));
+    assert_eq!(-turn - turn, AngleDelta::new(Degrees(-60.0)));
+
+    let mut heading = heading;
+    heading += turn;
+    heading -= AngleDelta::new(Degrees(10.0));
+    assert_eq!(heading, Degrees(370.0));
+
+    let angle: Angle = turn.into();
+    assert_eq!(AngleDelta::from(angle), turn);
+    assert_eq!(heading * 2.0, Degrees(740.0));
+}
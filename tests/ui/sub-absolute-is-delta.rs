@@ -0,0 +1,5 @@
+use ang::{Angle, Degrees};
+
+fn main() {
+    let _: Angle = Degrees(30.0) - Degrees(20.0);
+}
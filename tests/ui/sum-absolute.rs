@@ -0,0 +1,5 @@
+use ang::{Angle, Degrees};
+
+fn main() {
+    let _: Angle = [Degrees(10.0), Degrees(20.0)].iter().sum();
+}